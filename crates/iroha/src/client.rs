@@ -6,7 +6,7 @@ use std::{
     fmt::Debug,
     num::{NonZeroU32, NonZeroU64},
     thread,
-    time::Duration,
+    time::{Duration, SystemTime},
 };
 
 use derive_more::{DebugCustom, Display};
@@ -16,7 +16,7 @@ use http_default::{AsyncWebSocketStream, WebSocketStream};
 pub use iroha_config::client_api::ConfigDTO;
 use iroha_logger::prelude::*;
 use iroha_telemetry::metrics::Status;
-use iroha_torii_const::uri as torii_uri;
+use iroha_torii_const::{admin as torii_admin, uri as torii_uri};
 use iroha_version::prelude::*;
 use parity_scale_codec::DecodeAll;
 use rand::Rng;
@@ -25,13 +25,13 @@ use url::Url;
 use self::{blocks_api::AsyncBlockStream, events_api::AsyncEventStream};
 pub use crate::query::QueryError;
 use crate::{
-    config::Config,
-    crypto::{HashOf, KeyPair},
+    config::{Config, Tls},
+    crypto::{HashOf, KeyPair, Signature},
     data_model::{
-        block::SignedBlock,
+        block::{BlockHeader, SignedBlock, TransactionInclusionProof},
         events::pipeline::{
             BlockEventFilter, BlockStatus, PipelineEventBox, PipelineEventFilterBox,
-            TransactionEventFilter, TransactionStatus,
+            TransactionEvent, TransactionEventFilter, TransactionStatus,
         },
         isi::Instruction,
         prelude::*,
@@ -134,6 +134,8 @@ pub struct Client {
     pub account: AccountId,
     /// Http headers which will be appended to each request
     pub headers: HashMap<String, String>,
+    /// Custom TLS settings of the connections to the peer
+    pub tls: Option<Tls>,
     /// If `true` add nonce, which makes different hashes for
     /// transactions which occur repeatedly and/or simultaneously
     pub add_transaction_nonce: bool,
@@ -158,6 +160,7 @@ impl Client {
             torii_api_url,
             key_pair,
             basic_auth,
+            tls,
             transaction_add_nonce,
             transaction_ttl,
             transaction_status_timeout,
@@ -183,6 +186,7 @@ impl Client {
             transaction_status_timeout,
             account,
             headers,
+            tls,
             add_transaction_nonce: transaction_add_nonce,
         }
     }
@@ -285,6 +289,7 @@ impl Client {
         iroha_logger::trace!(tx=?transaction, "Submitting");
         let (req, hash) = self.prepare_transaction_request::<DefaultRequestBuilder>(transaction);
         let response = req
+            .tls(self.tls.as_ref())
             .build()?
             .send()
             .wrap_err_with(|| format!("Failed to send transaction with hash {hash:?}"))?;
@@ -292,6 +297,54 @@ impl Client {
         Ok(hash)
     }
 
+    /// Execute several Iroha Special Instructions on the peer without committing them.
+    /// Returns the outcome the transaction would have if it was submitted.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub fn simulate_all<I: Instruction>(
+        &self,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> Result<TransactionSimulation> {
+        self.simulate_transaction(&self.build_transaction(instructions, Metadata::default()))
+    }
+
+    /// Execute the prebuilt transaction on the peer without committing it.
+    /// The transaction isn't put into the queue.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails, if the peer doesn't accept it or if decoding fails
+    pub fn simulate_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<TransactionSimulation> {
+        iroha_logger::trace!(tx=?transaction, "Simulating");
+        let url = join_torii_url(&self.torii_url, torii_uri::TRANSACTION_SIMULATION);
+        let resp = DefaultRequestBuilder::new(HttpMethod::POST, url)
+            .headers(&self.headers)
+            .body(transaction.encode_versioned())
+            .tls(self.tls.as_ref())
+            .build()?
+            .send()
+            .wrap_err_with(|| {
+                format!(
+                    "Failed to send transaction with hash {:?} for simulation",
+                    transaction.hash()
+                )
+            })?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(ResponseReport::with_msg(
+                "Unexpected transaction simulation response",
+                &resp,
+            )
+            .unwrap_or_else(core::convert::identity)
+            .into());
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice())
+            .wrap_err("Failed to decode transaction simulation")
+    }
+
     /// Submit the prebuilt transaction and wait until it is either rejected or committed.
     /// If rejected, return the rejection reason.
     ///
@@ -392,6 +445,12 @@ impl Client {
                                 return Err((Clone::clone(&**reason)).into());
                             }
                             TransactionStatus::Expired => return Err(eyre!("Transaction expired")),
+                            TransactionStatus::Evicted => {
+                                return Err(eyre!("Transaction evicted from the queue"))
+                            }
+                            TransactionStatus::Evicted => {
+                                return Err(eyre!("Transaction evicted from the queue"))
+                            }
                         }
                     }
                     PipelineEventBox::Block(block_event) => {
@@ -496,7 +555,7 @@ impl Client {
         &self,
         event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
     ) -> Result<impl Iterator<Item = Result<EventBox>>> {
-        events_api::EventIterator::new(self.events_handler(event_filters)?)
+        events_api::EventIterator::new(self.events_handler(event_filters)?, self.tls.as_ref())
     }
 
     /// Connect asynchronously (through `WebSocket`) to listen for `Iroha` `pipeline` and `data` events.
@@ -508,7 +567,8 @@ impl Client {
         &self,
         event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>> + Send,
     ) -> Result<AsyncEventStream> {
-        events_api::AsyncEventStream::new(self.events_handler(event_filters)?).await
+        events_api::AsyncEventStream::new(self.events_handler(event_filters)?, self.tls.as_ref())
+            .await
     }
 
     /// Constructs an Events API handler. With it, you can use any WS client you want.
@@ -536,7 +596,7 @@ impl Client {
         &self,
         height: NonZeroU64,
     ) -> Result<impl Iterator<Item = Result<SignedBlock>>> {
-        blocks_api::BlockIterator::new(self.blocks_handler(height)?)
+        blocks_api::BlockIterator::new(self.blocks_handler(height)?, self.tls.as_ref())
     }
 
     /// Connect asynchronously (through `WebSocket`) to listen for `Iroha` blocks
@@ -545,7 +605,7 @@ impl Client {
     /// - Forwards from [`Self::events_handler`]
     /// - Forwards from [`blocks_api::BlockIterator::new`]
     pub async fn listen_for_blocks_async(&self, height: NonZeroU64) -> Result<AsyncBlockStream> {
-        blocks_api::AsyncBlockStream::new(self.blocks_handler(height)?).await
+        blocks_api::AsyncBlockStream::new(self.blocks_handler(height)?, self.tls.as_ref()).await
     }
 
     /// Construct a handler for Blocks API. With this handler you can use any WS client you want.
@@ -561,19 +621,51 @@ impl Client {
         )
     }
 
+    /// Sign a request to an administrative endpoint of the peer with the key pair of the client.
+    ///
+    /// The peer only accepts such requests if the public key of the client is listed in its `torii.admin_public_keys`.
+    fn sign_admin_request<B: RequestBuilder>(
+        &self,
+        builder: B,
+        method: &HttpMethod,
+        url: &Url,
+        body: &[u8],
+    ) -> B {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Failed to get the current system time")
+            .as_millis()
+            .try_into()
+            .expect("Unix timestamp exceeds u64::MAX");
+        let path_and_query = &url[url::Position::BeforePath..url::Position::AfterQuery];
+        let signature = Signature::new(
+            self.key_pair.private_key(),
+            &torii_admin::signature_payload(method.as_str(), path_and_query, timestamp_ms, body),
+        );
+
+        builder
+            .header(torii_admin::PUBLIC_KEY_HEADER, self.key_pair.public_key())
+            .header(torii_admin::TIMESTAMP_HEADER, &timestamp_ms)
+            .header(
+                torii_admin::SIGNATURE_HEADER,
+                &hex::encode(signature.payload()),
+            )
+    }
+
     /// Get value of config on peer
     ///
     /// # Errors
     /// Fails if sending request or decoding fails
     pub fn get_config(&self) -> Result<ConfigDTO> {
-        let resp = DefaultRequestBuilder::new(
-            HttpMethod::GET,
-            join_torii_url(&self.torii_url, torii_uri::CONFIGURATION),
-        )
-        .headers(&self.headers)
-        .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
-        .build()?
-        .send()?;
+        let url = join_torii_url(&self.torii_url, torii_uri::CONFIGURATION);
+        let builder = DefaultRequestBuilder::new(HttpMethod::GET, url.clone())
+            .headers(&self.headers)
+            .header(http::header::CONTENT_TYPE, APPLICATION_JSON);
+        let resp = self
+            .sign_admin_request(builder, &HttpMethod::GET, &url, &[])
+            .tls(self.tls.as_ref())
+            .build()?
+            .send()?;
 
         if resp.status() != StatusCode::OK {
             return Err(eyre!(
@@ -592,10 +684,13 @@ impl Client {
     pub fn set_config(&self, dto: &ConfigDTO) -> Result<()> {
         let body = serde_json::to_vec(&dto).wrap_err(format!("Failed to serialize {dto:?}"))?;
         let url = join_torii_url(&self.torii_url, torii_uri::CONFIGURATION);
-        let resp = DefaultRequestBuilder::new(HttpMethod::POST, url)
+        let builder = DefaultRequestBuilder::new(HttpMethod::POST, url.clone())
             .headers(&self.headers)
-            .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
+            .header(http::header::CONTENT_TYPE, APPLICATION_JSON);
+        let resp = self
+            .sign_admin_request(builder, &HttpMethod::POST, &url, &body)
             .body(body)
+            .tls(self.tls.as_ref())
             .build()?
             .send()?;
 
@@ -617,12 +712,122 @@ impl Client {
     pub fn get_status(&self) -> Result<Status> {
         let req = self
             .prepare_status_request::<DefaultRequestBuilder>()
-            .header(http::header::ACCEPT, "application/x-parity-scale");
+            .header(http::header::ACCEPT, "application/x-parity-scale")
+            .tls(self.tls.as_ref());
         let resp = req.build()?.send()?;
         let scaled_resp = StatusResponseHandler::handle(&resp).cloned()?;
         DecodeAll::decode_all(&mut scaled_resp.as_slice()).map_err(|err| eyre!("{err}"))
     }
 
+    /// Get the proof that the transaction with the given hash is included in a committed block.
+    ///
+    /// The proof should be checked with [`verify_transaction_inclusion`] against the hash of a block known to be committed.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails, or if the transaction is not committed
+    pub fn get_transaction_proof(
+        &self,
+        hash: &HashOf<SignedTransaction>,
+    ) -> Result<TransactionInclusionProof> {
+        let url = join_torii_url(
+            &self.torii_url,
+            &format!("{}/{hash}", torii_uri::TRANSACTION_PROOF),
+        );
+        let resp = DefaultRequestBuilder::new(HttpMethod::GET, url)
+            .headers(&self.headers)
+            .tls(self.tls.as_ref())
+            .build()?
+            .send()?;
+
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Err(eyre!("Transaction {hash} is not committed"));
+        }
+        if resp.status() != StatusCode::OK {
+            return Err(
+                ResponseReport::with_msg("Unexpected transaction proof response", &resp)
+                    .unwrap_or_else(core::convert::identity)
+                    .into(),
+            );
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice())
+            .wrap_err("Failed to decode transaction proof")
+    }
+
+    /// Get the status of the transaction with the given `hash` from the peer.
+    ///
+    /// Returns the status of a committed transaction along with the height of its block.
+    /// Returns `None` if the transaction is unknown to the peer.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_transaction_status(
+        &self,
+        hash: &HashOf<SignedTransaction>,
+    ) -> Result<Option<TransactionEvent>> {
+        let url = join_torii_url(
+            &self.torii_url,
+            &format!("{}/{hash}", torii_uri::TRANSACTION_STATUS),
+        );
+        let resp = DefaultRequestBuilder::new(HttpMethod::GET, url)
+            .headers(&self.headers)
+            .tls(self.tls.as_ref())
+            .build()?
+            .send()?;
+
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if resp.status() != StatusCode::OK {
+            return Err(
+                ResponseReport::with_msg("Unexpected transaction status response", &resp)
+                    .unwrap_or_else(core::convert::identity)
+                    .into(),
+            );
+        }
+        DecodeAll::decode_all(&mut resp.body().as_slice())
+            .map(Some)
+            .wrap_err("Failed to decode transaction status")
+    }
+
+    /// Poll the status of the transaction with the given `hash` until it is either rejected or committed.
+    /// If rejected, return the rejection reason.
+    ///
+    /// Unlike [`Self::submit_transaction_blocking`], doesn't keep a connection open in the meantime.
+    ///
+    /// # Errors
+    /// Fails if the transaction is rejected or expires, if requesting the status fails
+    /// or if the transaction isn't committed within `transaction_status_timeout`
+    pub fn poll_transaction_status(
+        &self,
+        hash: &HashOf<SignedTransaction>,
+    ) -> Result<HashOf<SignedTransaction>> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+        let deadline = std::time::Instant::now() + self.transaction_status_timeout;
+        loop {
+            if let Some(event) = self.get_transaction_status(hash)? {
+                match event.status() {
+                    TransactionStatus::Queued => {}
+                    TransactionStatus::Approved => return Ok(*hash),
+                    TransactionStatus::Rejected(reason) => {
+                        return Err((Clone::clone(&**reason)).into())
+                    }
+                    TransactionStatus::Expired => return Err(eyre!("Transaction expired")),
+                    TransactionStatus::Evicted => {
+                        return Err(eyre!("Transaction evicted from the queue"))
+                    }
+                }
+            }
+            if std::time::Instant::now() >= deadline {
+                return Err(eyre!(
+                    "haven't got tx confirmation within {:?} (configured with `transaction_status_timeout`)",
+                    self.transaction_status_timeout
+                ));
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
     /// Prepares http-request to implement [`Self::get_status`] on your own.
     ///
     /// For general usage example see [`Client::prepare_query_request`].
@@ -638,6 +843,31 @@ impl Client {
     }
 }
 
+/// Verify the proof obtained with [`Client::get_transaction_proof`] without trusting the peer which produced it.
+///
+/// # Errors
+/// Fails if the proof was made for another block than the one with `block_hash`
+/// or if it doesn't prove the inclusion of the transaction into the block
+pub fn verify_transaction_inclusion(
+    proof: &TransactionInclusionProof,
+    transaction_hash: &HashOf<SignedTransaction>,
+    block_hash: HashOf<BlockHeader>,
+) -> Result<()> {
+    let proof_block_hash = proof.block_header.hash();
+    if proof_block_hash != block_hash {
+        return Err(eyre!(
+            "Proof is made for block {proof_block_hash}, expected block {block_hash}"
+        ));
+    }
+    if !proof.verify(transaction_hash) {
+        return Err(eyre!(
+            "Transaction {transaction_hash} is not included in block {block_hash}"
+        ));
+    }
+
+    Ok(())
+}
+
 pub(crate) fn join_torii_url(url: &Url, path: &str) -> Url {
     // This is needed to prevent "https://iroha-peer.jp/peer1/".join("/query") == "https://iroha-peer.jp/query"
     let path = path.strip_prefix('/').unwrap_or(path);
@@ -679,6 +909,7 @@ pub mod stream_api {
         /// - Message is an error
         pub fn new<I: Init<DefaultWebSocketRequestBuilder>>(
             handler: I,
+            tls: Option<&Tls>,
         ) -> Result<SyncIterator<I::Next>> {
             trace!("Creating `SyncIterator`");
             let InitData {
@@ -687,7 +918,7 @@ pub mod stream_api {
                 next: next_handler,
             } = Init::<http_default::DefaultWebSocketRequestBuilder>::init(handler);
 
-            let mut stream = req.build()?.connect()?;
+            let mut stream = req.tls(tls).build()?.connect()?;
             stream.send(WebSocketMessage::Binary(first_message))?;
 
             trace!("`SyncIterator` created successfully");
@@ -763,6 +994,7 @@ pub mod stream_api {
         #[allow(clippy::future_not_send)]
         pub async fn new<I: Init<DefaultWebSocketRequestBuilder>>(
             handler: I,
+            tls: Option<&Tls>,
         ) -> Result<AsyncStream<I::Next>> {
             trace!("Creating `AsyncStream`");
             let InitData {
@@ -771,7 +1003,7 @@ pub mod stream_api {
                 next: next_handler,
             } = Init::<http_default::DefaultWebSocketRequestBuilder>::init(handler);
 
-            let mut stream = req.build()?.connect_async().await?;
+            let mut stream = req.tls(tls).build()?.connect_async().await?;
             stream.send(WebSocketMessage::Binary(first_message)).await?;
 
             trace!("`AsyncStream` created successfully");
@@ -999,6 +1231,7 @@ mod tests {
             account: account_id,
             torii_api_url: "http://127.0.0.1:8080".parse().unwrap(),
             basic_auth: None,
+            tls: None,
             transaction_add_nonce: false,
             transaction_ttl: Duration::from_secs(5),
             transaction_status_timeout: Duration::from_secs(10),
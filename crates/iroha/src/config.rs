@@ -1,7 +1,11 @@
 //! Module for client-related configuration and structs
 
 use core::str::FromStr;
-use std::{path::Path, time::Duration};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
 use derive_more::Display;
 use error_stack::ResultExt;
@@ -10,6 +14,7 @@ use iroha_config_base::{read::ConfigReader, toml::TomlSource};
 use iroha_primitives::small::SmallStr;
 use serde::{Deserialize, Serialize};
 use serde_with::{DeserializeFromStr, SerializeDisplay};
+use tokio_rustls::rustls::ClientConfig;
 use url::Url;
 
 use crate::{
@@ -59,6 +64,17 @@ pub struct BasicAuth {
     pub password: SecretString,
 }
 
+/// TLS settings of the connections to Torii
+#[derive(Clone, Debug, Serialize)]
+pub struct Tls {
+    /// Certificate of the CA which Torii certificate is verified against. The system roots are used if not set
+    pub ca_certificate: Option<PathBuf>,
+    /// Certificate presented to Torii if it verifies the certificates of the clients
+    pub client_certificate: Option<PathBuf>,
+    #[serde(skip)]
+    pub(crate) client_config: Arc<ClientConfig>,
+}
+
 /// Complete client configuration
 #[derive(Clone, Debug, Serialize)]
 #[allow(missing_docs)]
@@ -68,6 +84,7 @@ pub struct Config {
     pub key_pair: KeyPair,
     pub basic_auth: Option<BasicAuth>,
     pub torii_api_url: Url,
+    pub tls: Option<Tls>,
     pub transaction_ttl: Duration,
     pub transaction_status_timeout: Duration,
     pub transaction_add_nonce: bool,
@@ -176,6 +193,25 @@ mod tests {
         assert_eq!(config.torii_api_url.as_str(), "http://127.0.0.1/peer-1/");
     }
 
+    #[test]
+    fn tls_client_certificate_requires_private_key() {
+        let err = ConfigReader::new()
+            .with_toml_source(TomlSource::inline(config_sample()))
+            .with_env(MockEnv::from([
+                ("TORII_URL", "https://127.0.0.1:8080"),
+                ("TORII_TLS_CLIENT_CERTIFICATE", "client.crt"),
+            ]))
+            .read_and_complete::<user::Root>()
+            .unwrap()
+            .parse()
+            .expect_err("private key is not set");
+
+        assert_contains!(
+            format!("{err:#?}"),
+            "TLS client certificate and private key should be set together"
+        );
+    }
+
     #[test]
     fn invalid_toml_file_is_handled_properly() {
         use std::io::Write;
@@ -1,11 +1,19 @@
 //! User configuration view.
 
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::PathBuf,
+    sync::Arc,
+};
+
 use error_stack::{Report, ResultExt};
 use iroha_config_base::{
     attach::ConfigValueAndOrigin,
     util::{DurationMs, Emitter, EmitterResultExt},
     ReadConfig, WithOrigin,
 };
+use tokio_rustls::rustls::{pki_types::CertificateDer, ClientConfig, RootCertStore};
 use url::Url;
 
 use crate::{
@@ -23,6 +31,8 @@ pub struct Root {
     pub torii_url: WithOrigin<Url>,
     pub basic_auth: Option<BasicAuth>,
     #[config(nested)]
+    pub tls: Tls,
+    #[config(nested)]
     pub account: Account,
     #[config(nested)]
     pub transaction: Transaction,
@@ -36,6 +46,14 @@ pub enum ParseError {
     KeyPair,
     #[error("Unsupported URL scheme: `{scheme}`")]
     UnsupportedUrlScheme { scheme: String },
+    #[error("TLS settings can only be used with the `https` Torii URL")]
+    TlsWithoutHttps,
+    #[error("TLS settings are only supported if the client is built with one of the `tls-rustls-*` features")]
+    TlsUnsupported,
+    #[error("TLS client certificate and private key should be set together")]
+    TlsClientAuth,
+    #[error("Failed to load TLS certificates or private key")]
+    TlsFiles,
 }
 
 impl Root {
@@ -49,6 +67,7 @@ impl Root {
             chain: chain_id,
             torii_url,
             basic_auth,
+            tls,
             account:
                 Account {
                     domain: domain_id,
@@ -87,6 +106,42 @@ impl Root {
                 .attach_printable("Note: only `http` and `https` protocols are supported"),
             ),
         }
+        let tls = match tls {
+            Tls {
+                ca_certificate: None,
+                client_certificate: None,
+                client_private_key: None,
+            } => None,
+            Tls {
+                ca_certificate,
+                client_certificate,
+                client_private_key,
+            } => {
+                if !cfg!(feature = "__rustls") {
+                    emitter.emit(Report::new(ParseError::TlsUnsupported));
+                }
+                if torii_url.value().scheme() != "https" {
+                    emitter.emit(
+                        Report::new(ParseError::TlsWithoutHttps)
+                            .attach_printable(torii_url.clone().into_attachment()),
+                    );
+                }
+                let client_auth = match (client_certificate, client_private_key) {
+                    (Some(certificate), Some(private_key)) => Some((certificate, private_key)),
+                    (None, None) => None,
+                    (certificate, private_key) => {
+                        let mut report = Report::new(ParseError::TlsClientAuth);
+                        for path in certificate.into_iter().chain(private_key) {
+                            report = report.attach_printable(path.into_attachment().display_path());
+                        }
+                        emitter.emit(report);
+                        None
+                    }
+                };
+                load_tls(ca_certificate, client_auth).ok_or_emit(&mut emitter)
+            }
+        };
+
         let torii_api_url = {
             let mut url = torii_url.into_value();
             let path = url.path();
@@ -115,6 +170,7 @@ impl Root {
             key_pair: key_pair.unwrap(),
             torii_api_url,
             basic_auth,
+            tls,
             transaction_ttl: tx_ttl.into_value().get(),
             transaction_status_timeout: tx_timeout.into_value().get(),
             transaction_add_nonce: tx_add_nonce,
@@ -122,6 +178,76 @@ impl Root {
     }
 }
 
+fn load_tls(
+    ca_certificate: Option<WithOrigin<PathBuf>>,
+    client_auth: Option<(WithOrigin<PathBuf>, WithOrigin<PathBuf>)>,
+) -> error_stack::Result<super::Tls, ParseError> {
+    let mut roots = RootCertStore::empty();
+    match &ca_certificate {
+        Some(path) => {
+            for certificate in read_certificates(path)? {
+                roots
+                    .add(certificate)
+                    .change_context(ParseError::TlsFiles)
+                    .attach_printable_lazy(|| path.clone().into_attachment().display_path())?;
+            }
+        }
+        None => {
+            let certificates = rustls_native_certs::load_native_certs()
+                .change_context(ParseError::TlsFiles)
+                .attach_printable("failed to load the system root certificates")?;
+            roots.add_parsable_certificates(certificates);
+        }
+    }
+
+    let builder = ClientConfig::builder().with_root_certificates(roots);
+    let (client_config, client_certificate) = match client_auth {
+        Some((certificate, private_key)) => {
+            let chain = read_certificates(&certificate)?;
+            let key = read_pem(&private_key, rustls_pemfile::private_key)?.ok_or_else(|| {
+                Report::new(ParseError::TlsFiles)
+                    .attach_printable("no private key found in the file")
+                    .attach_printable(private_key.clone().into_attachment().display_path())
+            })?;
+            let client_config = builder
+                .with_client_auth_cert(chain, key)
+                .change_context(ParseError::TlsFiles)
+                .attach_printable("invalid TLS client certificate or private key")?;
+            (client_config, Some(certificate.into_value()))
+        }
+        None => (builder.with_no_client_auth(), None),
+    };
+
+    Ok(super::Tls {
+        ca_certificate: ca_certificate.map(WithOrigin::into_value),
+        client_certificate,
+        client_config: Arc::new(client_config),
+    })
+}
+
+fn read_certificates(
+    path: &WithOrigin<PathBuf>,
+) -> error_stack::Result<Vec<CertificateDer<'static>>, ParseError> {
+    let certificates = read_pem(path, |reader| rustls_pemfile::certs(reader).collect())?;
+    if certificates.is_empty() {
+        return Err(Report::new(ParseError::TlsFiles)
+            .attach_printable("no certificates found in the file")
+            .attach_printable(path.clone().into_attachment().display_path()));
+    }
+    Ok(certificates)
+}
+
+fn read_pem<T>(
+    path: &WithOrigin<PathBuf>,
+    read: impl FnOnce(&mut dyn BufRead) -> std::io::Result<T>,
+) -> error_stack::Result<T, ParseError> {
+    File::open(path.resolve_relative_path())
+        .map(BufReader::new)
+        .and_then(|mut reader| read(&mut reader))
+        .change_context(ParseError::TlsFiles)
+        .attach_printable_lazy(|| path.clone().into_attachment().display_path())
+}
+
 #[derive(Debug, Clone, ReadConfig)]
 #[allow(missing_docs)]
 pub struct Account {
@@ -130,6 +256,17 @@ pub struct Account {
     pub private_key: WithOrigin<PrivateKey>,
 }
 
+#[derive(Debug, Clone, ReadConfig)]
+#[allow(missing_docs)]
+pub struct Tls {
+    #[config(env = "TORII_TLS_CA_CERTIFICATE")]
+    pub ca_certificate: Option<WithOrigin<PathBuf>>,
+    #[config(env = "TORII_TLS_CLIENT_CERTIFICATE")]
+    pub client_certificate: Option<WithOrigin<PathBuf>>,
+    #[config(env = "TORII_TLS_CLIENT_PRIVATE_KEY")]
+    pub client_private_key: Option<WithOrigin<PathBuf>>,
+}
+
 #[derive(Debug, Clone, ReadConfig)]
 #[allow(missing_docs)]
 pub struct Transaction {
@@ -151,7 +288,12 @@ mod tests {
 
     #[test]
     fn parses_all_envs() {
-        let env = MockEnv::from([("TORII_URL", "http://localhost:8080")]);
+        let env = MockEnv::from([
+            ("TORII_URL", "http://localhost:8080"),
+            ("TORII_TLS_CA_CERTIFICATE", "ca.crt"),
+            ("TORII_TLS_CLIENT_CERTIFICATE", "client.crt"),
+            ("TORII_TLS_CLIENT_PRIVATE_KEY", "client.key"),
+        ]);
 
         let _ = ConfigReader::new()
             .with_env(env.clone())
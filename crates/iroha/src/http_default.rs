@@ -1,20 +1,33 @@
 //! Defaults for various items used in communication over http(s).
-use std::net::TcpStream;
+use std::{
+    net::{IpAddr, TcpStream},
+    sync::{mpsc, OnceLock},
+    time::Duration,
+};
 
 use attohttpc::{
     body as atto_body, RequestBuilder as AttoHttpRequestBuilder, Response as AttoHttpResponse,
 };
 use eyre::{eyre, Error, Result, WrapErr};
-use http::header::HeaderName;
+use http::header::{HeaderName, HOST};
+use http_body_util::{BodyExt, Full};
+use hyper_util::rt::TokioIo;
+use tokio_rustls::{rustls::pki_types::ServerName, TlsConnector};
 use tungstenite::{client::IntoClientRequest, stream::MaybeTlsStream, WebSocket};
 pub use tungstenite::{Error as WebSocketError, Message as WebSocketMessage};
-use url::Url;
+use url::{Host, Position, Url};
 
-use crate::http::{Method, RequestBuilder, Response};
+use crate::{
+    config::Tls,
+    http::{Method, RequestBuilder, Response},
+};
 
 type Bytes = Vec<u8>;
 type AttoHttpRequestBuilderWithBytes = AttoHttpRequestBuilder<atto_body::Bytes<Bytes>>;
 
+/// Timeout of the requests sent with the custom TLS settings, including the connection establishment
+const TLS_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
 fn header_name_from_str(str: &str) -> Result<HeaderName> {
     str.parse::<HeaderName>()
         .wrap_err_with(|| format!("Failed to parse header name {str}"))
@@ -25,6 +38,7 @@ fn header_name_from_str(str: &str) -> Result<HeaderName> {
 pub struct DefaultRequestBuilder {
     inner: Result<AttoHttpRequestBuilder>,
     body: Option<Vec<u8>>,
+    tls: Option<Tls>,
 }
 
 impl DefaultRequestBuilder {
@@ -39,16 +53,30 @@ impl DefaultRequestBuilder {
         }
     }
 
+    /// Use the custom TLS settings for the connection instead of the default ones.
+    #[must_use]
+    pub fn tls(self, tls: Option<&Tls>) -> Self {
+        Self {
+            tls: tls.cloned(),
+            ..self
+        }
+    }
+
     /// Build request by consuming self.
     pub fn build(self) -> Result<DefaultRequest> {
-        self.inner
-            .map(|b| DefaultRequest(b.bytes(self.body.map_or_else(Vec::new, |vec| vec))))
+        self.inner.map(|b| DefaultRequest {
+            inner: b.bytes(self.body.map_or_else(Vec::new, |vec| vec)),
+            tls: self.tls,
+        })
     }
 }
 
 /// Request built by [`DefaultRequestBuilder`].
 #[derive(Debug)]
-pub struct DefaultRequest(AttoHttpRequestBuilderWithBytes);
+pub struct DefaultRequest {
+    inner: AttoHttpRequestBuilderWithBytes,
+    tls: Option<Tls>,
+}
 
 impl DefaultRequest {
     /// Sends itself and returns byte response
@@ -57,12 +85,18 @@ impl DefaultRequest {
     /// Fails if request building and sending fails or response transformation fails
     pub fn send(mut self) -> Result<Response<Bytes>> {
         let (method, url) = {
-            let inspect = self.0.inspect();
+            let inspect = self.inner.inspect();
             (inspect.method().clone(), inspect.url().clone())
         };
 
+        if let Some(tls) = self.tls {
+            // `attohttpc` can't present client certificates
+            return send_with_tls(self.inner, &tls)
+                .wrap_err_with(|| format!("Failed to send http {method} request to {url}"));
+        }
+
         let response = self
-            .0
+            .inner
             .send()
             .wrap_err_with(|| format!("Failed to send http {method} request to {url}"))?;
 
@@ -70,11 +104,79 @@ impl DefaultRequest {
     }
 }
 
+/// Send the request over the TLS connection established with the given settings.
+fn send_with_tls(
+    mut request: AttoHttpRequestBuilderWithBytes,
+    tls: &Tls,
+) -> Result<Response<Bytes>> {
+    let mut inspect = request.inspect();
+    let url = inspect.url().clone();
+    let mut builder = http::Request::builder()
+        .method(inspect.method().clone())
+        .uri(&url[Position::BeforePath..Position::AfterQuery])
+        .header(HOST, &url[Position::BeforeHost..Position::BeforePath]);
+    for (name, value) in inspect.headers() {
+        builder = builder.header(name, value.clone());
+    }
+    let body = std::mem::take(&mut inspect.body().0);
+    let request = builder.body(Full::new(bytes::Bytes::from(body)))?;
+
+    let server_name = match url.host() {
+        Some(Host::Domain(domain)) => ServerName::try_from(domain)?.to_owned(),
+        Some(Host::Ipv4(ip)) => ServerName::from(IpAddr::V4(ip)),
+        Some(Host::Ipv6(ip)) => ServerName::from(IpAddr::V6(ip)),
+        None => return Err(eyre!("Missing host in {url}")),
+    };
+    let addrs = url.socket_addrs(|| None)?;
+    let connector = TlsConnector::from(tls.client_config.clone());
+
+    let send = async move {
+        let stream = tokio::net::TcpStream::connect(&*addrs).await?;
+        let stream = connector.connect(server_name, stream).await?;
+        let (mut sender, connection) =
+            hyper::client::conn::http1::handshake(TokioIo::new(stream)).await?;
+        tokio::spawn(async move {
+            if let Err(error) = connection.await {
+                iroha_logger::debug!(%error, "TLS connection failed");
+            }
+        });
+
+        let (parts, body) = sender.send_request(request).await?.into_parts();
+        let body = body.collect().await?.to_bytes().to_vec();
+        Ok::<_, Error>(Response::from_parts(parts, body))
+    };
+
+    // NOTE: Not `block_on` so that requests can also be sent from within an async context
+    let (result_sender, result_receiver) = mpsc::sync_channel(1);
+    tls_runtime().spawn(async move {
+        let result = tokio::time::timeout(TLS_REQUEST_TIMEOUT, send)
+            .await
+            .unwrap_or_else(|_| Err(eyre!("Request timed out after {TLS_REQUEST_TIMEOUT:?}")));
+        let _ = result_sender.send(result);
+    });
+    result_receiver.recv().wrap_err("TLS request was aborted")?
+}
+
+/// Runtime driving the requests sent with the custom TLS settings, shared by all clients.
+fn tls_runtime() -> &'static tokio::runtime::Runtime {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("iroha-client-tls")
+            .enable_all()
+            .build()
+            .expect("Failed to build the runtime for TLS connections")
+    })
+}
+
 impl RequestBuilder for DefaultRequestBuilder {
     fn new(method: Method, url: Url) -> Self {
         Self {
             inner: Ok(AttoHttpRequestBuilder::new(method, url)),
             body: None,
+            tls: None,
         }
     }
 
@@ -97,7 +199,10 @@ impl RequestBuilder for DefaultRequestBuilder {
 }
 
 /// Request builder built on top of [`http::request::Builder`]. Used for `WebSocket` connections.
-pub struct DefaultWebSocketRequestBuilder(Result<http::request::Builder>);
+pub struct DefaultWebSocketRequestBuilder {
+    inner: Result<http::request::Builder>,
+    tls: Option<Tls>,
+}
 
 impl DefaultWebSocketRequestBuilder {
     /// Same as [`DefaultRequestBuilder::and_then`].
@@ -105,12 +210,24 @@ impl DefaultWebSocketRequestBuilder {
     where
         F: FnOnce(http::request::Builder) -> Result<http::request::Builder>,
     {
-        Self(self.0.and_then(func))
+        Self {
+            inner: self.inner.and_then(func),
+            ..self
+        }
+    }
+
+    /// Same as [`DefaultRequestBuilder::tls`].
+    #[must_use]
+    pub fn tls(self, tls: Option<&Tls>) -> Self {
+        Self {
+            tls: tls.cloned(),
+            ..self
+        }
     }
 
     /// Consumes itself to build request.
     pub fn build(self) -> Result<DefaultWebSocketStreamRequest> {
-        let builder = self.0?;
+        let builder = self.inner?;
         let mut request = builder
             .uri_ref()
             .ok_or_else(|| eyre!("Missing URI"))?
@@ -121,36 +238,90 @@ impl DefaultWebSocketRequestBuilder {
         {
             request.headers_mut().entry(header).or_insert(value.clone());
         }
-        Ok(DefaultWebSocketStreamRequest(request))
+        Ok(DefaultWebSocketStreamRequest {
+            request,
+            tls: self.tls,
+        })
     }
 }
 
 /// `WebSocket` request built by [`DefaultWebSocketRequestBuilder`]
-pub struct DefaultWebSocketStreamRequest(http::Request<()>);
+pub struct DefaultWebSocketStreamRequest {
+    request: http::Request<()>,
+    tls: Option<Tls>,
+}
 
 impl DefaultWebSocketStreamRequest {
     /// Open [`WebSocketStream`] synchronously.
     pub fn connect(self) -> Result<WebSocketStream> {
-        let (stream, _) = tungstenite::connect(self.0)?;
+        let (stream, _) = match self.tls {
+            None => tungstenite::connect(self.request)?,
+            #[cfg(feature = "__rustls")]
+            Some(tls) => {
+                let uri = self.request.uri();
+                let host = uri.host().ok_or_else(|| eyre!("Missing host in {uri}"))?;
+                // NOTE: IPv6 addresses are enclosed in brackets
+                let host = host
+                    .strip_prefix('[')
+                    .and_then(|host| host.strip_suffix(']'))
+                    .unwrap_or(host);
+                let stream = TcpStream::connect((host, uri.port_u16().unwrap_or(443)))?;
+                tungstenite::client_tls_with_config(
+                    self.request,
+                    stream,
+                    None,
+                    Some(tungstenite::Connector::Rustls(tls.client_config)),
+                )
+                .map_err(|error| eyre!("WebSocket handshake failed: {error}"))?
+            }
+            #[cfg(not(feature = "__rustls"))]
+            Some(_) => {
+                return Err(eyre!(
+                    "TLS settings require one of the `tls-rustls-*` features"
+                ))
+            }
+        };
         Ok(stream)
     }
 
     /// Open [`AsyncWebSocketStream`].
     pub async fn connect_async(self) -> Result<AsyncWebSocketStream> {
-        let (stream, _) = tokio_tungstenite::connect_async(self.0).await?;
+        let (stream, _) = match self.tls {
+            None => tokio_tungstenite::connect_async(self.request).await?,
+            #[cfg(feature = "__rustls")]
+            Some(tls) => {
+                tokio_tungstenite::connect_async_tls_with_config(
+                    self.request,
+                    None,
+                    false,
+                    Some(tokio_tungstenite::Connector::Rustls(tls.client_config)),
+                )
+                .await?
+            }
+            #[cfg(not(feature = "__rustls"))]
+            Some(_) => {
+                return Err(eyre!(
+                    "TLS settings require one of the `tls-rustls-*` features"
+                ))
+            }
+        };
         Ok(stream)
     }
 }
 
 impl RequestBuilder for DefaultWebSocketRequestBuilder {
     fn new(method: Method, url: Url) -> Self {
-        Self(Ok(http::Request::builder()
-            .method(method)
-            .uri(url.as_ref())))
+        Self {
+            inner: Ok(http::Request::builder().method(method).uri(url.as_ref())),
+            tls: None,
+        }
     }
 
     fn param<K, V: ?Sized>(self, _key: K, _val: &V) -> Self {
-        Self(self.0.and(Err(eyre!("No params expected"))))
+        Self {
+            inner: self.inner.and(Err(eyre!("No params expected"))),
+            ..self
+        }
     }
 
     fn header<N: AsRef<str>, V: ToString + ?Sized>(self, name: N, value: &V) -> Self {
@@ -1,6 +1,6 @@
 //! Functions and types to make queries to the Iroha peer.
 
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt::Debug, num::NonZeroU64};
 
 use eyre::{eyre, Context, Result};
 use http::StatusCode;
@@ -11,14 +11,17 @@ use url::Url;
 
 use crate::{
     client::{join_torii_url, Client, QueryResult, ResponseReport},
+    config::Tls,
     crypto::KeyPair,
     data_model::{
-        account::AccountId,
+        account::{AccountId, AccountRef},
         query::{
+            account::FindAccountIdByAlias,
+            aggregate::{AggregateOutput, AggregateQuery},
             builder::{QueryBuilder, QueryExecutor},
             parameters::ForwardCursor,
             Query, QueryOutput, QueryRequest, QueryResponse, QueryWithParams, SingularQuery,
-            SingularQueryBox, SingularQueryOutputBox,
+            SingularQueryBox, SingularQueryOutputBox, SingularQueryWithParams,
         },
         ValidationFail,
     },
@@ -30,6 +33,7 @@ use crate::{
 struct ClientQueryRequestHead {
     torii_url: Url,
     headers: HashMap<String, String>,
+    tls: Option<Tls>,
     account_id: AccountId,
     key_pair: KeyPair,
 }
@@ -47,6 +51,7 @@ impl ClientQueryRequestHead {
         )
         .headers(self.headers.clone())
         .body(query.encode())
+        .tls(self.tls.as_ref())
     }
 }
 
@@ -111,6 +116,16 @@ fn decode_iterable_query_response(resp: &http::Response<Vec<u8>>) -> QueryResult
     Ok(resp)
 }
 
+fn decode_aggregate_query_response(resp: &http::Response<Vec<u8>>) -> QueryResult<AggregateOutput> {
+    let QueryResponse::Aggregate(resp) = decode_query_response(resp)? else {
+        return Err(eyre!(
+            "Got unexpected type of query response from the node (expected aggregate)"
+        )
+        .into());
+    };
+    Ok(resp)
+}
+
 /// An iterable query cursor for use in the client
 #[derive(Debug)]
 pub struct QueryCursor {
@@ -147,7 +162,7 @@ impl QueryExecutor for Client {
     ) -> Result<SingularQueryOutputBox, Self::Error> {
         let request_head = self.get_query_request_head();
 
-        let request = QueryRequest::Singular(query);
+        let request = QueryRequest::Singular(query.into());
 
         let response = request_head.assemble(request).build()?.send()?;
         let response = decode_singular_query_response(&response)?;
@@ -198,6 +213,20 @@ impl QueryExecutor for Client {
 
         Ok((batch, remaining_items, cursor))
     }
+
+    fn execute_aggregate_query(
+        &self,
+        query: AggregateQuery,
+    ) -> Result<AggregateOutput, Self::Error> {
+        let request_head = self.get_query_request_head();
+
+        let request = QueryRequest::Aggregate(query);
+
+        let response = request_head.assemble(request).build()?.send()?;
+        let response = decode_aggregate_query_response(&response)?;
+
+        Ok(response)
+    }
 }
 
 impl Client {
@@ -208,6 +237,7 @@ impl Client {
         ClientQueryRequestHead {
             torii_url: self.torii_url.clone(),
             headers: self.headers.clone(),
+            tls: self.tls.clone(),
             account_id: self.account.clone(),
             key_pair: self.key_pair.clone(),
         }
@@ -234,6 +264,49 @@ impl Client {
             .expect("BUG: iroha returned unexpected type in singular query"))
     }
 
+    /// Execute a singular query against the world state as it was after the block at the given height was committed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails or the peer no longer retains the state at `height`.
+    pub fn query_single_at_height<Q>(
+        &self,
+        query: Q,
+        height: NonZeroU64,
+    ) -> Result<Q::Output, QueryError>
+    where
+        Q: SingularQuery,
+        SingularQueryBox: From<Q>,
+        Q::Output: TryFrom<SingularQueryOutputBox>,
+        <Q::Output as TryFrom<SingularQueryOutputBox>>::Error: Debug,
+    {
+        let query =
+            SingularQueryWithParams::new(SingularQueryBox::from(query)).with_at_height(height);
+
+        let request_head = self.get_query_request_head();
+        let response = request_head
+            .assemble(QueryRequest::Singular(query))
+            .build()?
+            .send()?;
+        let result = decode_singular_query_response(&response)?;
+
+        Ok(result
+            .try_into()
+            .expect("BUG: iroha returned unexpected type in singular query"))
+    }
+
+    /// Resolve an [`AccountRef`] to the [`AccountId`] it refers to, querying the peer if it's an alias
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the alias isn't registered or the query execution fails.
+    pub fn resolve_account(&self, account: AccountRef) -> Result<AccountId, QueryError> {
+        match account {
+            AccountRef::Id(id) => Ok(id),
+            AccountRef::Alias(alias) => self.query_single(FindAccountIdByAlias::new(alias)),
+        }
+    }
+
     /// Build an iterable query and return a builder object
     pub fn query<Q>(&self, query: Q) -> QueryBuilder<Self, Q, Q::Item>
     where
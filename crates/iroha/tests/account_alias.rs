@@ -0,0 +1,147 @@
+use eyre::Result;
+use iroha::data_model::{prelude::*, transaction::error::TransactionRejectionReason};
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, is_invariant_violation, ALICE_ID, BOB_ID, BOB_KEYPAIR};
+
+#[test]
+fn alias_resolves_to_account() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice_client = network.client();
+    let bob_client = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+
+    // Given
+    let alias = "alice@wonderland".parse::<AccountAlias>()?;
+    alice_client.submit_blocking(RegisterAccountAlias::new(alias.clone(), ALICE_ID.clone()))?;
+
+    // Then
+    assert_eq!(
+        alice_client.query_single(FindAccountIdByAlias::new(alias.clone()))?,
+        *ALICE_ID
+    );
+    assert_eq!(
+        alice_client.resolve_account("alice@wonderland".parse()?)?,
+        *ALICE_ID
+    );
+    assert_eq!(
+        alice_client.query_single(FindAliasesByAccountId::new(ALICE_ID.clone()))?,
+        [alias.clone()]
+    );
+
+    // Aliases are unique
+    let err = bob_client
+        .submit_blocking(RegisterAccountAlias::new(alias.clone(), BOB_ID.clone()))
+        .expect_err("Alias is already registered");
+    assert!(is_invariant_violation(&err));
+
+    // When
+    alice_client.submit_blocking(TransferAccountAlias::new(alias.clone(), BOB_ID.clone()))?;
+
+    // Then
+    assert_eq!(
+        alice_client.query_single(FindAccountIdByAlias::new(alias.clone()))?,
+        *BOB_ID
+    );
+    assert!(alice_client
+        .query_single(FindAliasesByAccountId::new(ALICE_ID.clone()))?
+        .is_empty());
+    assert_eq!(
+        alice_client.query_single(FindAliasesByAccountId::new(BOB_ID.clone()))?,
+        [alias.clone()]
+    );
+
+    // When
+    bob_client.submit_blocking(UnregisterAccountAlias::new(alias.clone()))?;
+
+    // Then
+    alice_client
+        .query_single(FindAccountIdByAlias::new(alias))
+        .expect_err("Alias was unregistered");
+
+    Ok(())
+}
+
+#[test]
+fn alias_must_be_unambiguous_and_in_account_domain() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    test_client.submit_blocking(Register::domain(Domain::new("neverland".parse()?)))?;
+
+    let err = test_client
+        .submit_blocking(RegisterAccountAlias::new(
+            "alice@neverland".parse()?,
+            ALICE_ID.clone(),
+        ))
+        .expect_err("Alias belongs to another domain");
+    assert!(is_invariant_violation(&err));
+
+    let err = test_client
+        .submit_blocking(RegisterAccountAlias::new(
+            AccountAlias::new(
+                ALICE_ID.domain().clone(),
+                BOB_ID.signatory().to_string().parse()?,
+            ),
+            ALICE_ID.clone(),
+        ))
+        .expect_err("Alias can't be named after a public key");
+    assert!(is_invariant_violation(&err));
+
+    Ok(())
+}
+
+#[test]
+fn aliases_of_another_account_cant_be_changed() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice_client = network.client();
+    let (mouse_id, mouse_key_pair) = gen_account_in("wonderland");
+    let mouse_client = network
+        .peer()
+        .client_for(&mouse_id, mouse_key_pair.private_key().clone());
+    let alias = "alice@wonderland".parse::<AccountAlias>()?;
+    alice_client.submit_all_blocking::<InstructionBox>([
+        Register::account(Account::new(mouse_id.clone())).into(),
+        RegisterAccountAlias::new(alias.clone(), ALICE_ID.clone()).into(),
+    ])?;
+
+    // When
+    let err = mouse_client
+        .submit_blocking(TransferAccountAlias::new(alias, mouse_id))
+        .expect_err("Mouse doesn't own Alice's account");
+
+    // Then
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::NotPermitted(_)
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn aliases_are_removed_with_their_account() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    // Given
+    let (mouse_id, _mouse_keypair) = gen_account_in("wonderland");
+    let alias = "mouse@wonderland".parse::<AccountAlias>()?;
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::account(Account::new(mouse_id.clone())).into(),
+        RegisterAccountAlias::new(alias.clone(), mouse_id.clone()).into(),
+    ])?;
+
+    // When
+    test_client.submit_blocking(Unregister::account(mouse_id))?;
+
+    // Then
+    test_client
+        .query_single(FindAccountIdByAlias::new(alias.clone()))
+        .expect_err("Alias was removed with the account");
+    // The alias can be reused
+    test_client.submit_blocking(RegisterAccountAlias::new(alias, ALICE_ID.clone()))?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use eyre::Result;
+use iroha::crypto::KeyPair;
+use iroha_test_network::*;
+use iroha_test_samples::ALICE_KEYPAIR;
+
+#[test]
+fn admin_endpoints_require_signature_of_allowed_key() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config(|c| {
+            c.write(["torii", "admin_public_keys"], [ALICE_KEYPAIR.public_key()]);
+        })
+        .start_blocking()?;
+    let admin_client = network.client();
+
+    // Requests signed by an admin key are accepted
+    let config = admin_client.get_config()?;
+    admin_client.set_config(&config)?;
+
+    // Requests signed by any other key are forbidden
+    let mut other_client = network.client();
+    other_client.key_pair = KeyPair::random();
+    let err = other_client
+        .set_config(&config)
+        .expect_err("Key isn't allowed to access the admin endpoints");
+    assert!(err.to_string().contains("403"), "{err}");
+
+    // Unsigned requests are unauthorized
+    let url = admin_client.torii_url.join("configuration")?;
+    assert_eq!(attohttpc::get(url).send()?.status().as_u16(), 401);
+
+    // Public endpoints and metrics are still available without a signature
+    for endpoint in ["health", "metrics"] {
+        let url = admin_client.torii_url.join(endpoint)?;
+        assert!(attohttpc::get(url).send()?.is_success(), "{endpoint}");
+    }
+
+    Ok(())
+}
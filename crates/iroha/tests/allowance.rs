@@ -0,0 +1,136 @@
+use eyre::Result;
+use iroha::data_model::{
+    isi::{
+        error::{InstructionExecutionError, MathError},
+        InstructionBox,
+    },
+    prelude::*,
+    transaction::error::TransactionRejectionReason,
+};
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, ALICE_ID, BOB_ID};
+
+#[test]
+fn spender_can_transfer_within_allowance() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice_client = network.client();
+
+    // Given
+    let asset_definition_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let alice_asset = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+    let bob_asset = AssetId::new(asset_definition_id.clone(), BOB_ID.clone());
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    let mouse_client = network
+        .peer()
+        .client_for(&mouse_id, mouse_keypair.private_key().clone());
+    let allowance_id = AllowanceId::new(
+        ALICE_ID.clone(),
+        mouse_id.clone(),
+        asset_definition_id.clone(),
+    );
+
+    alice_client.submit_all_blocking::<InstructionBox>([
+        Register::account(Account::new(mouse_id.clone())).into(),
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into(),
+        Mint::asset_numeric(100_u32, alice_asset.clone()).into(),
+        Approve::new(allowance_id.clone(), numeric!(30)).into(),
+    ])?;
+    let allowances = || {
+        alice_client
+            .query(FindAllowances::new())
+            .filter_with(|allowance| allowance.id.eq(allowance_id.clone()))
+            .execute_all()
+    };
+    assert_eq!(
+        allowances()?,
+        [Allowance::new(allowance_id.clone(), numeric!(30))]
+    );
+
+    // When
+    mouse_client.submit_blocking(TransferFrom::new(
+        allowance_id.clone(),
+        numeric!(20),
+        BOB_ID.clone(),
+    ))?;
+
+    // Then
+    let asset_value = |asset_id: AssetId| {
+        alice_client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.eq(asset_id))
+            .execute_single()
+            .map(|asset| asset.value().clone())
+    };
+    assert_eq!(asset_value(alice_asset)?, AssetValue::Numeric(numeric!(80)));
+    assert_eq!(asset_value(bob_asset)?, AssetValue::Numeric(numeric!(20)));
+    assert_eq!(
+        allowances()?,
+        [Allowance::new(allowance_id.clone(), numeric!(10))]
+    );
+
+    // The spender can't exceed what is left of the allowance
+    let err = mouse_client
+        .submit_blocking(TransferFrom::new(
+            allowance_id.clone(),
+            numeric!(20),
+            BOB_ID.clone(),
+        ))
+        .expect_err("Should exceed the allowance");
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::InstructionFailed(InstructionExecutionError::Math(
+                MathError::NotEnoughQuantity
+            ))
+        ))
+    ));
+
+    // Approving zero revokes the allowance
+    alice_client.submit_blocking(Approve::new(allowance_id, numeric!(0)))?;
+    assert!(allowances()?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn only_spender_can_use_allowance() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice_client = network.client();
+
+    // Given
+    let asset_definition_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let (mouse_id, _mouse_keypair) = gen_account_in("wonderland");
+    let allowance_id = AllowanceId::new(
+        ALICE_ID.clone(),
+        mouse_id.clone(),
+        asset_definition_id.clone(),
+    );
+
+    alice_client.submit_all_blocking::<InstructionBox>([
+        Register::account(Account::new(mouse_id.clone())).into(),
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id.clone())).into(),
+        Mint::asset_numeric(100_u32, AssetId::new(asset_definition_id, ALICE_ID.clone())).into(),
+        Approve::new(allowance_id.clone(), numeric!(30)).into(),
+    ])?;
+
+    // Then
+    let err = alice_client
+        .submit_blocking(TransferFrom::new(
+            allowance_id,
+            numeric!(10),
+            BOB_ID.clone(),
+        ))
+        .expect_err("Only the spender can use the allowance");
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::NotPermitted(_)
+        ))
+    ));
+
+    Ok(())
+}
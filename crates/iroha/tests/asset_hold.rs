@@ -0,0 +1,149 @@
+use eyre::Result;
+use iroha::data_model::{
+    isi::{
+        error::{InstructionExecutionError, MathError},
+        InstructionBox,
+    },
+    prelude::*,
+    transaction::error::TransactionRejectionReason,
+};
+use iroha_executor_data_model::permission::asset::CanHoldAssetWithDefinition;
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, ALICE_ID, BOB_ID};
+
+#[test]
+fn held_balance_cannot_be_transferred_until_released() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    // Given
+    let asset_definition_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let asset_id = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into(),
+        Mint::asset_numeric(100_u32, asset_id.clone()).into(),
+        HoldAsset::new(asset_id.clone(), numeric!(60)).into(),
+    ])?;
+    let holds = || {
+        test_client
+            .query(FindAssetHolds::new())
+            .filter_with(|asset_hold| asset_hold.asset.eq(asset_id.clone()))
+            .execute_all()
+    };
+    assert_eq!(holds()?, [AssetHold::new(asset_id.clone(), numeric!(60))]);
+    let transfer =
+        |quantity: u32| Transfer::asset_numeric(asset_id.clone(), quantity, BOB_ID.clone());
+
+    // Only the available balance can be transferred
+    let err = test_client
+        .submit_blocking(transfer(50))
+        .expect_err("Should exceed the available balance");
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::InstructionFailed(InstructionExecutionError::Math(
+                MathError::NotEnoughQuantity
+            ))
+        ))
+    ));
+    test_client.submit_blocking(transfer(40))?;
+
+    // Releasing the hold makes the balance available again
+    test_client.submit_all_blocking::<InstructionBox>([
+        ReleaseAsset::new(asset_id.clone(), numeric!(60)).into(),
+        transfer(60).into(),
+    ])?;
+    assert!(holds()?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn holds_require_permission() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice_client = network.client();
+
+    // Given
+    let asset_definition_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let asset_id = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    let mouse_client = network
+        .peer()
+        .client_for(&mouse_id, mouse_keypair.private_key().clone());
+    alice_client.submit_all_blocking::<InstructionBox>([
+        Register::account(Account::new(mouse_id.clone())).into(),
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id.clone())).into(),
+        Mint::asset_numeric(100_u32, asset_id.clone()).into(),
+    ])?;
+    let hold = HoldAsset::new(asset_id, numeric!(10));
+
+    // Then
+    let err = mouse_client
+        .submit_blocking(hold.clone())
+        .expect_err("Mouse doesn't own the asset definition");
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::NotPermitted(_)
+        ))
+    ));
+
+    alice_client.submit_blocking(Grant::account_permission(
+        CanHoldAssetWithDefinition {
+            asset_definition: asset_definition_id,
+        },
+        mouse_id,
+    ))?;
+    mouse_client.submit_blocking(hold)?;
+
+    Ok(())
+}
+
+#[test]
+fn held_asset_cannot_be_unregistered() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    // Given
+    let asset_definition_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let (mouse_id, _mouse_keypair) = gen_account_in("wonderland");
+    let asset_id = AssetId::new(asset_definition_id.clone(), mouse_id.clone());
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::account(Account::new(mouse_id.clone())).into(),
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id.clone())).into(),
+        Mint::asset_numeric(100_u32, asset_id.clone()).into(),
+        HoldAsset::new(asset_id.clone(), numeric!(60)).into(),
+    ])?;
+
+    // Then the held funds can't be dropped
+    let unregister_instructions: [InstructionBox; 3] = [
+        Unregister::asset(asset_id.clone()).into(),
+        Unregister::account(mouse_id.clone()).into(),
+        Unregister::asset_definition(asset_definition_id.clone()).into(),
+    ];
+    for instruction in unregister_instructions {
+        let err = test_client
+            .submit_blocking(instruction)
+            .expect_err("Should keep the held funds");
+        assert!(matches!(
+            err.downcast_ref::<TransactionRejectionReason>(),
+            Some(TransactionRejectionReason::Validation(
+                ValidationFail::InstructionFailed(InstructionExecutionError::InvariantViolation(_))
+            ))
+        ));
+    }
+
+    // Until they are released
+    test_client.submit_all_blocking::<InstructionBox>([
+        ReleaseAsset::new(asset_id, numeric!(60)).into(),
+        Unregister::account(mouse_id).into(),
+    ])?;
+
+    Ok(())
+}
@@ -0,0 +1,368 @@
+use std::time::{Duration, SystemTime};
+
+use eyre::Result;
+use iroha::data_model::{
+    isi::InstructionBox,
+    prelude::*,
+    transaction::error::TransactionRejectionReason,
+};
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, is_invariant_violation, ALICE_ID, BOB_ID, BOB_KEYPAIR};
+
+const PREIMAGE: &[u8] = b"secret";
+
+fn curr_time() -> Duration {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+}
+
+fn hash_lock(name: &str, deadline: Duration) -> HashLock {
+    HashLock::new(
+        HashLockId::new(ALICE_ID.clone(), name.parse().expect("Valid")),
+        "xor#wonderland".parse().expect("Valid"),
+        numeric!(30),
+        BOB_ID.clone(),
+        Sha256Hash::new(PREIMAGE),
+        deadline.as_millis().try_into().expect("Valid"),
+    )
+}
+
+fn asset_value(client: &iroha::client::Client, asset_id: AssetId) -> Result<AssetValue> {
+    Ok(client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.eq(asset_id))
+        .execute_single()?
+        .value()
+        .clone())
+}
+
+#[test]
+fn recipient_claims_locked_asset_with_preimage() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice_client = network.client();
+    let bob_client = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+
+    // Given
+    let asset_definition_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let alice_asset = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+    let bob_asset = AssetId::new(asset_definition_id.clone(), BOB_ID.clone());
+    let lock = hash_lock("deal", curr_time() + Duration::from_secs(3600));
+    alice_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into(),
+        Mint::asset_numeric(100_u32, alice_asset.clone()).into(),
+        LockAsset::new(lock.clone()).into(),
+    ])?;
+    let open_locks = || {
+        alice_client
+            .query(FindHashLocks::new())
+            .filter_with(|hash_lock| hash_lock.id.eq(lock.id.clone()))
+            .execute_all()
+    };
+    assert_eq!(open_locks()?, [lock.clone()]);
+
+    // The locked quantity can't be transferred by the sender
+    alice_client
+        .submit_blocking(Transfer::asset_numeric(
+            alice_asset.clone(),
+            80_u32,
+            BOB_ID.clone(),
+        ))
+        .expect_err("Should exceed the available balance");
+
+    // A wrong preimage doesn't unlock the transfer
+    let err = bob_client
+        .submit_blocking(ClaimLockedAsset::new(lock.id.clone(), b"guess".to_vec()))
+        .expect_err("Preimage doesn't match");
+    assert!(is_invariant_violation(&err));
+
+    // When
+    bob_client.submit_blocking(ClaimLockedAsset::new(lock.id.clone(), PREIMAGE.to_vec()))?;
+
+    // Then
+    assert_eq!(
+        asset_value(&alice_client, alice_asset)?,
+        AssetValue::Numeric(numeric!(70))
+    );
+    assert_eq!(
+        asset_value(&alice_client, bob_asset)?,
+        AssetValue::Numeric(numeric!(30))
+    );
+    assert!(open_locks()?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn expired_lock_is_refunded_to_sender() -> Result<()> {
+    const GAP: Duration = Duration::from_secs(3);
+
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    // Given
+    let asset_definition_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let alice_asset = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+    let lock = hash_lock("deal", curr_time() + GAP);
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into(),
+        Mint::asset_numeric(100_u32, alice_asset.clone()).into(),
+        LockAsset::new(lock.clone()).into(),
+    ])?;
+
+    // The lock can't be refunded before its deadline
+    let err = test_client
+        .submit_blocking(RefundLockedAsset::new(lock.id.clone()))
+        .expect_err("Lock hasn't expired yet");
+    assert!(is_invariant_violation(&err));
+
+    // When
+    std::thread::sleep(GAP);
+
+    // Then the lock can't be claimed anymore, only refunded
+    let err = test_client
+        .submit_blocking(ClaimLockedAsset::new(lock.id.clone(), PREIMAGE.to_vec()))
+        .expect_err("Lock has expired");
+    assert!(is_invariant_violation(&err));
+    test_client.submit_all_blocking::<InstructionBox>([
+        RefundLockedAsset::new(lock.id).into(),
+        Transfer::asset_numeric(alice_asset.clone(), 100_u32, BOB_ID.clone()).into(),
+    ])?;
+    assert!(test_client
+        .query(FindHashLocks::new())
+        .execute_all()?
+        .is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn holds_and_hash_locks_share_the_balance() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    // Given
+    let asset_definition_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let asset_id = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into(),
+        Mint::asset_numeric(100_u32, asset_id.clone()).into(),
+        HoldAsset::new(asset_id.clone(), numeric!(40)).into(),
+        LockAsset::new(hash_lock("deal", curr_time() + Duration::from_secs(3600))).into(),
+    ])?;
+
+    // When
+    test_client.submit_blocking(HoldAsset::new(asset_id.clone(), numeric!(30)))?;
+
+    // Then
+    let holds = test_client
+        .query(FindAssetHolds::new())
+        .filter_with(|asset_hold| asset_hold.asset.eq(asset_id.clone()))
+        .execute_all()?;
+    assert_eq!(holds, [AssetHold::new(asset_id.clone(), numeric!(70))]);
+    test_client
+        .submit_blocking(HoldAsset::new(asset_id, numeric!(1)))
+        .expect_err("Whole balance is locked");
+
+    Ok(())
+}
+
+#[test]
+fn locked_asset_and_its_recipient_cannot_be_unregistered() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    // Given
+    let asset_definition_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let asset_id = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+    let (mouse_id, _mouse_keypair) = gen_account_in("wonderland");
+    let lock = HashLock {
+        recipient: mouse_id.clone(),
+        ..hash_lock("deal", curr_time() + Duration::from_secs(3600))
+    };
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::account(Account::new(mouse_id.clone())).into(),
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into(),
+        Mint::asset_numeric(100_u32, asset_id.clone()).into(),
+        LockAsset::new(lock).into(),
+    ])?;
+
+    // Then
+    let unregister_instructions: [InstructionBox; 2] = [
+        Unregister::asset(asset_id).into(),
+        Unregister::account(mouse_id).into(),
+    ];
+    for instruction in unregister_instructions {
+        let err = test_client
+            .submit_blocking(instruction)
+            .expect_err("Should keep the locked funds");
+        assert!(is_invariant_violation(&err));
+    }
+
+    Ok(())
+}
+
+fn swap_offer(offered: AssetId, requested: AssetId, expires_at: Duration) -> SwapOffer {
+    SwapOffer::new(
+        SwapOfferId::new(ALICE_ID.clone(), "deal".parse().expect("Valid")),
+        SwapLeg::new(offered, numeric!(50)),
+        SwapLeg::new(requested, numeric!(10)),
+        expires_at.as_millis().try_into().expect("Valid"),
+    )
+}
+
+#[test]
+fn accepted_swap_exchanges_both_legs_atomically() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice_client = network.client();
+    let bob_client = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+
+    // Given
+    let xor_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let tea_id = "tea#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    let mouse_client = network
+        .peer()
+        .client_for(&mouse_id, mouse_keypair.private_key().clone());
+    let alice_xor = AssetId::new(xor_id.clone(), ALICE_ID.clone());
+    let mouse_tea = AssetId::new(tea_id.clone(), mouse_id.clone());
+    alice_client.submit_all_blocking::<InstructionBox>([
+        Register::account(Account::new(mouse_id.clone())).into(),
+        Register::asset_definition(AssetDefinition::numeric(xor_id.clone())).into(),
+        Register::asset_definition(AssetDefinition::numeric(tea_id.clone())).into(),
+        Mint::asset_numeric(100_u32, alice_xor.clone()).into(),
+    ])?;
+    let offer = swap_offer(
+        alice_xor.clone(),
+        mouse_tea.clone(),
+        curr_time() + Duration::from_secs(3600),
+    );
+
+    // Only the owner of the offered asset can propose
+    let err = bob_client
+        .submit_blocking(ProposeSwap::new(offer.clone()))
+        .expect_err("Bob doesn't own the offered asset");
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::NotPermitted(_)
+        ))
+    ));
+    alice_client.submit_blocking(ProposeSwap::new(offer.clone()))?;
+    assert_eq!(
+        alice_client.query(FindSwapOffers::new()).execute_all()?,
+        [offer.clone()]
+    );
+    // Proposing doesn't move any balance
+    assert_eq!(
+        asset_value(&alice_client, alice_xor.clone())?,
+        AssetValue::Numeric(numeric!(100))
+    );
+
+    // Only the owner of the requested asset can accept
+    bob_client
+        .submit_blocking(AcceptSwap::new(offer.clone()))
+        .expect_err("Bob doesn't own the requested asset");
+    // Terms have to match the offer
+    let mut cheaper_offer = offer.clone();
+    cheaper_offer.requested.amount = numeric!(5);
+    let err = mouse_client
+        .submit_blocking(AcceptSwap::new(cheaper_offer))
+        .expect_err("Terms don't match");
+    assert!(is_invariant_violation(&err));
+    // If one leg fails, neither is executed
+    mouse_client
+        .submit_blocking(AcceptSwap::new(offer.clone()))
+        .expect_err("Mouse doesn't have any tea");
+    assert_eq!(
+        asset_value(&alice_client, alice_xor.clone())?,
+        AssetValue::Numeric(numeric!(100))
+    );
+
+    // When
+    alice_client.submit_blocking(Mint::asset_numeric(10_u32, mouse_tea))?;
+    mouse_client.submit_blocking(AcceptSwap::new(offer))?;
+
+    // Then
+    assert_eq!(
+        asset_value(&alice_client, alice_xor)?,
+        AssetValue::Numeric(numeric!(50))
+    );
+    assert_eq!(
+        asset_value(&alice_client, AssetId::new(xor_id, mouse_id))?,
+        AssetValue::Numeric(numeric!(50))
+    );
+    assert_eq!(
+        asset_value(&alice_client, AssetId::new(tea_id, ALICE_ID.clone()))?,
+        AssetValue::Numeric(numeric!(10))
+    );
+    assert!(alice_client
+        .query(FindSwapOffers::new())
+        .execute_all()?
+        .is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn expired_swap_offer_cannot_be_accepted_and_can_be_cancelled_by_anyone() -> Result<()> {
+    const GAP: Duration = Duration::from_secs(2);
+
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice_client = network.client();
+    let bob_client = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+
+    // Given
+    let xor_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let alice_xor = AssetId::new(xor_id.clone(), ALICE_ID.clone());
+    let bob_xor = AssetId::new(xor_id.clone(), BOB_ID.clone());
+    let offer = swap_offer(alice_xor.clone(), bob_xor.clone(), curr_time() + GAP);
+    alice_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(xor_id)).into(),
+        Mint::asset_numeric(100_u32, alice_xor).into(),
+        Mint::asset_numeric(100_u32, bob_xor).into(),
+        ProposeSwap::new(offer.clone()).into(),
+    ])?;
+
+    // The offer can't be cancelled by others before it expires
+    let err = bob_client
+        .submit_blocking(CancelSwap::new(offer.id.clone()))
+        .expect_err("Offer hasn't expired yet");
+    assert!(is_invariant_violation(&err));
+
+    // When
+    std::thread::sleep(GAP);
+
+    // Then
+    let err = bob_client
+        .submit_blocking(AcceptSwap::new(offer.clone()))
+        .expect_err("Offer has expired");
+    assert!(is_invariant_violation(&err));
+    bob_client.submit_blocking(CancelSwap::new(offer.id))?;
+    assert!(alice_client
+        .query(FindSwapOffers::new())
+        .execute_all()?
+        .is_empty());
+
+    Ok(())
+}
@@ -1,5 +1,12 @@
 use eyre::Result;
-use iroha::data_model::{isi::InstructionBox, prelude::*};
+use iroha::data_model::{
+    isi::{
+        error::{InstructionExecutionError, MintabilityError},
+        InstructionBox,
+    },
+    prelude::*,
+    transaction::error::TransactionRejectionReason,
+};
 use iroha_test_network::*;
 use iroha_test_samples::ALICE_ID;
 
@@ -124,3 +131,98 @@ fn non_mintable_asset_can_be_minted_if_registered_with_zero_value() -> Result<()
 
     Ok(())
 }
+
+#[test]
+fn capped_asset_can_be_minted_until_cap_is_reached() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    // Given
+    let asset_definition_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    let create_asset = Register::asset_definition(
+        AssetDefinition::numeric(asset_definition_id.clone()).mintable_capped(numeric!(100)),
+    );
+    let asset_id = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+    let register_asset = Register::asset(Asset::new(asset_id.clone(), 30_u32));
+    let mint = |quantity: u32| Mint::asset_numeric(quantity, asset_id.clone());
+    let total_quantity = || {
+        test_client
+            .query(FindAssetsDefinitions::new())
+            .filter_with(|asset_definition| asset_definition.id.eq(asset_definition_id.clone()))
+            .execute_single()
+            .map(|asset_definition| asset_definition.total_quantity())
+    };
+
+    // We can register and mint the capped token repeatedly
+    test_client.submit_all_blocking::<InstructionBox>([
+        create_asset.into(),
+        register_asset.into(),
+        mint(20).into(),
+        mint(50).into(),
+    ])?;
+    assert_eq!(total_quantity()?, numeric!(100));
+
+    // But not above the cap
+    let err = test_client
+        .submit_blocking(mint(1))
+        .expect_err("Should exceed the cap");
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::InstructionFailed(InstructionExecutionError::Mintability(
+                MintabilityError::MintCapExceeded
+            ))
+        ))
+    ));
+
+    // Burning frees up the supply
+    test_client.submit_all_blocking::<InstructionBox>([
+        Burn::asset_numeric(10_u32, asset_id.clone()).into(),
+        mint(10).into(),
+    ])?;
+    assert_eq!(total_quantity()?, numeric!(100));
+
+    // And the owner can raise the cap
+    test_client.submit_all_blocking::<InstructionBox>([
+        SetMintCap::new(asset_definition_id.clone(), numeric!(150)).into(),
+        mint(50).into(),
+    ])?;
+    assert_eq!(total_quantity()?, numeric!(150));
+    assert!(test_client.submit_blocking(mint(1)).is_err());
+
+    // But can't lower it below the total quantity
+    let err = test_client
+        .submit_blocking(SetMintCap::new(asset_definition_id.clone(), numeric!(149)))
+        .expect_err("Should be below the total quantity");
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::InstructionFailed(InstructionExecutionError::Mintability(
+                MintabilityError::MintCapExceeded
+            ))
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn mint_cap_cannot_be_set_on_non_mintable_asset() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let asset_definition_id = "xor#wonderland"
+        .parse::<AssetDefinitionId>()
+        .expect("Valid");
+    test_client.submit_blocking(Register::asset_definition(
+        AssetDefinition::numeric(asset_definition_id.clone()).mintable_once(),
+    ))?;
+
+    assert!(test_client
+        .submit_blocking(SetMintCap::new(asset_definition_id, numeric!(100)))
+        .is_err());
+
+    Ok(())
+}
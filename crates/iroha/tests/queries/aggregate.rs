@@ -0,0 +1,127 @@
+use eyre::Result;
+use iroha::{
+    client::QueryError,
+    data_model::{prelude::*, query::error::QueryExecutionFail},
+};
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, ALICE_ID};
+
+#[test]
+fn aggregate_assets() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let domain_id: DomainId = "looking_glass".parse()?;
+    test_client.submit_blocking(Register::domain(Domain::new(domain_id.clone())))?;
+
+    let coin: AssetDefinitionId = "coin#wonderland".parse()?;
+    let token: AssetDefinitionId = "token#wonderland".parse()?;
+    test_client.submit_all_blocking([
+        Register::asset_definition(AssetDefinition::numeric(coin.clone())),
+        Register::asset_definition(AssetDefinition::numeric(token.clone())),
+    ])?;
+
+    let accounts: [AccountId; 3] = [
+        ALICE_ID.clone(),
+        gen_account_in("wonderland").0,
+        gen_account_in("looking_glass").0,
+    ];
+    test_client.submit_all_blocking(
+        accounts
+            .iter()
+            .skip(1) // Alice has already been registered in genesis
+            .cloned()
+            .map(|account_id| Register::account(Account::new(account_id))),
+    )?;
+
+    test_client.submit_all_blocking([
+        Mint::asset_numeric(
+            numeric!(10),
+            AssetId::new(coin.clone(), accounts[0].clone()),
+        ),
+        Mint::asset_numeric(
+            numeric!(2.5),
+            AssetId::new(coin.clone(), accounts[1].clone()),
+        ),
+        Mint::asset_numeric(numeric!(7), AssetId::new(coin.clone(), accounts[2].clone())),
+        Mint::asset_numeric(
+            numeric!(1),
+            AssetId::new(token.clone(), accounts[2].clone()),
+        ),
+    ])?;
+
+    let coins = || {
+        test_client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.definition.eq(coin.clone()))
+    };
+
+    // check aggregations over all the results
+    assert_eq!(
+        coins().aggregate(Aggregation::Count)?,
+        AggregateOutput::Value(AggregateValue::Count(3))
+    );
+    let cases = [
+        (Aggregation::Sum, numeric!(19.5)),
+        (Aggregation::Min, numeric!(2.5)),
+        (Aggregation::Max, numeric!(10)),
+    ];
+    for (aggregation, expected) in cases {
+        let output = coins()
+            .select_with(|asset| asset.value.numeric)
+            .aggregate(aggregation)?;
+        assert_eq!(
+            output,
+            AggregateOutput::Value(AggregateValue::Numeric(Some(expected)))
+        );
+    }
+
+    // check aggregation by group, the groups are ordered by the key
+    let AggregateOutput::Groups(supply_by_domain) = coins()
+        .select_with(|asset| (asset.id.account.domain, asset.value.numeric))
+        .aggregate_by_group(Aggregation::Sum)?
+    else {
+        panic!("Expected grouped output");
+    };
+    assert_eq!(
+        supply_by_domain.into_typed::<DomainId>().unwrap(),
+        vec![
+            (domain_id, AggregateValue::Numeric(Some(numeric!(7)))),
+            (
+                "wonderland".parse()?,
+                AggregateValue::Numeric(Some(numeric!(12.5)))
+            ),
+        ]
+    );
+    let AggregateOutput::Groups(holders_by_definition) = test_client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.account.eq(accounts[2].clone()))
+        .select_with(|asset| asset.id.definition)
+        .aggregate_by_group(Aggregation::Count)?
+    else {
+        panic!("Expected grouped output");
+    };
+    assert_eq!(
+        holders_by_definition
+            .into_typed::<AssetDefinitionId>()
+            .unwrap(),
+        vec![
+            (coin.clone(), AggregateValue::Count(1)),
+            (token, AggregateValue::Count(1)),
+        ]
+    );
+
+    // check that only numeric fields can be summed
+    let err = coins()
+        .select_with(|asset| asset.id.account)
+        .aggregate(Aggregation::Sum)
+        .expect_err("Should fail");
+    assert!(matches!(
+        err,
+        QueryError::Validation(ValidationFail::QueryFailed(
+            QueryExecutionFail::InvalidAggregation
+        ))
+    ));
+
+    Ok(())
+}
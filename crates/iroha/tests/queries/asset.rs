@@ -119,6 +119,109 @@ fn find_asset_total_quantity() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn find_assets_by_numeric_value() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let test_client = network.client();
+
+    let definition_id: AssetDefinitionId = "coin#wonderland".parse()?;
+    test_client.submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+        definition_id.clone(),
+    )))?;
+
+    let accounts: [AccountId; 4] = [
+        ALICE_ID.clone(),
+        gen_account_in("wonderland").0,
+        gen_account_in("wonderland").0,
+        gen_account_in("wonderland").0,
+    ];
+    let register_accounts = accounts
+        .iter()
+        .skip(1) // Alice has already been registered in genesis
+        .cloned()
+        .map(|account_id| Register::account(Account::new(account_id)))
+        .collect::<Vec<_>>();
+    test_client.submit_all_blocking(register_accounts)?;
+
+    let balances = [numeric!(1), numeric!(5), numeric!(10), numeric!(20.50)];
+    let mint_assets = accounts
+        .iter()
+        .cloned()
+        .zip(balances)
+        .map(|(account_id, balance)| {
+            Mint::asset_numeric(balance, AssetId::new(definition_id.clone(), account_id))
+        })
+        .collect::<Vec<_>>();
+    test_client.submit_all_blocking(mint_assets)?;
+
+    let find_holders = |predicate: CompoundPredicate<Asset>| -> Result<Vec<AccountId>> {
+        let mut holders = test_client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.definition.eq(definition_id.clone()))
+            .filter(predicate)
+            .select_with(|asset| asset.id.account)
+            .execute_all()?;
+        holders.sort();
+        Ok(holders)
+    };
+    let expected_holders = |indices: &[usize]| {
+        let mut holders = indices
+            .iter()
+            .map(|&idx| accounts[idx].clone())
+            .collect::<Vec<_>>();
+        holders.sort();
+        holders
+    };
+
+    let cases: [(CompoundPredicate<Asset>, &[usize]); 5] = [
+        (
+            CompoundPredicate::<Asset>::build(|asset| asset.value.numeric.gt(numeric!(5))),
+            &[2, 3],
+        ),
+        (
+            CompoundPredicate::<Asset>::build(|asset| asset.value.numeric.le(numeric!(5))),
+            &[0, 1],
+        ),
+        // equality does not depend on the scale
+        (
+            CompoundPredicate::<Asset>::build(|asset| asset.value.numeric.eq(numeric!(10.0))),
+            &[2],
+        ),
+        (
+            CompoundPredicate::<Asset>::build(|asset| {
+                asset
+                    .value
+                    .numeric
+                    .in_range((numeric!(5)..numeric!(20.5)).into())
+            }),
+            &[1, 2],
+        ),
+        (
+            CompoundPredicate::<Asset>::build(|asset| asset.value.numeric.scale_eq(2)),
+            &[3],
+        ),
+    ];
+    for (predicate, expected) in cases {
+        assert_eq!(find_holders(predicate)?, expected_holders(expected));
+    }
+
+    let definitions = test_client
+        .query(FindAssetsDefinitions::new())
+        .filter_with(|asset_definition| asset_definition.total_quantity.ge(numeric!(36.5)))
+        .select_with(|asset_definition| asset_definition.id)
+        .execute_all()?;
+    assert!(definitions.contains(&definition_id));
+
+    let definitions = test_client
+        .query(FindAssetsDefinitions::new())
+        .filter_with(|asset_definition| asset_definition.total_quantity.lt(numeric!(36.5)))
+        .select_with(|asset_definition| asset_definition.id)
+        .execute_all()?;
+    assert!(!definitions.contains(&definition_id));
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 fn test_total_quantity<T>(
     test_client: &Client,
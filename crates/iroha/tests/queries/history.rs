@@ -0,0 +1,97 @@
+use std::num::NonZeroU64;
+
+use eyre::Result;
+use iroha::{
+    client::{Client, QueryError},
+    data_model::{
+        parameter::{BlockParameter, Parameter},
+        prelude::*,
+        query::error::QueryExecutionFail,
+    },
+};
+use iroha_test_network::*;
+use iroha_test_samples::ALICE_ID;
+use nonzero_ext::nonzero;
+
+fn latest_height(client: &Client) -> Result<NonZeroU64> {
+    Ok(NonZeroU64::new(client.get_status()?.blocks).expect("Genesis is committed"))
+}
+
+#[test]
+fn query_recent_heights() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config(|c| {
+            c.write(["torii", "query_history_depth"], 2);
+        })
+        .start_blocking()?;
+    let test_client = network.client();
+
+    let definition_id: AssetDefinitionId = "history#wonderland".parse()?;
+    let asset_id = AssetId::new(definition_id.clone(), ALICE_ID.clone());
+    test_client.submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+        definition_id,
+    )))?;
+    let registered_at = latest_height(&test_client)?;
+
+    let mut heights = Vec::new();
+    for quantity in [1_u32, 2, 4] {
+        test_client.submit_blocking(Mint::asset_numeric(quantity, asset_id.clone()))?;
+        heights.push(latest_height(&test_client)?);
+    }
+
+    let quantity_at = |height| {
+        test_client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.eq(asset_id.clone()))
+            .select_with(|asset| asset.value.numeric)
+            .at_height(height)
+            .execute_single()
+    };
+
+    // the latest height is always available
+    assert_eq!(quantity_at(heights[2])?, numeric!(7));
+    // the previous height is retained
+    assert_eq!(quantity_at(heights[1])?, numeric!(3));
+
+    // heights out of the retained window and heights in the future are rejected
+    for height in [registered_at, heights[2].checked_add(1).unwrap()] {
+        let err = test_client
+            .query(FindAssets::new())
+            .at_height(height)
+            .execute_all()
+            .expect_err("Should fail");
+        assert!(matches!(
+            err,
+            QueryError::Validation(ValidationFail::QueryFailed(
+                QueryExecutionFail::HeightNotAvailable(h)
+            )) if h == height.get()
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn singular_query_recent_heights() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config(|c| {
+            c.write(["torii", "query_history_depth"], 2);
+        })
+        .start_blocking()?;
+    let test_client = network.client();
+
+    test_client.submit_blocking(SetParameter::new(Parameter::Block(
+        BlockParameter::MaxTransactions(nonzero!(16_u64)),
+    )))?;
+    let before = latest_height(&test_client)?;
+    test_client.submit_blocking(SetParameter::new(Parameter::Block(
+        BlockParameter::MaxTransactions(nonzero!(32_u64)),
+    )))?;
+
+    let params = test_client.query_single_at_height(FindParameters::new(), before)?;
+    assert_eq!(params.block().max_transactions(), nonzero!(16_u64));
+    let params = test_client.query_single(FindParameters::new())?;
+    assert_eq!(params.block().max_transactions(), nonzero!(32_u64));
+
+    Ok(())
+}
@@ -87,3 +87,74 @@ fn find_accounts_with_asset() {
     assert_eq!(accounts.len(), 1);
     assert_eq!(accounts[0], BOB_ID.clone());
 }
+
+#[test]
+fn find_accounts_with_metadata_predicates() {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let test_client = network.client();
+
+    let kyc_level = Name::from_str("kyc_level").unwrap();
+    let profile = Name::from_str("profile").unwrap();
+
+    test_client
+        .submit_all_blocking([
+            SetKeyValue::account(ALICE_ID.clone(), kyc_level.clone(), 1_u32),
+            SetKeyValue::account(BOB_ID.clone(), kyc_level.clone(), 3_u32),
+            SetKeyValue::account(
+                BOB_ID.clone(),
+                profile.clone(),
+                json!({"country": "JP", "limits": {"daily": "150.5"}}),
+            ),
+        ])
+        .unwrap();
+
+    let find_accounts = |predicate: CompoundPredicate<Account>| {
+        test_client
+            .query(FindAccounts)
+            .filter_with(|account| account.id.eq(ALICE_ID.clone()) | account.id.eq(BOB_ID.clone()))
+            .filter(predicate)
+            .select_with(|account| account.id)
+            .execute_all()
+            .unwrap()
+    };
+
+    // check key presence
+    let has_profile = CompoundPredicate::<Account>::build(|account| {
+        account.metadata.contains_key(profile.clone())
+    });
+    assert_eq!(find_accounts(has_profile), vec![BOB_ID.clone()]);
+
+    // check numeric comparison on a top-level value
+    let kyc_passed = CompoundPredicate::<Account>::build(|account| {
+        account
+            .metadata
+            .key(kyc_level.clone())
+            .numeric(NumericPredicateAtom::Ge(numeric!(2)))
+    });
+    assert_eq!(find_accounts(kyc_passed), vec![BOB_ID.clone()]);
+
+    // check comparisons on nested values, numbers stored as strings are compared as numbers
+    let from_japan = CompoundPredicate::<Account>::build(|account| {
+        account.metadata.key(profile.clone()).pointer(
+            "/country",
+            JsonValuePredicateAtom::String(StringPredicateAtom::Equals("JP".to_owned())),
+        )
+    });
+    assert_eq!(find_accounts(from_japan), vec![BOB_ID.clone()]);
+    let low_daily_limit = CompoundPredicate::<Account>::build(|account| {
+        account.metadata.key(profile.clone()).pointer(
+            "/limits/daily",
+            JsonValuePredicateAtom::Numeric(NumericPredicateAtom::Lt(numeric!(200))),
+        )
+    });
+    assert_eq!(find_accounts(low_daily_limit), vec![BOB_ID.clone()]);
+
+    // check that a pointer to a missing value evaluates to false
+    let missing = CompoundPredicate::<Account>::build(|account| {
+        account
+            .metadata
+            .key(profile.clone())
+            .pointer("/missing", JsonValuePredicateAtom::Equals(Json::new("JP")))
+    });
+    assert!(find_accounts(missing).is_empty());
+}
@@ -8,7 +8,9 @@ use iroha::{
 use iroha_test_network::*;
 
 mod account;
+mod aggregate;
 mod asset;
+mod history;
 mod metadata;
 mod query_errors;
 mod role;
@@ -0,0 +1,82 @@
+use eyre::Result;
+use iroha::{client::QueryError, data_model::prelude::*};
+use iroha_test_network::*;
+use iroha_test_samples::{BOB_ID, BOB_KEYPAIR};
+
+#[test]
+fn queries_are_limited_per_account() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new()
+        .with_config(|c| {
+            c.write(
+                [
+                    "torii",
+                    "rate_limits",
+                    "queries_per_account",
+                    "rate_per_sec",
+                ],
+                1,
+            )
+            .write(["torii", "rate_limits", "queries_per_account", "burst"], 2);
+        })
+        .start_blocking()?;
+    let alice_client = network.client();
+    let bob_client = network
+        .peer()
+        .client_for(&BOB_ID, BOB_KEYPAIR.private_key().clone());
+
+    // Burst is allowed
+    for _ in 0..2 {
+        alice_client.query(FindDomains::new()).execute_all()?;
+    }
+
+    // Then the account is throttled
+    let Err(QueryError::Other(err)) = alice_client.query(FindDomains::new()).execute_all() else {
+        panic!("Query should be throttled");
+    };
+    assert!(err.to_string().contains("429"), "{err}");
+
+    // Other accounts aren't affected
+    bob_client.query(FindDomains::new()).execute_all()?;
+
+    Ok(())
+}
+
+#[test]
+fn subscriptions_are_limited_per_ip() -> Result<()> {
+    let (network, rt) = NetworkBuilder::new()
+        .with_config(|c| {
+            c.write(
+                [
+                    "torii",
+                    "rate_limits",
+                    "subscriptions_per_ip",
+                    "rate_per_sec",
+                ],
+                1,
+            )
+            .write(["torii", "rate_limits", "subscriptions_per_ip", "burst"], 2);
+        })
+        .start_blocking()?;
+    let test_client = network.client();
+    let url = test_client.torii_url.join("events")?;
+
+    // The peer monitor reports new blocks only once it has subscribed to the events,
+    // so it can't take a token from the bucket afterwards
+    test_client.submit(Register::domain(Domain::new("neverland".parse()?)))?;
+    rt.block_on(async { network.ensure_blocks(2).await })?;
+
+    // Tokens are refilled once per second, so the bucket is emptied within a few quick requests
+    let throttled = (0..10)
+        .map(|_| attohttpc::get(url.clone()).send())
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .find(|response| response.status().as_u16() == 429)
+        .expect("Subscriptions should be throttled");
+    assert!(throttled.headers().contains_key("retry-after"));
+
+    // Other endpoints aren't affected
+    let url = test_client.torii_url.join("health")?;
+    assert!(attohttpc::get(url).send()?.is_success());
+
+    Ok(())
+}
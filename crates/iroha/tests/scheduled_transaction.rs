@@ -0,0 +1,46 @@
+use std::time::{Duration, SystemTime};
+
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_test_network::*;
+use iroha_test_samples::ALICE_ID;
+
+fn curr_time() -> Duration {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+}
+
+#[test]
+fn scheduled_transaction_is_held_until_not_before() -> Result<()> {
+    const DELAY: Duration = Duration::from_secs(3);
+
+    let (network, rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let neverland_exists = || -> Result<bool> {
+        Ok(!test_client
+            .query(FindDomains::new())
+            .filter_with(|domain| domain.id.eq("neverland".parse().expect("Valid")))
+            .execute_all()?
+            .is_empty())
+    };
+
+    // When
+    let not_before = curr_time() + DELAY;
+    let mut builder = TransactionBuilder::new(network.chain_id(), ALICE_ID.clone())
+        .with_instructions([Register::domain(Domain::new("neverland".parse()?))]);
+    builder.set_not_before(not_before);
+    test_client.submit_transaction(&test_client.sign_transaction(builder))?;
+
+    // Then the transaction waits in the queue
+    assert_eq!(test_client.get_status()?.scheduled_queue_size, 1);
+    assert!(!neverland_exists()?);
+
+    // And is committed once it becomes valid
+    rt.block_on(async { network.ensure_blocks(2).await })?;
+    assert!(curr_time() >= not_before);
+    assert!(neverland_exists()?);
+    assert_eq!(test_client.get_status()?.scheduled_queue_size, 0);
+
+    Ok(())
+}
@@ -28,3 +28,38 @@ fn can_change_parameter_value() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn prunable_blocks_are_hidden_and_max_prunable_height_is_not_decreased() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    test_client.submit_blocking(SetParameter::new(Parameter::Block(
+        BlockParameter::MaxPrunableHeight(2),
+    )))?;
+    test_client.submit_blocking(Register::domain(Domain::new("pruning".parse()?)))?;
+
+    let heights = test_client
+        .query(FindBlockHeaders::new())
+        .execute_all()?
+        .into_iter()
+        .map(|header| header.height().get())
+        .collect::<Vec<_>>();
+    assert!(heights.contains(&1));
+    assert!(!heights.contains(&2));
+    assert!(heights.contains(&3));
+
+    let err = test_client
+        .submit_blocking(SetParameter::new(Parameter::Block(
+            BlockParameter::MaxPrunableHeight(1),
+        )))
+        .expect_err("Should fail");
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::InstructionFailed(InstructionExecutionError::InvariantViolation(_))
+        ))
+    ));
+
+    Ok(())
+}
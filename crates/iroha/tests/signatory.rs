@@ -0,0 +1,129 @@
+use eyre::Result;
+use iroha::{
+    crypto::KeyPair,
+    data_model::{
+        isi::error::InstructionExecutionError, prelude::*,
+        transaction::error::TransactionRejectionReason,
+    },
+};
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, ALICE_ID, ALICE_KEYPAIR};
+
+fn register_domain(name: &str) -> Register<Domain> {
+    Register::domain(Domain::new(name.parse().expect("Valid")))
+}
+
+#[test]
+fn added_signatory_can_sign_for_account() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice_client = network.client();
+    let new_key_pair = KeyPair::random();
+    let new_key_client = network
+        .peer()
+        .client_for(&ALICE_ID, new_key_pair.private_key().clone());
+
+    // A key which isn't a signatory of Alice can't sign for her
+    new_key_client
+        .submit_blocking(register_domain("neverland"))
+        .expect_err("Key isn't a signatory of Alice");
+
+    // When
+    alice_client.submit_blocking(AddSignatory::new(
+        ALICE_ID.clone(),
+        new_key_pair.public_key().clone(),
+    ))?;
+
+    // Then both keys can sign for Alice
+    new_key_client.submit_blocking(register_domain("neverland"))?;
+    alice_client.submit_blocking(register_domain("underland"))?;
+
+    // When
+    new_key_client.submit_blocking(RemoveSignatory::new(
+        ALICE_ID.clone(),
+        ALICE_KEYPAIR.public_key().clone(),
+    ))?;
+
+    // Then only the remaining key can sign for Alice
+    alice_client
+        .submit_blocking(register_domain("overland"))
+        .expect_err("Key was removed from Alice's signatories");
+    new_key_client.submit_blocking(register_domain("overland"))?;
+
+    Ok(())
+}
+
+#[test]
+fn rotated_key_replaces_all_signatories() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice_client = network.client();
+    let new_key_pair = KeyPair::random();
+    let new_key_client = network
+        .peer()
+        .client_for(&ALICE_ID, new_key_pair.private_key().clone());
+
+    // When
+    alice_client.submit_blocking(RotateKey::new(
+        ALICE_ID.clone(),
+        new_key_pair.public_key().clone(),
+    ))?;
+
+    // Then only the new key can sign for the account, which keeps its id
+    alice_client
+        .submit_blocking(register_domain("neverland"))
+        .expect_err("Old key was rotated out");
+    new_key_client.submit_blocking(register_domain("neverland"))?;
+    let domain = new_key_client
+        .query(FindDomains::new())
+        .filter_with(|domain| domain.id.eq("neverland".parse().expect("Valid")))
+        .execute_single()?;
+    assert_eq!(domain.owned_by(), &*ALICE_ID);
+    alice_client
+        .query(FindDomains::new())
+        .execute_all()
+        .expect_err("Old key can't sign queries either");
+
+    // The last signatory can't be removed
+    let err = new_key_client
+        .submit_blocking(RemoveSignatory::new(
+            ALICE_ID.clone(),
+            new_key_pair.public_key().clone(),
+        ))
+        .expect_err("Account must keep at least one signatory");
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::InstructionFailed(InstructionExecutionError::InvariantViolation(_))
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn signatories_of_another_account_cant_be_changed() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let alice_client = network.client();
+    let (mouse_id, mouse_key_pair) = gen_account_in("wonderland");
+    let mouse_client = network
+        .peer()
+        .client_for(&mouse_id, mouse_key_pair.private_key().clone());
+    alice_client.submit_blocking(Register::account(Account::new(mouse_id.clone())))?;
+
+    // When
+    let err = mouse_client
+        .submit_blocking(AddSignatory::new(
+            ALICE_ID.clone(),
+            mouse_key_pair.public_key().clone(),
+        ))
+        .expect_err("Mouse doesn't own Alice's account");
+
+    // Then
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::NotPermitted(_)
+        ))
+    ));
+
+    Ok(())
+}
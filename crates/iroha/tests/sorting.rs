@@ -345,3 +345,59 @@ fn sort_only_elements_which_have_sorting_key() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn sort_by_multiple_fields() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let test_client = network.client();
+
+    // quantities with ties, so that the second key decides the order of some assets
+    let input = [
+        ("sorted_a", 3_u32),
+        ("sorted_b", 1),
+        ("sorted_c", 3),
+        ("sorted_d", 2),
+    ];
+    let mut instructions = vec![];
+    for (name, quantity) in input {
+        let asset_definition_id = format!("{name}#wonderland")
+            .parse::<AssetDefinitionId>()
+            .expect("Valid");
+        instructions.push(InstructionBox::from(Register::asset_definition(
+            AssetDefinition::numeric(asset_definition_id.clone()),
+        )));
+        instructions.push(InstructionBox::from(Mint::asset_numeric(
+            quantity,
+            AssetId::new(asset_definition_id, ALICE_ID.clone()),
+        )));
+    }
+    test_client
+        .submit_all_blocking(instructions)
+        .wrap_err("Failed to register assets")?;
+
+    let res = test_client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.definition.name.starts_with("sorted_"))
+        .sort_by_with(SortOrder::Descending, |asset| asset.value.numeric)
+        .sort_by_with(SortOrder::Ascending, |asset| asset.id.definition.name)
+        .select_with(|asset| asset.id.definition.name)
+        .execute_all()
+        .wrap_err("Failed to submit request")?;
+    let expected = ["sorted_a", "sorted_c", "sorted_d", "sorted_b"];
+    assert_eq!(res, expected.map(|name| name.parse::<Name>().unwrap()));
+
+    // pagination is applied after sorting
+    let res = test_client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.definition.name.starts_with("sorted_"))
+        .sort_by_with(SortOrder::Descending, |asset| asset.value.numeric)
+        .sort_by_with(SortOrder::Descending, |asset| asset.id.definition.name)
+        .with_pagination(Pagination::new(Some(nonzero!(2_u64)), 1))
+        .select_with(|asset| asset.id.definition.name)
+        .execute_all()
+        .wrap_err("Failed to submit request")?;
+    let expected = ["sorted_a", "sorted_d"];
+    assert_eq!(res, expected.map(|name| name.parse::<Name>().unwrap()));
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use std::{
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use eyre::Result;
+use iroha::data_model::prelude::*;
+use iroha_executor_data_model::permission::account::CanModifyAccountMetadata;
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, is_invariant_violation, ALICE_ID};
+
+fn curr_time() -> Duration {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+}
+
+#[test]
+fn temporary_grants_are_revoked_after_expiry() -> Result<()> {
+    const LIFETIME: Duration = Duration::from_secs(3);
+
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let (mouse_id, _mouse_keypair) = gen_account_in("wonderland");
+    let role_id = "ON_CALL".parse::<RoleId>()?;
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::account(Account::new(mouse_id.clone())).into(),
+        Register::role(Role::new(role_id.clone(), ALICE_ID.clone())).into(),
+    ])?;
+
+    // When
+    let expires_at = curr_time() + LIFETIME;
+    test_client.submit_all_blocking::<InstructionBox>([
+        Grant::account_permission(
+            CanModifyAccountMetadata {
+                account: ALICE_ID.clone(),
+            },
+            mouse_id.clone(),
+        )
+        .expiring_at(expires_at)
+        .into(),
+        Grant::account_role(role_id, mouse_id.clone())
+            .expiring_at(expires_at)
+            .into(),
+    ])?;
+
+    // Then the grants are reported along with their remaining lifetime
+    let grants = test_client.query_single(FindTemporaryGrantsByAccountId::new(mouse_id.clone()))?;
+    assert_eq!(grants.len(), 2);
+    for grant in &grants {
+        assert_eq!(grant.expires_at().as_millis(), expires_at.as_millis());
+        assert!(Duration::ZERO < grant.remaining() && grant.remaining() <= LIFETIME);
+    }
+
+    // And are revoked by the first block created after the expiry
+    thread::sleep(LIFETIME);
+    test_client.submit_blocking(Register::domain(Domain::new("neverland".parse()?)))?;
+    assert!(test_client
+        .query(FindPermissionsByAccountId::new(mouse_id.clone()))
+        .execute_all()?
+        .is_empty());
+    assert!(test_client
+        .query(FindRolesByAccountId::new(mouse_id.clone()))
+        .execute_all()?
+        .is_empty());
+    assert!(test_client
+        .query_single(FindTemporaryGrantsByAccountId::new(mouse_id))?
+        .is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn temporary_role_permissions_are_revoked_after_expiry() -> Result<()> {
+    const LIFETIME: Duration = Duration::from_secs(3);
+
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let role_id = "ON_CALL".parse::<RoleId>()?;
+    test_client.submit_blocking(Register::role(Role::new(role_id.clone(), ALICE_ID.clone())))?;
+    let role_permissions_count = || -> Result<usize> {
+        Ok(test_client
+            .query(FindRoles::new())
+            .filter_with(|role| role.id.eq(role_id.clone()))
+            .execute_single()?
+            .permissions()
+            .len())
+    };
+
+    // When
+    test_client.submit_blocking(
+        Grant::role_permission(
+            CanModifyAccountMetadata {
+                account: ALICE_ID.clone(),
+            },
+            role_id.clone(),
+        )
+        .expiring_at(curr_time() + LIFETIME),
+    )?;
+    assert_eq!(role_permissions_count()?, 1);
+
+    // Then
+    thread::sleep(LIFETIME);
+    test_client.submit_blocking(Register::domain(Domain::new("neverland".parse()?)))?;
+    assert_eq!(role_permissions_count()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn temporary_grants_are_removed_with_their_role() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let (mouse_id, _mouse_keypair) = gen_account_in("wonderland");
+    let role_id = "ON_CALL".parse::<RoleId>()?;
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::account(Account::new(mouse_id.clone())).into(),
+        Register::role(Role::new(role_id.clone(), ALICE_ID.clone())).into(),
+    ])?;
+    test_client.submit_blocking(
+        Grant::account_role(role_id.clone(), mouse_id.clone())
+            .expiring_at(curr_time() + Duration::from_secs(3600)),
+    )?;
+
+    // When
+    test_client.submit_blocking(Unregister::role(role_id))?;
+
+    // Then
+    assert!(test_client
+        .query_single(FindTemporaryGrantsByAccountId::new(mouse_id))?
+        .is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn expired_grants_are_rejected() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let (mouse_id, _mouse_keypair) = gen_account_in("wonderland");
+    let role_id = "ON_CALL".parse::<RoleId>()?;
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::account(Account::new(mouse_id.clone())).into(),
+        Register::role(Role::new(role_id.clone(), ALICE_ID.clone())).into(),
+    ])?;
+    let permission = CanModifyAccountMetadata {
+        account: ALICE_ID.clone(),
+    };
+    let expired_at = curr_time() - Duration::from_secs(1);
+
+    let err = test_client
+        .submit_blocking(
+            Grant::account_permission(permission.clone(), mouse_id).expiring_at(expired_at),
+        )
+        .expect_err("Grant has already expired");
+    assert!(is_invariant_violation(&err));
+
+    let err = test_client
+        .submit_blocking(Grant::role_permission(permission, role_id).expiring_at(expired_at))
+        .expect_err("Grant has already expired");
+    assert!(is_invariant_violation(&err));
+
+    Ok(())
+}
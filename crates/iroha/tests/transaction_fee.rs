@@ -0,0 +1,198 @@
+use eyre::Result;
+use iroha::{
+    client::Client,
+    data_model::{
+        isi::InstructionBox, parameter::Parameter, prelude::*,
+        transaction::error::TransactionRejectionReason,
+    },
+};
+use iroha_test_network::*;
+use iroha_test_samples::{gen_account_in, ALICE_ID, BOB_ID};
+
+fn asset_value(client: &Client, asset_id: AssetId) -> Result<AssetValue> {
+    Ok(client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.eq(asset_id))
+        .execute_single()?
+        .value()
+        .clone())
+}
+
+fn committed_fee(
+    client: &Client,
+    hash: HashOf<SignedTransaction>,
+) -> Result<Option<TransactionFee>> {
+    Ok(client
+        .query(FindTransactions::new())
+        .filter_with(|tx| tx.value.hash.eq(hash))
+        .execute_single()?
+        .fee()
+        .clone())
+}
+
+/// Register the fee asset, give Alice 100 of it and charge `1 + 2 * instructions` per transaction
+fn setup_fees(client: &Client) -> Result<AssetDefinitionId> {
+    let fee_asset = "fee#wonderland".parse::<AssetDefinitionId>()?;
+    client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(fee_asset.clone())).into(),
+        Mint::asset_numeric(100_u32, AssetId::new(fee_asset.clone(), ALICE_ID.clone())).into(),
+        SetParameter::new(Parameter::Fee(Some(FeeSchedule::new(
+            fee_asset.clone(),
+            BOB_ID.clone(),
+            numeric!(1),
+            numeric!(2),
+            numeric!(0),
+        ))))
+        .into(),
+    ])?;
+
+    Ok(fee_asset)
+}
+
+#[test]
+fn fee_is_paid_to_collector_and_reported() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let fee_asset = setup_fees(&test_client)?;
+    let alice_fee_asset = AssetId::new(fee_asset.clone(), ALICE_ID.clone());
+
+    // When
+    let hash = test_client.submit_all_blocking::<InstructionBox>([
+        Register::domain(Domain::new("neverland".parse()?)).into(),
+        Register::domain(Domain::new("underland".parse()?)).into(),
+    ])?;
+
+    // Then
+    assert_eq!(
+        asset_value(&test_client, alice_fee_asset.clone())?,
+        AssetValue::Numeric(numeric!(95))
+    );
+    assert_eq!(
+        asset_value(&test_client, AssetId::new(fee_asset, BOB_ID.clone()))?,
+        AssetValue::Numeric(numeric!(5))
+    );
+    assert_eq!(
+        committed_fee(&test_client, hash)?,
+        Some(TransactionFee {
+            asset: alice_fee_asset,
+            amount: numeric!(5),
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn tip_is_paid_on_top_of_fee() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let fee_asset = setup_fees(&test_client)?;
+    let alice_fee_asset = AssetId::new(fee_asset, ALICE_ID.clone());
+
+    // When
+    let mut transaction = TransactionBuilder::new(network.chain_id(), ALICE_ID.clone())
+        .with_instructions([Register::domain(Domain::new("neverland".parse()?))]);
+    transaction.set_tip(numeric!(10));
+    let transaction = test_client.sign_transaction(transaction);
+    test_client.submit_transaction_blocking(&transaction)?;
+
+    // Then
+    assert_eq!(
+        asset_value(&test_client, alice_fee_asset.clone())?,
+        AssetValue::Numeric(numeric!(87))
+    );
+    assert_eq!(
+        committed_fee(&test_client, transaction.hash())?,
+        Some(TransactionFee {
+            asset: alice_fee_asset,
+            amount: numeric!(13),
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn fee_is_paid_for_rejected_transaction() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let fee_asset = setup_fees(&test_client)?;
+    let alice_fee_asset = AssetId::new(fee_asset, ALICE_ID.clone());
+
+    // When
+    let transaction = test_client.build_transaction(
+        [Register::domain(Domain::new("wonderland".parse()?))],
+        Metadata::default(),
+    );
+    test_client
+        .submit_transaction_blocking(&transaction)
+        .expect_err("Domain already exists");
+
+    // Then
+    assert_eq!(
+        asset_value(&test_client, alice_fee_asset.clone())?,
+        AssetValue::Numeric(numeric!(97))
+    );
+    assert_eq!(
+        committed_fee(&test_client, transaction.hash())?,
+        Some(TransactionFee {
+            asset: alice_fee_asset,
+            amount: numeric!(3),
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn transaction_is_rejected_if_fee_cant_be_paid() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    let mouse_client = network
+        .peer()
+        .client_for(&mouse_id, mouse_keypair.private_key().clone());
+    test_client.submit_blocking(Register::account(Account::new(mouse_id.clone())))?;
+    setup_fees(&test_client)?;
+
+    // When
+    let err = mouse_client
+        .submit_blocking(Register::domain(Domain::new("neverland".parse()?)))
+        .expect_err("Mouse doesn't have the fee asset");
+
+    // Then
+    assert!(matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::InsufficientFee(_))
+    ));
+    assert!(test_client
+        .query(FindDomains::new())
+        .filter_with(|domain| domain.id.eq("neverland".parse().expect("Valid")))
+        .execute_all()?
+        .is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn fee_asset_and_collector_cant_be_unregistered() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+    let fee_asset = setup_fees(&test_client)?;
+
+    // Then
+    let _err = test_client
+        .submit_blocking(Unregister::asset_definition(fee_asset.clone()))
+        .expect_err("Fee asset is used by the fee schedule");
+    let _err = test_client
+        .submit_blocking(Unregister::account(BOB_ID.clone()))
+        .expect_err("Bob collects the fees");
+
+    // When
+    test_client.submit_blocking(SetParameter::new(Parameter::Fee(None)))?;
+
+    // Then
+    test_client.submit_blocking(Unregister::asset_definition(fee_asset))?;
+
+    Ok(())
+}
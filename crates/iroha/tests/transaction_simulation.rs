@@ -0,0 +1,64 @@
+use eyre::Result;
+use iroha::data_model::{
+    isi::error::InstructionExecutionError, prelude::*,
+    transaction::error::TransactionRejectionReason,
+};
+use iroha_test_network::*;
+
+fn neverland_exists(client: &iroha::client::Client) -> Result<bool> {
+    Ok(!client
+        .query(FindDomains::new())
+        .filter_with(|domain| domain.id.eq("neverland".parse().expect("Valid")))
+        .execute_all()?
+        .is_empty())
+}
+
+#[test]
+fn simulated_transaction_is_not_committed() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let neverland_id = "neverland".parse::<DomainId>()?;
+
+    // When
+    let simulation =
+        test_client.simulate_all([Register::domain(Domain::new(neverland_id.clone()))])?;
+
+    // Then the outcome is reported
+    assert_eq!(simulation.rejection_reason(), &None);
+    assert_eq!(simulation.instructions, 1);
+    assert_eq!(simulation.fuel, 0);
+    assert!(simulation.events().iter().any(|event| matches!(
+        event,
+        DataEvent::Domain(DomainEvent::Created(domain)) if *domain.id() == neverland_id
+    )));
+
+    // But the transaction is neither queued nor committed
+    assert_eq!(test_client.get_status()?.queue_size, 0);
+    assert!(!neverland_exists(&test_client)?);
+
+    Ok(())
+}
+
+#[test]
+fn simulation_reports_rejection_reason() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    // When
+    let simulation =
+        test_client.simulate_all([Register::domain(Domain::new("wonderland".parse()?))])?;
+
+    // Then
+    assert!(matches!(
+        simulation.rejection_reason(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::InstructionFailed(InstructionExecutionError::Repetition(_))
+        ))
+    ));
+    assert!(simulation.events().is_empty());
+    // Resources consumed before the rejection are still reported
+    assert_eq!(simulation.instructions, 1);
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use eyre::Result;
+use iroha::data_model::{
+    events::pipeline::TransactionStatus, prelude::*, transaction::error::TransactionRejectionReason,
+};
+use iroha_test_network::*;
+
+#[test]
+fn status_of_committed_and_rejected_transactions() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    // When
+    let committed = test_client.submit(Register::domain(Domain::new("neverland".parse()?)))?;
+    test_client.poll_transaction_status(&committed)?;
+
+    // Then
+    let event = test_client
+        .get_transaction_status(&committed)?
+        .expect("Transaction is committed");
+    assert_eq!(event.status(), &TransactionStatus::Approved);
+    assert!(event.block_height().is_some());
+
+    // When
+    let rejected = test_client.submit(Register::domain(Domain::new("neverland".parse()?)))?;
+    let err = test_client
+        .poll_transaction_status(&rejected)
+        .expect_err("Domain already exists");
+
+    // Then
+    assert!(err.downcast_ref::<TransactionRejectionReason>().is_some());
+    let event = test_client
+        .get_transaction_status(&rejected)?
+        .expect("Transaction is rejected");
+    assert!(matches!(event.status(), TransactionStatus::Rejected(_)));
+    assert!(event.block_height().is_some());
+
+    Ok(())
+}
+
+#[test]
+fn status_of_unknown_transaction() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let transaction = test_client.build_transaction(
+        [Register::domain(Domain::new("neverland".parse()?))],
+        Metadata::default(),
+    );
+
+    assert_eq!(
+        test_client.get_transaction_status(&transaction.hash())?,
+        None
+    );
+
+    Ok(())
+}
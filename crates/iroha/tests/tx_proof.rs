@@ -0,0 +1,35 @@
+use eyre::Result;
+use iroha::{client::verify_transaction_inclusion, data_model::prelude::*};
+use iroha_test_network::*;
+
+#[test]
+fn committed_transactions_have_inclusion_proofs() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let domain_id: DomainId = "proof".parse()?;
+    let registered = client.submit_blocking(Register::domain(Domain::new(domain_id)))?;
+
+    let blocks = client.query(FindBlocks).execute_all()?;
+    let (genesis, latest) = (blocks.last().unwrap(), blocks.first().unwrap());
+    assert!(latest.transactions().any(|tx| tx.hash() == registered));
+
+    for block in &blocks {
+        for tx in block.transactions() {
+            let proof = client.get_transaction_proof(&tx.hash())?;
+            assert_eq!(proof.block_header, block.header());
+            verify_transaction_inclusion(&proof, &tx.hash(), block.hash())?;
+        }
+    }
+
+    let proof = client.get_transaction_proof(&registered)?;
+    let genesis_tx = genesis.transactions().next().unwrap().hash();
+    // the proof doesn't hold for other transactions or other blocks
+    assert!(verify_transaction_inclusion(&proof, &genesis_tx, latest.hash()).is_err());
+    assert!(verify_transaction_inclusion(&proof, &registered, genesis.hash()).is_err());
+
+    let unknown = HashOf::from_untyped_unchecked(iroha::crypto::Hash::new([0; 8]));
+    assert!(client.get_transaction_proof(&unknown).is_err());
+
+    Ok(())
+}
@@ -83,6 +83,9 @@ struct Args {
     /// More verbose output
     #[arg(short, long)]
     verbose: bool,
+    /// Execute transactions on the peer without committing them and print the outcome
+    #[arg(long)]
+    dry_run: bool,
     /// Subcommands of client cli
     #[command(subcommand)]
     subcommand: Subcommand,
@@ -124,6 +127,9 @@ trait RunContext {
         Client::new(self.configuration().clone())
     }
 
+    /// Whether transactions should only be simulated instead of being submitted
+    fn dry_run(&self) -> bool;
+
     /// Serialize and print data
     ///
     /// # Errors
@@ -135,6 +141,7 @@ trait RunContext {
 struct PrintJsonContext<W> {
     write: W,
     config: Config,
+    dry_run: bool,
 }
 
 impl<W: std::io::Write> RunContext for PrintJsonContext<W> {
@@ -142,6 +149,10 @@ impl<W: std::io::Write> RunContext for PrintJsonContext<W> {
         &self.config
     }
 
+    fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
     fn print_data(&mut self, data: &dyn Serialize) -> Result<()> {
         writeln!(&mut self.write, "{}", serde_json::to_string_pretty(data)?)?;
         Ok(())
@@ -187,6 +198,7 @@ fn main() -> error_stack::Result<(), MainError> {
         config: config_path,
         subcommand,
         verbose,
+        dry_run,
     } = clap::Parser::parse();
 
     error_stack::Report::set_color_mode(color_mode());
@@ -207,6 +219,7 @@ fn main() -> error_stack::Result<(), MainError> {
     let mut context = PrintJsonContext {
         write: stdout(),
         config,
+        dry_run,
     };
     subcommand
         .run(&mut context)
@@ -227,6 +240,7 @@ fn color_mode() -> ColorMode {
 }
 
 /// Submit instruction with metadata to network.
+/// In dry-run mode, print the outcome of the transaction instead of submitting it.
 ///
 /// # Errors
 /// Fails if submitting over network fails
@@ -240,6 +254,14 @@ fn submit(
     let instructions = instructions.into();
     let tx = client.build_transaction(instructions, metadata);
 
+    if context.dry_run() {
+        let simulation = client
+            .simulate_transaction(&tx)
+            .wrap_err("Failed to simulate transaction.")?;
+        context.print_data(&simulation)?;
+        return Ok(());
+    }
+
     #[cfg(not(debug_assertions))]
     let err_msg = "Failed to submit transaction.";
     #[cfg(debug_assertions)]
@@ -509,12 +531,12 @@ mod domain {
         /// Domain name as double-quited string
         #[arg(short, long)]
         pub id: DomainId,
-        /// Account from which to transfer (in form `name@domain_name`)
+        /// Account from which to transfer (in form `signatory@domain_name` or `alias@domain_name`)
         #[arg(short, long)]
-        pub from: AccountId,
-        /// Account to which to transfer (in form `name@domain_name`)
+        pub from: AccountRef,
+        /// Account to which to transfer (in form `signatory@domain_name` or `alias@domain_name`)
         #[arg(short, long)]
-        pub to: AccountId,
+        pub to: AccountRef,
         #[command(flatten)]
         pub metadata: MetadataArgs,
     }
@@ -527,6 +549,13 @@ mod domain {
                 to,
                 metadata,
             } = self;
+            let client = context.client_from_config();
+            let from = client
+                .resolve_account(from)
+                .wrap_err("Failed to resolve the source account")?;
+            let to = client
+                .resolve_account(to)
+                .wrap_err("Failed to resolve the destination account")?;
             let transfer_domain = iroha::data_model::isi::Transfer::domain(from, id, to);
             submit([transfer_domain], metadata.load()?, context)
                 .wrap_err("Failed to transfer domain")
@@ -618,6 +647,9 @@ mod account {
         Grant(Grant),
         /// List all account permissions
         ListPermissions(ListPermissions),
+        /// Manage account aliases
+        #[clap(subcommand)]
+        Alias(alias::Args),
     }
 
     impl RunArgs for Args {
@@ -627,6 +659,7 @@ mod account {
                 Args::List,
                 Args::Grant,
                 Args::ListPermissions,
+                Args::Alias,
             })
         }
     }
@@ -679,9 +712,9 @@ mod account {
 
     #[derive(clap::Args, Debug)]
     pub struct Grant {
-        /// Account id
+        /// Account id or alias (in form `signatory@domain_name` or `alias@domain_name`)
         #[arg(short, long)]
-        pub id: AccountId,
+        pub id: AccountRef,
         /// The JSON/JSON5 file with a permission token
         #[arg(short, long)]
         pub permission: Permission,
@@ -714,6 +747,10 @@ mod account {
                 permission,
                 metadata,
             } = self;
+            let id = context
+                .client_from_config()
+                .resolve_account(id)
+                .wrap_err("Failed to resolve the account")?;
             let grant = iroha::data_model::isi::Grant::account_permission(permission.0, id);
             submit([grant], metadata.load()?, context)
                 .wrap_err("Failed to grant the permission to the account")
@@ -723,15 +760,18 @@ mod account {
     /// List all account permissions
     #[derive(clap::Args, Debug)]
     pub struct ListPermissions {
-        /// Account id
+        /// Account id or alias (in form `signatory@domain_name` or `alias@domain_name`)
         #[arg(short, long)]
-        id: AccountId,
+        id: AccountRef,
     }
 
     impl RunArgs for ListPermissions {
         fn run(self, context: &mut dyn RunContext) -> Result<()> {
             let client = context.client_from_config();
-            let find_all_permissions = FindPermissionsByAccountId::new(self.id);
+            let id = client
+                .resolve_account(self.id)
+                .wrap_err("Failed to resolve the account")?;
+            let find_all_permissions = FindPermissionsByAccountId::new(id);
             let permissions = client
                 .query(find_all_permissions)
                 .execute_all()
@@ -740,6 +780,157 @@ mod account {
             Ok(())
         }
     }
+
+    mod alias {
+        use super::*;
+
+        /// Subcommands for account aliases
+        #[derive(Debug, Clone, clap::Subcommand)]
+        pub enum Args {
+            /// Register an alias for the account
+            Register(Register),
+            /// Make the alias refer to another account of its domain
+            Transfer(Transfer),
+            /// Unregister the alias
+            Unregister(Unregister),
+            /// Print the id of the account the alias refers to
+            Resolve(Resolve),
+            /// List all aliases of the account
+            List(List),
+        }
+
+        impl RunArgs for Args {
+            fn run(self, context: &mut dyn RunContext) -> Result<()> {
+                match_all!((self, context), {
+                    Args::Register,
+                    Args::Transfer,
+                    Args::Unregister,
+                    Args::Resolve,
+                    Args::List,
+                })
+            }
+        }
+
+        /// Register an alias for the account
+        #[derive(Debug, Clone, clap::Args)]
+        pub struct Register {
+            /// Alias in form `alias@domain_name`
+            #[arg(short, long)]
+            pub alias: AccountAlias,
+            /// Account id or alias (in form `signatory@domain_name` or `alias@domain_name`)
+            #[arg(short, long)]
+            pub id: AccountRef,
+            #[command(flatten)]
+            pub metadata: MetadataArgs,
+        }
+
+        impl RunArgs for Register {
+            fn run(self, context: &mut dyn RunContext) -> Result<()> {
+                let Self {
+                    alias,
+                    id,
+                    metadata,
+                } = self;
+                let id = context
+                    .client_from_config()
+                    .resolve_account(id)
+                    .wrap_err("Failed to resolve the account")?;
+                let register_alias = RegisterAccountAlias::new(alias, id);
+                submit([register_alias], metadata.load()?, context)
+                    .wrap_err("Failed to register the alias")
+            }
+        }
+
+        /// Make the alias refer to another account of its domain
+        #[derive(Debug, Clone, clap::Args)]
+        pub struct Transfer {
+            /// Alias in form `alias@domain_name`
+            #[arg(short, long)]
+            pub alias: AccountAlias,
+            /// Account id or alias (in form `signatory@domain_name` or `alias@domain_name`)
+            #[arg(short, long)]
+            pub to: AccountRef,
+            #[command(flatten)]
+            pub metadata: MetadataArgs,
+        }
+
+        impl RunArgs for Transfer {
+            fn run(self, context: &mut dyn RunContext) -> Result<()> {
+                let Self {
+                    alias,
+                    to,
+                    metadata,
+                } = self;
+                let to = context
+                    .client_from_config()
+                    .resolve_account(to)
+                    .wrap_err("Failed to resolve the account")?;
+                let transfer_alias = TransferAccountAlias::new(alias, to);
+                submit([transfer_alias], metadata.load()?, context)
+                    .wrap_err("Failed to transfer the alias")
+            }
+        }
+
+        /// Unregister the alias
+        #[derive(Debug, Clone, clap::Args)]
+        pub struct Unregister {
+            /// Alias in form `alias@domain_name`
+            #[arg(short, long)]
+            pub alias: AccountAlias,
+            #[command(flatten)]
+            pub metadata: MetadataArgs,
+        }
+
+        impl RunArgs for Unregister {
+            fn run(self, context: &mut dyn RunContext) -> Result<()> {
+                let Self { alias, metadata } = self;
+                let unregister_alias = UnregisterAccountAlias::new(alias);
+                submit([unregister_alias], metadata.load()?, context)
+                    .wrap_err("Failed to unregister the alias")
+            }
+        }
+
+        /// Print the id of the account the alias refers to
+        #[derive(Debug, Clone, clap::Args)]
+        pub struct Resolve {
+            /// Alias in form `alias@domain_name`
+            #[arg(short, long)]
+            pub alias: AccountAlias,
+        }
+
+        impl RunArgs for Resolve {
+            fn run(self, context: &mut dyn RunContext) -> Result<()> {
+                let client = context.client_from_config();
+                let id = client
+                    .query_single(FindAccountIdByAlias::new(self.alias))
+                    .wrap_err("Failed to resolve the alias")?;
+                context.print_data(&id)?;
+                Ok(())
+            }
+        }
+
+        /// List all aliases of the account
+        #[derive(Debug, Clone, clap::Args)]
+        pub struct List {
+            /// Account id or alias (in form `signatory@domain_name` or `alias@domain_name`)
+            #[arg(short, long)]
+            pub id: AccountRef,
+        }
+
+        impl RunArgs for List {
+            fn run(self, context: &mut dyn RunContext) -> Result<()> {
+                let client = context.client_from_config();
+                let id = client
+                    .resolve_account(self.id)
+                    .wrap_err("Failed to resolve the account")?;
+                let aliases = client
+                    .query_single(FindAliasesByAccountId::new(id))
+                    .wrap_err("Failed to get all account aliases")?;
+                context.print_data(&aliases)?;
+                Ok(())
+            }
+        }
+    }
 }
 
 mod asset {
@@ -925,9 +1116,9 @@ mod asset {
     /// Transfer asset between accounts
     #[derive(clap::Args, Debug)]
     pub struct Transfer {
-        /// Account to which to transfer (in form `name@domain_name`)
+        /// Account to which to transfer (in form `signatory@domain_name` or `alias@domain_name`)
         #[arg(long)]
-        pub to: AccountId,
+        pub to: AccountRef,
         /// Asset id to transfer (in form like `asset##account@domain_name`)
         #[arg(long)]
         pub id: AssetId,
@@ -946,6 +1137,10 @@ mod asset {
                 quantity,
                 metadata,
             } = self;
+            let to = context
+                .client_from_config()
+                .resolve_account(to)
+                .wrap_err("Failed to resolve the destination account")?;
             let transfer_asset =
                 iroha::data_model::isi::Transfer::asset_numeric(asset_id, quantity, to);
             submit([transfer_asset], metadata.load()?, context).wrap_err("Failed to transfer asset")
@@ -1301,9 +1496,9 @@ mod multisig {
         /// ID of the multisig account to be registered
         #[arg(short, long)]
         pub account: AccountId,
-        /// Signatories of the multisig account
+        /// Signatories of the multisig account (in form `signatory@domain_name` or `alias@domain_name`)
         #[arg(short, long, num_args(2..))]
-        pub signatories: Vec<AccountId>,
+        pub signatories: Vec<AccountRef>,
         /// Relative weights of responsibility of respective signatories
         #[arg(short, long, num_args(2..))]
         pub weights: Vec<u8>,
@@ -1324,10 +1519,17 @@ mod multisig {
             if self.signatories.len() != self.weights.len() {
                 return Err(eyre!("signatories and weights must be equal in length"));
             }
+            let client = context.client_from_config();
+            let signatories = self
+                .signatories
+                .into_iter()
+                .map(|signatory| client.resolve_account(signatory))
+                .collect::<Result<Vec<_>, _>>()
+                .wrap_err("Failed to resolve the signatories")?;
             let register_multisig_account = MultisigRegister::new(
                 self.account,
                 MultisigSpec::new(
-                    self.signatories.into_iter().zip(self.weights).collect(),
+                    signatories.into_iter().zip(self.weights).collect(),
                     NonZeroU16::new(self.quorum).expect("quorum should not be 0"),
                     self.transaction_ttl
                         .as_millis()
@@ -1346,9 +1548,9 @@ mod multisig {
     /// Args to propose a multisig transaction
     #[derive(Debug, clap::Args)]
     pub struct Propose {
-        /// Multisig authority of the multisig transaction
+        /// Multisig authority of the multisig transaction (in form `signatory@domain_name` or `alias@domain_name`)
         #[arg(short, long)]
-        pub account: AccountId,
+        pub account: AccountRef,
         /// Time-to-live of multisig transactions that overrides to shorten the account default
         #[arg(short, long)]
         pub transaction_ttl: Option<humantime::Duration>,
@@ -1372,11 +1574,16 @@ mod multisig {
                     .expect("ttl should be between 1 ms and 584942417 years")
             });
 
+            let account = context
+                .client_from_config()
+                .resolve_account(self.account)
+                .wrap_err("Failed to resolve the multisig account")?;
+
             let instructions_hash = HashOf::new(&instructions);
             println!("{instructions_hash}");
 
             let propose_multisig_transaction =
-                MultisigPropose::new(self.account, instructions, transaction_ttl_ms);
+                MultisigPropose::new(account, instructions, transaction_ttl_ms);
 
             submit([propose_multisig_transaction], Metadata::default(), context)
                 .wrap_err("Failed to propose transaction")
@@ -1386,9 +1593,9 @@ mod multisig {
     /// Args to approve a multisig transaction
     #[derive(Debug, clap::Args)]
     pub struct Approve {
-        /// Multisig authority of the multisig transaction
+        /// Multisig authority of the multisig transaction (in form `signatory@domain_name` or `alias@domain_name`)
         #[arg(short, long)]
-        pub account: AccountId,
+        pub account: AccountRef,
         /// Instructions to approve
         #[arg(short, long)]
         pub instructions_hash: ProposalKey,
@@ -1396,8 +1603,12 @@ mod multisig {
 
     impl RunArgs for Approve {
         fn run(self, context: &mut dyn RunContext) -> Result<()> {
+            let account = context
+                .client_from_config()
+                .resolve_account(self.account)
+                .wrap_err("Failed to resolve the multisig account")?;
             let approve_multisig_transaction =
-                MultisigApprove::new(self.account, self.instructions_hash);
+                MultisigApprove::new(account, self.instructions_hash);
 
             submit([approve_multisig_transaction], Metadata::default(), context)
                 .wrap_err("Failed to approve transaction")
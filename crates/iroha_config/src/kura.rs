@@ -26,9 +26,55 @@ pub enum InitMode {
     Fast,
 }
 
+/// Kura block pruning mode.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    strum::EnumString,
+    strum::Display,
+    DeserializeFromStr,
+    SerializeDisplay,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum PruningMode {
+    /// Keep all blocks.
+    #[default]
+    Disabled,
+    /// Drop blocks older than the retained window.
+    Drop,
+    /// Move blocks older than the retained window to the archive directory.
+    Archive,
+}
+
+/// Compression of the blocks written by Kura.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    strum::EnumString,
+    strum::Display,
+    DeserializeFromStr,
+    SerializeDisplay,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum Compression {
+    /// Store blocks as they are.
+    #[default]
+    None,
+    /// Compress blocks with LZ4.
+    Lz4,
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::kura::InitMode;
+    use crate::kura::{Compression, InitMode, PruningMode};
 
     #[test]
     fn init_mode_display_reprs() {
@@ -37,4 +83,21 @@ mod tests {
         assert_eq!("strict".parse::<InitMode>().unwrap(), InitMode::Strict);
         assert_eq!("fast".parse::<InitMode>().unwrap(), InitMode::Fast);
     }
+
+    #[test]
+    fn pruning_mode_display_reprs() {
+        assert_eq!(format!("{}", PruningMode::Disabled), "disabled");
+        assert_eq!(format!("{}", PruningMode::Drop), "drop");
+        assert_eq!(
+            "archive".parse::<PruningMode>().unwrap(),
+            PruningMode::Archive
+        );
+    }
+
+    #[test]
+    fn compression_display_reprs() {
+        assert_eq!(format!("{}", Compression::None), "none");
+        assert_eq!(format!("{}", Compression::Lz4), "lz4");
+        assert_eq!("lz4".parse::<Compression>().unwrap(), Compression::Lz4);
+    }
 }
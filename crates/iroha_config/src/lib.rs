@@ -8,7 +8,9 @@ pub mod client_api;
 pub mod kura;
 pub mod logger;
 pub mod parameters;
+pub mod queue;
 pub mod snapshot;
+pub mod torii;
 
 /// Enables tracing of configuration via [`stderrlog`].
 /// # Errors
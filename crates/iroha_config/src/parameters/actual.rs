@@ -19,8 +19,10 @@ use url::Url;
 pub use user::{DevTelemetry, Logger, Snapshot};
 
 use crate::{
-    kura::InitMode,
+    kura::{Compression, InitMode, PruningMode},
     parameters::{defaults, user},
+    queue::Policy as QueuePolicy,
+    torii::TokenBucket,
 };
 
 /// Parsed configuration root
@@ -98,7 +100,9 @@ pub struct Genesis {
 pub struct Queue {
     pub capacity: NonZeroUsize,
     pub capacity_per_user: NonZeroUsize,
+    pub capacity_scheduled: NonZeroUsize,
     pub transaction_time_to_live: Duration,
+    pub policy: QueuePolicy,
 }
 
 #[allow(missing_docs)]
@@ -107,6 +111,10 @@ pub struct Kura {
     pub init_mode: InitMode,
     pub store_dir: WithOrigin<PathBuf>,
     pub blocks_in_memory: NonZeroUsize,
+    pub compression: Compression,
+    pub pruning_mode: PruningMode,
+    pub pruning_retain_blocks: NonZeroUsize,
+    pub pruning_archive_dir: WithOrigin<PathBuf>,
     pub debug_output_new_blocks: bool,
 }
 
@@ -116,6 +124,8 @@ impl Default for Queue {
             transaction_time_to_live: defaults::queue::TRANSACTION_TIME_TO_LIVE,
             capacity: defaults::queue::CAPACITY,
             capacity_per_user: defaults::queue::CAPACITY_PER_USER,
+            capacity_scheduled: defaults::queue::CAPACITY_SCHEDULED,
+            policy: QueuePolicy::default(),
         }
     }
 }
@@ -155,6 +165,8 @@ pub struct LiveQueryStore {
     pub idle_time: Duration,
     pub capacity: NonZeroUsize,
     pub capacity_per_user: NonZeroUsize,
+    pub history_depth: usize,
+    pub history_max_bytes: Bytes<u64>,
 }
 
 impl Default for LiveQueryStore {
@@ -163,6 +175,8 @@ impl Default for LiveQueryStore {
             idle_time: defaults::torii::QUERY_IDLE_TIME,
             capacity: defaults::torii::QUERY_STORE_CAPACITY,
             capacity_per_user: defaults::torii::QUERY_STORE_CAPACITY_PER_USER,
+            history_depth: defaults::torii::QUERY_HISTORY_DEPTH,
+            history_max_bytes: defaults::torii::QUERY_HISTORY_MAX_BYTES,
         }
     }
 }
@@ -186,6 +200,28 @@ pub struct TransactionGossiper {
 pub struct Torii {
     pub address: WithOrigin<SocketAddr>,
     pub max_content_len: Bytes<u64>,
+    pub admin_address: Option<WithOrigin<SocketAddr>>,
+    pub admin_public_keys: Vec<PublicKey>,
+    pub tls: Option<ToriiTls>,
+    pub rate_limits: ToriiRateLimits,
+}
+
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct ToriiTls {
+    pub certificate: WithOrigin<PathBuf>,
+    pub private_key: WithOrigin<PathBuf>,
+    pub client_ca: Option<WithOrigin<PathBuf>>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(missing_docs)]
+pub struct ToriiRateLimits {
+    pub transactions_per_ip: Option<TokenBucket>,
+    pub transactions_per_account: Option<TokenBucket>,
+    pub queries_per_ip: Option<TokenBucket>,
+    pub queries_per_account: Option<TokenBucket>,
+    pub subscriptions_per_ip: Option<TokenBucket>,
 }
 
 /// Complete configuration needed to start regular telemetry.
@@ -15,6 +15,7 @@ pub mod queue {
 
     pub const CAPACITY: NonZeroUsize = nonzero!(2_usize.pow(16));
     pub const CAPACITY_PER_USER: NonZeroUsize = nonzero!(2_usize.pow(16));
+    pub const CAPACITY_SCHEDULED: NonZeroUsize = nonzero!(2_usize.pow(12));
     // 24 hours
     pub const TRANSACTION_TIME_TO_LIVE: Duration = Duration::from_secs(24 * 60 * 60);
 }
@@ -26,6 +27,8 @@ pub mod kura {
 
     pub const STORE_DIR: &str = "./storage";
     pub const BLOCKS_IN_MEMORY: NonZeroUsize = nonzero!(128_usize);
+    pub const PRUNING_RETAIN_BLOCKS: NonZeroUsize = nonzero!(10_000_usize);
+    pub const PRUNING_ARCHIVE_DIR: &str = "./storage/archive";
 }
 
 pub mod network {
@@ -58,6 +61,9 @@ pub mod torii {
     pub const QUERY_IDLE_TIME: Duration = Duration::from_secs(10);
     pub const QUERY_STORE_CAPACITY: NonZeroUsize = nonzero!(128usize);
     pub const QUERY_STORE_CAPACITY_PER_USER: NonZeroUsize = nonzero!(128usize);
+    // Disabled, since every retained height keeps a copy of the world state in memory
+    pub const QUERY_HISTORY_DEPTH: usize = 0;
+    pub const QUERY_HISTORY_MAX_BYTES: Bytes<u64> = Bytes(2_u64.pow(20) * 512);
 }
 
 pub mod telemetry {
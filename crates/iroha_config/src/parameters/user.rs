@@ -18,7 +18,7 @@ use std::{
     path::PathBuf,
 };
 
-use error_stack::{Result, ResultExt};
+use error_stack::{Report, Result, ResultExt};
 use iroha_config_base::{
     attach::ConfigValueAndOrigin,
     env::FromEnvStr,
@@ -32,10 +32,14 @@ use serde::Deserialize;
 use url::Url;
 
 use crate::{
-    kura::InitMode as KuraInitMode,
+    kura::{
+        Compression as KuraCompression, InitMode as KuraInitMode, PruningMode as KuraPruningMode,
+    },
     logger::{Directives, Format as LoggerFormat},
     parameters::{actual, defaults},
-    snapshot::Mode as SnapshotMode,
+    queue::Policy as QueuePolicy,
+    snapshot::{Format as SnapshotFormat, Mode as SnapshotMode},
+    torii::TokenBucket,
 };
 
 #[derive(Deserialize, Debug)]
@@ -87,6 +91,10 @@ pub struct Root {
 pub enum ParseError {
     #[error("Failed to construct the key pair")]
     BadKeyPair,
+    #[error("Torii TLS certificate and private key should be set together")]
+    ToriiTls,
+    #[error("Kura pruning requires the snapshot mode to be `read_write`")]
+    KuraPruning,
 }
 
 impl Root {
@@ -131,8 +139,19 @@ impl Root {
         let logger = self.logger;
         let queue = self.queue;
         let snapshot = self.snapshot;
+        // Blocks are only pruned once the state is persisted in a snapshot
+        if kura.pruning_mode != KuraPruningMode::Disabled
+            && !matches!(snapshot.mode, SnapshotMode::ReadWrite)
+        {
+            emitter.emit(
+                Report::new(ParseError::KuraPruning).attach_printable(format!(
+                    "kura.pruning.mode = `{}`, snapshot.mode = `{}`",
+                    kura.pruning_mode, snapshot.mode
+                )),
+            );
+        }
         let dev_telemetry = self.dev_telemetry;
-        let (torii, live_query_store) = self.torii.parse();
+        let (torii, live_query_store) = self.torii.parse(&mut emitter);
         let telemetry = self.telemetry.map(actual::Telemetry::from);
 
         let sumeragi = self.sumeragi.parse();
@@ -197,6 +216,10 @@ pub struct Kura {
         default = "defaults::kura::BLOCKS_IN_MEMORY"
     )]
     pub blocks_in_memory: NonZeroUsize,
+    #[config(env = "KURA_COMPRESSION", default)]
+    pub compression: KuraCompression,
+    #[config(nested)]
+    pub pruning: KuraPruning,
     #[config(nested)]
     pub debug: KuraDebug,
 }
@@ -207,6 +230,13 @@ impl Kura {
             init_mode,
             store_dir,
             blocks_in_memory,
+            compression,
+            pruning:
+                KuraPruning {
+                    mode: pruning_mode,
+                    retain_blocks: pruning_retain_blocks,
+                    archive_dir: pruning_archive_dir,
+                },
             debug:
                 KuraDebug {
                     output_new_blocks: debug_output_new_blocks,
@@ -217,11 +247,30 @@ impl Kura {
             init_mode,
             store_dir,
             blocks_in_memory,
+            compression,
+            pruning_mode,
+            pruning_retain_blocks,
+            pruning_archive_dir,
             debug_output_new_blocks,
         }
     }
 }
 
+#[derive(Debug, Clone, ReadConfig)]
+pub struct KuraPruning {
+    /// Blocks are never pruned above the `max_prunable_height` block parameter set on-chain.
+    #[config(env = "KURA_PRUNING_MODE", default)]
+    pub mode: KuraPruningMode,
+    /// The number of the most recent blocks before the latest snapshot that are never pruned
+    #[config(default = "defaults::kura::PRUNING_RETAIN_BLOCKS")]
+    pub retain_blocks: NonZeroUsize,
+    #[config(
+        env = "KURA_PRUNING_ARCHIVE_DIR",
+        default = "PathBuf::from(defaults::kura::PRUNING_ARCHIVE_DIR)"
+    )]
+    pub archive_dir: WithOrigin<PathBuf>,
+}
+
 #[derive(Debug, Clone, Copy, ReadConfig)]
 pub struct KuraDebug {
     #[config(env = "KURA_DEBUG_OUTPUT_NEW_BLOCKS", default)]
@@ -339,9 +388,16 @@ pub struct Queue {
     /// Use this option to apply throttling.
     #[config(default = "defaults::queue::CAPACITY_PER_USER")]
     pub capacity_per_user: NonZeroUsize,
+    /// The upper limit of the number of scheduled transactions held in the queue until they become valid.
+    /// Scheduled transactions don't count towards `capacity`.
+    #[config(default = "defaults::queue::CAPACITY_SCHEDULED")]
+    pub capacity_scheduled: NonZeroUsize,
     /// The transaction will be dropped after this time if it is still in the queue.
     #[config(default = "defaults::queue::TRANSACTION_TIME_TO_LIVE.into()")]
     pub transaction_time_to_live_ms: DurationMs,
+    /// The order in which transactions are taken from the queue into blocks.
+    #[config(env = "QUEUE_POLICY", default)]
+    pub policy: QueuePolicy,
 }
 
 impl Queue {
@@ -349,12 +405,16 @@ impl Queue {
         let Self {
             capacity,
             capacity_per_user,
+            capacity_scheduled,
             transaction_time_to_live_ms: transaction_time_to_live,
+            policy,
         } = self;
         actual::Queue {
             capacity,
             capacity_per_user,
+            capacity_scheduled,
             transaction_time_to_live: transaction_time_to_live.0,
+            policy,
         }
     }
 }
@@ -437,6 +497,12 @@ pub struct Snapshot {
         env = "SNAPSHOT_STORE_DIR"
     )]
     pub store_dir: WithOrigin<PathBuf>,
+    #[config(default, env = "SNAPSHOT_FORMAT")]
+    pub format: SnapshotFormat,
+    /// Only write the parts of the binary snapshot that changed since the previous one.
+    /// The whole state is still encoded in memory for every snapshot.
+    #[config(default)]
+    pub incremental: bool,
 }
 
 #[derive(Debug, ReadConfig)]
@@ -453,21 +519,130 @@ pub struct Torii {
     /// The upper limit of the number of live queries for a single user.
     #[config(default = "defaults::torii::QUERY_STORE_CAPACITY_PER_USER")]
     pub query_store_capacity_per_user: NonZeroUsize,
+    /// The number of recent block heights whose state is retained for historical queries.
+    #[config(default = "defaults::torii::QUERY_HISTORY_DEPTH")]
+    pub query_history_depth: usize,
+    /// The maximum total size of the retained states, older heights are dropped to stay below it.
+    #[config(default = "defaults::torii::QUERY_HISTORY_MAX_BYTES")]
+    pub query_history_max_bytes: Bytes<u64>,
+    /// The address of a separate listener for the administrative endpoints.
+    /// If not set, they are served on `address` along with the public API.
+    #[config(env = "API_ADMIN_ADDRESS")]
+    pub admin_address: Option<WithOrigin<SocketAddr>>,
+    /// The keys allowed to sign requests to the administrative endpoints.
+    /// If empty, the requests aren't authenticated.
+    /// Metrics are always served without authentication so that they can be scraped.
+    #[config(default)]
+    pub admin_public_keys: Vec<PublicKey>,
+    #[config(nested)]
+    pub tls: ToriiTls,
+    #[config(nested)]
+    pub rate_limits: ToriiRateLimits,
 }
 
 impl Torii {
-    fn parse(self) -> (actual::Torii, actual::LiveQueryStore) {
+    fn parse(self, emitter: &mut Emitter<ParseError>) -> (actual::Torii, actual::LiveQueryStore) {
+        let tls = match self.tls {
+            ToriiTls {
+                certificate: Some(certificate),
+                private_key: Some(private_key),
+                client_ca,
+            } => Some(actual::ToriiTls {
+                certificate,
+                private_key,
+                client_ca,
+            }),
+            ToriiTls {
+                certificate: None,
+                private_key: None,
+                client_ca: None,
+            } => None,
+            ToriiTls {
+                certificate,
+                private_key,
+                client_ca,
+            } => {
+                let mut report = Report::new(ParseError::ToriiTls);
+                for path in [certificate, private_key, client_ca].into_iter().flatten() {
+                    report = report.attach_printable(path.into_attachment().display_path());
+                }
+                emitter.emit(report.attach_printable(
+                    "Note: the client CA can only be used along with the certificate and private key",
+                ));
+                None
+            }
+        };
+
         let torii = actual::Torii {
             address: self.address,
             max_content_len: self.max_content_len,
+            admin_address: self.admin_address,
+            admin_public_keys: self.admin_public_keys,
+            tls,
+            rate_limits: self.rate_limits.parse(),
         };
 
         let query = actual::LiveQueryStore {
             idle_time: self.query_idle_time_ms.get(),
             capacity: self.query_store_capacity,
             capacity_per_user: self.query_store_capacity_per_user,
+            history_depth: self.query_history_depth,
+            history_max_bytes: self.query_history_max_bytes,
         };
 
         (torii, query)
     }
 }
+
+/// TLS termination of the Torii listeners. It is enabled if both `certificate` and `private_key` are set.
+#[derive(Debug, ReadConfig)]
+pub struct ToriiTls {
+    /// PEM file with the certificate chain presented to the clients.
+    #[config(env = "API_TLS_CERTIFICATE")]
+    pub certificate: Option<WithOrigin<PathBuf>>,
+    /// PEM file with the private key of the certificate.
+    #[config(env = "API_TLS_PRIVATE_KEY")]
+    pub private_key: Option<WithOrigin<PathBuf>>,
+    /// PEM file with the CA certificates the client certificates are verified against.
+    /// If set, clients must present a certificate signed by one of them (mutual TLS).
+    #[config(env = "API_TLS_CLIENT_CA")]
+    pub client_ca: Option<WithOrigin<PathBuf>>,
+}
+
+/// Token-bucket limits of the request rate to Torii. The limits which aren't set aren't enforced.
+///
+/// Requests from a single IP address are limited before the request is processed,
+/// while requests signed by a single account are limited once the signature is verified.
+#[derive(Debug, ReadConfig)]
+pub struct ToriiRateLimits {
+    /// Limit of transactions submitted or simulated from a single IP address.
+    pub transactions_per_ip: Option<TokenBucket>,
+    /// Limit of transactions submitted or simulated by a single account.
+    pub transactions_per_account: Option<TokenBucket>,
+    /// Limit of queries from a single IP address.
+    pub queries_per_ip: Option<TokenBucket>,
+    /// Limit of queries by a single account.
+    pub queries_per_account: Option<TokenBucket>,
+    /// Limit of event and block stream subscriptions from a single IP address.
+    pub subscriptions_per_ip: Option<TokenBucket>,
+}
+
+impl ToriiRateLimits {
+    fn parse(self) -> actual::ToriiRateLimits {
+        let Self {
+            transactions_per_ip,
+            transactions_per_account,
+            queries_per_ip,
+            queries_per_account,
+            subscriptions_per_ip,
+        } = self;
+
+        actual::ToriiRateLimits {
+            transactions_per_ip,
+            transactions_per_account,
+            queries_per_ip,
+            queries_per_account,
+            subscriptions_per_ip,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Configuration related to the transaction queue
+
+/// Order in which queued transactions are included into blocks
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+    serde_with::SerializeDisplay,
+    serde_with::DeserializeFromStr,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum Policy {
+    /// Transactions are included in the order of arrival
+    #[default]
+    Fifo,
+    /// Transactions with the highest tip set by their submitter are included first, with accounts taking turns.
+    /// When the queue is full, the transactions with the lowest tip are evicted in favour of new ones
+    Priority,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::queue::Policy;
+
+    #[test]
+    fn policy_display_form() {
+        assert_eq!(
+            format!("{} {}", Policy::Fifo, Policy::Priority),
+            "fifo priority"
+        );
+        assert_eq!("priority".parse::<Policy>().unwrap(), Policy::Priority);
+    }
+}
@@ -22,9 +22,31 @@ pub enum Mode {
     Disabled,
 }
 
+/// Encoding of the snapshot file
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+    serde_with::SerializeDisplay,
+    serde_with::DeserializeFromStr,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum Format {
+    /// JSON-encoded state with a versioned header and a checksum, can be written incrementally
+    #[default]
+    Binary,
+    /// Human-readable JSON, useful to export the state
+    Json,
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::snapshot::Mode;
+    use crate::snapshot::{Format, Mode};
 
     #[test]
     fn mode_display_form() {
@@ -33,4 +55,9 @@ mod tests {
             "read_write readonly disabled"
         );
     }
+
+    #[test]
+    fn format_display_form() {
+        assert_eq!(format!("{} {}", Format::Binary, Format::Json), "binary json");
+    }
 }
@@ -0,0 +1,18 @@
+//! Configuration related to Torii specifically
+
+use std::num::NonZeroU32;
+
+use serde::{Deserialize, Serialize};
+
+/// Token bucket limiting the rate of requests.
+///
+/// The bucket holds up to `burst` tokens and is refilled with `rate_per_sec` tokens per second.
+/// Each request takes a token, and requests arriving at the empty bucket are rejected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TokenBucket {
+    /// Sustained number of requests per second
+    pub rate_per_sec: NonZeroU32,
+    /// Number of requests which can be made at once
+    pub burst: NonZeroU32,
+}
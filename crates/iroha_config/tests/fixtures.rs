@@ -142,6 +142,16 @@ fn minimal_config_snapshot() {
                 max_content_len: Bytes(
                     16777216,
                 ),
+                admin_address: None,
+                admin_public_keys: [],
+                tls: None,
+                rate_limits: ToriiRateLimits {
+                    transactions_per_ip: None,
+                    transactions_per_account: None,
+                    queries_per_ip: None,
+                    queries_per_account: None,
+                    subscriptions_per_ip: None,
+                },
             },
             kura: Kura {
                 init_mode: Strict,
@@ -152,6 +162,15 @@ fn minimal_config_snapshot() {
                     },
                 },
                 blocks_in_memory: 128,
+                compression: None,
+                pruning_mode: Disabled,
+                pruning_retain_blocks: 10000,
+                pruning_archive_dir: WithOrigin {
+                    value: "./storage/archive",
+                    origin: Default {
+                        id: ParameterId(kura.pruning.archive_dir),
+                    },
+                },
                 debug_output_new_blocks: false,
             },
             sumeragi: Sumeragi {
@@ -169,6 +188,10 @@ fn minimal_config_snapshot() {
                 idle_time: 10s,
                 capacity: 128,
                 capacity_per_user: 128,
+                history_depth: 0,
+                history_max_bytes: Bytes(
+                    536870912,
+                ),
             },
             logger: Logger {
                 level: info,
@@ -177,7 +200,9 @@ fn minimal_config_snapshot() {
             queue: Queue {
                 capacity: 65536,
                 capacity_per_user: 65536,
+                capacity_scheduled: 4096,
                 transaction_time_to_live: 86400s,
+                policy: Fifo,
             },
             snapshot: Snapshot {
                 mode: ReadWrite,
@@ -190,6 +215,8 @@ fn minimal_config_snapshot() {
                         id: ParameterId(snapshot.store_dir),
                     },
                 },
+                format: Binary,
+                incremental: false,
             },
             telemetry: None,
             dev_telemetry: DevTelemetry {
@@ -238,6 +265,28 @@ fn extra_fields() {
     assert_contains!(format!("{error:?}"), "unknown parameter: `foo`");
 }
 
+#[test]
+fn torii_tls_without_private_key() {
+    let error = load_config_from_fixtures("bad.torii_tls_without_key.toml")
+        .expect_err("should fail without TLS private key");
+
+    assert_contains!(
+        format!("{error:?}"),
+        "Torii TLS certificate and private key should be set together"
+    );
+}
+
+#[test]
+fn kura_pruning_without_snapshots() {
+    let error = load_config_from_fixtures("bad.kura_pruning_without_snapshots.toml")
+        .expect_err("should fail without writing snapshots");
+
+    assert_contains!(
+        format!("{error:?}"),
+        "Kura pruning requires the snapshot mode to be `read_write`"
+    );
+}
+
 /// Aims the purpose of checking that every single provided env variable is consumed and parsed
 /// into a valid config.
 #[test]
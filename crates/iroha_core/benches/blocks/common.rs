@@ -48,6 +48,7 @@ pub fn create_block<'a>(
         &chain_id,
         max_clock_drift,
         tx_limits,
+        &state.world.view(),
     )
     .unwrap()])
     .chain(0, state.view().latest_block().as_deref())
@@ -220,6 +221,7 @@ pub fn build_state(rt: &tokio::runtime::Handle, account_id: &AccountId) -> State
             &chain_id,
             max_clock_drift,
             tx_limits,
+            &state.world.view(),
         )
         .unwrap()])
         .chain(0, state.view().latest_block().as_deref())
@@ -51,6 +51,7 @@ pub fn create_block(
         &chain_id,
         max_clock_drift,
         tx_limits,
+        &state.world,
     )
     .unwrap()])
     .chain(0, state)
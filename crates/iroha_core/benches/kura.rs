@@ -3,7 +3,10 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use iroha_config::{
     base::WithOrigin,
-    parameters::{actual::Kura as Config, defaults::kura::BLOCKS_IN_MEMORY},
+    parameters::{
+        actual::Kura as Config,
+        defaults::kura::{BLOCKS_IN_MEMORY, PRUNING_RETAIN_BLOCKS},
+    },
 };
 use iroha_core::{
     block::*,
@@ -25,7 +28,11 @@ async fn measure_block_size_for_n_executors(n_executors: u32) {
         init_mode: iroha_config::kura::InitMode::Strict,
         debug_output_new_blocks: false,
         blocks_in_memory: BLOCKS_IN_MEMORY,
+        compression: iroha_config::kura::Compression::None,
         store_dir: WithOrigin::inline(dir.path().to_path_buf()),
+        pruning_mode: iroha_config::kura::PruningMode::Disabled,
+        pruning_retain_blocks: PRUNING_RETAIN_BLOCKS,
+        pruning_archive_dir: WithOrigin::inline(dir.path().join("archive")),
     };
     let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
     let (kura, _) = iroha_core::kura::Kura::new(&cfg).unwrap();
@@ -46,8 +53,14 @@ async fn measure_block_size_for_n_executors(n_executors: u32) {
         let params = state_view.parameters();
         (params.sumeragi().max_clock_drift(), params.transaction)
     };
-    let tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
-        .expect("Failed to accept Transaction.");
+    let tx = AcceptedTransaction::accept(
+        tx,
+        &chain_id,
+        max_clock_drift,
+        tx_limits,
+        &state.world.view(),
+    )
+    .expect("Failed to accept Transaction.");
     let peer_key_pair = KeyPair::random();
     let peer_id = PeerId::new(peer_key_pair.public_key().clone());
     let topology = Topology::new(vec![peer_id]);
@@ -63,6 +63,7 @@ fn build_test_and_transient_state() -> State {
             &chain_id,
             max_clock_drift,
             tx_limits,
+            &state.world.view(),
         )
         .unwrap()])
         .chain(0, state.view().latest_block().as_deref())
@@ -105,6 +106,7 @@ fn accept_transaction(criterion: &mut Criterion) {
                 &chain_id,
                 max_clock_drift,
                 tx_limits,
+                &state.world.view(),
             ) {
                 Ok(_) => success_count += 1,
                 Err(_) => failures_count += 1,
@@ -151,6 +153,7 @@ fn validate_transaction(criterion: &mut Criterion) {
         &chain_id,
         max_clock_drift,
         tx_limits,
+        &state.world.view(),
     )
     .unwrap()])
     .chain(0, state.view().latest_block().as_deref())
@@ -161,6 +164,7 @@ fn validate_transaction(criterion: &mut Criterion) {
         &chain_id,
         max_clock_drift,
         tx_limits,
+        &state.world.view(),
     )
     .expect("Failed to accept transaction.");
     let mut success_count = 0;
@@ -195,6 +199,7 @@ fn sign_blocks(criterion: &mut Criterion) {
         &chain_id,
         max_clock_drift,
         tx_limits,
+        &state.world.view(),
     )
     .expect("Failed to accept transaction.");
     let (_, peer_private_key) = KeyPair::random().into_parts();
@@ -4,7 +4,7 @@
 //! 2. If a block is received, i.e. deserialized:
 //!    `SignedBlock` -> `ValidBlock` -> `CommittedBlock`
 //!    [`Block`]s are organised into a linear sequence over time (also known as the block chain).
-use std::time::Duration;
+use std::{collections::BTreeMap, time::Duration};
 
 use iroha_crypto::{HashOf, KeyPair, MerkleTree};
 use iroha_data_model::{
@@ -75,6 +75,8 @@ pub enum BlockValidationError {
     BlockInThePast,
     /// Block's creation time is later than the current node local time
     BlockInTheFuture,
+    /// Block contains a transaction which can't be committed before the block's creation time
+    PrematureTransaction,
 }
 
 /// Error during signature verification
@@ -266,6 +268,7 @@ mod new {
     impl NewBlock {
         /// Categorize transactions of this block to produce a [`ValidBlock`]
         pub fn categorize(self, state_block: &mut StateBlock<'_>) -> WithEvents<ValidBlock> {
+            state_block.revoke_expired_grants();
             let mut wasm_cache = WasmCache::new();
             let errors = self
                 .transactions
@@ -593,6 +596,13 @@ mod valid {
                 return Err(BlockValidationError::HasCommittedTransactions);
             }
 
+            if block.transactions().any(|tx| {
+                tx.not_before()
+                    .is_some_and(|not_before| not_before > block.header().creation_time())
+            }) {
+                return Err(BlockValidationError::PrematureTransaction);
+            }
+
             Ok(())
         }
 
@@ -607,6 +617,22 @@ mod valid {
                 (params.sumeragi().max_clock_drift(), params.transaction)
             };
 
+            state_block.revoke_expired_grants();
+            // Rejecting a transaction because of its signature is only legitimate if the signatories
+            // of its authority changed earlier in the block. Otherwise the leader could fill the block
+            // with forged transactions and have them recorded as rejected on behalf of any account.
+            let signatories_before_block: BTreeMap<_, _> = if block.header().is_genesis() {
+                BTreeMap::new()
+            } else {
+                block
+                    .transactions()
+                    .map(|tx| {
+                        let authority = tx.authority();
+                        let signatories = state_block.world().account_signatories_of(authority);
+                        (authority.clone(), signatories)
+                    })
+                    .collect()
+            };
             let mut wasm_cache = WasmCache::new();
             let errors = block
                 .transactions()
@@ -622,7 +648,7 @@ mod valid {
                             genesis_account,
                         )
                     } else {
-                        AcceptedTransaction::accept(
+                        AcceptedTransaction::accept_in_block(
                             tx,
                             expected_chain_id,
                             max_clock_drift,
@@ -630,6 +656,18 @@ mod valid {
                         )
                     }?;
 
+                    let authority = accepted_tx.as_ref().authority();
+                    if let Some(signatories) = signatories_before_block.get(authority) {
+                        if let Err(err) = accepted_tx.verify_signature_by(signatories) {
+                            if state_block.world().account_signatories_of(authority) == *signatories
+                            {
+                                return Err(
+                                    AcceptTransactionFail::SignatureVerification(err).into()
+                                );
+                            }
+                        }
+                    }
+
                     if let Err((rejected_tx, error)) =
                         state_block.validate(accepted_tx, &mut wasm_cache)
                     {
@@ -1197,8 +1235,14 @@ mod tests {
         let tx = TransactionBuilder::new(chain_id.clone(), alice_id)
             .with_instructions([create_asset_definition])
             .sign(alice_keypair.private_key());
-        let tx =
-            AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits).expect("Valid");
+        let tx = AcceptedTransaction::accept(
+            tx,
+            &chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )
+        .expect("Valid");
 
         // Creating a block of two identical transactions and validating it
         let transactions = vec![tx.clone(), tx];
@@ -1244,8 +1288,14 @@ mod tests {
         let tx = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
             .with_instructions([create_asset_definition])
             .sign(alice_keypair.private_key());
-        let tx =
-            AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits).expect("Valid");
+        let tx = AcceptedTransaction::accept(
+            tx,
+            &chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )
+        .expect("Valid");
 
         let fail_mint = Mint::asset_numeric(
             20u32,
@@ -1258,14 +1308,26 @@ mod tests {
         let tx0 = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
             .with_instructions([fail_mint])
             .sign(alice_keypair.private_key());
-        let tx0 =
-            AcceptedTransaction::accept(tx0, &chain_id, max_clock_drift, tx_limits).expect("Valid");
+        let tx0 = AcceptedTransaction::accept(
+            tx0,
+            &chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )
+        .expect("Valid");
 
         let tx2 = TransactionBuilder::new(chain_id.clone(), alice_id)
             .with_instructions([succeed_mint])
             .sign(alice_keypair.private_key());
-        let tx2 =
-            AcceptedTransaction::accept(tx2, &chain_id, max_clock_drift, tx_limits).expect("Valid");
+        let tx2 = AcceptedTransaction::accept(
+            tx2,
+            &chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )
+        .expect("Valid");
 
         // Creating a block of two identical transactions and validating it
         let transactions = vec![tx0, tx, tx2];
@@ -1310,14 +1372,25 @@ mod tests {
         let tx_fail = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
             .with_instructions::<InstructionBox>([create_domain.clone().into(), fail_isi.into()])
             .sign(alice_keypair.private_key());
-        let tx_fail = AcceptedTransaction::accept(tx_fail, &chain_id, max_clock_drift, tx_limits)
-            .expect("Valid");
+        let tx_fail = AcceptedTransaction::accept(
+            tx_fail,
+            &chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )
+        .expect("Valid");
         let tx_accept = TransactionBuilder::new(chain_id.clone(), alice_id)
             .with_instructions::<InstructionBox>([create_domain.into(), create_asset.into()])
             .sign(alice_keypair.private_key());
-        let tx_accept =
-            AcceptedTransaction::accept(tx_accept, &chain_id, max_clock_drift, tx_limits)
-                .expect("Valid");
+        let tx_accept = AcceptedTransaction::accept(
+            tx_accept,
+            &chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )
+        .expect("Valid");
 
         // Creating a block of where first transaction must fail and second one fully executed
         let transactions = vec![tx_fail, tx_accept];
@@ -260,10 +260,24 @@ pub mod message {
                         nonzero_ext::nonzero!(1_usize)
                     };
 
-                    let blocks = block_sync
-                        .state
-                        .view()
-                        .all_blocks(start_height)
+                    if start_height.get() <= block_sync.kura.pruned_height() {
+                        debug!(
+                            peer=%block_sync.peer,
+                            %start_height,
+                            pruned_height=block_sync.kura.pruned_height(),
+                            "Requested blocks are pruned, not sharing them"
+                        );
+
+                        return;
+                    }
+
+                    let latest_height = block_sync.state.view().height();
+                    // Blocks are loaded from Kura directly since they might be pruned meanwhile
+                    let blocks = (start_height.get()..=latest_height)
+                        .map_while(|height| {
+                            NonZeroUsize::new(height)
+                                .and_then(|height| block_sync.kura.get_block(height))
+                        })
                         .skip_while(|block| Some(block.hash()) == *latest_hash)
                         .skip_while(|block| seen_blocks.contains(&block.hash()))
                         .take(block_sync.gossip_size.get() as usize)
@@ -207,8 +207,10 @@ impl Executor {
         trace!("Running query validation");
 
         let query = match query {
-            QueryRequest::Singular(singular) => AnyQueryBox::Singular(singular.clone()),
+            QueryRequest::Singular(singular) => AnyQueryBox::Singular(singular.query.clone()),
             QueryRequest::Start(iterable) => AnyQueryBox::Iterable(iterable.clone()),
+            // the aggregate is computed from the results of the iterable query, so it needs the same permissions
+            QueryRequest::Aggregate(aggregate) => AnyQueryBox::Iterable(aggregate.query.clone()),
             QueryRequest::Continue(_) => {
                 // The iterable query was already validated when it started
                 return Ok(());
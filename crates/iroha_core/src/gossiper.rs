@@ -101,9 +101,9 @@ impl TransactionGossiper {
     }
 
     fn gossip_transactions(&self) {
-        let txs = self
-            .queue
-            .gossip_batch(self.gossip_size.get(), &self.state.view());
+        let state_view = self.state.view();
+        self.queue.release_scheduled_transactions();
+        let txs = self.queue.gossip_batch(self.gossip_size.get(), &state_view);
 
         if txs.is_empty() {
             return;
@@ -125,7 +125,13 @@ impl TransactionGossiper {
                 (params.sumeragi().max_clock_drift(), params.transaction)
             };
 
-            match AcceptedTransaction::accept(tx, &self.chain_id, max_clock_drift, tx_limits) {
+            match AcceptedTransaction::accept(
+                tx,
+                &self.chain_id,
+                max_clock_drift,
+                tx_limits,
+                &self.state.world.view(),
+            ) {
                 Ok(tx) => match self.queue.push(tx, self.state.view()) {
                     Ok(()) => {}
                     Err(crate::queue::Failure {
@@ -3,16 +3,20 @@
 //! new [`Block`](`crate::block::SignedBlock`)s on the
 //! blockchain.
 use std::{
+    cell::Cell,
     fmt::Debug,
     io::{BufWriter, Read, Seek, SeekFrom, Write},
     num::NonZeroUsize,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
     time::Duration,
 };
 
 use iroha_config::{
-    kura::InitMode,
+    kura::{Compression, InitMode, PruningMode},
     parameters::{actual::Kura as Config, defaults::kura::BLOCKS_IN_MEMORY},
 };
 use iroha_crypto::{Hash, HashOf};
@@ -25,11 +29,22 @@ use parking_lot::Mutex;
 
 use crate::block::CommittedBlock;
 
-const INDEX_FILE_NAME: &str = "blocks.index";
-const DATA_FILE_NAME: &str = "blocks.data";
-const HASHES_FILE_NAME: &str = "blocks.hashes";
+/// Name of the file with the locations of the blocks in the data file
+pub const INDEX_FILE_NAME: &str = "blocks.index";
+/// Name of the file with the encoded blocks
+pub const DATA_FILE_NAME: &str = "blocks.data";
+/// Name of the file with the hashes of the blocks
+pub const HASHES_FILE_NAME: &str = "blocks.hashes";
+/// Name of the file describing the pruned blocks, absent if no blocks were pruned
+pub const PRUNED_FILE_NAME: &str = "blocks.pruned";
+const DATA_TMP_FILE_NAME: &str = "blocks.data.tmp";
+const PRUNED_TMP_FILE_NAME: &str = "blocks.pruned.tmp";
 
 const SIZE_OF_BLOCK_HASH: u64 = Hash::LENGTH as u64;
+/// The highest byte of the block length in the index file stores the compression of the block,
+/// so that the indices written before compression was introduced remain valid
+const COMPRESSION_SHIFT: u32 = 56;
+const BLOCK_LENGTH_MASK: u64 = (1 << COMPRESSION_SHIFT) - 1;
 
 /// The interface of Kura subsystem
 #[derive(Debug)]
@@ -45,6 +60,18 @@ pub struct Kura {
     blocks_in_memory: NonZeroUsize,
     /// Amount of blocks loaded during initialization
     init_block_count: usize,
+    /// Pruning of the blocks older than the retained window, [`None`] if disabled
+    pruning: Option<Pruning>,
+    /// Height of the latest pruned block or `0` if no blocks are pruned
+    pruned_height: AtomicUsize,
+}
+
+#[derive(Debug)]
+struct Pruning {
+    /// The number of the most recent blocks before the latest snapshot that are never pruned
+    retain_blocks: NonZeroUsize,
+    /// Directory of the block store which pruned blocks are moved to, [`None`] if they are dropped
+    archive_dir: Option<PathBuf>,
 }
 
 type BlockData = Vec<(HashOf<BlockHeader>, Option<Arc<SignedBlock>>)>;
@@ -59,7 +86,7 @@ impl Kura {
     /// path.
     pub fn new(config: &Config) -> Result<(Arc<Self>, BlockCount)> {
         let store_dir = config.store_dir.resolve_relative_path();
-        let mut block_store = BlockStore::new(&store_dir);
+        let mut block_store = BlockStore::new(&store_dir).with_compression(config.compression);
         block_store.create_files_if_they_do_not_exist()?;
 
         let block_plain_text_path = config
@@ -68,7 +95,20 @@ impl Kura {
 
         let block_data = Kura::init(&mut block_store, config.init_mode)?;
         let block_count = block_data.len();
-        info!(mode=?config.init_mode, block_count, "Kura init complete");
+        let pruned_height = block_store.read_pruned_blocks()?.height().try_into()?;
+        info!(mode=?config.init_mode, block_count, pruned_height, "Kura init complete");
+
+        let pruning = match config.pruning_mode {
+            PruningMode::Disabled => None,
+            PruningMode::Drop => Some(Pruning {
+                retain_blocks: config.pruning_retain_blocks,
+                archive_dir: None,
+            }),
+            PruningMode::Archive => Some(Pruning {
+                retain_blocks: config.pruning_retain_blocks,
+                archive_dir: Some(config.pruning_archive_dir.resolve_relative_path()),
+            }),
+        };
 
         let kura = Arc::new(Self {
             block_store: Mutex::new(block_store),
@@ -76,6 +116,8 @@ impl Kura {
             block_plain_text_path,
             blocks_in_memory: config.blocks_in_memory,
             init_block_count: block_count,
+            pruning,
+            pruned_height: AtomicUsize::new(pruned_height),
         });
 
         Ok((kura, BlockCount(block_count)))
@@ -90,6 +132,8 @@ impl Kura {
             block_plain_text_path: None,
             blocks_in_memory: BLOCKS_IN_MEMORY,
             init_block_count: 0,
+            pruning: None,
+            pruned_height: AtomicUsize::new(0),
         })
     }
 
@@ -157,14 +201,24 @@ impl Kura {
 
         let mut block_indices = vec![BlockIndex::default(); block_index_count];
         block_store.read_block_indices(0, &mut block_indices)?;
+        let pruned = block_store.read_pruned_blocks()?;
 
         let mut prev_block_hash = None;
-        for block in block_indices {
-            // This is re-allocated every iteration. This could cause a problem.
-            let mut block_data_buffer = vec![0_u8; block.length.try_into()?];
+        for (block_index, block) in (0..).zip(block_indices) {
+            if pruned.contains(block_index) {
+                // Data of the pruned blocks is gone, so their hashes are trusted
+                let [hash] = block_store
+                    .read_block_hashes(block_index, 1)?
+                    .try_into()
+                    .expect("Exactly one hash is read");
+                block_hashes.push(hash);
+                prev_block_hash = Some(hash);
+                continue;
+            }
 
-            match block_store.read_block_data(block.start, &mut block_data_buffer) {
-                Ok(()) => match SignedBlock::decode_all_versioned(&block_data_buffer) {
+            // This is re-allocated every iteration. This could cause a problem.
+            match block_store.read_block_bytes(block) {
+                Ok(block_data) => match SignedBlock::decode_all_versioned(&block_data) {
                     Ok(decoded_block) => {
                         if prev_block_hash != decoded_block.header().prev_block_hash {
                             error!(expected=?prev_block_hash, actual=?decoded_block.header().prev_block_hash,
@@ -304,10 +358,12 @@ impl Kura {
     }
 
     /// Get a reference to block by height, loading it from disk if needed.
+    ///
+    /// Returns [`None`] if the block is pruned, see [`Self::is_pruned`].
     pub fn get_block(&self, block_height: NonZeroUsize) -> Option<Arc<SignedBlock>> {
         let mut data_array_guard = self.block_data.lock();
 
-        if data_array_guard.len() < block_height.get() {
+        if data_array_guard.len() < block_height.get() || self.is_pruned(block_height) {
             return None;
         }
 
@@ -317,17 +373,16 @@ impl Kura {
         };
 
         let block_store = self.block_store.lock();
-        let BlockIndex { start, length } = block_store
+        // Blocks might have been pruned while waiting for the lock
+        if self.is_pruned(block_height) {
+            return None;
+        }
+        let block_index_entry = block_store
             .read_block_index(block_index as u64)
             .expect("INTERNAL BUG: Failed to read block index from disk.");
 
-        let mut block_buf = vec![
-            0_u8;
-            usize::try_from(length)
-                .expect("INTERNAL BUG: index_len didn't fit in 32-bits")
-        ];
-        block_store
-            .read_block_data(start, &mut block_buf)
+        let block_buf = block_store
+            .read_block_bytes(block_index_entry)
             .expect("INTERNAL BUG: Failed to read block data.");
         let block = SignedBlock::decode_all_versioned(&block_buf)
             .expect("INTERNAL BUG: Failed to decode block");
@@ -340,6 +395,64 @@ impl Kura {
         Some(block_arc)
     }
 
+    /// Height of the latest pruned block or `0` if no blocks are pruned.
+    ///
+    /// Blocks following the genesis block up to this height are pruned.
+    /// Their hashes are still available with [`Self::get_block_hash`].
+    pub fn pruned_height(&self) -> usize {
+        self.pruned_height.load(Ordering::Acquire)
+    }
+
+    /// Check if the block at the given height is pruned.
+    pub fn is_pruned(&self, block_height: NonZeroUsize) -> bool {
+        (2..=self.pruned_height()).contains(&block_height.get())
+    }
+
+    /// Prune the blocks older than the retained window before the block at `snapshot_height`
+    /// if pruning is enabled, but never above `max_prunable_height` set on-chain.
+    ///
+    /// Must only be called once the state at `snapshot_height` is persisted in a snapshot,
+    /// since the state can't be restored from the pruned blocks anymore.
+    ///
+    /// # Errors
+    /// Fails if there are filesystem errors while pruning the blocks.
+    pub fn prune(&self, snapshot_height: usize, max_prunable_height: usize) -> Result<()> {
+        let Some(pruning) = &self.pruning else {
+            return Ok(());
+        };
+        let until = snapshot_height
+            .saturating_sub(pruning.retain_blocks.get())
+            .min(max_prunable_height);
+        if until <= self.pruned_height() {
+            return Ok(());
+        }
+
+        let pruned = {
+            let mut block_store = self.block_store.lock();
+            let mut archive = pruning
+                .archive_dir
+                .as_ref()
+                .map(|archive_dir| {
+                    let mut archive = BlockStore::new(archive_dir);
+                    archive.create_files_if_they_do_not_exist()?;
+                    Ok::<_, Error>(archive)
+                })
+                .transpose()?;
+            let pruned = block_store.prune_blocks((until - 1) as u64, archive.as_mut())?;
+            self.pruned_height
+                .store(pruned.height().try_into()?, Ordering::Release);
+            pruned
+        };
+
+        let pruned_count = pruned.count.try_into()?;
+        for (_hash, block) in self.block_data.lock().iter_mut().skip(1).take(pruned_count) {
+            *block = None;
+        }
+        info!(pruned_height = pruned.height(), "Pruned old blocks");
+
+        Ok(())
+    }
+
     /// Put a block in kura's in memory block store.
     pub fn store_block(&self, block: CommittedBlock) {
         let block = Arc::new(SignedBlock::from(block));
@@ -373,11 +486,81 @@ impl Kura {
 #[derive(Clone, Copy, Debug)]
 pub struct BlockCount(pub usize);
 
+/// Blocks whose data was removed from the data file by pruning.
+///
+/// The genesis block is never pruned, so the pruned blocks directly follow it
+/// and their data used to occupy a contiguous range of the data file.
+/// Index and hashes files are left intact, so block locations in the index
+/// still refer to the data file as it was before pruning.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrunedBlocks {
+    /// Number of the pruned blocks following the genesis block
+    pub count: u64,
+    /// Start of the data of the pruned blocks in bytes
+    pub data_start: u64,
+    /// End of the data of the pruned blocks in bytes
+    pub data_end: u64,
+}
+
+impl PrunedBlocks {
+    const SIZE: usize = 3 * std::mem::size_of::<u64>();
+
+    /// Check if the block at the given 0-based index is pruned.
+    pub fn contains(&self, block_index: u64) -> bool {
+        (1..=self.count).contains(&block_index)
+    }
+
+    /// Height of the latest pruned block or `0` if no blocks are pruned.
+    pub fn height(&self) -> u64 {
+        if self.count == 0 {
+            0
+        } else {
+            self.count + 1
+        }
+    }
+
+    /// Location in the data file of a block that was at `start` before pruning.
+    fn data_location(&self, start: u64) -> u64 {
+        if start >= self.data_end {
+            start - (self.data_end - self.data_start)
+        } else {
+            start
+        }
+    }
+
+    fn to_bytes(self) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        for (chunk, value) in bytes.chunks_exact_mut(std::mem::size_of::<u64>()).zip([
+            self.count,
+            self.data_start,
+            self.data_end,
+        ]) {
+            chunk.copy_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: [u8; Self::SIZE]) -> Self {
+        let mut values = bytes
+            .chunks_exact(std::mem::size_of::<u64>())
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().expect("Chunk is 8 bytes long")));
+        let mut next = || values.next().expect("There are exactly 3 values");
+        Self {
+            count: next(),
+            data_start: next(),
+            data_end: next(),
+        }
+    }
+}
+
 /// An implementation of a block store for `Kura`
 /// that uses `std::fs`, the default IO file in Rust.
 #[derive(Debug)]
 pub struct BlockStore {
     path_to_blockchain: PathBuf,
+    compression: Compression,
+    /// Contents of the pruned blocks file, read on first use
+    pruned: Cell<Option<PrunedBlocks>>,
 }
 
 #[derive(Default, Debug, Clone, Copy)]
@@ -387,6 +570,8 @@ pub struct BlockIndex {
     pub start: u64,
     /// Length of block section in bytes
     pub length: u64,
+    /// Compression of the block section
+    pub compression: Compression,
 }
 
 impl BlockStore {
@@ -394,9 +579,20 @@ impl BlockStore {
     pub fn new(store_path: impl AsRef<Path>) -> Self {
         Self {
             path_to_blockchain: store_path.as_ref().to_path_buf(),
+            compression: Compression::None,
+            pruned: Cell::new(None),
         }
     }
 
+    /// Set the compression of the blocks appended to this block store.
+    ///
+    /// Blocks which are already stored are left as they are.
+    #[must_use]
+    pub fn with_compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
     /// Read a series of block indices from the block index file and
     /// attempt to fill all of `dest_buffer`.
     ///
@@ -430,15 +626,15 @@ impl BlockStore {
         for current_buffer in dest_buffer.iter_mut() {
             let mut buffer = [0; core::mem::size_of::<u64>()];
 
+            index_file.read_exact(&mut buffer).add_err_context(&path)?;
+            let start = u64::from_le_bytes(buffer);
+            index_file.read_exact(&mut buffer).add_err_context(&path)?;
+            let length = u64::from_le_bytes(buffer);
+
             *current_buffer = BlockIndex {
-                start: {
-                    index_file.read_exact(&mut buffer).add_err_context(&path)?;
-                    u64::from_le_bytes(buffer)
-                },
-                length: {
-                    index_file.read_exact(&mut buffer).add_err_context(&path)?;
-                    u64::from_le_bytes(buffer)
-                },
+                start,
+                length: length & BLOCK_LENGTH_MASK,
+                compression: compression_from_tag(length >> COMPRESSION_SHIFT)?,
             };
         }
 
@@ -450,10 +646,7 @@ impl BlockStore {
     /// # Errors
     /// IO Error.
     pub fn read_block_index(&self, block_height: u64) -> Result<BlockIndex> {
-        let mut index = BlockIndex {
-            start: 0,
-            length: 0,
-        };
+        let mut index = BlockIndex::default();
         self.read_block_indices(block_height, std::slice::from_mut(&mut index))?;
         Ok(index)
     }
@@ -545,6 +738,8 @@ impl BlockStore {
     /// `start_location_in_data_file` in data file in order to fill
     /// `dest_buffer`.
     ///
+    /// The location is the one stored in the index file, it's adjusted for the pruned blocks.
+    ///
     /// # Errors
     /// IO Error.
     pub fn read_block_data(
@@ -552,6 +747,8 @@ impl BlockStore {
         start_location_in_data_file: u64,
         dest_buffer: &mut [u8],
     ) -> Result<()> {
+        let start_location_in_data_file =
+            (self.read_pruned_blocks()?).data_location(start_location_in_data_file);
         let path = self.path_to_blockchain.join(DATA_FILE_NAME);
         let mut data_file = std::fs::OpenOptions::new()
             .read(true)
@@ -565,13 +762,32 @@ impl BlockStore {
         Ok(())
     }
 
+    /// Read the data of the block with the given index and decompress it if needed.
+    ///
+    /// Returns the versioned SCALE encoding of the block.
+    ///
+    /// # Errors
+    /// - IO Error
+    /// - Block data couldn't be decompressed
+    pub fn read_block_bytes(&self, index: BlockIndex) -> Result<Vec<u8>> {
+        let mut data = vec![0_u8; index.length.try_into()?];
+        self.read_block_data(index.start, &mut data)?;
+        decompress_block_data(data, index.compression)
+    }
+
     /// Write the index of a single block at the specified `block_height`.
     /// If `block_height` is beyond the end of the index file, attempt to
     /// extend the index file.
     ///
     /// # Errors
     /// IO Error.
-    pub fn write_block_index(&mut self, block_height: u64, start: u64, length: u64) -> Result<()> {
+    pub fn write_block_index(
+        &mut self,
+        block_height: u64,
+        start: u64,
+        length: u64,
+        compression: Compression,
+    ) -> Result<()> {
         let path = self.path_to_blockchain.join(INDEX_FILE_NAME);
         let mut index_file = std::fs::OpenOptions::new()
             .write(true)
@@ -596,7 +812,9 @@ impl BlockStore {
             .write_all(&start.to_le_bytes())
             .add_err_context(&path)?;
         index_file
-            .write_all(&length.to_le_bytes())
+            .write_all(
+                &(length | (compression_tag(compression) << COMPRESSION_SHIFT)).to_le_bytes(),
+            )
             .add_err_context(&path)?;
         Ok(())
     }
@@ -627,6 +845,8 @@ impl BlockStore {
     /// `start_location_in_data_file`. Extend the file if
     /// necessary.
     ///
+    /// The location is the one stored in the index file, it's adjusted for the pruned blocks.
+    ///
     /// # Errors
     /// IO Error.
     pub fn write_block_data(
@@ -634,6 +854,8 @@ impl BlockStore {
         start_location_in_data_file: u64,
         block_data: &[u8],
     ) -> Result<()> {
+        let start_location_in_data_file =
+            (self.read_pruned_blocks()?).data_location(start_location_in_data_file);
         let path = self.path_to_blockchain.join(DATA_FILE_NAME);
         let mut data_file = std::fs::OpenOptions::new()
             .write(true)
@@ -735,18 +957,29 @@ impl BlockStore {
             .create(true)
             .open(path.clone())
             .add_err_context(&path)?;
-        Ok(())
+        self.recover_interrupted_pruning()
     }
 
     /// Append `block_data` to this block store. First write
     /// the data to the data file and then create a new index
     /// for it in the index file.
     ///
+    /// The block is compressed if it makes it smaller.
+    ///
     /// # Errors
     /// Fails if any of the required platform-specific functions
     /// fail.
     pub fn append_block_to_chain(&mut self, block: &SignedBlock) -> Result<()> {
-        let bytes = block.encode_versioned();
+        let (data, compression) = compress_block_data(block.encode_versioned(), self.compression);
+        self.append_block_data_to_chain(&data, compression, block.hash())
+    }
+
+    fn append_block_data_to_chain(
+        &mut self,
+        bytes: &[u8],
+        compression: Compression,
+        hash: HashOf<BlockHeader>,
+    ) -> Result<()> {
         let new_block_height = self.read_index_count()?;
         let start_location_in_data_file = if new_block_height == 0 {
             0
@@ -755,16 +988,320 @@ impl BlockStore {
             ultimate_block.start + ultimate_block.length
         };
 
-        self.write_block_data(start_location_in_data_file, &bytes)?;
+        self.write_block_data(start_location_in_data_file, bytes)?;
         self.write_block_index(
             new_block_height,
             start_location_in_data_file,
             bytes.len() as u64,
+            compression,
         )?;
-        self.write_block_hash(new_block_height, block.hash())?;
+        self.write_block_hash(new_block_height, hash)?;
 
         Ok(())
     }
+
+    /// Read the information about the pruned blocks.
+    ///
+    /// # Errors
+    /// IO Error.
+    pub fn read_pruned_blocks(&self) -> Result<PrunedBlocks> {
+        if let Some(pruned) = self.pruned.get() {
+            return Ok(pruned);
+        }
+
+        let path = self.path_to_blockchain.join(PRUNED_FILE_NAME);
+        let mut bytes = [0; PrunedBlocks::SIZE];
+        let pruned = match std::fs::File::open(&path) {
+            Ok(mut file) => {
+                file.read_exact(&mut bytes).add_err_context(&path)?;
+                PrunedBlocks::from_bytes(bytes)
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => PrunedBlocks::default(),
+            Err(error) => return Err(Error::IO(error, path)),
+        };
+        self.pruned.set(Some(pruned));
+        Ok(pruned)
+    }
+
+    /// Remove the data of the blocks following the genesis block up to the block at
+    /// 0-based index `until` inclusive. The latest block is never pruned.
+    ///
+    /// If `archive` is provided, the pruned blocks are appended to it first, so that
+    /// the archive is a block store containing the chain from the genesis block.
+    ///
+    /// Returns the information about all blocks pruned so far.
+    ///
+    /// # Errors
+    /// - IO Error
+    /// - The archive doesn't end right before the blocks being pruned
+    pub fn prune_blocks(
+        &mut self,
+        until: u64,
+        archive: Option<&mut BlockStore>,
+    ) -> Result<PrunedBlocks> {
+        let pruned = self.read_pruned_blocks()?;
+        let count = until.min(self.read_index_count()?.saturating_sub(2));
+        if count <= pruned.count {
+            return Ok(pruned);
+        }
+
+        if let Some(archive) = archive {
+            let archived = archive.read_index_count()?;
+            if archived < pruned.height() || archived > count + 1 {
+                return Err(Error::ArchiveHeightMismatch {
+                    archive_height: archived,
+                    pruned_height: pruned.height(),
+                });
+            }
+            for block_index in archived..=count {
+                let index = self.read_block_index(block_index)?;
+                let mut data = vec![0_u8; index.length.try_into()?];
+                self.read_block_data(index.start, &mut data)?;
+                let [hash] = self
+                    .read_block_hashes(block_index, 1)?
+                    .try_into()
+                    .expect("Exactly one hash is read");
+                archive.append_block_data_to_chain(&data, index.compression, hash)?;
+            }
+        }
+
+        let genesis = self.read_block_index(0)?;
+        let new_pruned = PrunedBlocks {
+            count,
+            data_start: genesis.start + genesis.length,
+            data_end: self.read_block_index(count + 1)?.start,
+        };
+
+        // The retained data is copied into a new file which replaces the data file
+        let path = self.path_to_blockchain.join(DATA_FILE_NAME);
+        let tmp_path = self.path_to_blockchain.join(DATA_TMP_FILE_NAME);
+        {
+            let mut data_file = std::fs::File::open(&path).add_err_context(&path)?;
+            let mut tmp_file =
+                BufWriter::new(std::fs::File::create(&tmp_path).add_err_context(&tmp_path)?);
+            std::io::copy(
+                &mut (&mut data_file).take(new_pruned.data_start),
+                &mut tmp_file,
+            )
+            .add_err_context(&path)?;
+            data_file
+                .seek(SeekFrom::Start(pruned.data_location(new_pruned.data_end)))
+                .add_err_context(&path)?;
+            std::io::copy(&mut data_file, &mut tmp_file).add_err_context(&path)?;
+            tmp_file
+                .into_inner()
+                .map_err(std::io::IntoInnerError::into_error)
+                .and_then(|file| file.sync_all())
+                .add_err_context(&tmp_path)?;
+        }
+
+        // NOTE: Data file is replaced after the new pruned blocks are written to the temporary file,
+        // if this is interrupted, `recover_interrupted_pruning` picks the matching one
+        let pruned_path = self.path_to_blockchain.join(PRUNED_FILE_NAME);
+        let pruned_tmp_path = self.path_to_blockchain.join(PRUNED_TMP_FILE_NAME);
+        std::fs::File::create(&pruned_tmp_path)
+            .and_then(|mut file| {
+                file.write_all(&new_pruned.to_bytes())?;
+                file.sync_all()
+            })
+            .add_err_context(&pruned_tmp_path)?;
+        // Renames are only durable once the directory is synced
+        std::fs::rename(&tmp_path, &path).add_err_context(&path)?;
+        self.sync_dir()?;
+        std::fs::rename(&pruned_tmp_path, &pruned_path).add_err_context(&pruned_path)?;
+        self.sync_dir()?;
+        self.pruned.set(Some(new_pruned));
+
+        Ok(new_pruned)
+    }
+
+    /// Complete or discard pruning if it was interrupted.
+    ///
+    /// # Errors
+    /// IO Error.
+    fn recover_interrupted_pruning(&mut self) -> Result<()> {
+        self.pruned.set(None);
+        let path = self.path_to_blockchain.join(DATA_FILE_NAME);
+        let tmp_path = self.path_to_blockchain.join(DATA_TMP_FILE_NAME);
+        if tmp_path.exists() {
+            std::fs::remove_file(&tmp_path).add_err_context(&tmp_path)?;
+        }
+
+        let pruned_path = self.path_to_blockchain.join(PRUNED_FILE_NAME);
+        let pruned_tmp_path = self.path_to_blockchain.join(PRUNED_TMP_FILE_NAME);
+        let Ok(bytes) = std::fs::read(&pruned_tmp_path) else {
+            return Ok(());
+        };
+
+        // Data file was already replaced if its length matches the new pruned blocks
+        let index_count = self.read_index_count()?;
+        let data_len = std::fs::metadata(&path).add_err_context(&path)?.len();
+        let is_replaced = match (
+            index_count.checked_sub(1),
+            <[u8; PrunedBlocks::SIZE]>::try_from(bytes),
+        ) {
+            (Some(latest), Ok(bytes)) => {
+                let new_pruned = PrunedBlocks::from_bytes(bytes);
+                let latest = self.read_block_index(latest)?;
+                (latest.start + latest.length)
+                    .checked_sub(new_pruned.data_end - new_pruned.data_start)
+                    == Some(data_len)
+            }
+            _ => false,
+        };
+
+        if is_replaced {
+            warn!("Completing interrupted pruning of blocks");
+            std::fs::rename(&pruned_tmp_path, &pruned_path).add_err_context(&pruned_path)?;
+        } else {
+            warn!("Discarding interrupted pruning of blocks");
+            std::fs::remove_file(&pruned_tmp_path).add_err_context(&pruned_tmp_path)?;
+        }
+        self.sync_dir()
+    }
+
+    /// Persist the renames and removals of files in the block store directory.
+    ///
+    /// # Errors
+    /// IO Error.
+    fn sync_dir(&self) -> Result<()> {
+        std::fs::File::open(&self.path_to_blockchain)
+            .and_then(|dir| dir.sync_all())
+            .add_err_context(&self.path_to_blockchain)
+    }
+
+    /// Copy all blocks into the empty `destination` block store,
+    /// compressing them with the compression of the destination.
+    ///
+    /// Pruned blocks remain pruned in the destination.
+    ///
+    /// # Errors
+    /// - IO Error
+    /// - Block data couldn't be decompressed
+    /// - The destination isn't empty
+    pub fn migrate_to(&self, destination: &mut BlockStore) -> Result<()> {
+        if destination.read_index_count()? != 0 {
+            return Err(Error::NotEmpty(destination.path_to_blockchain.clone()));
+        }
+
+        let pruned = self.read_pruned_blocks()?;
+        let hashes = self.read_block_hashes(0, self.read_index_count()?.try_into()?)?;
+        for (block_index, hash) in (0..).zip(hashes) {
+            if pruned.contains(block_index) {
+                // Pruned blocks have no data, so they occupy nothing right after the genesis block
+                let genesis = destination.read_block_index(0)?;
+                destination.write_block_index(
+                    block_index,
+                    genesis.start + genesis.length,
+                    0,
+                    Compression::None,
+                )?;
+                destination.write_block_hash(block_index, hash)?;
+                continue;
+            }
+
+            let bytes = self.read_block_bytes(self.read_block_index(block_index)?)?;
+            let (data, compression) = compress_block_data(bytes, destination.compression);
+            destination.append_block_data_to_chain(&data, compression, hash)?;
+        }
+
+        if pruned.count > 0 {
+            let genesis = destination.read_block_index(0)?;
+            let genesis_end = genesis.start + genesis.length;
+            let pruned = PrunedBlocks {
+                count: pruned.count,
+                data_start: genesis_end,
+                data_end: genesis_end,
+            };
+            let path = destination.path_to_blockchain.join(PRUNED_FILE_NAME);
+            std::fs::write(&path, pruned.to_bytes()).add_err_context(&path)?;
+            destination.pruned.set(Some(pruned));
+        }
+
+        Ok(())
+    }
+
+    /// Remove all blocks following the first `block_count` blocks from the index, hashes and data files.
+    ///
+    /// # Errors
+    /// - IO Error
+    /// - The latest remaining block would be a pruned one
+    pub fn truncate(&mut self, block_count: u64) -> Result<()> {
+        let pruned = self.read_pruned_blocks()?;
+        if pruned.count > 0 && block_count <= pruned.height() {
+            return Err(Error::TruncatePruned {
+                pruned_height: pruned.height(),
+            });
+        }
+
+        let block_count = block_count.min(self.read_index_count()?);
+        let data_len = match block_count.checked_sub(1) {
+            Some(latest) => {
+                let latest = self.read_block_index(latest)?;
+                pruned.data_location(latest.start) + latest.length
+            }
+            None => 0,
+        };
+
+        let path = self.path_to_blockchain.join(DATA_FILE_NAME);
+        let data_file = std::fs::OpenOptions::new()
+            .write(true)
+            .open(path.clone())
+            .add_err_context(&path)?;
+        if data_file.metadata().add_err_context(&path)?.len() > data_len {
+            data_file.set_len(data_len).add_err_context(&path)?;
+        }
+
+        let path = self.path_to_blockchain.join(HASHES_FILE_NAME);
+        let hashes_file = std::fs::OpenOptions::new()
+            .write(true)
+            .open(path.clone())
+            .add_err_context(&path)?;
+        if self.read_hashes_count()? > block_count {
+            hashes_file
+                .set_len(block_count * SIZE_OF_BLOCK_HASH)
+                .add_err_context(&path)?;
+        }
+
+        self.write_index_count(block_count)
+    }
+}
+
+fn compression_tag(compression: Compression) -> u64 {
+    match compression {
+        Compression::None => 0,
+        Compression::Lz4 => 1,
+    }
+}
+
+fn compression_from_tag(tag: u64) -> Result<Compression> {
+    match tag {
+        0 => Ok(Compression::None),
+        1 => Ok(Compression::Lz4),
+        _ => Err(Error::UnknownCompression(tag)),
+    }
+}
+
+/// Compress the encoded block unless it doesn't get any smaller.
+fn compress_block_data(bytes: Vec<u8>, compression: Compression) -> (Vec<u8>, Compression) {
+    match compression {
+        Compression::None => (bytes, Compression::None),
+        Compression::Lz4 => {
+            let compressed = lz4_flex::compress_prepend_size(&bytes);
+            if compressed.len() < bytes.len() {
+                (compressed, Compression::Lz4)
+            } else {
+                (bytes, Compression::None)
+            }
+        }
+    }
+}
+
+fn decompress_block_data(data: Vec<u8>, compression: Compression) -> Result<Vec<u8>> {
+    match compression {
+        Compression::None => Ok(data),
+        Compression::Lz4 => Ok(lz4_flex::decompress_size_prepended(&data)?),
+    }
 }
 
 type Result<T, E = Error> = std::result::Result<T, E>;
@@ -792,6 +1329,24 @@ pub enum Error {
     IntConversion(#[from] std::num::TryFromIntError),
     /// Blocks count differs hashes file and index file
     HashesFileHeightMismatch,
+    /// Unknown compression {0} of the block in the index file
+    UnknownCompression(u64),
+    /// Failed to decompress block
+    Decompression(#[from] lz4_flex::block::DecompressError),
+    /// Block store at {0:?} is not empty
+    NotEmpty(PathBuf),
+    /// Blocks up to height `{pruned_height}` are pruned, so the block store can't be truncated to them
+    TruncatePruned {
+        /// Height of the latest pruned block
+        pruned_height: u64,
+    },
+    /// Archive has `{archive_height}` blocks, but the blocks up to height `{pruned_height}` are already pruned
+    ArchiveHeightMismatch {
+        /// The amount of blocks in the archive
+        archive_height: u64,
+        /// Height of the latest pruned block
+        pruned_height: u64,
+    },
 }
 
 trait AddErrContextExt<T> {
@@ -812,7 +1367,7 @@ impl<T> AddErrContextExt<T> for Result<T, std::io::Error> {
 mod tests {
     use std::{str::FromStr, thread, time::Duration};
 
-    use iroha_config::parameters::defaults::kura::BLOCKS_IN_MEMORY;
+    use iroha_config::parameters::defaults::kura::{BLOCKS_IN_MEMORY, PRUNING_RETAIN_BLOCKS};
     use iroha_crypto::KeyPair;
     use iroha_data_model::{
         account::Account,
@@ -838,10 +1393,7 @@ mod tests {
     };
 
     fn indices<const N: usize>(value: [(u64, u64); N]) -> [BlockIndex; N] {
-        let mut ret = [BlockIndex {
-            start: 0,
-            length: 0,
-        }; N];
+        let mut ret = [BlockIndex::default(); N];
         for idx in 0..value.len() {
             ret[idx] = value[idx].into();
         }
@@ -865,6 +1417,7 @@ mod tests {
             Self {
                 start: value.0,
                 length: value.1,
+                compression: Compression::None,
             }
         }
     }
@@ -875,14 +1428,22 @@ mod tests {
         let mut block_store = BlockStore::new(dir.path());
         block_store.create_files_if_they_do_not_exist().unwrap();
 
-        block_store.write_block_index(0, 5, 7).unwrap();
+        block_store
+            .write_block_index(0, 5, 7, Compression::None)
+            .unwrap();
         assert_eq!(block_store.read_block_index(0).unwrap(), (5, 7));
 
-        block_store.write_block_index(0, 2, 9).unwrap();
+        block_store
+            .write_block_index(0, 2, 9, Compression::None)
+            .unwrap();
         assert_ne!(block_store.read_block_index(0).unwrap(), (5, 7));
 
-        block_store.write_block_index(3, 1, 2).unwrap();
-        block_store.write_block_index(2, 6, 3).unwrap();
+        block_store
+            .write_block_index(3, 1, 2, Compression::None)
+            .unwrap();
+        block_store
+            .write_block_index(2, 6, 3, Compression::None)
+            .unwrap();
 
         assert_eq!(block_store.read_block_index(0).unwrap(), (2, 9));
         assert_eq!(block_store.read_block_index(2).unwrap(), (6, 3));
@@ -996,24 +1557,136 @@ mod tests {
 
         let block_data = dummy_block.encode_versioned();
         for i in 0..append_count {
-            let BlockIndex { start, length } = block_store.read_block_index(i).unwrap();
+            let BlockIndex { start, length, .. } = block_store.read_block_index(i).unwrap();
             assert_eq!(i * block_data.len() as u64, start);
             assert_eq!(block_data.len() as u64, length);
         }
     }
 
     #[test]
-    fn strict_init_kura() {
-        let temp_dir = TempDir::new().unwrap();
-        Kura::new(&Config {
+    fn prune_blocks_keeps_index_and_hashes() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut block_store = BlockStore::new(dir.path().join("store"));
+        block_store.create_files_if_they_do_not_exist().unwrap();
+        let mut archive = BlockStore::new(dir.path().join("archive"));
+        archive.create_files_if_they_do_not_exist().unwrap();
+
+        let key_pair = KeyPair::random();
+        let blocks = (1..=11)
+            .map(|height| {
+                ValidBlock::new_dummy_and_modify_header(key_pair.private_key(), |header| {
+                    header.height = std::num::NonZeroU64::new(height).unwrap();
+                })
+                .into()
+            })
+            .collect::<Vec<SignedBlock>>();
+        let read_block = |block_store: &BlockStore, block_index: u64| {
+            let index = block_store.read_block_index(block_index).unwrap();
+            let block_buf = block_store.read_block_bytes(index).unwrap();
+            SignedBlock::decode_all_versioned(&block_buf).unwrap()
+        };
+        let data_len = || {
+            std::fs::metadata(dir.path().join("store").join(DATA_FILE_NAME))
+                .unwrap()
+                .len()
+        };
+
+        for block in &blocks[..10] {
+            block_store.append_block_to_chain(block).unwrap();
+        }
+        let full_data_len = data_len();
+
+        let pruned = block_store.prune_blocks(4, Some(&mut archive)).unwrap();
+        assert_eq!(pruned, block_store.read_pruned_blocks().unwrap());
+        assert_eq!(pruned.height(), 5);
+        assert!(data_len() < full_data_len);
+        for block_index in [0, 5, 9] {
+            assert_eq!(
+                read_block(&block_store, block_index),
+                blocks[block_index as usize]
+            );
+        }
+        assert_eq!(block_store.read_index_count().unwrap(), 10);
+        assert_eq!(
+            block_store.read_block_hashes(0, 10).unwrap(),
+            blocks[..10]
+                .iter()
+                .map(SignedBlock::hash)
+                .collect::<Vec<_>>()
+        );
+
+        // Archive contains the chain up to the latest pruned block
+        assert_eq!(archive.read_index_count().unwrap(), 5);
+        for block_index in 0..5 {
+            assert_eq!(
+                read_block(&archive, block_index),
+                blocks[block_index as usize]
+            );
+        }
+
+        // Blocks are appended after pruning and the latest block is never pruned
+        block_store.append_block_to_chain(&blocks[10]).unwrap();
+        let pruned = block_store.prune_blocks(100, Some(&mut archive)).unwrap();
+        assert_eq!(pruned.height(), 10);
+        assert_eq!(read_block(&block_store, 0), blocks[0]);
+        assert_eq!(read_block(&block_store, 10), blocks[10]);
+        assert_eq!(archive.read_index_count().unwrap(), 10);
+    }
+
+    #[test]
+    fn truncate_removes_blocks_from_all_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut block_store = BlockStore::new(dir.path());
+        block_store.create_files_if_they_do_not_exist().unwrap();
+
+        let dummy_block = ValidBlock::new_dummy(KeyPair::random().private_key()).into();
+        for _ in 0..5 {
+            block_store.append_block_to_chain(&dummy_block).unwrap();
+        }
+        // Data of the interrupted write following the latest block
+        let block_data = dummy_block.encode_versioned();
+        block_store
+            .write_block_data(5 * block_data.len() as u64, &block_data)
+            .unwrap();
+
+        block_store.truncate(3).unwrap();
+        assert_eq!(block_store.read_index_count().unwrap(), 3);
+        assert_eq!(block_store.read_hashes_count().unwrap(), 3);
+        assert_eq!(
+            std::fs::metadata(dir.path().join(DATA_FILE_NAME))
+                .unwrap()
+                .len(),
+            3 * block_data.len() as u64
+        );
+
+        block_store.prune_blocks(1, None).unwrap();
+        assert!(matches!(
+            block_store.truncate(2),
+            Err(Error::TruncatePruned { pruned_height: 2 })
+        ));
+    }
+
+    fn test_config(temp_dir: &TempDir) -> Config {
+        Config {
             init_mode: InitMode::Strict,
             store_dir: iroha_config::base::WithOrigin::inline(
                 temp_dir.path().to_str().unwrap().into(),
             ),
             blocks_in_memory: BLOCKS_IN_MEMORY,
+            compression: Compression::None,
+            pruning_mode: PruningMode::Disabled,
+            pruning_retain_blocks: PRUNING_RETAIN_BLOCKS,
+            pruning_archive_dir: iroha_config::base::WithOrigin::inline(
+                temp_dir.path().join("archive"),
+            ),
             debug_output_new_blocks: false,
-        })
-        .unwrap();
+        }
+    }
+
+    #[test]
+    fn strict_init_kura() {
+        let temp_dir = TempDir::new().unwrap();
+        Kura::new(&test_config(&temp_dir)).unwrap();
     }
 
     #[test]
@@ -1035,15 +1708,7 @@ mod tests {
 
         // Reinitialize kura and check that correct blocks are loaded
         {
-            let (kura, block_count) = Kura::new(&Config {
-                init_mode: InitMode::Strict,
-                store_dir: iroha_config::base::WithOrigin::inline(
-                    temp_dir.path().to_str().unwrap().into(),
-                ),
-                blocks_in_memory: BLOCKS_IN_MEMORY,
-                debug_output_new_blocks: false,
-            })
-            .unwrap();
+            let (kura, block_count) = Kura::new(&test_config(&temp_dir)).unwrap();
 
             assert_eq!(block_count.0, 3);
 
@@ -1062,6 +1727,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn migrate_blocks_with_compression() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        {
+            let _rt_guard = rt.enter();
+            let _logger = iroha_logger::test_logger();
+        }
+
+        let temp_dir = TempDir::new().unwrap();
+        let [block_genesis, _block, block_soft_fork, block_next] =
+            create_blocks(&rt, &temp_dir).try_into().unwrap();
+
+        let migrated_dir = TempDir::new().unwrap();
+        let mut migrated = BlockStore::new(migrated_dir.path()).with_compression(Compression::Lz4);
+        migrated.create_files_if_they_do_not_exist().unwrap();
+        BlockStore::new(temp_dir.path())
+            .migrate_to(&mut migrated)
+            .unwrap();
+        assert!(matches!(
+            BlockStore::new(temp_dir.path()).migrate_to(&mut migrated),
+            Err(Error::NotEmpty(_))
+        ));
+
+        // Genesis block contains the executor, so it's compressed
+        let genesis_index = migrated.read_block_index(0).unwrap();
+        assert_eq!(genesis_index.compression, Compression::Lz4);
+        assert!(
+            genesis_index.length
+                < BlockStore::new(temp_dir.path())
+                    .read_block_index(0)
+                    .unwrap()
+                    .length
+        );
+
+        let (kura, block_count) = Kura::new(&test_config(&migrated_dir)).unwrap();
+        assert_eq!(block_count.0, 3);
+        for (height, block) in [
+            (nonzero!(1_usize), block_genesis),
+            (nonzero!(2_usize), block_soft_fork),
+            (nonzero!(3_usize), block_next),
+        ] {
+            assert_eq!(kura.get_block(height), Some(Arc::new(block.into())));
+        }
+    }
+
     #[allow(clippy::too_many_lines)]
     fn create_blocks(rt: &tokio::runtime::Runtime, temp_dir: &TempDir) -> Vec<CommittedBlock> {
         const BLOCK_FLUSH_TIMEOUT: Duration = Duration::from_secs(1);
@@ -1087,15 +1801,7 @@ mod tests {
             LiveQueryStore::start_test()
         };
 
-        let (kura, block_count) = Kura::new(&Config {
-            init_mode: InitMode::Strict,
-            store_dir: iroha_config::base::WithOrigin::inline(
-                temp_dir.path().to_str().unwrap().into(),
-            ),
-            blocks_in_memory: BLOCKS_IN_MEMORY,
-            debug_output_new_blocks: false,
-        })
-        .unwrap();
+        let (kura, block_count) = Kura::new(&test_config(&temp_dir)).unwrap();
         // Starting with empty block store
         assert_eq!(block_count.0, 0);
 
@@ -1149,10 +1855,22 @@ mod tests {
         let tx2 = TransactionBuilder::new(chain_id.clone(), account_id)
             .with_instructions([Log::new(Level::INFO, "msg2".to_string())])
             .sign(account_keypair.private_key());
-        let tx1 =
-            crate::AcceptedTransaction::accept(tx1, &chain_id, max_clock_drift, tx_limits).unwrap();
-        let tx2 =
-            crate::AcceptedTransaction::accept(tx2, &chain_id, max_clock_drift, tx_limits).unwrap();
+        let tx1 = crate::AcceptedTransaction::accept(
+            tx1,
+            &chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )
+        .unwrap();
+        let tx2 = crate::AcceptedTransaction::accept(
+            tx2,
+            &chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )
+        .unwrap();
 
         {
             let unverified_block = BlockBuilder::new(vec![tx1.clone()])
@@ -114,6 +114,90 @@ pub mod role {
         key: RoleIdWithOwnerRef<'_>,
         trait: AsRoleIdWithOwnerRef
     }
+
+    /// Account or role a temporary grant was given to.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Decode, Encode, Deserialize, Serialize,
+    )]
+    pub enum GrantOwner {
+        /// Permission or role granted to an account.
+        Account(AccountId),
+        /// Permission granted to a role.
+        Role(RoleId),
+    }
+
+    /// [`GrantedObject`] with its [`GrantOwner`] attached to it.
+    #[derive(
+        Debug,
+        Clone,
+        Constructor,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+    )]
+    pub struct GrantWithOwner {
+        /// Owner of the grant.
+        pub owner: GrantOwner,
+        /// Permission or role granted to the owner.
+        pub object: GrantedObject,
+    }
+
+    /// [`GrantWithOwner`] ordered by its expiry time first.
+    #[derive(
+        Debug,
+        Clone,
+        Constructor,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+    )]
+    pub struct GrantExpiry {
+        /// Expiry time (unix time in milliseconds).
+        pub expires_at_ms: u64,
+        /// The expiring grant.
+        pub grant: GrantWithOwner,
+    }
+}
+
+pub mod alias {
+    //! Module with extension for [`AccountAlias`] to be stored inside state.
+
+    use derive_more::Constructor;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// [`AccountAlias`] with the [`AccountId`] it refers to attached to it,
+    /// ordered by the account first.
+    #[derive(
+        Debug,
+        Clone,
+        Constructor,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+    )]
+    pub struct AccountAliasWithOwner {
+        /// [`AccountId`] the alias refers to.
+        pub account: AccountId,
+        /// The alias itself.
+        pub alias: AccountAlias,
+    }
 }
 
 pub mod prelude {
@@ -67,12 +67,16 @@ impl MetricsReporter {
         {
             let mut block_index = start_index;
             while block_index < state_view.height() {
-                let Some(block) = NonZeroUsize::new(
-                    block_index
-                        .checked_add(1)
-                        .expect("INTERNAL BUG: Blockchain height exceeds usize::MAX"),
-                )
-                .and_then(|index| self.kura.get_block(index)) else {
+                let height = NonZeroUsize::MIN
+                    .checked_add(block_index)
+                    .expect("INTERNAL BUG: Blockchain height exceeds usize::MAX");
+                if self.kura.is_pruned(height) {
+                    // Transactions of the pruned blocks can't be counted
+                    block_index += 1;
+                    self.metrics.block_height.inc();
+                    continue;
+                }
+                let Some(block) = self.kura.get_block(height) else {
                     break;
                 };
                 block_index += 1;
@@ -140,6 +144,9 @@ impl MetricsReporter {
         }
 
         self.metrics.queue_size.set(self.queue.tx_len() as u64);
+        self.metrics
+            .scheduled_queue_size
+            .set(self.queue.scheduled_tx_len() as u64);
 
         Ok(())
     }
@@ -0,0 +1,335 @@
+//! Server-side evaluation of [`AggregateQuery`](iroha_data_model::query::aggregate::AggregateQuery).
+
+use std::cmp::Ordering;
+
+use iroha_data_model::{
+    prelude::*,
+    query::{
+        aggregate::{AggregateGroups, AggregateOutput, AggregateValue, Aggregation},
+        dsl::{EvaluateSelector, HasProjection, SelectorMarker},
+        error::QueryExecutionFail as Error,
+        parameters::MAX_FETCH_SIZE,
+        QueryOutputBatchBox, QueryOutputBatchBoxTuple,
+    },
+};
+
+use super::cursor::evaluate_selector_tuple;
+
+/// Number of the query results which are evaluated and reduced at once
+const BATCH_SIZE: usize = 1024;
+
+/// Reduce the selected fields of the query output with the given aggregation.
+///
+/// The output is evaluated in batches, so only a single batch and the groups are kept in memory.
+/// Groups are ordered by their keys, so the output doesn't depend on the order of the query results.
+///
+/// # Errors
+///
+/// Returns an error if the selected fields don't fit the aggregation, if the sum overflows
+/// or if there are more groups than can be returned at once.
+pub fn aggregate<T>(
+    iter: impl Iterator<Item = T>,
+    selector: &SelectorTuple<T>,
+    aggregation: Aggregation,
+    group_by: bool,
+) -> Result<AggregateOutput, Error>
+where
+    T: HasProjection<SelectorMarker, AtomType = ()> + 'static,
+    T::Projection: EvaluateSelector<T>,
+{
+    let mut iter = iter.fuse();
+    let mut batches = core::iter::from_fn(|| {
+        let batch = iter.by_ref().take(BATCH_SIZE).collect::<Vec<_>>();
+        (!batch.is_empty()).then_some(batch)
+    });
+
+    // the first batch is evaluated even if empty to learn the type of the grouping key
+    let first = batches.next().unwrap_or_default();
+    let mut aggregator = Aggregator::new(
+        aggregation,
+        group_by,
+        evaluate_selector_tuple(first, selector)?,
+    )?;
+    for batch in batches {
+        aggregator.push(evaluate_selector_tuple(batch, selector)?)?;
+    }
+
+    Ok(aggregator.finish())
+}
+
+/// Running reduction of the batches of the selected fields
+struct Aggregator {
+    aggregation: Aggregation,
+    state: State,
+}
+
+enum State {
+    Value(AggregateValue),
+    Groups {
+        /// Distinct keys of the groups in ascending order
+        keys: QueryOutputBatchBox,
+        /// Values of the groups in the order of `keys`
+        values: Vec<AggregateValue>,
+    },
+}
+
+impl Aggregator {
+    fn new(
+        aggregation: Aggregation,
+        group_by: bool,
+        first: QueryOutputBatchBoxTuple,
+    ) -> Result<Self, Error> {
+        let state = if group_by {
+            let keys = first.tuple.first().ok_or(Error::InvalidAggregation)?;
+            State::Groups {
+                keys: keys.select_elements(&[]),
+                values: Vec::new(),
+            }
+        } else {
+            State::Value(initial_value(aggregation))
+        };
+
+        let mut aggregator = Self { aggregation, state };
+        aggregator.push(first)?;
+        Ok(aggregator)
+    }
+
+    fn push(&mut self, batch: QueryOutputBatchBoxTuple) -> Result<(), Error> {
+        let aggregation = self.aggregation;
+        let mut fields = batch.into_iter();
+
+        match &mut self.state {
+            State::Value(value) => {
+                let batch_value = match aggregation {
+                    Aggregation::Count => {
+                        AggregateValue::Count(fields.next().map_or(0, |field| field.len() as u64))
+                    }
+                    _ => numeric_field(fields.next())?.into_iter().try_fold(
+                        initial_value(aggregation),
+                        |acc, value| {
+                            combine(aggregation, acc, AggregateValue::Numeric(Some(value)))
+                        },
+                    )?,
+                };
+                *value = combine(aggregation, value.clone(), batch_value)?;
+            }
+            State::Groups { keys, values } => {
+                let batch_keys = fields.next().ok_or(Error::InvalidAggregation)?;
+                let batch_values = match aggregation {
+                    Aggregation::Count => vec![AggregateValue::Count(1); batch_keys.len()],
+                    _ => numeric_field(fields.next())?
+                        .into_iter()
+                        .map(|value| AggregateValue::Numeric(Some(value)))
+                        .collect(),
+                };
+
+                // keys of the known groups go first, followed by the keys of the batch
+                let known = values.len();
+                keys.extend(batch_keys);
+                let value_at = |idx: usize| {
+                    if idx < known {
+                        values[idx].clone()
+                    } else {
+                        batch_values[idx - known].clone()
+                    }
+                };
+
+                let mut incomparable = false;
+                let mut indices = (0..keys.len()).collect::<Vec<_>>();
+                indices.sort_by(|&left, &right| {
+                    keys.compare_elements(left, right).unwrap_or_else(|| {
+                        incomparable = true;
+                        Ordering::Equal
+                    })
+                });
+                if incomparable {
+                    return Err(Error::InvalidAggregation);
+                }
+
+                let mut group_keys = Vec::new();
+                let mut group_values = Vec::new();
+                for group in indices.chunk_by(|&left, &right| {
+                    keys.compare_elements(left, right) == Some(Ordering::Equal)
+                }) {
+                    let value = group
+                        .iter()
+                        .try_fold(initial_value(aggregation), |acc, &idx| {
+                            combine(aggregation, acc, value_at(idx))
+                        })?;
+                    group_keys.push(group[0]);
+                    group_values.push(value);
+                }
+                if group_values.len() as u64 > MAX_FETCH_SIZE.get() {
+                    return Err(Error::TooManyGroups);
+                }
+
+                *keys = keys.select_elements(&group_keys);
+                *values = group_values;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> AggregateOutput {
+        match self.state {
+            State::Value(value) => AggregateOutput::Value(value),
+            State::Groups { keys, values } => {
+                AggregateOutput::Groups(AggregateGroups { keys, values })
+            }
+        }
+    }
+}
+
+fn numeric_field(field: Option<QueryOutputBatchBox>) -> Result<Vec<Numeric>, Error> {
+    match field {
+        Some(QueryOutputBatchBox::Numeric(values)) => Ok(values),
+        _ => Err(Error::InvalidAggregation),
+    }
+}
+
+fn initial_value(aggregation: Aggregation) -> AggregateValue {
+    match aggregation {
+        Aggregation::Count => AggregateValue::Count(0),
+        Aggregation::Sum => AggregateValue::Numeric(Some(Numeric::ZERO)),
+        Aggregation::Min | Aggregation::Max => AggregateValue::Numeric(None),
+    }
+}
+
+/// Combine two partial results of the aggregation
+fn combine(
+    aggregation: Aggregation,
+    acc: AggregateValue,
+    value: AggregateValue,
+) -> Result<AggregateValue, Error> {
+    use AggregateValue::{Count, Numeric as Value};
+
+    let value = match (aggregation, acc, value) {
+        (Aggregation::Count, Count(left), Count(right)) => {
+            Count(left.checked_add(right).ok_or(Error::AggregationOverflow)?)
+        }
+        (Aggregation::Sum, Value(left), Value(right)) => Value(match (left, right) {
+            (Some(left), Some(right)) => {
+                Some(left.checked_add(right).ok_or(Error::AggregationOverflow)?)
+            }
+            (left, right) => left.or(right),
+        }),
+        (Aggregation::Min, Value(left), Value(right)) => Value(left.into_iter().chain(right).min()),
+        (Aggregation::Max, Value(left), Value(right)) => Value(left.into_iter().chain(right).max()),
+        _ => return Err(Error::InvalidAggregation),
+    };
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(fields: Vec<QueryOutputBatchBox>) -> QueryOutputBatchBoxTuple {
+        QueryOutputBatchBoxTuple::new(fields)
+    }
+
+    fn aggregate_batches(
+        mut batches: impl Iterator<Item = QueryOutputBatchBoxTuple>,
+        aggregation: Aggregation,
+        group_by: bool,
+    ) -> Result<AggregateOutput, Error> {
+        let mut aggregator = Aggregator::new(aggregation, group_by, batches.next().unwrap())?;
+        for batch in batches {
+            aggregator.push(batch)?;
+        }
+        Ok(aggregator.finish())
+    }
+
+    #[test]
+    fn aggregate_without_grouping() {
+        let values = || {
+            [
+                batch(vec![QueryOutputBatchBox::Numeric(vec![
+                    numeric!(3),
+                    numeric!(1.5),
+                ])]),
+                batch(vec![QueryOutputBatchBox::Numeric(vec![numeric!(2)])]),
+            ]
+            .into_iter()
+        };
+
+        let cases = [
+            (Aggregation::Count, AggregateValue::Count(3)),
+            (
+                Aggregation::Sum,
+                AggregateValue::Numeric(Some(numeric!(6.5))),
+            ),
+            (
+                Aggregation::Min,
+                AggregateValue::Numeric(Some(numeric!(1.5))),
+            ),
+            (Aggregation::Max, AggregateValue::Numeric(Some(numeric!(3)))),
+        ];
+        for (aggregation, expected) in cases {
+            assert_eq!(
+                aggregate_batches(values(), aggregation, false).unwrap(),
+                AggregateOutput::Value(expected)
+            );
+        }
+
+        let empty = batch(vec![QueryOutputBatchBox::Numeric(vec![])]);
+        assert_eq!(
+            aggregate_batches([empty].into_iter(), Aggregation::Max, false).unwrap(),
+            AggregateOutput::Value(AggregateValue::Numeric(None))
+        );
+    }
+
+    #[test]
+    fn aggregate_by_group() {
+        let names = ["b", "a", "b", "c"].map(|name| name.parse::<Name>().unwrap());
+        let batches = [
+            batch(vec![
+                QueryOutputBatchBox::Name(names[..2].to_vec()),
+                QueryOutputBatchBox::Numeric(vec![numeric!(1), numeric!(2)]),
+            ]),
+            batch(vec![
+                QueryOutputBatchBox::Name(names[2..].to_vec()),
+                QueryOutputBatchBox::Numeric(vec![numeric!(3), numeric!(4)]),
+            ]),
+        ];
+
+        let AggregateOutput::Groups(groups) =
+            aggregate_batches(batches.into_iter(), Aggregation::Sum, true).unwrap()
+        else {
+            panic!("Expected grouped output");
+        };
+        assert_eq!(
+            groups.into_typed::<Name>().unwrap(),
+            vec![
+                (names[1].clone(), AggregateValue::Numeric(Some(numeric!(2)))),
+                (names[0].clone(), AggregateValue::Numeric(Some(numeric!(4)))),
+                (names[3].clone(), AggregateValue::Numeric(Some(numeric!(4)))),
+            ]
+        );
+    }
+
+    #[test]
+    fn aggregate_non_numeric_field_fails() {
+        let fields = batch(vec![QueryOutputBatchBox::String(vec!["1".to_owned()])]);
+
+        assert_eq!(
+            aggregate_batches([fields].into_iter(), Aggregation::Sum, false).unwrap_err(),
+            Error::InvalidAggregation
+        );
+    }
+
+    #[test]
+    fn too_many_groups_fail() {
+        let keys = (0..=MAX_FETCH_SIZE.get()).collect::<Vec<_>>();
+        let batches = keys
+            .chunks(BATCH_SIZE)
+            .map(|keys| batch(vec![QueryOutputBatchBox::U64(keys.to_vec())]));
+
+        assert_eq!(
+            aggregate_batches(batches, Aggregation::Count, true).unwrap_err(),
+            Error::TooManyGroups
+        );
+    }
+}
@@ -11,7 +11,7 @@ use iroha_data_model::{
     },
 };
 
-fn evaluate_selector_tuple<T>(
+pub(crate) fn evaluate_selector_tuple<T>(
     batch: Vec<T>,
     selector: &SelectorTuple<T>,
 ) -> Result<QueryOutputBatchBoxTuple, QueryExecutionFail>
@@ -0,0 +1,130 @@
+//! Retention of past world states for historical queries.
+
+use std::{
+    collections::VecDeque,
+    num::{NonZeroU64, NonZeroUsize},
+    sync::Arc,
+};
+
+use iroha_crypto::HashOf;
+use iroha_data_model::block::BlockHeader;
+use iroha_logger::prelude::*;
+use parking_lot::Mutex;
+use serde::de::DeserializeSeed;
+
+use crate::state::{deserialize::KuraSeed, State, StateReadOnly};
+
+/// World states of the most recent block heights, used to execute queries
+/// with [`at_height`](iroha_data_model::query::QueryWithParams::at_height) set.
+///
+/// Every retained height owns an encoded copy of the whole state,
+/// which is decoded for each query against that height.
+/// States are retained starting from the moment the peer is started.
+#[derive(Default)]
+pub struct StateHistory {
+    /// The number of the most recent heights to retain, `0` disables the history
+    depth: usize,
+    /// The maximum total size of the encoded states, older heights are dropped to stay below it
+    max_bytes: u64,
+    states: Mutex<VecDeque<RetainedState>>,
+}
+
+struct RetainedState {
+    height: NonZeroUsize,
+    block_hash: HashOf<BlockHeader>,
+    encoded: Arc<Vec<u8>>,
+}
+
+impl StateHistory {
+    /// Construct [`StateHistory`] retaining states of `depth` most recent heights
+    /// whose encoded size doesn't exceed `max_bytes` in total.
+    pub fn new(depth: usize, max_bytes: u64) -> Self {
+        Self {
+            depth,
+            max_bytes,
+            states: Mutex::new(VecDeque::with_capacity(depth)),
+        }
+    }
+
+    /// Get the state as it was after the block at the given height was committed.
+    ///
+    /// Returns `None` if the height is not committed yet or is no longer retained.
+    pub(crate) fn get(&self, height: NonZeroU64, seed: KuraSeed) -> Option<State> {
+        let height = usize::try_from(height.get()).ok()?;
+
+        let encoded = self
+            .states
+            .lock()
+            .iter()
+            .find(|retained| retained.height.get() == height)
+            .map(|retained| Arc::clone(&retained.encoded))?;
+
+        seed.deserialize(&mut serde_json::Deserializer::from_slice(&encoded))
+            .inspect_err(|error| {
+                error!(%error, %height, "Failed to decode the retained state");
+            })
+            .ok()
+    }
+
+    /// Retain a copy of the given state if its latest block wasn't retained yet.
+    ///
+    /// Must be called by the committer of blocks so that the state doesn't change while it is copied.
+    pub(crate) fn record(&self, state: &State) {
+        if self.depth == 0 {
+            return;
+        }
+
+        let (height, block_hash) = {
+            let view = state.view();
+            let (Some(height), Some(block_hash)) =
+                (NonZeroUsize::new(view.height()), view.latest_block_hash())
+            else {
+                return;
+            };
+            (height, block_hash)
+        };
+
+        if self
+            .states
+            .lock()
+            .back()
+            .is_some_and(|retained| retained.block_hash == block_hash)
+        {
+            return;
+        }
+
+        let encoded = match serde_json::to_vec(state) {
+            Ok(encoded) => encoded,
+            Err(error) => {
+                error!(%error, %height, "Failed to retain the state for historical queries");
+                return;
+            }
+        };
+
+        let mut states = self.states.lock();
+        // Block at the same height is replaced in case of a soft fork
+        while states
+            .back()
+            .is_some_and(|retained| retained.height >= height)
+        {
+            states.pop_back();
+        }
+        states.push_back(RetainedState {
+            height,
+            block_hash,
+            encoded: Arc::new(encoded),
+        });
+        while states.len() > self.depth || Self::total_bytes(&states) > self.max_bytes {
+            if states.pop_front().is_none() {
+                break;
+            }
+        }
+    }
+
+    fn total_bytes(states: &VecDeque<RetainedState>) -> u64 {
+        states
+            .iter()
+            .map(|retained| retained.encoded.len() as u64)
+            .sum()
+    }
+}
@@ -1,5 +1,7 @@
 //! This module contains [`QueryService`](service::QueryService) and helpers.
 
+pub mod aggregate;
 pub mod cursor;
+pub mod history;
 pub mod pagination;
 pub mod store;
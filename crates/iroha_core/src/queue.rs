@@ -1,20 +1,29 @@
 //! Module with queue actor
-use core::time::Duration;
-use std::{num::NonZeroUsize, ops::Deref, sync::Arc};
+use core::{cmp::Reverse, time::Duration};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    num::NonZeroUsize,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
 
 use crossbeam_queue::ArrayQueue;
 use dashmap::{mapref::entry::Entry, DashMap};
 use eyre::Result;
-use indexmap::IndexSet;
-use iroha_config::parameters::actual::Queue as Config;
+use indexmap::{IndexMap, IndexSet};
+use iroha_config::{parameters::actual::Queue as Config, queue::Policy};
 use iroha_crypto::HashOf;
 use iroha_data_model::{
     account::AccountId,
     events::pipeline::{TransactionEvent, TransactionStatus},
     transaction::prelude::*,
 };
-use iroha_logger::{trace, warn};
-use iroha_primitives::time::TimeSource;
+use iroha_logger::{debug, trace, warn};
+use iroha_primitives::{numeric::Numeric, time::TimeSource};
+use parking_lot::Mutex;
 use thiserror::Error;
 
 use crate::{prelude::*, EventsSender};
@@ -51,6 +60,115 @@ pub struct Queue {
     pub tx_time_to_live: Duration,
     /// Queue to gossip transactions
     tx_gossip: ArrayQueue<HashOf<SignedTransaction>>,
+    /// The order in which transactions are taken from the queue
+    policy: Policy,
+    /// Transactions waiting to be taken from the queue in the order they are taken.
+    /// Used instead of [`Self::tx_hashes`] with [`Policy::Priority`]
+    prioritized_txs: Mutex<PrioritizedTransactions>,
+    /// Arrival counter, keeps transactions of the same priority in the order of arrival
+    arrivals: AtomicU64,
+    /// Scheduled [`AcceptedTransaction`]s held until they become valid, addressed by `Hash`
+    scheduled_txs: DashMap<HashOf<SignedTransaction>, AcceptedTransaction>,
+    /// The maximum number of scheduled transactions in the queue
+    capacity_scheduled: NonZeroUsize,
+    /// Statuses of the transactions which were recently dropped from the queue, the oldest first.
+    /// Holds at most [`Self::capacity`] transactions
+    dropped_txs: Mutex<IndexMap<HashOf<SignedTransaction>, TransactionStatus>>,
+}
+
+/// Queued transaction ordered by [`Policy::Priority`], the highest priority first
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct PrioritizedTransaction {
+    priority: Reverse<Numeric>,
+    arrival: u64,
+    hash: HashOf<SignedTransaction>,
+    authority: AccountId,
+}
+
+/// Transactions waiting in the queue under [`Policy::Priority`].
+///
+/// Accounts take turns in rounds: every account with waiting transactions gets
+/// its highest priority transaction taken once per round, the accounts with the
+/// highest priority transactions first.
+#[derive(Debug, Default)]
+struct PrioritizedTransactions {
+    /// Waiting transactions of every account
+    per_account: HashMap<AccountId, BTreeSet<PrioritizedTransaction>>,
+    /// The highest priority transaction of every account which didn't have its turn in the current round yet
+    current_round: BTreeSet<PrioritizedTransaction>,
+    /// Accounts which already had their turn in the current round
+    next_round: HashSet<AccountId>,
+    /// All waiting transactions, used to find the lowest priority one
+    all: BTreeSet<PrioritizedTransaction>,
+}
+
+impl PrioritizedTransactions {
+    fn insert(&mut self, tx: PrioritizedTransaction) {
+        self.all.insert(tx.clone());
+        let account_txs = self.per_account.entry(tx.authority.clone()).or_default();
+        let prev_first = account_txs.first().cloned();
+        account_txs.insert(tx.clone());
+
+        if self.next_round.contains(&tx.authority) {
+            return;
+        }
+        match prev_first {
+            None => {
+                self.current_round.insert(tx);
+            }
+            Some(prev_first) if tx < prev_first => {
+                self.current_round.remove(&prev_first);
+                self.current_round.insert(tx);
+            }
+            Some(_) => {}
+        }
+    }
+
+    /// Take the next transaction in turn.
+    fn pop(&mut self) -> Option<PrioritizedTransaction> {
+        if self.current_round.is_empty() {
+            for authority in core::mem::take(&mut self.next_round) {
+                if let Some(first) = self.per_account.get(&authority).and_then(BTreeSet::first) {
+                    self.current_round.insert(first.clone());
+                }
+            }
+        }
+
+        let tx = self.current_round.pop_first()?;
+        self.remove_from_account(&tx);
+        self.all.remove(&tx);
+        self.next_round.insert(tx.authority.clone());
+        Some(tx)
+    }
+
+    /// Remove the lowest priority transaction if its priority is lower than `priority`.
+    fn pop_lower_priority(&mut self, priority: Numeric) -> Option<PrioritizedTransaction> {
+        if self.all.last()?.priority.0 >= priority {
+            return None;
+        }
+
+        let tx = self.all.pop_last()?;
+        self.remove_from_account(&tx);
+        if self.current_round.remove(&tx) {
+            if let Some(first) = self
+                .per_account
+                .get(&tx.authority)
+                .and_then(BTreeSet::first)
+            {
+                self.current_round.insert(first.clone());
+            }
+        }
+        Some(tx)
+    }
+
+    fn remove_from_account(&mut self, tx: &PrioritizedTransaction) {
+        if let Some(account_txs) = self.per_account.get_mut(&tx.authority) {
+            account_txs.remove(tx);
+            if account_txs.is_empty() {
+                self.per_account.remove(&tx.authority);
+            }
+        }
+    }
 }
 
 /// Queue push error
@@ -59,6 +177,10 @@ pub struct Queue {
 pub enum Error {
     /// Queue is full
     Full,
+    /// Queue can't hold any more scheduled transactions
+    ScheduledFull,
+    /// Transaction becomes valid later than the queue's transaction time to live from now
+    ScheduledTooFar,
     /// Transaction expired
     Expired,
     /// Transaction is already applied
@@ -67,6 +189,8 @@ pub enum Error {
     MaximumTransactionsPerUser,
     /// The transaction is already in the queue
     IsInQueue,
+    /// Transaction is not signed by any of the authority's signatories
+    InvalidSignature,
 }
 
 /// Failure that can pop up when pushing transaction into the queue
@@ -105,7 +229,9 @@ impl Queue {
         Config {
             capacity,
             capacity_per_user,
+            capacity_scheduled,
             transaction_time_to_live,
+            policy,
         }: Config,
         events_sender: EventsSender,
     ) -> Self {
@@ -119,6 +245,12 @@ impl Queue {
             time_source: TimeSource::new_system(),
             tx_time_to_live: transaction_time_to_live,
             tx_gossip: ArrayQueue::new(capacity.get()),
+            policy,
+            prioritized_txs: Mutex::new(PrioritizedTransactions::default()),
+            arrivals: AtomicU64::new(0),
+            scheduled_txs: DashMap::new(),
+            capacity_scheduled,
+            dropped_txs: Mutex::new(IndexMap::new()),
         }
     }
 
@@ -127,8 +259,15 @@ impl Queue {
     }
 
     /// Checks if the transaction is waiting longer than its TTL or than the TTL from [`Config`].
+    ///
+    /// Scheduled transactions are waiting from the time they become valid.
     pub fn is_expired(&self, tx: &AcceptedTransaction) -> bool {
-        let tx_creation_time = tx.as_ref().creation_time();
+        let tx_creation_time = tx
+            .as_ref()
+            .not_before()
+            .map_or(tx.as_ref().creation_time(), |not_before| {
+                core::cmp::max(tx.as_ref().creation_time(), not_before)
+            });
 
         let time_limit = tx.as_ref().time_to_live().map_or_else(
             || self.tx_time_to_live,
@@ -139,6 +278,13 @@ impl Queue {
         curr_time.saturating_sub(tx_creation_time) > time_limit
     }
 
+    /// Checks if the transaction can't be committed yet.
+    fn is_scheduled(&self, tx: &AcceptedTransaction) -> bool {
+        tx.as_ref()
+            .not_before()
+            .is_some_and(|not_before| not_before > self.time_source.get_unix_time())
+    }
+
     /// Returns all pending transactions.
     pub fn all_transactions<'state>(
         &'state self,
@@ -171,11 +317,18 @@ impl Queue {
         batch
     }
 
+    /// Priority of the transaction under [`Policy::Priority`], i.e. the tip set by its submitter.
+    fn priority(tx: &AcceptedTransaction) -> Numeric {
+        tx.as_ref().tip().unwrap_or(Numeric::ZERO)
+    }
+
     fn check_tx(&self, tx: &AcceptedTransaction, state_view: &StateView) -> Result<(), Error> {
         if self.is_expired(tx) {
             Err(Error::Expired)
         } else if tx.is_in_blockchain(state_view) {
             Err(Error::InBlockchain)
+        } else if tx.verify_signature(state_view.world()).is_err() {
+            Err(Error::InvalidSignature)
         } else {
             Ok(())
         }
@@ -190,11 +343,31 @@ impl Queue {
         if let Err(err) = self.check_tx(&tx, &state_view) {
             return Err(Failure { tx, err });
         }
+        if self.is_scheduled(&tx) {
+            return self.push_scheduled(tx);
+        }
+        let priority = match self.policy {
+            Policy::Fifo => None,
+            Policy::Priority => Some(Self::priority(&tx)),
+        };
         drop(state_view);
 
+        let hash = tx.as_ref().hash();
+        if self.scheduled_txs.contains_key(&hash) {
+            return Err(Failure {
+                tx,
+                err: Error::IsInQueue,
+            });
+        }
+        if let Some(priority) = priority {
+            // Evict before entry to avoid deadlock
+            if self.txs.len() >= self.capacity.get() && !self.txs.contains_key(&hash) {
+                self.evict_lower_priority_transaction(priority);
+            }
+        }
+
         // Get `txs_len` before entry to avoid deadlock
         let txs_len = self.txs.len();
-        let hash = tx.as_ref().hash();
         let entry = match self.txs.entry(hash) {
             Entry::Occupied(_) => {
                 return Err(Failure {
@@ -220,23 +393,111 @@ impl Queue {
             return Err(Failure { tx, err });
         }
 
+        let authority = tx.as_ref().authority().clone();
         // Insert entry first so that the `tx` popped from `queue` will always have a `(hash, tx)` record in `txs`.
         entry.insert(tx);
-        self.tx_hashes.push(hash).map_err(|err_hash| {
-            warn!("Queue is full");
-            let (_, err_tx) = self
-                .txs
-                .remove(&err_hash)
-                .expect("Inserted just before match");
-            self.decrease_per_user_tx_count(err_tx.as_ref().authority());
-            Failure {
-                tx: err_tx,
-                err: Error::Full,
+        self.enqueue(hash, authority, priority)?;
+        let _ = self.events_sender.send(
+            TransactionEvent {
+                hash,
+                block_height: None,
+                status: TransactionStatus::Queued,
             }
-        })?;
+            .into(),
+        );
+        trace!("Transaction queue length = {}", self.txs.len());
+        Ok(())
+    }
+
+    /// Make the transaction recorded in [`Self::txs`] available to be taken from the queue and gossip it.
+    fn enqueue(
+        &self,
+        hash: HashOf<SignedTransaction>,
+        authority: AccountId,
+        priority: Option<Numeric>,
+    ) -> Result<(), Failure> {
+        if let Some(priority) = priority {
+            self.prioritized_txs.lock().insert(PrioritizedTransaction {
+                priority: Reverse(priority),
+                arrival: self.arrivals.fetch_add(1, Ordering::Relaxed),
+                hash,
+                authority,
+            });
+        } else {
+            self.tx_hashes.push(hash).map_err(|err_hash| {
+                warn!("Queue is full");
+                let (_, err_tx) = self
+                    .txs
+                    .remove(&err_hash)
+                    .expect("Inserted just before enqueueing");
+                self.decrease_per_user_tx_count(err_tx.as_ref().authority());
+                Failure {
+                    tx: err_tx,
+                    err: Error::Full,
+                }
+            })?;
+        }
         if let Err(err_hash) = self.tx_gossip.push(hash) {
             warn!(tx=%err_hash, "Gossiper is lagging behind, not able to queue tx for gossiping");
         }
+
+        Ok(())
+    }
+
+    /// Hold transaction which can't be committed yet until it becomes valid.
+    ///
+    /// Scheduled transactions are accounted against [`Self::capacity_scheduled`]
+    /// and released into the queue by [`Self::release_scheduled_transactions`].
+    /// Transactions can be scheduled at most [`Self::tx_time_to_live`] ahead.
+    fn push_scheduled(&self, tx: AcceptedTransaction) -> Result<(), Failure> {
+        let now = self.time_source.get_unix_time();
+        if tx
+            .as_ref()
+            .not_before()
+            .is_some_and(|not_before| not_before.saturating_sub(now) > self.tx_time_to_live)
+        {
+            return Err(Failure {
+                tx,
+                err: Error::ScheduledTooFar,
+            });
+        }
+
+        let hash = tx.as_ref().hash();
+        if self.txs.contains_key(&hash) {
+            return Err(Failure {
+                tx,
+                err: Error::IsInQueue,
+            });
+        }
+
+        // Get `scheduled_txs_len` before entry to avoid deadlock
+        let scheduled_txs_len = self.scheduled_txs.len();
+        let entry = match self.scheduled_txs.entry(hash) {
+            Entry::Occupied(_) => {
+                return Err(Failure {
+                    tx,
+                    err: Error::IsInQueue,
+                })
+            }
+            Entry::Vacant(entry) => entry,
+        };
+
+        if scheduled_txs_len >= self.capacity_scheduled.get() {
+            warn!(
+                max = self.capacity_scheduled,
+                "Achieved maximum amount of scheduled transactions"
+            );
+            return Err(Failure {
+                tx,
+                err: Error::ScheduledFull,
+            });
+        }
+
+        if let Err(err) = self.check_and_increase_per_user_tx_count(tx.as_ref().authority()) {
+            return Err(Failure { tx, err });
+        }
+
+        entry.insert(tx);
         let _ = self.events_sender.send(
             TransactionEvent {
                 hash,
@@ -245,18 +506,75 @@ impl Queue {
             }
             .into(),
         );
-        trace!("Transaction queue length = {}", self.tx_hashes.len(),);
+        trace!(
+            "Scheduled transaction queue length = {}",
+            self.scheduled_txs.len()
+        );
         Ok(())
     }
 
+    /// Move scheduled transactions which became valid into the queue, earliest first,
+    /// and gossip them.
+    ///
+    /// Transactions stay scheduled while the queue is full.
+    /// Should be called periodically on every peer, not only on the leader.
+    pub fn release_scheduled_transactions(&self) {
+        let now = self.time_source.get_unix_time();
+        let mut due = self
+            .scheduled_txs
+            .iter()
+            .filter_map(|tx| {
+                tx.as_ref()
+                    .not_before()
+                    .filter(|not_before| *not_before <= now)
+                    .map(|not_before| (not_before, *tx.key()))
+            })
+            .collect::<Vec<_>>();
+        due.sort();
+
+        for (_, hash) in due {
+            if self.txs.len() >= self.capacity.get() {
+                break;
+            }
+            let Some((hash, tx)) = self.scheduled_txs.remove(&hash) else {
+                continue;
+            };
+
+            debug!(tx=%hash, "Releasing scheduled transaction into the queue");
+            let authority = tx.as_ref().authority().clone();
+            let priority = match self.policy {
+                Policy::Fifo => None,
+                Policy::Priority => Some(Self::priority(&tx)),
+            };
+            self.txs.insert(hash, tx);
+            if let Err(failure) = self.enqueue(hash, authority, priority) {
+                warn!(tx=%hash, reason=%failure.err, "Failed to release scheduled transaction");
+            }
+        }
+    }
+
+    /// Evict the lowest priority transaction waiting in the queue if its priority is lower than `priority`.
+    fn evict_lower_priority_transaction(&self, priority: Numeric) {
+        let Some(evicted) = self.prioritized_txs.lock().pop_lower_priority(priority) else {
+            return;
+        };
+
+        if let Some((_, tx)) = self.txs.remove(&evicted.hash) {
+            debug!(tx=%evicted.hash, "Evicting lower priority transaction from the full queue");
+            self.decrease_per_user_tx_count(tx.as_ref().authority());
+            self.report_dropped(evicted.hash, TransactionStatus::Evicted);
+        }
+    }
+
     /// Pop single transaction from the queue. Removes all transactions that fail the `tx_check`.
     fn pop_from_queue(
         self: &Arc<Self>,
+        next_hash: &mut impl FnMut() -> Option<HashOf<SignedTransaction>>,
         state_view: &StateView,
         expired_transactions: &mut Vec<AcceptedTransaction>,
     ) -> Option<TransactionGuard> {
         loop {
-            let hash = self.tx_hashes.pop()?;
+            let hash = next_hash()?;
 
             let entry = match self.txs.entry(hash) {
                 Entry::Occupied(entry) => entry,
@@ -292,6 +610,11 @@ impl Queue {
         self.txs.len()
     }
 
+    /// Return the number of scheduled transactions held in the queue until they become valid.
+    pub fn scheduled_tx_len(&self) -> usize {
+        self.scheduled_txs.len()
+    }
+
     /// Gets transactions till they fill whole block or till the end of queue.
     ///
     /// BEWARE: Shouldn't be called in parallel with itself.
@@ -319,10 +642,16 @@ impl Queue {
             return;
         }
 
+        self.release_scheduled_transactions();
         let mut expired_transactions = Vec::new();
 
-        let txs_from_queue =
-            core::iter::from_fn(|| self.pop_from_queue(state_view, &mut expired_transactions));
+        let mut next_hash = || match self.policy {
+            Policy::Fifo => self.tx_hashes.pop(),
+            Policy::Priority => self.prioritized_txs.lock().pop().map(|tx| tx.hash),
+        };
+        let txs_from_queue = core::iter::from_fn(|| {
+            self.pop_from_queue(&mut next_hash, state_view, &mut expired_transactions)
+        });
 
         let transactions_hashes: IndexSet<HashOf<SignedTransaction>> =
             transactions.iter().map(|tx| tx.as_ref().hash()).collect();
@@ -333,14 +662,7 @@ impl Queue {
 
         expired_transactions
             .into_iter()
-            .map(|tx| TransactionEvent {
-                hash: tx.as_ref().hash(),
-                block_height: None,
-                status: TransactionStatus::Expired,
-            })
-            .for_each(|e| {
-                let _ = self.events_sender.send(e.into());
-            });
+            .for_each(|tx| self.report_expired(tx.as_ref().hash()));
     }
 
     /// Overview:
@@ -357,14 +679,45 @@ impl Queue {
             self.decrease_per_user_tx_count(tx.as_ref().authority());
 
             if self.is_expired(tx) {
-                let event = TransactionEvent {
-                    hash: tx.as_ref().hash(),
-                    block_height: None,
-                    status: TransactionStatus::Expired,
-                };
-                let _ = self.events_sender.send(event.into());
+                self.report_expired(tx.as_ref().hash());
+            }
+        }
+    }
+
+    /// Remember that the transaction expired and notify subscribers.
+    fn report_expired(&self, hash: HashOf<SignedTransaction>) {
+        self.report_dropped(hash, TransactionStatus::Expired);
+    }
+
+    /// Remember the status of the transaction dropped from the queue and notify subscribers.
+    fn report_dropped(&self, hash: HashOf<SignedTransaction>, status: TransactionStatus) {
+        {
+            let mut dropped_txs = self.dropped_txs.lock();
+            if dropped_txs.len() >= self.capacity.get() {
+                dropped_txs.shift_remove_index(0);
             }
+            dropped_txs.insert(hash, status.clone());
         }
+
+        let event = TransactionEvent {
+            hash,
+            block_height: None,
+            status,
+        };
+        let _ = self.events_sender.send(event.into());
+    }
+
+    /// Check if the transaction is waiting in the queue, including scheduled transactions
+    /// and transactions taken from the queue but not yet committed.
+    pub fn contains(&self, hash: &HashOf<SignedTransaction>) -> bool {
+        self.txs.contains_key(hash) || self.scheduled_txs.contains_key(hash)
+    }
+
+    /// Status of the transaction if it recently expired or was evicted from the queue.
+    ///
+    /// Only the latest dropped transactions, as many as the queue capacity, are remembered.
+    pub fn dropped_status(&self, hash: &HashOf<SignedTransaction>) -> Option<TransactionStatus> {
+        self.dropped_txs.lock().get(hash).cloned()
     }
 
     /// Check that the user adhered to the maximum transaction per user limit and increment their transaction count.
@@ -436,6 +789,12 @@ pub mod tests {
                 capacity_per_user: cfg.capacity_per_user,
                 time_source: time_source.clone(),
                 tx_time_to_live: cfg.transaction_time_to_live,
+                policy: cfg.policy,
+                prioritized_txs: Mutex::new(PrioritizedTransactions::default()),
+                arrivals: AtomicU64::new(0),
+                scheduled_txs: DashMap::new(),
+                capacity_scheduled: cfg.capacity_scheduled,
+                dropped_txs: Mutex::new(IndexMap::new()),
             }
         }
     }
@@ -449,22 +808,41 @@ pub mod tests {
         account_id: AccountId,
         key_pair: &KeyPair,
         time_source: &TimeSource,
+    ) -> AcceptedTransaction {
+        accepted_tx_with_tip(account_id, key_pair, None, time_source)
+    }
+
+    /// Transaction with the given tip, i.e. with the given priority under [`Policy::Priority`]
+    fn accepted_tx_with_tip(
+        account_id: AccountId,
+        key_pair: &KeyPair,
+        tip: Option<u32>,
+        time_source: &TimeSource,
     ) -> AcceptedTransaction {
         let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
         // Random name needed so all transactions will be different
         let domain_name = format!("dummy{}", rand::random::<u64>());
         let fail_isi = Unregister::domain(domain_name.parse().unwrap());
         let instructions = [fail_isi];
-        let tx =
+        let mut tx =
             TransactionBuilder::new_with_time_source(chain_id.clone(), account_id, time_source)
-                .with_instructions(instructions)
-                .sign(key_pair.private_key());
+                .with_instructions(instructions);
+        if let Some(tip) = tip {
+            tx.set_tip(Numeric::from(tip));
+        }
+        let tx = tx.sign(key_pair.private_key());
         let tx_limits = TransactionParameters {
             max_instructions: nonzero!(4096_u64),
             smart_contract_size: nonzero!(1024_u64),
         };
-        AcceptedTransaction::accept(tx, &chain_id, Duration::from_millis(10), tx_limits)
-            .expect("Failed to accept Transaction.")
+        AcceptedTransaction::accept(
+            tx,
+            &chain_id,
+            Duration::from_millis(10),
+            tx_limits,
+            &World::default().view(),
+        )
+        .expect("Failed to accept Transaction.")
     }
 
     pub fn world_with_test_domains() -> World {
@@ -691,12 +1069,20 @@ pub mod tests {
         tx.set_ttl(Duration::from_millis(TTL_MS));
         let tx = tx.sign(alice_keypair.private_key());
         let tx_hash = tx.hash();
-        let tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
-            .expect("Failed to accept Transaction.");
+        let tx = AcceptedTransaction::accept(
+            tx,
+            &chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )
+        .expect("Failed to accept Transaction.");
         queue
             .push(tx.clone(), state.view())
             .expect("Failed to push tx into queue");
         let queued_tx_event = event_receiver.recv().await.unwrap();
+        assert!(queue.contains(&tx_hash));
+        assert_eq!(queue.dropped_status(&tx_hash), None);
 
         assert_eq!(
             queued_tx_event,
@@ -714,6 +1100,11 @@ pub mod tests {
         queue.get_transactions_for_block(&state.view(), max_txs_in_block, &mut txs);
         let expired_tx_event = event_receiver.recv().await.unwrap();
         assert!(txs.is_empty());
+        assert!(!queue.contains(&tx_hash));
+        assert_eq!(
+            queue.dropped_status(&tx_hash),
+            Some(TransactionStatus::Expired)
+        );
 
         assert_eq!(
             expired_tx_event,
@@ -826,6 +1217,7 @@ pub mod tests {
                 transaction_time_to_live: Duration::from_secs(100),
                 capacity: 100.try_into().unwrap(),
                 capacity_per_user: 1.try_into().unwrap(),
+                ..Config::default()
             },
             &time_source,
         );
@@ -895,4 +1287,190 @@ pub mod tests {
             )
             .expect("Failed to push tx into queue");
     }
+
+    #[test]
+    async fn priority_policy_takes_turns_between_accounts() {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world_with_test_domains(), kura, query_handle);
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+        let queue = Arc::new(Queue::test(
+            Config {
+                policy: Policy::Priority,
+                ..config_factory()
+            },
+            &time_source,
+        ));
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let (bob_id, bob_keypair) = gen_account_in("wonderland");
+
+        let txs = [
+            accepted_tx_with_tip(alice_id.clone(), &alice_keypair, Some(9), &time_source),
+            accepted_tx_with_tip(alice_id, &alice_keypair, Some(10), &time_source),
+            accepted_tx_with_tip(bob_id.clone(), &bob_keypair, None, &time_source),
+            accepted_tx_with_tip(bob_id, &bob_keypair, None, &time_source),
+        ];
+        for tx in txs.clone() {
+            queue
+                .push(tx, state.view())
+                .expect("Failed to push tx into queue");
+        }
+
+        let hashes = |txs: Vec<TransactionGuard>| {
+            txs.iter().map(|tx| tx.as_ref().hash()).collect::<Vec<_>>()
+        };
+        // Within a turn the highest priority goes first, Bob's turns keep the arrival order
+        assert_eq!(
+            hashes(queue.collect_transactions_for_block(&state.view(), nonzero!(4_usize))),
+            [&txs[1], &txs[2], &txs[0], &txs[3]].map(|tx| tx.as_ref().hash())
+        );
+    }
+
+    #[test]
+    async fn priority_policy_evicts_lower_priority_when_full() {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world_with_test_domains(), kura, query_handle);
+        let (_time_handle, time_source) = TimeSource::new_mock(Duration::default());
+        let queue = Arc::new(Queue::test(
+            Config {
+                capacity: nonzero!(2_usize),
+                policy: Policy::Priority,
+                ..config_factory()
+            },
+            &time_source,
+        ));
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let tx_with_priority = |priority| {
+            accepted_tx_with_tip(
+                alice_id.clone(),
+                &alice_keypair,
+                Some(priority),
+                &time_source,
+            )
+        };
+
+        let low = tx_with_priority(1);
+        let high = tx_with_priority(5);
+        queue
+            .push(low.clone(), state.view())
+            .expect("Queue isn't full");
+        queue
+            .push(high.clone(), state.view())
+            .expect("Queue isn't full");
+        assert!(matches!(
+            queue.push(tx_with_priority(1), state.view()),
+            Err(Failure {
+                err: Error::Full,
+                ..
+            })
+        ));
+
+        // When
+        let higher = tx_with_priority(3);
+        queue
+            .push(higher.clone(), state.view())
+            .expect("Lower priority transaction should be evicted");
+
+        // Then
+        let taken = queue.collect_transactions_for_block(&state.view(), nonzero!(10_usize));
+        assert_eq!(
+            taken
+                .iter()
+                .map(|tx| tx.as_ref().hash())
+                .collect::<Vec<_>>(),
+            [high.as_ref().hash(), higher.as_ref().hash()]
+        );
+        assert!(!queue.txs.contains_key(&low.as_ref().hash()));
+        assert_eq!(
+            queue.dropped_status(&low.as_ref().hash()),
+            Some(TransactionStatus::Evicted)
+        );
+    }
+
+    #[test]
+    async fn scheduled_transaction_is_held_until_valid() {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world_with_test_domains(), kura, query_handle);
+        let (time_handle, time_source) = TimeSource::new_mock(Duration::default());
+        let queue = Arc::new(Queue::test(
+            Config {
+                capacity_scheduled: nonzero!(1_usize),
+                ..config_factory()
+            },
+            &time_source,
+        ));
+        let scheduled_tx = |not_before| {
+            let (account_id, key_pair) = gen_account_in("wonderland");
+            let mut tx = TransactionBuilder::new_with_time_source(
+                chain_id.clone(),
+                account_id,
+                &time_source,
+            )
+            .with_instructions([Unregister::domain("dummy".parse().unwrap())]);
+            tx.set_not_before(not_before);
+            let tx_limits = TransactionParameters {
+                max_instructions: nonzero!(4096_u64),
+                smart_contract_size: nonzero!(1024_u64),
+            };
+            AcceptedTransaction::accept(
+                tx.sign(key_pair.private_key()),
+                &chain_id,
+                Duration::from_millis(10),
+                tx_limits,
+                &World::default().view(),
+            )
+            .expect("Failed to accept Transaction.")
+        };
+
+        let tx = scheduled_tx(Duration::from_secs(1));
+        queue
+            .push(tx.clone(), state.view())
+            .expect("Failed to push tx into queue");
+        assert_eq!(queue.tx_len(), 0);
+        assert_eq!(queue.scheduled_tx_len(), 1);
+        assert!(matches!(
+            queue.push(scheduled_tx(Duration::from_secs(1)), state.view()),
+            Err(Failure {
+                err: Error::ScheduledFull,
+                ..
+            })
+        ));
+        assert!(matches!(
+            queue.push(scheduled_tx(Duration::from_secs(101)), state.view()),
+            Err(Failure {
+                err: Error::ScheduledTooFar,
+                ..
+            })
+        ));
+        assert!(queue
+            .collect_transactions_for_block(&state.view(), nonzero!(10_usize))
+            .is_empty());
+
+        // When
+        time_handle.advance(Duration::from_secs(1));
+        queue.release_scheduled_transactions();
+
+        // Then
+        assert_eq!(queue.tx_len(), 1);
+        assert_eq!(
+            queue
+                .gossip_batch(10, &state.view())
+                .iter()
+                .map(|tx| tx.as_ref().hash())
+                .collect::<Vec<_>>(),
+            [tx.as_ref().hash()]
+        );
+        let taken = queue.collect_transactions_for_block(&state.view(), nonzero!(10_usize));
+        assert_eq!(
+            taken
+                .iter()
+                .map(|tx| tx.as_ref().hash())
+                .collect::<Vec<_>>(),
+            [tx.as_ref().hash()]
+        );
+        assert_eq!(queue.scheduled_tx_len(), 0);
+    }
 }
@@ -23,6 +23,8 @@ impl Registrable for iroha_data_model::account::NewAccount {
 /// - grant permissions and roles
 /// - Revoke permissions or roles
 pub mod isi {
+    use core::time::Duration;
+
     use iroha_data_model::{
         asset::{AssetType, AssetValue},
         isi::{
@@ -35,7 +37,10 @@ pub mod isi {
 
     use self::asset::isi::assert_numeric_spec;
     use super::*;
-    use crate::{role::RoleIdWithOwner, state::StateTransaction};
+    use crate::{
+        role::{GrantOwner, GrantWithOwner, RoleIdWithOwner},
+        state::StateTransaction,
+    };
 
     impl Execute for Register<Asset> {
         #[metrics(+"register_asset")]
@@ -64,6 +69,10 @@ pub mod isi {
                                 state_transaction
                                     .world
                                     .increase_asset_total_amount(&asset_id.definition, increment)?;
+                                asset::isi::emit_if_mint_cap_reached(
+                                    &asset_id.definition,
+                                    state_transaction,
+                                )?;
                             }
                             AssetValue::Store(_) => {
                                 state_transaction.world.increase_asset_total_amount(
@@ -94,6 +103,9 @@ pub mod isi {
         ) -> Result<(), Error> {
             let asset_id = self.object;
 
+            state_transaction
+                .world
+                .ensure_asset_has_no_locked_funds(&asset_id)?;
             let asset = state_transaction
                 .world
                 .assets
@@ -223,6 +235,213 @@ pub mod isi {
         }
     }
 
+    impl Execute for AddSignatory {
+        #[metrics(+"add_signatory")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account;
+            state_transaction.world.account(&account_id)?;
+
+            let mut signatories = state_transaction.world.account_signatories_of(&account_id);
+            if !signatories.insert(self.public_key.clone()) {
+                return Err(Error::InvariantViolation(format!(
+                    "Key `{}` is already a signatory of `{account_id}`",
+                    self.public_key
+                )));
+            }
+            state_transaction
+                .world
+                .set_account_signatories(&account_id, signatories);
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::SignatoryAdded(
+                    AccountSignatoryChanged {
+                        account: account_id,
+                        public_key: self.public_key,
+                    },
+                )));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RemoveSignatory {
+        #[metrics(+"remove_signatory")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account;
+            state_transaction.world.account(&account_id)?;
+
+            let mut signatories = state_transaction.world.account_signatories_of(&account_id);
+            if !signatories.remove(&self.public_key) {
+                return Err(Error::InvariantViolation(format!(
+                    "Key `{}` isn't a signatory of `{account_id}`",
+                    self.public_key
+                )));
+            }
+            if signatories.is_empty() {
+                return Err(Error::InvariantViolation(
+                    "Can't remove the last signatory of an account".to_owned(),
+                ));
+            }
+            state_transaction
+                .world
+                .set_account_signatories(&account_id, signatories);
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::SignatoryRemoved(
+                    AccountSignatoryChanged {
+                        account: account_id,
+                        public_key: self.public_key,
+                    },
+                )));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RotateKey {
+        #[metrics(+"rotate_key")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.account;
+            state_transaction.world.account(&account_id)?;
+
+            let mut old_signatories = state_transaction.world.account_signatories_of(&account_id);
+            let was_signatory = old_signatories.remove(&self.public_key);
+            state_transaction.world.set_account_signatories(
+                &account_id,
+                core::iter::once(self.public_key.clone()).collect(),
+            );
+
+            let removed = old_signatories.into_iter().map(|public_key| {
+                AccountEvent::SignatoryRemoved(AccountSignatoryChanged {
+                    account: account_id.clone(),
+                    public_key,
+                })
+            });
+            let added = (!was_signatory).then(|| {
+                AccountEvent::SignatoryAdded(AccountSignatoryChanged {
+                    account: account_id.clone(),
+                    public_key: self.public_key,
+                })
+            });
+            state_transaction.world.emit_events(removed.chain(added));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RegisterAccountAlias {
+        #[metrics(+"register_account_alias")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let alias = self.alias;
+            let account_id = self.account;
+            state_transaction.world.account(&account_id)?;
+            assert_alias_in_account_domain(&alias, &account_id)?;
+
+            // Otherwise it would be impossible to tell an alias from an id
+            if alias.name.as_ref().parse::<PublicKey>().is_ok() {
+                return Err(Error::InvariantViolation(format!(
+                    "Alias `{alias}` can't be named after a public key"
+                )));
+            }
+            if state_transaction
+                .world
+                .account_aliases
+                .get(&alias)
+                .is_some()
+            {
+                return Err(Error::InvariantViolation(format!(
+                    "Alias `{alias}` is already registered"
+                )));
+            }
+            state_transaction
+                .world
+                .insert_account_alias(alias.clone(), account_id.clone());
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AliasRegistered(AccountAliasChanged {
+                    account: account_id,
+                    alias,
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for TransferAccountAlias {
+        #[metrics(+"transfer_account_alias")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let alias = self.alias;
+            let destination = self.destination;
+            let source = state_transaction.world.account_by_alias(&alias)?.clone();
+            state_transaction.world.account(&destination)?;
+            assert_alias_in_account_domain(&alias, &destination)?;
+
+            state_transaction
+                .world
+                .insert_account_alias(alias.clone(), destination.clone());
+
+            state_transaction.world.emit_events([
+                AccountEvent::AliasUnregistered(AccountAliasChanged {
+                    account: source,
+                    alias: alias.clone(),
+                }),
+                AccountEvent::AliasRegistered(AccountAliasChanged {
+                    account: destination,
+                    alias,
+                }),
+            ]);
+
+            Ok(())
+        }
+    }
+
+    impl Execute for UnregisterAccountAlias {
+        #[metrics(+"unregister_account_alias")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let alias = self.alias;
+            let account_id = state_transaction
+                .world
+                .remove_account_alias(&alias)
+                .ok_or_else(|| FindError::AccountAlias(alias.clone()))?;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AliasUnregistered(AccountAliasChanged {
+                    account: account_id,
+                    alias,
+                })));
+
+            Ok(())
+        }
+    }
+
     impl Execute for Grant<Permission, Account> {
         #[metrics(+"grant_account_permission")]
         fn execute(
@@ -235,6 +454,7 @@ pub mod isi {
 
             // Check if account exists
             state_transaction.world.account_mut(&account_id)?;
+            assert_grant_not_expired(self.expires_at_ms, state_transaction)?;
 
             if state_transaction
                 .world
@@ -250,6 +470,15 @@ pub mod isi {
             state_transaction
                 .world
                 .add_account_permission(&account_id, permission.clone());
+            if let Some(expires_at_ms) = self.expires_at_ms {
+                state_transaction.world.insert_grant_expiry(
+                    GrantWithOwner::new(
+                        GrantOwner::Account(account_id.clone()),
+                        GrantedObject::Permission(permission.clone()),
+                    ),
+                    expires_at_ms,
+                );
+            }
 
             state_transaction
                 .world
@@ -283,6 +512,12 @@ pub mod isi {
             {
                 return Err(FindError::Permission(permission).into());
             }
+            state_transaction
+                .world
+                .remove_grant_expiry(GrantWithOwner::new(
+                    GrantOwner::Account(account_id.clone()),
+                    GrantedObject::Permission(permission.clone()),
+                ));
 
             state_transaction
                 .world
@@ -308,6 +543,7 @@ pub mod isi {
             let role_id = self.object;
 
             state_transaction.world.account(&account_id)?;
+            assert_grant_not_expired(self.expires_at_ms, state_transaction)?;
 
             if state_transaction
                 .world
@@ -324,6 +560,15 @@ pub mod isi {
                 }
                 .into());
             }
+            if let Some(expires_at_ms) = self.expires_at_ms {
+                state_transaction.world.insert_grant_expiry(
+                    GrantWithOwner::new(
+                        GrantOwner::Account(account_id.clone()),
+                        GrantedObject::Role(role_id.clone()),
+                    ),
+                    expires_at_ms,
+                );
+            }
 
             state_transaction
                 .world
@@ -357,6 +602,12 @@ pub mod isi {
             {
                 return Err(FindError::Role(role_id).into());
             }
+            state_transaction
+                .world
+                .remove_grant_expiry(GrantWithOwner::new(
+                    GrantOwner::Account(account_id.clone()),
+                    GrantedObject::Role(role_id.clone()),
+                ));
 
             state_transaction
                 .world
@@ -369,6 +620,35 @@ pub mod isi {
         }
     }
 
+    /// Assert that a temporary grant doesn't expire before the current block.
+    pub(crate) fn assert_grant_not_expired(
+        expires_at_ms: Option<u64>,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        if let Some(expires_at_ms) = expires_at_ms {
+            if Duration::from_millis(expires_at_ms) <= state_transaction.curr_block.creation_time()
+            {
+                return Err(Error::InvariantViolation(
+                    "Grant expiry time has already passed".to_owned(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Assert that the alias can refer to the account.
+    fn assert_alias_in_account_domain(
+        alias: &AccountAlias,
+        account_id: &AccountId,
+    ) -> Result<(), Error> {
+        if alias.domain != account_id.domain {
+            return Err(Error::InvariantViolation(format!(
+                "Alias `{alias}` can't refer to an account of another domain: `{account_id}`"
+            )));
+        }
+        Ok(())
+    }
+
     /// Assert that this asset can be registered to an account.
     fn assert_can_register(
         definition_id: &AssetDefinitionId,
@@ -387,6 +667,12 @@ pub mod isi {
 
         match definition.mintable {
             Mintable::Infinitely => Ok(()),
+            Mintable::Capped(cap) => match value {
+                AssetValue::Numeric(amount) => {
+                    asset::isi::assert_within_mint_cap(&definition, *amount, cap)
+                }
+                AssetValue::Store(_) => Ok(()),
+            },
             Mintable::Not => Err(Error::Mintability(MintabilityError::MintUnmintable)),
             Mintable::Once => {
                 if !value.is_zero_value() {
@@ -445,7 +731,10 @@ pub mod query {
     };
 
     use super::*;
-    use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+    use crate::{
+        smartcontracts::{ValidQuery, ValidSingularQuery},
+        state::StateReadOnly,
+    };
 
     impl ValidQuery for FindRolesByAccountId {
         #[metrics(+"find_roles_by_account_id")]
@@ -522,4 +811,44 @@ pub mod query {
                 .cloned())
         }
     }
+
+    impl ValidSingularQuery for FindAccountIdByAlias {
+        #[metrics(+"find_account_id_by_alias")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<AccountId, Error> {
+            Ok(state_ro.world().account_by_alias(&self.alias)?.clone())
+        }
+    }
+
+    impl ValidSingularQuery for FindAliasesByAccountId {
+        #[metrics(+"find_aliases_by_account_id")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Vec<AccountAlias>, Error> {
+            let account_id = &self.id;
+            state_ro.world().account(account_id)?;
+            Ok(state_ro
+                .world()
+                .aliases_of_account(account_id)
+                .cloned()
+                .collect())
+        }
+    }
+
+    impl ValidSingularQuery for FindTemporaryGrantsByAccountId {
+        #[metrics(+"find_temporary_grants_by_account_id")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Vec<TemporaryGrant>, Error> {
+            let account_id = &self.id;
+            state_ro.world().account(account_id)?;
+            let now_ms = state_ro
+                .latest_block()
+                .map_or(0, |block| block.header().creation_time_ms);
+            Ok(state_ro
+                .world()
+                .temporary_grants_of_account(account_id)
+                .map(|(object, expires_at_ms)| TemporaryGrant {
+                    object: object.clone(),
+                    expires_at_ms,
+                    remaining_ms: expires_at_ms.saturating_sub(now_ms),
+                })
+                .collect())
+        }
+    }
 }
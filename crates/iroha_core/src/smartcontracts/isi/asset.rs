@@ -33,6 +33,8 @@ impl Registrable for NewAssetDefinition {
 /// - update metadata
 /// - transfer, etc.
 pub mod isi {
+    use core::time::Duration;
+
     use iroha_data_model::{asset::AssetType, isi::error::MintabilityError};
 
     use super::*;
@@ -182,7 +184,7 @@ pub mod isi {
             )?;
             assert_numeric_spec(&self.object, &asset_definition)?;
 
-            assert_can_mint(&asset_definition, state_transaction)?;
+            assert_can_mint(&asset_definition, self.object, state_transaction)?;
             let asset = state_transaction
                 .world
                 .asset_or_insert(&asset_id, Numeric::ZERO)?;
@@ -203,6 +205,7 @@ pub mod isi {
                     .world
                     .increase_asset_total_amount(&asset_id.definition, self.object)?;
             }
+            emit_if_mint_cap_reached(&asset_id.definition, state_transaction)?;
 
             state_transaction
                 .world
@@ -215,6 +218,47 @@ pub mod isi {
         }
     }
 
+    impl Execute for SetMintCap {
+        #[metrics(+"set_mint_cap")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_definition_id = self.asset_definition;
+
+            let asset_definition = assert_asset_type(
+                &asset_definition_id,
+                state_transaction,
+                expected_asset_type_numeric,
+            )?;
+            assert_numeric_spec(&self.cap, &asset_definition)?;
+
+            let asset_definition = state_transaction
+                .world
+                .asset_definition_mut(&asset_definition_id)?;
+            match asset_definition.mintable {
+                Mintable::Infinitely | Mintable::Capped(_) => {
+                    // The cap can't be set below what is already minted
+                    if asset_definition.total_quantity > self.cap {
+                        return Err(Error::Mintability(MintabilityError::MintCapExceeded));
+                    }
+                    asset_definition.mintable = Mintable::Capped(self.cap);
+                }
+                Mintable::Once | Mintable::Not => {
+                    return Err(Error::Mintability(MintabilityError::CapOnUnmintable));
+                }
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetDefinitionEvent::MintabilityChanged(
+                    asset_definition_id.clone(),
+                )));
+            emit_if_mint_cap_reached(&asset_definition_id, state_transaction)
+        }
+    }
+
     impl Execute for Burn<Numeric, Asset> {
         fn execute(
             self,
@@ -230,6 +274,7 @@ pub mod isi {
             )?;
             assert_numeric_spec(&self.object, &asset_definition)?;
 
+            let held = state_transaction.world.asset_held_amount(&asset_id)?;
             let asset = state_transaction
                 .world
                 .assets
@@ -240,6 +285,7 @@ pub mod isi {
             };
             *quantity = quantity
                 .checked_sub(self.object)
+                .filter(|remaining| *remaining >= held)
                 .ok_or(MathError::NotEnoughQuantity)?;
 
             if asset.value.is_zero_value() {
@@ -290,6 +336,7 @@ pub mod isi {
             assert_numeric_spec(&self.object, &asset_definition)?;
 
             {
+                let held = state_transaction.world.asset_held_amount(&source_id)?;
                 let asset = state_transaction
                     .world
                     .assets
@@ -300,6 +347,7 @@ pub mod isi {
                 };
                 *quantity = quantity
                     .checked_sub(self.object)
+                    .filter(|remaining| *remaining >= held)
                     .ok_or(MathError::NotEnoughQuantity)?;
                 if asset.value.is_zero_value() {
                     assert!(state_transaction
@@ -345,6 +393,446 @@ pub mod isi {
         }
     }
 
+    impl Execute for Approve {
+        #[metrics(+"approve")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let allowance_id = self.allowance;
+
+            let asset_definition = assert_asset_type(
+                &allowance_id.asset_definition,
+                state_transaction,
+                expected_asset_type_numeric,
+            )?;
+            assert_numeric_spec(&self.amount, &asset_definition)?;
+            state_transaction.world.account(&allowance_id.owner)?;
+            state_transaction.world.account(&allowance_id.spender)?;
+
+            if self.amount.is_zero() {
+                state_transaction
+                    .world
+                    .allowances
+                    .remove(allowance_id.clone());
+            } else {
+                state_transaction
+                    .world
+                    .allowances
+                    .insert(allowance_id.clone(), self.amount);
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AllowanceChanged(Allowance::new(
+                    allowance_id,
+                    self.amount,
+                ))));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for TransferFrom {
+        #[metrics(+"transfer_from")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let allowance_id = self.allowance;
+
+            let remaining = {
+                let allowance = state_transaction
+                    .world
+                    .allowances
+                    .get_mut(&allowance_id)
+                    .ok_or_else(|| FindError::Allowance(allowance_id.clone()))?;
+                *allowance = allowance
+                    .checked_sub(self.amount)
+                    .ok_or(MathError::NotEnoughQuantity)?;
+                *allowance
+            };
+            if remaining.is_zero() {
+                state_transaction
+                    .world
+                    .allowances
+                    .remove(allowance_id.clone())
+                    .ok_or_else(|| FindError::Allowance(allowance_id.clone()))?;
+            }
+
+            Transfer::asset_numeric(allowance_id.asset(), self.amount, self.destination)
+                .execute(authority, state_transaction)?;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AllowanceChanged(Allowance::new(
+                    allowance_id,
+                    remaining,
+                ))));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for HoldAsset {
+        #[metrics(+"hold_asset")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.asset;
+
+            let asset_definition = assert_asset_type(
+                &asset_id.definition,
+                state_transaction,
+                expected_asset_type_numeric,
+            )?;
+            assert_numeric_spec(&self.amount, &asset_definition)?;
+
+            let AssetValue::Numeric(quantity) = state_transaction.world.asset(&asset_id)?.value
+            else {
+                return Err(Error::Conversion("Expected numeric asset type".to_owned()));
+            };
+            let locked = state_transaction
+                .world
+                .asset_held_amount(&asset_id)?
+                .checked_add(self.amount)
+                .ok_or(MathError::Overflow)?;
+            if locked > quantity {
+                return Err(MathError::NotEnoughQuantity.into());
+            }
+            let held = state_transaction
+                .world
+                .asset_holds
+                .get(&asset_id)
+                .copied()
+                .unwrap_or(Numeric::ZERO)
+                .checked_add(self.amount)
+                .ok_or(MathError::Overflow)?;
+            state_transaction
+                .world
+                .asset_holds
+                .insert(asset_id.clone(), held);
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::Held(AssetChanged {
+                    asset: asset_id,
+                    amount: self.amount.into(),
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for ReleaseAsset {
+        #[metrics(+"release_asset")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.asset;
+
+            let asset_definition = assert_asset_type(
+                &asset_id.definition,
+                state_transaction,
+                expected_asset_type_numeric,
+            )?;
+            assert_numeric_spec(&self.amount, &asset_definition)?;
+
+            let held = state_transaction
+                .world
+                .asset_holds
+                .get(&asset_id)
+                .copied()
+                .unwrap_or(Numeric::ZERO)
+                .checked_sub(self.amount)
+                .ok_or(MathError::NotEnoughQuantity)?;
+            if held.is_zero() {
+                state_transaction.world.asset_holds.remove(asset_id.clone());
+            } else {
+                state_transaction
+                    .world
+                    .asset_holds
+                    .insert(asset_id.clone(), held);
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::Released(AssetChanged {
+                    asset: asset_id,
+                    amount: self.amount.into(),
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for LockAsset {
+        #[metrics(+"lock_asset")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let hash_lock = self.lock;
+            let asset_id = hash_lock.asset();
+
+            let asset_definition = assert_asset_type(
+                &asset_id.definition,
+                state_transaction,
+                expected_asset_type_numeric,
+            )?;
+            assert_numeric_spec(&hash_lock.amount, &asset_definition)?;
+            state_transaction.world.account(&hash_lock.recipient)?;
+
+            if state_transaction
+                .world
+                .hash_locks
+                .get(&hash_lock.id)
+                .is_some()
+            {
+                return Err(Error::InvariantViolation(format!(
+                    "Hash lock `{}` already exists",
+                    hash_lock.id
+                )));
+            }
+            if Duration::from_millis(hash_lock.deadline_ms)
+                <= state_transaction.curr_block.creation_time()
+            {
+                return Err(Error::InvariantViolation(
+                    "Hash lock deadline has already passed".to_owned(),
+                ));
+            }
+
+            let AssetValue::Numeric(quantity) = state_transaction.world.asset(&asset_id)?.value
+            else {
+                return Err(Error::Conversion("Expected numeric asset type".to_owned()));
+            };
+            let locked = state_transaction
+                .world
+                .asset_held_amount(&asset_id)?
+                .checked_add(hash_lock.amount)
+                .ok_or(MathError::Overflow)?;
+            if locked > quantity {
+                return Err(MathError::NotEnoughQuantity.into());
+            }
+            state_transaction
+                .world
+                .insert_hash_lock(hash_lock.clone())?;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::HashLockCreated(hash_lock)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for ClaimLockedAsset {
+        #[metrics(+"claim_locked_asset")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let hash_lock = state_transaction.world.remove_hash_lock(self.lock)?;
+
+            if Duration::from_millis(hash_lock.deadline_ms)
+                <= state_transaction.curr_block.creation_time()
+            {
+                return Err(Error::InvariantViolation(
+                    "Hash lock has expired".to_owned(),
+                ));
+            }
+            if Sha256Hash::new(&self.preimage) != hash_lock.hash {
+                return Err(Error::InvariantViolation(
+                    "Preimage doesn't match the hash of the lock".to_owned(),
+                ));
+            }
+
+            // The lock is removed, so its amount is no longer held and can be transferred
+            Transfer::asset_numeric(
+                hash_lock.asset(),
+                hash_lock.amount,
+                hash_lock.recipient.clone(),
+            )
+            .execute(&hash_lock.id.sender, state_transaction)?;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::HashLockClaimed(hash_lock)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RefundLockedAsset {
+        #[metrics(+"refund_locked_asset")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let hash_lock = state_transaction.world.remove_hash_lock(self.lock)?;
+
+            if state_transaction.curr_block.creation_time()
+                < Duration::from_millis(hash_lock.deadline_ms)
+            {
+                return Err(Error::InvariantViolation(
+                    "Hash lock hasn't expired yet".to_owned(),
+                ));
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::HashLockRefunded(hash_lock)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for ProposeSwap {
+        #[metrics(+"propose_swap")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let offer = self.offer;
+
+            if offer.offered.asset.account != offer.id.proposer {
+                return Err(Error::InvariantViolation(
+                    "Offered asset has to belong to the proposer".to_owned(),
+                ));
+            }
+            if offer.requested.asset.account == offer.id.proposer {
+                return Err(Error::InvariantViolation(
+                    "Requested asset has to belong to another account".to_owned(),
+                ));
+            }
+            for leg in [&offer.offered, &offer.requested] {
+                let asset_definition = assert_asset_type(
+                    &leg.asset.definition,
+                    state_transaction,
+                    expected_asset_type_numeric,
+                )?;
+                assert_numeric_spec(&leg.amount, &asset_definition)?;
+            }
+            state_transaction
+                .world
+                .account(&offer.requested.asset.account)?;
+
+            if state_transaction.world.swap_offers.get(&offer.id).is_some() {
+                return Err(Error::InvariantViolation(format!(
+                    "Swap offer `{}` already exists",
+                    offer.id
+                )));
+            }
+            if Duration::from_millis(offer.expires_at_ms)
+                <= state_transaction.curr_block.creation_time()
+            {
+                return Err(Error::InvariantViolation(
+                    "Swap offer has already expired".to_owned(),
+                ));
+            }
+
+            state_transaction
+                .world
+                .swap_offers
+                .insert(offer.id.clone(), offer.clone());
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::SwapProposed(offer)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for AcceptSwap {
+        #[metrics(+"accept_swap")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let offer = state_transaction
+                .world
+                .swap_offers
+                .remove(self.offer.id.clone())
+                .ok_or_else(|| FindError::SwapOffer(self.offer.id.clone()))?;
+
+            if offer != self.offer {
+                return Err(Error::InvariantViolation(
+                    "Swap offer doesn't match the accepted terms".to_owned(),
+                ));
+            }
+            if Duration::from_millis(offer.expires_at_ms)
+                <= state_transaction.curr_block.creation_time()
+            {
+                return Err(Error::InvariantViolation(
+                    "Swap offer has expired".to_owned(),
+                ));
+            }
+
+            let proposer = offer.id.proposer.clone();
+            let counterparty = offer.requested.asset.account.clone();
+            Transfer::asset_numeric(
+                offer.offered.asset.clone(),
+                offer.offered.amount,
+                counterparty.clone(),
+            )
+            .execute(&proposer, state_transaction)?;
+            Transfer::asset_numeric(
+                offer.requested.asset.clone(),
+                offer.requested.amount,
+                proposer,
+            )
+            .execute(&counterparty, state_transaction)?;
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::SwapAccepted(offer)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for CancelSwap {
+        #[metrics(+"cancel_swap")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let offer = state_transaction
+                .world
+                .swap_offers
+                .remove(self.offer.clone())
+                .ok_or(FindError::SwapOffer(self.offer))?;
+
+            if *authority != offer.id.proposer
+                && state_transaction.curr_block.creation_time()
+                    < Duration::from_millis(offer.expires_at_ms)
+            {
+                return Err(Error::InvariantViolation(
+                    "Only the proposer can cancel a swap offer before it expires".to_owned(),
+                ));
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::SwapCancelled(offer)));
+
+            Ok(())
+        }
+    }
+
     /// Assert that asset type is Numeric and that it satisfy asset definition spec
     pub(crate) fn assert_numeric_spec(
         object: &Numeric,
@@ -383,13 +871,15 @@ pub mod isi {
             .map_err(Into::into)
     }
 
-    /// Assert that this asset is `mintable`.
+    /// Assert that `amount` of this asset can be minted.
     fn assert_can_mint(
         asset_definition: &AssetDefinition,
+        amount: Numeric,
         state_transaction: &mut StateTransaction<'_, '_>,
     ) -> Result<(), Error> {
         match asset_definition.mintable {
             Mintable::Infinitely => Ok(()),
+            Mintable::Capped(cap) => assert_within_mint_cap(asset_definition, amount, cap),
             Mintable::Not => Err(Error::Mintability(MintabilityError::MintUnmintable)),
             Mintable::Once => {
                 let asset_definition_id = asset_definition.id.clone();
@@ -405,6 +895,42 @@ pub mod isi {
         }
     }
 
+    /// Assert that minting `amount` keeps the total quantity of this asset within `cap`.
+    pub(crate) fn assert_within_mint_cap(
+        asset_definition: &AssetDefinition,
+        amount: Numeric,
+        cap: Numeric,
+    ) -> Result<(), Error> {
+        let total_quantity = asset_definition
+            .total_quantity
+            .checked_add(amount)
+            .ok_or(MathError::Overflow)?;
+        if total_quantity > cap {
+            return Err(Error::Mintability(MintabilityError::MintCapExceeded));
+        }
+        Ok(())
+    }
+
+    /// Emit [`AssetDefinitionEvent::MintCapReached`] if the total quantity of a capped asset reached its cap.
+    pub(crate) fn emit_if_mint_cap_reached(
+        definition_id: &AssetDefinitionId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        let asset_definition = state_transaction
+            .world
+            .asset_definition_mut(definition_id)?;
+        if let Mintable::Capped(cap) = asset_definition.mintable {
+            if asset_definition.total_quantity >= cap {
+                state_transaction
+                    .world
+                    .emit_events(Some(AssetDefinitionEvent::MintCapReached(
+                        definition_id.clone(),
+                    )));
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn expected_asset_type_numeric(asset_type: &AssetType) -> Result<(), TypeError> {
         match asset_type {
             AssetType::Numeric(_) => Ok(()),
@@ -424,7 +950,7 @@ pub mod isi {
 pub mod query {
     use eyre::Result;
     use iroha_data_model::{
-        asset::{Asset, AssetDefinition},
+        asset::{Allowance, Asset, AssetDefinition, AssetHold, HashLock, SwapOffer},
         query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error},
     };
 
@@ -459,4 +985,59 @@ pub mod query {
                 .cloned())
         }
     }
+    impl ValidQuery for FindAllowances {
+        #[metrics(+"find_allowances")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<Allowance>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = Allowance>, Error> {
+            Ok(state_ro
+                .world()
+                .allowances_iter()
+                .filter(move |allowance| filter.applies(allowance)))
+        }
+    }
+    impl ValidQuery for FindAssetHolds {
+        #[metrics(+"find_asset_holds")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<AssetHold>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = AssetHold>, Error> {
+            Ok(state_ro
+                .world()
+                .asset_holds_iter()
+                .filter(move |asset_hold| filter.applies(asset_hold)))
+        }
+    }
+    impl ValidQuery for FindHashLocks {
+        #[metrics(+"find_hash_locks")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<HashLock>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = HashLock>, Error> {
+            Ok(state_ro
+                .world()
+                .hash_locks_iter()
+                .filter(move |&hash_lock| filter.applies(hash_lock))
+                .cloned())
+        }
+    }
+
+    impl ValidQuery for FindSwapOffers {
+        #[metrics(+"find_swap_offers")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<SwapOffer>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = SwapOffer>, Error> {
+            Ok(state_ro
+                .world()
+                .swap_offers_iter()
+                .filter(move |&swap_offer| filter.applies(swap_offer))
+                .cloned())
+        }
+    }
 }
@@ -31,6 +31,7 @@ pub mod isi {
     use iroha_logger::prelude::*;
 
     use super::*;
+    use crate::smartcontracts::asset::isi::assert_numeric_spec;
 
     impl Execute for Register<Account> {
         #[metrics(+"register_account")]
@@ -78,6 +79,12 @@ pub mod isi {
         ) -> Result<(), Error> {
             let account_id = self.object;
 
+            state_transaction
+                .world
+                .ensure_account_has_no_locked_funds(&account_id)?;
+            state_transaction
+                .world
+                .ensure_not_used_for_fees(|collector| *collector == account_id, |_| false)?;
             state_transaction
                 .world()
                 .triggers()
@@ -100,9 +107,21 @@ pub mod isi {
                 .world
                 .account_permissions
                 .remove(account_id.clone());
+            state_transaction
+                .world
+                .account_signatories
+                .remove(account_id.clone());
 
             state_transaction.world.remove_account_roles(&account_id);
 
+            state_transaction.world.remove_allowances(|allowance_id| {
+                allowance_id.owner == account_id || allowance_id.spender == account_id
+            });
+            state_transaction.world.remove_account_aliases(&account_id);
+            state_transaction
+                .world
+                .remove_account_grant_expiries(&account_id);
+
             let remove_assets: Vec<AssetId> = state_transaction
                 .world
                 .assets_in_account_iter(&account_id)
@@ -137,6 +156,9 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let asset_definition = self.object.build(authority);
+            if let Mintable::Capped(cap) = asset_definition.mintable {
+                assert_numeric_spec(&cap, &asset_definition)?;
+            }
 
             let asset_definition_id = asset_definition.id().clone();
             if state_transaction
@@ -178,6 +200,14 @@ pub mod isi {
         ) -> Result<(), Error> {
             let asset_definition_id = self.object;
 
+            state_transaction
+                .world
+                .ensure_assets_have_no_locked_funds(|asset_id| {
+                    asset_id.definition == asset_definition_id
+                })?;
+            state_transaction
+                .world
+                .ensure_not_used_for_fees(|_| false, |asset| *asset == asset_definition_id)?;
             let mut assets_to_remove = Vec::new();
             assets_to_remove.extend(
                 state_transaction
@@ -189,6 +219,10 @@ pub mod isi {
                     .cloned(),
             );
 
+            state_transaction.world.remove_allowances(|allowance_id| {
+                allowance_id.asset_definition == asset_definition_id
+            });
+
             let mut events = Vec::with_capacity(assets_to_remove.len() + 1);
             for asset_id in assets_to_remove {
                 if state_transaction
@@ -55,6 +55,23 @@ impl Execute for InstructionBox {
             Self::SetParameter(isi) => isi.execute(authority, state_transaction),
             Self::Upgrade(isi) => isi.execute(authority, state_transaction),
             Self::Log(isi) => isi.execute(authority, state_transaction),
+            Self::SetMintCap(isi) => isi.execute(authority, state_transaction),
+            Self::Approve(isi) => isi.execute(authority, state_transaction),
+            Self::TransferFrom(isi) => isi.execute(authority, state_transaction),
+            Self::HoldAsset(isi) => isi.execute(authority, state_transaction),
+            Self::ReleaseAsset(isi) => isi.execute(authority, state_transaction),
+            Self::LockAsset(isi) => isi.execute(authority, state_transaction),
+            Self::ClaimLockedAsset(isi) => isi.execute(authority, state_transaction),
+            Self::RefundLockedAsset(isi) => isi.execute(authority, state_transaction),
+            Self::ProposeSwap(isi) => isi.execute(authority, state_transaction),
+            Self::AcceptSwap(isi) => isi.execute(authority, state_transaction),
+            Self::CancelSwap(isi) => isi.execute(authority, state_transaction),
+            Self::AddSignatory(isi) => isi.execute(authority, state_transaction),
+            Self::RemoveSignatory(isi) => isi.execute(authority, state_transaction),
+            Self::RotateKey(isi) => isi.execute(authority, state_transaction),
+            Self::RegisterAccountAlias(isi) => isi.execute(authority, state_transaction),
+            Self::TransferAccountAlias(isi) => isi.execute(authority, state_transaction),
+            Self::UnregisterAccountAlias(isi) => isi.execute(authority, state_transaction),
             Self::Custom(_) => {
                 panic!("Custom instructions should be handled in custom executor");
             }
@@ -488,7 +505,13 @@ mod tests {
             .with_instructions::<InstructionBox>([])
             .sign(SAMPLE_GENESIS_ACCOUNT_KEYPAIR.private_key());
         assert!(matches!(
-            AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits),
+            AcceptedTransaction::accept(
+                tx,
+                &chain_id,
+                max_clock_drift,
+                tx_limits,
+                &state.world.view()
+            ),
             Err(AcceptTransactionFail::UnexpectedGenesisAccountSignature)
         ));
         Ok(())
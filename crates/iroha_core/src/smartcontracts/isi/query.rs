@@ -1,14 +1,15 @@
 //! Query functionality. The common error type is also defined here,
 //! alongside functions for converting them into HTTP responses.
-use std::cmp::Ordering;
+use std::{cmp::Ordering, num::NonZeroU64};
 
 use eyre::Result;
 use iroha_data_model::{
     prelude::*,
     query::{
+        aggregate::{AggregateOutput, AggregateQuery, Aggregation},
         dsl::{EvaluateSelector, HasProjection, SelectorMarker},
         error::QueryExecutionFail as Error,
-        parameters::QueryParams,
+        parameters::{HasSortSelector, Pagination, QueryParams, SortOrder, Sorting},
         CommittedTransaction, QueryBox, QueryOutputBatchBox, QueryRequest,
         QueryRequestWithAuthority, QueryResponse, SingularQueryBox, SingularQueryOutputBox,
     },
@@ -16,7 +17,10 @@ use iroha_data_model::{
 
 use crate::{
     prelude::ValidSingularQuery,
-    query::{cursor::ErasedQueryIterator, pagination::Paginate as _, store::LiveQueryStoreHandle},
+    query::{
+        aggregate, cursor::ErasedQueryIterator, pagination::Paginate as _,
+        store::LiveQueryStoreHandle,
+    },
     smartcontracts::{wasm, ValidQuery},
     state::{StateReadOnly, WorldReadOnly},
 };
@@ -56,6 +60,30 @@ impl SortableQueryOutput for Asset {
     }
 }
 
+impl SortableQueryOutput for Allowance {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
+impl SortableQueryOutput for AssetHold {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
+impl SortableQueryOutput for HashLock {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
+impl SortableQueryOutput for SwapOffer {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+}
+
 impl SortableQueryOutput for Role {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
@@ -110,6 +138,75 @@ impl SortableQueryOutput for iroha_data_model::block::BlockHeader {
     }
 }
 
+/// Sorts the query output as requested by [`Sorting`].
+///
+/// The sort is stable, so the items with equal keys keep their original order, which keeps pagination consistent between requests.
+///
+/// # Errors
+///
+/// Returns an error if a sort key doesn't apply to the output type, or if the selected field cannot be ordered or is missing.
+fn sort_query_output<T>(items: Vec<T>, sorting: &Sorting) -> Result<Vec<T>, Error>
+where
+    T: SortableQueryOutput + HasSortSelector + 'static,
+    T::Projection: EvaluateSelector<T>,
+{
+    let metadata_keys = sorting.sort_by_metadata_key.as_ref().map(|key| {
+        items
+            .iter()
+            .map(|item| item.get_metadata_sorting_key(key))
+            .collect::<Vec<_>>()
+    });
+    let sort_keys = sorting
+        .keys
+        .iter()
+        .map(|key| {
+            let selector = T::from_sort_selector(&key.selector).ok_or(Error::InvalidSortKey)?;
+            Ok((selector.project_clone(items.iter())?, key.order))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut incomparable = false;
+    let mut indices = (0..items.len()).collect::<Vec<_>>();
+    indices.sort_by(|&left, &right| {
+        let by_metadata = metadata_keys.as_ref().map_or(Ordering::Equal, |keys| {
+            match (&keys[left], &keys[right]) {
+                (Some(l), Some(r)) => l.cmp(r),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        });
+
+        sort_keys
+            .iter()
+            .fold(by_metadata, |ordering, (batch, order)| {
+                ordering.then_with(|| {
+                    let Some(ordering) = batch.compare_elements(left, right) else {
+                        incomparable = true;
+                        return Ordering::Equal;
+                    };
+                    match order {
+                        SortOrder::Ascending => ordering,
+                        SortOrder::Descending => ordering.reverse(),
+                    }
+                })
+            })
+    });
+    if incomparable {
+        return Err(Error::InvalidSortKey);
+    }
+
+    let mut items = items.into_iter().map(Some).collect::<Vec<_>>();
+    Ok(indices
+        .into_iter()
+        .map(|idx| {
+            items[idx]
+                .take()
+                .expect("each index is visited exactly once")
+        })
+        .collect())
+}
+
 /// Applies sorting and pagination to the query output and wraps it into a type-erasing batching iterator.
 ///
 /// # Errors
@@ -126,7 +223,7 @@ pub fn apply_query_postprocessing<I>(
 ) -> Result<ErasedQueryIterator, Error>
 where
     I: Iterator<Item: SortableQueryOutput + Send + Sync + 'static>,
-    I::Item: HasProjection<SelectorMarker, AtomType = ()> + 'static,
+    I::Item: HasSortSelector + 'static,
     <I::Item as HasProjection<SelectorMarker>>::Projection: EvaluateSelector<I::Item> + Send + Sync,
     QueryOutputBatchBox: From<Vec<I::Item>>,
 {
@@ -139,29 +236,7 @@ where
     }
 
     // sort & paginate, erase the iterator with QueryBatchedErasedIterator
-    let output = if let Some(key) = &sorting.sort_by_metadata_key {
-        // if sorting was requested, we need to retrieve all the results first
-        let mut pairs: Vec<(Option<Json>, I::Item)> = iter
-            .map(|value| {
-                let key = value.get_metadata_sorting_key(key);
-                (key, value)
-            })
-            .collect();
-        pairs.sort_by(
-            |(left_key, _), (right_key, _)| match (left_key, right_key) {
-                (Some(l), Some(r)) => l.cmp(r),
-                (Some(_), None) => Ordering::Less,
-                (None, Some(_)) => Ordering::Greater,
-                (None, None) => Ordering::Equal,
-            },
-        );
-
-        ErasedQueryIterator::new(
-            pairs.into_iter().map(|(_, val)| val).paginate(pagination),
-            selector,
-            fetch_size,
-        )
-    } else {
+    let output = if sorting.is_empty() {
         // FP: this collect is very deliberate
         #[allow(clippy::needless_collect)]
         let output = iter
@@ -174,11 +249,84 @@ where
             .collect::<Vec<_>>();
 
         ErasedQueryIterator::new(output.into_iter(), selector, fetch_size)
+    } else {
+        // if sorting was requested, we need to retrieve all the results first
+        let output = sort_query_output(iter.collect(), sorting)?;
+
+        ErasedQueryIterator::new(
+            output.into_iter().paginate(pagination),
+            selector,
+            fetch_size,
+        )
     };
 
     Ok(output)
 }
 
+/// Applies pagination to the query output and reduces the selected fields with the aggregation.
+///
+/// The output is only sorted if a page of it is aggregated, otherwise the order doesn't affect the result
+/// and the output is reduced in batches without being collected.
+///
+/// # Errors
+///
+/// Returns an error if the selected fields cannot be aggregated
+pub fn apply_query_aggregation<I>(
+    iter: I,
+    selector: SelectorTuple<I::Item>,
+    &QueryParams {
+        pagination,
+        ref sorting,
+        fetch_size: _,
+    }: &QueryParams,
+    aggregation: Aggregation,
+    group_by: bool,
+) -> Result<AggregateOutput, Error>
+where
+    I: Iterator<Item: SortableQueryOutput + 'static>,
+    I::Item: HasSortSelector + 'static,
+    <I::Item as HasProjection<SelectorMarker>>::Projection: EvaluateSelector<I::Item>,
+{
+    if sorting.is_empty() || pagination == Pagination::default() {
+        aggregate::aggregate(iter.paginate(pagination), &selector, aggregation, group_by)
+    } else {
+        let output = sort_query_output(iter.collect(), sorting)?;
+        aggregate::aggregate(
+            output.into_iter().paginate(pagination),
+            &selector,
+            aggregation,
+            group_by,
+        )
+    }
+}
+
+/// Dispatches on the type of the iterable query, binding the concrete query to `$query` in `$body`.
+macro_rules! with_iterable_query {
+    ($query_box:expr, |$query:ident| $body:expr) => {
+        match $query_box {
+            QueryBox::FindDomains($query) => $body,
+            QueryBox::FindAccounts($query) => $body,
+            QueryBox::FindAssets($query) => $body,
+            QueryBox::FindAssetsDefinitions($query) => $body,
+            QueryBox::FindAllowances($query) => $body,
+            QueryBox::FindAssetHolds($query) => $body,
+            QueryBox::FindHashLocks($query) => $body,
+            QueryBox::FindSwapOffers($query) => $body,
+            QueryBox::FindRoles($query) => $body,
+            QueryBox::FindRoleIds($query) => $body,
+            QueryBox::FindPermissionsByAccountId($query) => $body,
+            QueryBox::FindRolesByAccountId($query) => $body,
+            QueryBox::FindAccountsWithAsset($query) => $body,
+            QueryBox::FindPeers($query) => $body,
+            QueryBox::FindActiveTriggerIds($query) => $body,
+            QueryBox::FindTriggers($query) => $body,
+            QueryBox::FindTransactions($query) => $body,
+            QueryBox::FindBlocks($query) => $body,
+            QueryBox::FindBlockHeaders($query) => $body,
+        }
+    };
+}
+
 /// Query Request statefully validated on the Iroha node side.
 #[derive(Debug, Clone)]
 pub struct ValidQueryRequest(QueryRequest);
@@ -200,6 +348,11 @@ impl ValidQueryRequest {
         Ok(Self(query.request))
     }
 
+    /// Height of the historical state this request must be executed against, `None` for the latest state.
+    pub fn at_height(&self) -> Option<NonZeroU64> {
+        self.0.at_height()
+    }
+
     /// Validate a query for a wasm program.
     ///
     /// The validation logic is defined by the implementation of the [`ValidateQueryOperation`] trait.
@@ -216,6 +369,13 @@ impl ValidQueryRequest {
     {
         use wasm::state::ValidateQueryOperation as _;
 
+        // NOTE: retained history differs between peers, so historical queries would make execution non-deterministic
+        if let Some(height) = query.at_height() {
+            return Err(ValidationFail::QueryFailed(Error::HeightNotAvailable(
+                height.get(),
+            )));
+        }
+
         state.validate_query(state.authority(), &query)?;
 
         Ok(Self(query))
@@ -223,10 +383,12 @@ impl ValidQueryRequest {
 
     /// Execute a validated query request
     ///
+    /// The request is executed against the given state regardless of its [`QueryRequest::at_height`],
+    /// the caller is responsible for looking up the historical state with [`StateHistory`](crate::query::history::StateHistory).
+    ///
     /// # Errors
     ///
     /// Returns an error if the query execution fails.
-    #[allow(clippy::too_many_lines)] // not much we can do, we _need_ to list all the box types here
     pub fn execute(
         self,
         live_query_store: &LiveQueryStoreHandle,
@@ -235,96 +397,35 @@ impl ValidQueryRequest {
     ) -> Result<QueryResponse, Error> {
         match self.0 {
             QueryRequest::Singular(singular_query) => {
-                let output = match singular_query {
+                let output = match singular_query.query {
                     SingularQueryBox::FindExecutorDataModel(q) => {
                         SingularQueryOutputBox::from(q.execute(state)?)
                     }
                     SingularQueryBox::FindParameters(q) => {
                         SingularQueryOutputBox::from(q.execute(state)?)
                     }
+                    SingularQueryBox::FindAccountIdByAlias(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindAliasesByAccountId(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindTemporaryGrantsByAccountId(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
                 };
 
                 Ok(QueryResponse::Singular(output))
             }
             QueryRequest::Start(iter_query) => {
-                let output = match iter_query.query {
-                    // dispatch on a concrete query type, erasing the type with `QueryBatchedErasedIterator` in the end
-                    QueryBox::FindDomains(q) => apply_query_postprocessing(
+                // dispatch on a concrete query type, erasing the type with `QueryBatchedErasedIterator` in the end
+                let output = with_iterable_query!(iter_query.query, |q| {
+                    apply_query_postprocessing(
                         ValidQuery::execute(q.query, q.predicate, state)?,
                         q.selector,
                         &iter_query.params,
-                    )?,
-                    QueryBox::FindAccounts(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindAssets(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindAssetsDefinitions(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindRoles(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindRoleIds(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindPermissionsByAccountId(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindRolesByAccountId(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindAccountsWithAsset(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindPeers(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindActiveTriggerIds(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindTriggers(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindTransactions(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindBlocks(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                    QueryBox::FindBlockHeaders(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
-                        q.selector,
-                        &iter_query.params,
-                    )?,
-                };
+                    )?
+                });
 
                 Ok(QueryResponse::Iterable(
                     live_query_store.handle_iter_start(output, authority)?,
@@ -333,8 +434,28 @@ impl ValidQueryRequest {
             QueryRequest::Continue(cursor) => Ok(QueryResponse::Iterable(
                 live_query_store.handle_iter_continue(cursor)?,
             )),
+            QueryRequest::Aggregate(aggregate_query) => Ok(QueryResponse::Aggregate(
+                Self::execute_aggregate(aggregate_query, state)?,
+            )),
         }
     }
+
+    fn execute_aggregate(
+        AggregateQuery {
+            query: iter_query,
+            aggregation,
+            group_by,
+        }: AggregateQuery,
+        state: &impl StateReadOnly,
+    ) -> Result<AggregateOutput, Error> {
+        with_iterable_query!(iter_query.query, |q| apply_query_aggregation(
+            ValidQuery::execute(q.query, q.predicate, state)?,
+            q.selector,
+            &iter_query.params,
+            aggregation,
+            group_by,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -386,14 +507,26 @@ mod tests {
                 let tx = TransactionBuilder::new(chain_id.clone(), ALICE_ID.clone())
                     .with_instructions::<InstructionBox>([])
                     .sign(ALICE_KEYPAIR.private_key());
-                AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)?
+                AcceptedTransaction::accept(
+                    tx,
+                    &chain_id,
+                    max_clock_drift,
+                    tx_limits,
+                    &state.world.view(),
+                )?
             };
             let invalid_tx = {
                 let fail_isi = Unregister::domain("dummy".parse().unwrap());
                 let tx = TransactionBuilder::new(chain_id.clone(), ALICE_ID.clone())
                     .with_instructions([fail_isi.clone(), fail_isi])
                     .sign(ALICE_KEYPAIR.private_key());
-                AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)?
+                AcceptedTransaction::accept(
+                    tx,
+                    &chain_id,
+                    max_clock_drift,
+                    tx_limits,
+                    &state.world.view(),
+                )?
             };
 
             let mut transactions = vec![valid_tx; valid_tx_per_block];
@@ -549,7 +682,13 @@ mod tests {
             .with_instructions::<InstructionBox>([])
             .sign(ALICE_KEYPAIR.private_key());
 
-        let va_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)?;
+        let va_tx = AcceptedTransaction::accept(
+            tx,
+            &chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )?;
 
         let (peer_public_key, _) = KeyPair::random().into_parts();
         let peer_id = PeerId::new(peer_public_key);
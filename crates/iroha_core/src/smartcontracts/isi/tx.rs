@@ -68,13 +68,15 @@ impl ValidQuery for FindTransactions {
             .all_blocks(nonzero!(1_usize))
             .rev()
             .flat_map(BlockTransactionIter::new)
-            .map(|tx| {
+            .map(move |tx| {
                 let (value, error) = tx.value();
+                let fee = state_ro.transaction_fees().get(&value.hash()).cloned();
 
                 CommittedTransaction {
                     block_hash: tx.block_hash(),
                     value,
                     error,
+                    fee,
                 }
             })
             .filter(move |tx| filter.applies(tx)))
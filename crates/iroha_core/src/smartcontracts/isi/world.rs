@@ -28,6 +28,13 @@ pub mod isi {
     use iroha_primitives::{json::Json, unique_vec::PushResult};
 
     use super::*;
+    use crate::{
+        role::{GrantOwner, GrantWithOwner},
+        smartcontracts::{
+            account::isi::assert_grant_not_expired,
+            asset::isi::{assert_asset_type, assert_numeric_spec, expected_asset_type_numeric},
+        },
+    };
 
     impl Execute for Register<Peer> {
         #[metrics(+"register_peer")]
@@ -115,6 +122,21 @@ pub mod isi {
         ) -> Result<(), Error> {
             let domain_id = self.object;
 
+            for account in state_transaction.world.accounts_in_domain_iter(&domain_id) {
+                state_transaction
+                    .world
+                    .ensure_account_has_no_locked_funds(account.id())?;
+            }
+            // Accounts of other domains can hold assets defined in this domain
+            state_transaction
+                .world
+                .ensure_assets_have_no_locked_funds(|asset_id| {
+                    asset_id.definition.domain == domain_id
+                })?;
+            state_transaction.world.ensure_not_used_for_fees(
+                |collector| collector.domain == domain_id,
+                |asset| asset.domain == domain_id,
+            )?;
             state_transaction
                 .world()
                 .triggers()
@@ -133,6 +155,12 @@ pub mod isi {
                         .expect("should succeed")
                 });
 
+            state_transaction.world.remove_allowances(|allowance_id| {
+                allowance_id.owner.domain == domain_id
+                    || allowance_id.spender.domain == domain_id
+                    || allowance_id.asset_definition.domain == domain_id
+            });
+
             let remove_accounts: Vec<AccountId> = state_transaction
                 .world
                 .accounts_in_domain_iter(&domain_id)
@@ -143,8 +171,16 @@ pub mod isi {
                     .world
                     .account_permissions
                     .remove(account.clone());
+                state_transaction
+                    .world
+                    .account_signatories
+                    .remove(account.clone());
 
                 state_transaction.world.remove_account_roles(&account);
+                state_transaction.world.remove_account_aliases(&account);
+                state_transaction
+                    .world
+                    .remove_account_grant_expiries(&account);
 
                 let remove_assets: Vec<AssetId> = state_transaction
                     .world
@@ -233,6 +269,7 @@ pub mod isi {
                 .cloned()
                 .collect::<Vec<_>>();
 
+            // Revoking also removes the expiry times of the role granted to accounts temporarily
             for account_id in accounts_with_role {
                 let revoke = Revoke {
                     object: role_id.clone(),
@@ -245,6 +282,7 @@ pub mod isi {
             if world.roles.remove(role_id.clone()).is_none() {
                 return Err(FindError::Role(role_id).into());
             }
+            world.remove_role_grant_expiries(&role_id);
 
             world.emit_events(Some(RoleEvent::Deleted(role_id)));
 
@@ -262,6 +300,8 @@ pub mod isi {
             let role_id = self.destination;
             let permission = self.object;
 
+            assert_grant_not_expired(self.expires_at_ms, state_transaction)?;
+
             let Some(role) = state_transaction.world.roles.get_mut(&role_id) else {
                 return Err(FindError::Role(role_id).into());
             };
@@ -273,6 +313,15 @@ pub mod isi {
                 }
                 .into());
             }
+            if let Some(expires_at_ms) = self.expires_at_ms {
+                state_transaction.world.insert_grant_expiry(
+                    GrantWithOwner::new(
+                        GrantOwner::Role(role_id.clone()),
+                        GrantedObject::Permission(permission.clone()),
+                    ),
+                    expires_at_ms,
+                );
+            }
 
             state_transaction
                 .world
@@ -302,6 +351,12 @@ pub mod isi {
             if !role.permissions.remove(&permission) {
                 return Err(FindError::Permission(permission).into());
             }
+            state_transaction
+                .world
+                .remove_grant_expiry(GrantWithOwner::new(
+                    GrantOwner::Role(role_id.clone()),
+                    GrantedObject::Permission(permission.clone()),
+                ));
 
             state_transaction
                 .world
@@ -341,6 +396,35 @@ pub mod isi {
                                 }))
                             );
                         })*
+                        Parameter::Fee(next) => {
+                            if let Some(fee_schedule) = &next {
+                                let asset_definition = assert_asset_type(
+                                    &fee_schedule.asset,
+                                    state_transaction,
+                                    expected_asset_type_numeric,
+                                )?;
+                                for amount in [
+                                    fee_schedule.base,
+                                    fee_schedule.per_instruction,
+                                    fee_schedule.per_fuel_unit,
+                                ] {
+                                    assert_numeric_spec(&amount, &asset_definition)?;
+                                }
+                                state_transaction.world.account(&fee_schedule.collector)?;
+                            }
+
+                            let prev = core::mem::replace(
+                                &mut state_transaction.world.parameters.fee,
+                                next.clone(),
+                            );
+
+                            state_transaction
+                                .world
+                                .emit_events(Some(ConfigurationEvent::Changed(ParameterChanged {
+                                    old_value: Parameter::Fee(prev),
+                                    new_value: Parameter::Fee(next),
+                                })));
+                        }
                         Parameter::Custom(next) => {
                             let prev = state_transaction
                                 .world
@@ -370,12 +454,26 @@ pub mod isi {
                 };
             }
 
+            // Blocks might already be pruned up to the previous height
+            if let Parameter::Block(
+                iroha_data_model::parameter::BlockParameter::MaxPrunableHeight(next),
+            ) = self.0
+            {
+                let prev = state_transaction.world.parameters.block.max_prunable_height;
+                if next < prev {
+                    return Err(Error::InvariantViolation(format!(
+                        "Max prunable height can't be decreased from {prev} to {next}"
+                    )));
+                }
+            }
+
             set_parameter!(
                 Sumeragi(sumeragi.max_clock_drift_ms) => SumeragiParameter::MaxClockDriftMs,
                 Sumeragi(sumeragi.block_time_ms) => SumeragiParameter::BlockTimeMs,
                 Sumeragi(sumeragi.commit_time_ms) => SumeragiParameter::CommitTimeMs,
 
                 Block(block.max_transactions) => BlockParameter::MaxTransactions,
+                Block(block.max_prunable_height) => BlockParameter::MaxPrunableHeight,
 
                 Transaction(transaction.max_instructions) => TransactionParameter::MaxInstructions,
                 Transaction(transaction.smart_contract_size) => TransactionParameter::SmartContractSize,
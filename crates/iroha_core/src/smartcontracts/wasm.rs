@@ -270,6 +270,15 @@ fn forget_all_executed_queries(
     }
 }
 
+/// Resources consumed by the execution of a smartcontract
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct ConsumedResources {
+    /// Amount of fuel consumed
+    pub fuel: u64,
+    /// Number of instructions executed
+    pub instructions: u64,
+}
+
 /// Limits checker for smartcontracts.
 #[derive(Copy, Clone)]
 struct LimitsExecutor {
@@ -977,11 +986,14 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
             state::specific::SmartContract::new(None),
         );
 
-        self.execute_smart_contract_with_state(bytes, state)
+        self.execute_smart_contract_with_state(bytes, state).1
     }
 
     /// Validates that the given smartcontract is eligible for execution
     ///
+    /// Returns the amount of fuel consumed and the number of instructions executed by the smartcontract
+    /// together with the validation result, resources consumed before a failure are reported too.
+    ///
     /// # Errors
     ///
     /// - if instructions failed to validate, but queries are permitted
@@ -993,7 +1005,7 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         authority: AccountId,
         bytes: impl AsRef<[u8]>,
         max_instruction_count: NonZeroU64,
-    ) -> Result<()> {
+    ) -> (ConsumedResources, Result<()>) {
         let span = wasm_log_span!("Smart contract validation", %authority);
         let state = state::SmartContract::new(
             authority,
@@ -1010,21 +1022,44 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         &mut self,
         bytes: impl AsRef<[u8]>,
         state: state::SmartContract<'wrld, 'block, 'state>,
-    ) -> Result<()> {
+    ) -> (ConsumedResources, Result<()>) {
         let mut store = self.create_store(state);
-        let smart_contract = self.create_smart_contract(&mut store, bytes)?;
+        let result = self.call_smart_contract_main(&mut store, bytes);
+
+        let consumed_fuel = self.config.fuel.get()
+            - store
+                .get_fuel()
+                .expect("Wasm Runtime config is malformed, this is a bug");
+        let mut state = store.into_data();
+        let executed_instructions = state
+            .specific_state
+            .limits_executor
+            .map_or(0, |limits_executor| limits_executor.instruction_count);
+        let executed_queries = state.take_executed_queries();
+        forget_all_executed_queries(state.state.0.query_handle, executed_queries);
+
+        let consumed = ConsumedResources {
+            fuel: consumed_fuel,
+            instructions: executed_instructions,
+        };
+        (consumed, result)
+    }
+
+    fn call_smart_contract_main(
+        &self,
+        store: &mut Store<state::SmartContract<'wrld, 'block, 'state>>,
+        bytes: impl AsRef<[u8]>,
+    ) -> Result<()> {
+        let smart_contract = self.create_smart_contract(store, bytes)?;
 
         let main_fn: TypedFunc<_, ()> =
-            Self::get_typed_func(&smart_contract, &mut store, import::SMART_CONTRACT_MAIN)?;
-        let context = Self::get_smart_contract_context(&smart_contract, &mut store);
+            Self::get_typed_func(&smart_contract, &mut *store, import::SMART_CONTRACT_MAIN)?;
+        let context = Self::get_smart_contract_context(&smart_contract, store);
 
         // NOTE: This function takes ownership of the pointer
         main_fn
-            .call(&mut store, context)
+            .call(&mut *store, context)
             .map_err(ExportFnCallError::from)?;
-        let mut state = store.into_data();
-        let executed_queries = state.take_executed_queries();
-        forget_all_executed_queries(state.state.0.query_handle, executed_queries);
 
         Ok(())
     }
@@ -1804,7 +1839,7 @@ mod tests {
         let query_handle = LiveQueryStore::start_test();
         let state = State::new(world_with_test_account(&authority), kura, query_handle);
         let query_hex = encode_hex(QueryRequest::Singular(
-            SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
+            SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel).into(),
         ));
 
         let wat = format!(
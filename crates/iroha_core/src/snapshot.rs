@@ -1,40 +1,92 @@
 //! This module contains [`State`] snapshot actor service.
+//!
+//! Snapshots are written either as plain JSON or in a binary container.
+//! A binary snapshot file starts with magic bytes and the format version
+//! followed by SCALE-encoded body, which contains the checksum of the JSON-encoded state.
+//! The encoded state is either stored in the snapshot file itself
+//! or, in the incremental mode, split into content-defined chunks which are stored separately.
+//! The whole state is still encoded in memory for every snapshot,
+//! the incremental mode only saves writing the chunks which didn't change since the previous snapshot.
 use std::{
-    io::Read,
+    collections::BTreeSet,
+    io::{Read, Write},
     num::NonZeroUsize,
     path::{Path, PathBuf},
     sync::Arc,
     time::Duration,
 };
 
-use iroha_config::{parameters::actual::Snapshot as Config, snapshot::Mode};
-use iroha_crypto::HashOf;
+use iroha_config::{
+    parameters::actual::Snapshot as Config,
+    snapshot::{Format, Mode},
+};
+use iroha_crypto::{Hash, HashOf};
 use iroha_data_model::block::BlockHeader;
 use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
 use iroha_logger::prelude::*;
-use serde::{de::DeserializeSeed, Serialize};
+use parity_scale_codec::{Decode, DecodeAll, Encode};
+use serde::de::DeserializeSeed;
 
 use crate::{
     kura::{BlockCount, Kura},
     query::store::LiveQueryStoreHandle,
-    state::{deserialize::KuraSeed, State, StateReadOnly},
+    state::{deserialize::KuraSeed, State, StateReadOnly, WorldReadOnly},
 };
 
 /// Name of the [`State`] snapshot file.
 const SNAPSHOT_FILE_NAME: &str = "snapshot.data";
 /// Name of the temporary [`State`] snapshot file.
 const SNAPSHOT_TMP_FILE_NAME: &str = "snapshot.tmp";
+/// Name of the directory with the chunks of the incremental snapshot.
+const SNAPSHOT_CHUNKS_DIR_NAME: &str = "chunks";
+/// Magic bytes identifying the binary snapshot file.
+const SNAPSHOT_MAGIC: [u8; 8] = *b"IROHASNP";
+/// Version of the binary snapshot format, bumped on incompatible changes.
+const SNAPSHOT_FORMAT_VERSION: u16 = 1;
+
+/// Chunks are never smaller than this unless it is the last chunk.
+const CHUNK_MIN_SIZE: usize = 64 * 1024;
+/// Chunks are never larger than this.
+const CHUNK_MAX_SIZE: usize = 1024 * 1024;
+/// Chunk boundary is placed where the rolling hash has these bits unset,
+/// which gives chunks of `CHUNK_MIN_SIZE + 256 KiB` on average.
+const CHUNK_BOUNDARY_MASK: u64 = (1 << 18) - 1;
 
 // /// Errors produced by [`SnapshotMaker`] actor.
 // pub type Result<T, E = Error> = core::result::Result<T, E>;
 
+/// Body of the binary snapshot file
+#[derive(Debug, Encode, Decode)]
+enum SnapshotBody {
+    /// Encoded state is stored in the snapshot file
+    Full {
+        /// Hash of the encoded state
+        checksum: Hash,
+        /// Encoded state
+        state: Vec<u8>,
+    },
+    /// Encoded state is split into chunks stored in [`SNAPSHOT_CHUNKS_DIR_NAME`]
+    Chunked {
+        /// Hash of the encoded state
+        checksum: Hash,
+        /// Hashes of the chunks which are also the names of the chunk files
+        chunks: Vec<Hash>,
+    },
+}
+
 /// Actor responsible for [`State`] snapshot reading and writing.
 pub struct SnapshotMaker {
     state: Arc<State>,
+    /// Kura whose old blocks are pruned once they are covered by a snapshot
+    kura: Arc<Kura>,
     /// Frequency at which snapshot is made
     create_every: Duration,
     /// Path to the directory where snapshots are stored
     store_dir: PathBuf,
+    /// Encoding of the snapshot file
+    format: Format,
+    /// Whether to write only chunks changed since the previous snapshot
+    incremental: bool,
     /// Hash of the latest block stored in the state
     latest_block_hash: Option<HashOf<BlockHeader>>,
 }
@@ -72,25 +124,33 @@ impl SnapshotMaker {
     /// Invoke snapshot creation task
     async fn create_snapshot(&mut self) {
         let store_dir = self.store_dir.clone();
+        let (format, incremental) = (self.format, self.incremental);
         let latest_block_hash;
         let at_height;
+        let max_prunable_height;
         {
             let state_view = self.state.view();
             latest_block_hash = state_view.latest_block_hash();
             at_height = state_view.height();
+            max_prunable_height = state_view
+                .world()
+                .parameters()
+                .block()
+                .max_prunable_height();
         }
 
         if latest_block_hash != self.latest_block_hash {
             let state = self.state.clone();
             let handle = tokio::task::spawn_blocking(move || -> Result<(), TryWriteError> {
                 // TODO: enhance error by attaching `store_dir` parameter origin
-                try_write_snapshot(&state, store_dir)
+                try_write_snapshot(&state, store_dir, format, incremental)
             });
 
             match handle.await {
                 Ok(Ok(())) => {
                     iroha_logger::info!(at_height, "Successfully created a snapshot of state");
                     self.latest_block_hash = latest_block_hash;
+                    self.prune_blocks(at_height, max_prunable_height).await;
                 }
                 Ok(Err(error)) => {
                     iroha_logger::error!(%error, "Failed to create a snapshot of state");
@@ -102,16 +162,36 @@ impl SnapshotMaker {
         }
     }
 
+    /// Prune blocks which are no longer needed to restore the state
+    async fn prune_blocks(&self, snapshot_height: usize, max_prunable_height: u64) {
+        let kura = Arc::clone(&self.kura);
+        let max_prunable_height = usize::try_from(max_prunable_height).unwrap_or(usize::MAX);
+        match tokio::task::spawn_blocking(move || kura.prune(snapshot_height, max_prunable_height))
+            .await
+        {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => {
+                iroha_logger::error!(%error, "Failed to prune blocks");
+            }
+            Err(panic) => {
+                iroha_logger::error!(%panic, "Task panicked during pruning of blocks");
+            }
+        }
+    }
+
     /// Create from [`Config`].
     ///
     /// Might return [`None`] if the configuration is not suitable for _making_ snapshots.
-    pub fn from_config(config: &Config, state: Arc<State>) -> Option<Self> {
+    pub fn from_config(config: &Config, state: Arc<State>, kura: Arc<Kura>) -> Option<Self> {
         if let Mode::ReadWrite = config.mode {
             let latest_block_hash = state.view().latest_block_hash();
             Some(Self {
                 state,
+                kura,
                 create_every: config.create_every_ms.get(),
                 store_dir: config.store_dir.resolve_relative_path(),
+                format: config.format,
+                incremental: config.incremental,
                 latest_block_hash,
             })
         } else {
@@ -122,9 +202,12 @@ impl SnapshotMaker {
 
 /// Try to deserialize [`State`] from a snapshot file.
 ///
+/// Both binary and JSON snapshots are accepted.
+///
 /// # Errors
 /// - IO errors
 /// - Deserialization errors
+/// - Unsupported version or checksum mismatch of the binary snapshot
 pub fn try_read_snapshot(
     store_dir: impl AsRef<Path>,
     kura: &Arc<Kura>,
@@ -145,12 +228,16 @@ pub fn try_read_snapshot(
     };
     file.read_to_end(&mut bytes)
         .map_err(|err| TryReadError::IO(err, path.clone()))?;
-    let mut deserializer = serde_json::Deserializer::from_slice(&bytes);
     let seed = KuraSeed {
         kura: Arc::clone(kura),
         query_handle: live_query_store_lazy(),
     };
-    let state = seed.deserialize(&mut deserializer)?;
+    let state = if let Some(rest) = bytes.strip_prefix(&SNAPSHOT_MAGIC) {
+        let encoded = read_binary_snapshot(store_dir.as_ref(), rest)?;
+        seed.deserialize(&mut serde_json::Deserializer::from_slice(&encoded))?
+    } else {
+        seed.deserialize(&mut serde_json::Deserializer::from_slice(&bytes))?
+    };
     let state_view = state.view();
     let snapshot_height = state_view.height();
     if snapshot_height > block_count {
@@ -160,22 +247,27 @@ pub fn try_read_snapshot(
         });
     }
     for height in 1..=snapshot_height {
-        let kura_block = NonZeroUsize::new(height)
-            .and_then(|height| kura.get_block(height))
+        let height = NonZeroUsize::new(height).expect("Iterating from 1");
+        // Hashes are compared without loading the blocks, which might be pruned
+        let kura_block_hash = kura
+            .get_block_hash(height)
             .expect("Kura has height at least as large as state height");
-        let snapshot_block_hash = state_view.block_hashes[height - 1];
-        if kura_block.hash() != snapshot_block_hash {
+        let snapshot_block_hash = state_view.block_hashes[height.get() - 1];
+        if kura_block_hash != snapshot_block_hash {
             // If last block hash is different it might mean that snapshot was crated for soft-fork block so just drop changes made by this block
-            if height == snapshot_height {
+            if height.get() == snapshot_height {
                 iroha_logger::warn!(
                     "Snapshot has incorrect latest block hash, discarding changes made by this block"
                 );
+                let kura_block = kura
+                    .get_block(height)
+                    .expect("Block at the snapshot height is never pruned");
                 state.block_and_revert(kura_block.header()).commit();
             } else {
                 return Err(TryReadError::MismatchedHash {
-                    height,
+                    height: height.get(),
                     snapshot_block_hash,
-                    kura_block_hash: kura_block.hash(),
+                    kura_block_hash,
                 });
             }
         }
@@ -183,30 +275,189 @@ pub fn try_read_snapshot(
     Ok(state)
 }
 
+/// Decode the state from the binary snapshot file contents following [`SNAPSHOT_MAGIC`].
+fn read_binary_snapshot(store_dir: &Path, mut bytes: &[u8]) -> Result<Vec<u8>, TryReadError> {
+    let version = u16::decode(&mut bytes)?;
+    if version != SNAPSHOT_FORMAT_VERSION {
+        return Err(TryReadError::UnsupportedVersion(version));
+    }
+
+    let (checksum, encoded) = match SnapshotBody::decode_all(&mut bytes)? {
+        SnapshotBody::Full { checksum, state } => (checksum, state),
+        SnapshotBody::Chunked { checksum, chunks } => {
+            let chunks_dir = store_dir.join(SNAPSHOT_CHUNKS_DIR_NAME);
+            let mut encoded = Vec::new();
+            for chunk in chunks {
+                let path = chunks_dir.join(chunk.to_string());
+                let mut file = std::fs::File::open(&path)
+                    .map_err(|err| TryReadError::IO(err, path.clone()))?;
+                file.read_to_end(&mut encoded)
+                    .map_err(|err| TryReadError::IO(err, path.clone()))?;
+            }
+            (checksum, encoded)
+        }
+    };
+
+    if Hash::new(&encoded) != checksum {
+        return Err(TryReadError::ChecksumMismatch);
+    }
+    Ok(encoded)
+}
+
 /// Serialize and write snapshot to file,
 /// overwriting any previously stored data.
 ///
+/// The state is encoded in memory first so that it is read for as short as possible,
+/// hashing and disk IO happen afterwards.
+///
 /// # Errors
 /// - IO errors
 /// - Serialization errors
-fn try_write_snapshot(state: &State, store_dir: impl AsRef<Path>) -> Result<(), TryWriteError> {
-    std::fs::create_dir_all(store_dir.as_ref())
-        .map_err(|err| TryWriteError::IO(err, store_dir.as_ref().to_path_buf()))?;
-    let path_to_file = store_dir.as_ref().join(SNAPSHOT_FILE_NAME);
-    let path_to_tmp_file = store_dir.as_ref().join(SNAPSHOT_TMP_FILE_NAME);
-    let file = std::fs::OpenOptions::new()
-        .create(true)
-        .write(true)
-        .truncate(true)
-        .open(&path_to_tmp_file)
-        .map_err(|err| TryWriteError::IO(err, path_to_tmp_file.clone()))?;
-    let mut serializer = serde_json::Serializer::new(file);
-    state.serialize(&mut serializer)?;
+fn try_write_snapshot(
+    state: &State,
+    store_dir: impl AsRef<Path>,
+    format: Format,
+    incremental: bool,
+) -> Result<(), TryWriteError> {
+    let store_dir = store_dir.as_ref();
+    std::fs::create_dir_all(store_dir)
+        .map_err(|err| TryWriteError::IO(err, store_dir.to_path_buf()))?;
+
+    let mut referenced_chunks = BTreeSet::new();
+    let contents = match format {
+        Format::Json => serde_json::to_vec(state)?,
+        Format::Binary => {
+            let encoded = serde_json::to_vec(state)?;
+            let checksum = Hash::new(&encoded);
+            let body = if incremental {
+                let chunks = write_chunks(store_dir, &encoded)?;
+                referenced_chunks.extend(chunks.iter().copied());
+                SnapshotBody::Chunked { checksum, chunks }
+            } else {
+                SnapshotBody::Full {
+                    checksum,
+                    state: encoded,
+                }
+            };
+            let mut contents = SNAPSHOT_MAGIC.to_vec();
+            SNAPSHOT_FORMAT_VERSION.encode_to(&mut contents);
+            body.encode_to(&mut contents);
+            contents
+        }
+    };
+
+    let path_to_file = store_dir.join(SNAPSHOT_FILE_NAME);
+    let path_to_tmp_file = store_dir.join(SNAPSHOT_TMP_FILE_NAME);
+    write_file(&path_to_tmp_file, &contents)?;
     std::fs::rename(path_to_tmp_file, &path_to_file)
         .map_err(|err| TryWriteError::IO(err, path_to_file.clone()))?;
+
+    // Chunks of the previous snapshot can only be removed once it is replaced
+    remove_unreferenced_chunks(store_dir, &referenced_chunks)
+}
+
+/// Write chunks of the encoded state which aren't stored yet, returning hashes of all chunks.
+fn write_chunks(store_dir: &Path, encoded: &[u8]) -> Result<Vec<Hash>, TryWriteError> {
+    let chunks_dir = store_dir.join(SNAPSHOT_CHUNKS_DIR_NAME);
+    std::fs::create_dir_all(&chunks_dir)
+        .map_err(|err| TryWriteError::IO(err, chunks_dir.clone()))?;
+
+    let mut hashes = Vec::new();
+    let mut rest = encoded;
+    while !rest.is_empty() {
+        let (chunk, tail) = rest.split_at(chunk_len(rest));
+        let hash = Hash::new(chunk);
+        let path = chunks_dir.join(hash.to_string());
+        // Chunks are named after their contents, so the existing one is the same
+        if !path.exists() {
+            let path_to_tmp_file = chunks_dir.join(SNAPSHOT_TMP_FILE_NAME);
+            write_file(&path_to_tmp_file, chunk)?;
+            std::fs::rename(path_to_tmp_file, &path)
+                .map_err(|err| TryWriteError::IO(err, path.clone()))?;
+        }
+        hashes.push(hash);
+        rest = tail;
+    }
+
+    Ok(hashes)
+}
+
+fn remove_unreferenced_chunks(
+    store_dir: &Path,
+    referenced: &BTreeSet<Hash>,
+) -> Result<(), TryWriteError> {
+    let chunks_dir = store_dir.join(SNAPSHOT_CHUNKS_DIR_NAME);
+    let entries = match std::fs::read_dir(&chunks_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(TryWriteError::IO(err, chunks_dir)),
+    };
+
+    for entry in entries {
+        let path = entry
+            .map_err(|err| TryWriteError::IO(err, chunks_dir.clone()))?
+            .path();
+        let is_referenced = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.parse::<Hash>().ok())
+            .is_some_and(|hash| referenced.contains(&hash));
+        if !is_referenced {
+            std::fs::remove_file(&path).map_err(|err| TryWriteError::IO(err, path.clone()))?;
+        }
+    }
+
     Ok(())
 }
 
+/// Length of the next content-defined chunk of `data`.
+///
+/// Boundaries are chosen by a rolling gear hash over the last 64 bytes,
+/// so a local change of the data only moves the boundaries near it.
+fn chunk_len(data: &[u8]) -> usize {
+    let mut fingerprint = 0_u64;
+    for (idx, &byte) in data
+        .iter()
+        .enumerate()
+        .take(CHUNK_MAX_SIZE)
+        .skip(CHUNK_MIN_SIZE)
+    {
+        fingerprint = (fingerprint << 1).wrapping_add(GEAR[usize::from(byte)]);
+        if fingerprint & CHUNK_BOUNDARY_MASK == 0 {
+            return idx + 1;
+        }
+    }
+    data.len().min(CHUNK_MAX_SIZE)
+}
+
+/// Pseudo-random values for [`chunk_len`], generated with `SplitMix64`.
+const GEAR: [u64; 256] = {
+    let mut table = [0; 256];
+    let mut seed = 0_u64;
+    let mut idx = 0;
+    while idx < table.len() {
+        seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut value = seed;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        table[idx] = value ^ (value >> 31);
+        idx += 1;
+    }
+    table
+};
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<(), TryWriteError> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(path)
+        .map_err(|err| TryWriteError::IO(err, path.to_path_buf()))?;
+    file.write_all(contents)
+        .and_then(|()| file.sync_all())
+        .map_err(|err| TryWriteError::IO(err, path.to_path_buf()))
+}
+
 /// Error variants for snapshot reading
 #[derive(thiserror::Error, Debug, displaydoc::Display)]
 pub enum TryReadError {
@@ -216,6 +467,12 @@ pub enum TryReadError {
     IO(#[source] std::io::Error, PathBuf),
     /// Error (de)serializing state snapshot
     Serialization(#[from] serde_json::Error),
+    /// Error decoding binary state snapshot header
+    Header(#[from] parity_scale_codec::Error),
+    /// Binary snapshot format version {0} is not supported
+    UnsupportedVersion(u16),
+    /// Checksum of the binary snapshot doesn't match its contents
+    ChecksumMismatch,
     /// Snapshot is in a non-consistent state. Snapshot has greater height (`snapshot_height`) than kura block store (`kura_height`)
     MismatchedHeight {
         /// The amount of block hashes stored by snapshot
@@ -273,7 +530,7 @@ mod tests {
         let snapshot_store_dir = tmp_root.path().join("path/to/snapshot/dir");
         let state = state_factory();
 
-        try_write_snapshot(&state, &snapshot_store_dir).unwrap();
+        try_write_snapshot(&state, &snapshot_store_dir, Format::Binary, false).unwrap();
 
         assert!(Path::exists(snapshot_store_dir.as_path()))
     }
@@ -284,7 +541,7 @@ mod tests {
         let store_dir = tmp_root.path().join("snapshot");
         let state = state_factory();
 
-        try_write_snapshot(&state, &store_dir).unwrap();
+        try_write_snapshot(&state, &store_dir, Format::Binary, false).unwrap();
         let _wsv = try_read_snapshot(
             &store_dir,
             &Kura::blank_kura_for_testing(),
@@ -333,6 +590,94 @@ mod tests {
         assert_eq!(format!("{error}"), "Error (de)serializing state snapshot");
     }
 
+    #[test]
+    async fn can_read_snapshot_in_every_format() {
+        let state = state_factory();
+
+        for (format, incremental) in [
+            (Format::Json, false),
+            (Format::Binary, false),
+            (Format::Binary, true),
+        ] {
+            let tmp_root = tempdir().unwrap();
+            let store_dir = tmp_root.path().join("snapshot");
+
+            try_write_snapshot(&state, &store_dir, format, incremental).unwrap();
+            let read = try_read_snapshot(
+                &store_dir,
+                &Kura::blank_kura_for_testing(),
+                LiveQueryStore::start_test,
+                BlockCount(state.view().height()),
+            )
+            .unwrap();
+
+            assert_eq!(
+                serde_json::to_value(&read).unwrap(),
+                serde_json::to_value(&state).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    async fn incremental_snapshot_writes_only_changed_chunks() {
+        let tmp_root = tempdir().unwrap();
+        let store_dir = tmp_root.path().join("snapshot");
+        let chunks_dir = store_dir.join(SNAPSHOT_CHUNKS_DIR_NAME);
+        let chunk_names = || {
+            std::fs::read_dir(&chunks_dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name())
+                .collect::<BTreeSet<_>>()
+        };
+
+        let mut value = 1_u64;
+        let mut data = (0..4 * CHUNK_MAX_SIZE)
+            .map(|_| {
+                value ^= value << 13;
+                value ^= value >> 7;
+                value ^= value << 17;
+                value.to_le_bytes()[0]
+            })
+            .collect::<Vec<_>>();
+        let chunks = write_chunks(&store_dir, &data).unwrap();
+        assert!(chunks.len() > 1);
+        assert_eq!(chunk_names().len(), chunks.len());
+
+        // Inserting at the beginning of the data keeps the rest of the chunks
+        data.insert(0, 0);
+        let changed_chunks = write_chunks(&store_dir, &data).unwrap();
+        assert_ne!(changed_chunks[0], chunks[0]);
+        assert_eq!(changed_chunks[1..], chunks[1..]);
+        assert_eq!(chunk_names().len(), chunks.len() + 1);
+
+        remove_unreferenced_chunks(&store_dir, &changed_chunks.into_iter().collect()).unwrap();
+        assert_eq!(chunk_names().len(), chunks.len());
+    }
+
+    #[test]
+    async fn cannot_read_snapshot_with_wrong_checksum() {
+        let tmp_root = tempdir().unwrap();
+        let store_dir = tmp_root.path().join("snapshot");
+        let state = state_factory();
+
+        try_write_snapshot(&state, &store_dir, Format::Binary, false).unwrap();
+        let path = store_dir.join(SNAPSHOT_FILE_NAME);
+        let mut contents = std::fs::read(&path).unwrap();
+        *contents.last_mut().unwrap() ^= 1;
+        std::fs::write(&path, contents).unwrap();
+
+        let Err(error) = try_read_snapshot(
+            &store_dir,
+            &Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test,
+            BlockCount(state.view().height()),
+        ) else {
+            panic!("should not be ok")
+        };
+
+        assert!(matches!(error, TryReadError::ChecksumMismatch));
+    }
+
     #[test]
     async fn can_read_multiple_blocks() {
         let tmp_root = tempdir().unwrap();
@@ -376,7 +721,7 @@ mod tests {
         }
         kura.store_block(committed_block);
 
-        try_write_snapshot(&state, &store_dir).unwrap();
+        try_write_snapshot(&state, &store_dir, Format::Binary, false).unwrap();
 
         let state = try_read_snapshot(
             &store_dir,
@@ -445,7 +790,7 @@ mod tests {
             .unwrap();
         kura.store_block(committed_block);
 
-        try_write_snapshot(&state, &store_dir).unwrap();
+        try_write_snapshot(&state, &store_dir, Format::Binary, false).unwrap();
 
         let state = try_read_snapshot(
             &store_dir,
@@ -1,12 +1,16 @@
 //! This module provides the [`State`] — an in-memory representation of the current blockchain state.
 use std::{
-    collections::BTreeSet, marker::PhantomData, num::NonZeroUsize, sync::Arc, time::Duration,
+    collections::BTreeSet,
+    marker::PhantomData,
+    num::{NonZeroU64, NonZeroUsize},
+    sync::Arc,
+    time::Duration,
 };
 
 use eyre::Result;
 use iroha_crypto::HashOf;
 use iroha_data_model::{
-    account::AccountId,
+    account::{AccountAlias, AccountId},
     block::{BlockHeader, SignedBlock},
     events::{
         pipeline::BlockEvent,
@@ -40,11 +44,12 @@ use serde::{
 };
 
 use crate::{
+    alias::AccountAliasWithOwner,
     block::CommittedBlock,
     executor::Executor,
     kura::Kura,
-    query::store::LiveQueryStoreHandle,
-    role::RoleIdWithOwner,
+    query::{history::StateHistory, store::LiveQueryStoreHandle},
+    role::{GrantExpiry, GrantOwner, GrantWithOwner, RoleIdWithOwner},
     smartcontracts::{
         triggers::{
             self,
@@ -75,12 +80,33 @@ pub struct World {
     pub(crate) asset_definitions: Storage<AssetDefinitionId, AssetDefinition>,
     /// Registered assets.
     pub(crate) assets: Storage<AssetId, Asset>,
+    /// Allowances granted by asset owners to spenders.
+    pub(crate) allowances: Storage<AllowanceId, Numeric>,
+    /// Locked parts of asset balances.
+    pub(crate) asset_holds: Storage<AssetId, Numeric>,
+    /// Open hash-time-locked transfers.
+    pub(crate) hash_locks: Storage<HashLockId, HashLock>,
+    pub(crate) swap_offers: Storage<SwapOfferId, SwapOffer>,
+    /// Total quantities of assets locked by open hash locks.
+    pub(crate) hash_locked_amounts: Storage<AssetId, Numeric>,
+    /// Total quantities of assets claimable by the recipients of open hash locks.
+    pub(crate) hash_claimable_amounts: Storage<AssetId, Numeric>,
+    /// Keys controlling accounts whose key set differs from their [`AccountId::signatory`].
+    pub(crate) account_signatories: Storage<AccountId, BTreeSet<PublicKey>>,
+    /// Human-readable aliases of accounts.
+    pub(crate) account_aliases: Storage<AccountAlias, AccountId>,
+    /// Aliases of accounts, ordered by the account they refer to.
+    pub(crate) account_aliases_by_account: Storage<AccountAliasWithOwner, ()>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: Storage<RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: Storage<AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: Storage<RoleIdWithOwner, ()>,
+    /// Expiry time (unix time in milliseconds) of permissions and roles granted temporarily.
+    pub(crate) grant_expiries: Storage<GrantWithOwner, u64>,
+    /// Temporary grants ordered by their expiry time
+    pub(crate) grants_by_expiry: Storage<GrantExpiry, ()>,
     /// Triggers
     pub(crate) triggers: TriggerSet,
     /// Runtime Executor
@@ -103,12 +129,33 @@ pub struct WorldBlock<'world> {
     pub(crate) asset_definitions: StorageBlock<'world, AssetDefinitionId, AssetDefinition>,
     /// Registered assets.
     pub(crate) assets: StorageBlock<'world, AssetId, Asset>,
+    /// Allowances granted by asset owners to spenders.
+    pub(crate) allowances: StorageBlock<'world, AllowanceId, Numeric>,
+    /// Locked parts of asset balances.
+    pub(crate) asset_holds: StorageBlock<'world, AssetId, Numeric>,
+    /// Open hash-time-locked transfers.
+    pub(crate) hash_locks: StorageBlock<'world, HashLockId, HashLock>,
+    pub(crate) swap_offers: StorageBlock<'world, SwapOfferId, SwapOffer>,
+    /// Total quantities of assets locked by open hash locks.
+    pub(crate) hash_locked_amounts: StorageBlock<'world, AssetId, Numeric>,
+    /// Total quantities of assets claimable by the recipients of open hash locks.
+    pub(crate) hash_claimable_amounts: StorageBlock<'world, AssetId, Numeric>,
+    /// Keys controlling accounts whose key set differs from their [`AccountId::signatory`].
+    pub(crate) account_signatories: StorageBlock<'world, AccountId, BTreeSet<PublicKey>>,
+    /// Human-readable aliases of accounts.
+    pub(crate) account_aliases: StorageBlock<'world, AccountAlias, AccountId>,
+    /// Aliases of accounts, ordered by the account they refer to.
+    pub(crate) account_aliases_by_account: StorageBlock<'world, AccountAliasWithOwner, ()>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: StorageBlock<'world, RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: StorageBlock<'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageBlock<'world, RoleIdWithOwner, ()>,
+    /// Expiry time (unix time in milliseconds) of permissions and roles granted temporarily.
+    pub(crate) grant_expiries: StorageBlock<'world, GrantWithOwner, u64>,
+    /// Temporary grants ordered by their expiry time
+    pub(crate) grants_by_expiry: StorageBlock<'world, GrantExpiry, ()>,
     /// Triggers
     pub(crate) triggers: TriggerSetBlock<'world>,
     /// Runtime Executor
@@ -134,12 +181,35 @@ pub struct WorldTransaction<'block, 'world> {
         StorageTransaction<'block, 'world, AssetDefinitionId, AssetDefinition>,
     /// Registered assets.
     pub(crate) assets: StorageTransaction<'block, 'world, AssetId, Asset>,
+    /// Allowances granted by asset owners to spenders.
+    pub(crate) allowances: StorageTransaction<'block, 'world, AllowanceId, Numeric>,
+    /// Locked parts of asset balances.
+    pub(crate) asset_holds: StorageTransaction<'block, 'world, AssetId, Numeric>,
+    /// Open hash-time-locked transfers.
+    pub(crate) hash_locks: StorageTransaction<'block, 'world, HashLockId, HashLock>,
+    pub(crate) swap_offers: StorageTransaction<'block, 'world, SwapOfferId, SwapOffer>,
+    /// Total quantities of assets locked by open hash locks.
+    pub(crate) hash_locked_amounts: StorageTransaction<'block, 'world, AssetId, Numeric>,
+    /// Total quantities of assets claimable by the recipients of open hash locks.
+    pub(crate) hash_claimable_amounts: StorageTransaction<'block, 'world, AssetId, Numeric>,
+    /// Keys controlling accounts whose key set differs from their [`AccountId::signatory`].
+    pub(crate) account_signatories:
+        StorageTransaction<'block, 'world, AccountId, BTreeSet<PublicKey>>,
+    /// Human-readable aliases of accounts.
+    pub(crate) account_aliases: StorageTransaction<'block, 'world, AccountAlias, AccountId>,
+    /// Aliases of accounts, ordered by the account they refer to.
+    pub(crate) account_aliases_by_account:
+        StorageTransaction<'block, 'world, AccountAliasWithOwner, ()>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: StorageTransaction<'block, 'world, RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: StorageTransaction<'block, 'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageTransaction<'block, 'world, RoleIdWithOwner, ()>,
+    /// Expiry time (unix time in milliseconds) of permissions and roles granted temporarily.
+    pub(crate) grant_expiries: StorageTransaction<'block, 'world, GrantWithOwner, u64>,
+    /// Temporary grants ordered by their expiry time
+    pub(crate) grants_by_expiry: StorageTransaction<'block, 'world, GrantExpiry, ()>,
     /// Triggers
     pub(crate) triggers: TriggerSetTransaction<'block, 'world>,
     /// Runtime Executor
@@ -172,12 +242,33 @@ pub struct WorldView<'world> {
     pub(crate) asset_definitions: StorageView<'world, AssetDefinitionId, AssetDefinition>,
     /// Registered assets.
     pub(crate) assets: StorageView<'world, AssetId, Asset>,
+    /// Allowances granted by asset owners to spenders.
+    pub(crate) allowances: StorageView<'world, AllowanceId, Numeric>,
+    /// Locked parts of asset balances.
+    pub(crate) asset_holds: StorageView<'world, AssetId, Numeric>,
+    /// Open hash-time-locked transfers.
+    pub(crate) hash_locks: StorageView<'world, HashLockId, HashLock>,
+    pub(crate) swap_offers: StorageView<'world, SwapOfferId, SwapOffer>,
+    /// Total quantities of assets locked by open hash locks.
+    pub(crate) hash_locked_amounts: StorageView<'world, AssetId, Numeric>,
+    /// Total quantities of assets claimable by the recipients of open hash locks.
+    pub(crate) hash_claimable_amounts: StorageView<'world, AssetId, Numeric>,
+    /// Keys controlling accounts whose key set differs from their [`AccountId::signatory`].
+    pub(crate) account_signatories: StorageView<'world, AccountId, BTreeSet<PublicKey>>,
+    /// Human-readable aliases of accounts.
+    pub(crate) account_aliases: StorageView<'world, AccountAlias, AccountId>,
+    /// Aliases of accounts, ordered by the account they refer to.
+    pub(crate) account_aliases_by_account: StorageView<'world, AccountAliasWithOwner, ()>,
     /// Roles. [`Role`] pairs.
     pub(crate) roles: StorageView<'world, RoleId, Role>,
     /// Permission tokens of an account.
     pub(crate) account_permissions: StorageView<'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageView<'world, RoleIdWithOwner, ()>,
+    /// Expiry time (unix time in milliseconds) of permissions and roles granted temporarily.
+    pub(crate) grant_expiries: StorageView<'world, GrantWithOwner, u64>,
+    /// Temporary grants ordered by their expiry time
+    pub(crate) grants_by_expiry: StorageView<'world, GrantExpiry, ()>,
     /// Triggers
     pub(crate) triggers: TriggerSetView<'world>,
     /// Runtime Executor
@@ -196,6 +287,8 @@ pub struct State {
     pub block_hashes: Cell<Vec<HashOf<BlockHeader>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: Storage<HashOf<SignedTransaction>, NonZeroUsize>,
+    /// Fees paid for transactions, recomputed by every peer executing the block
+    pub transaction_fees: Storage<HashOf<SignedTransaction>, TransactionFee>,
     /// Topology used to commit latest block
     pub commit_topology: Cell<Vec<PeerId>>,
     /// Topology used to commit previous block
@@ -217,6 +310,9 @@ pub struct State {
     /// Lock to prevent getting inconsistent view of the state
     #[serde(skip)]
     view_lock: parking_lot::RwLock<()>,
+    /// States of the recent blocks retained for historical queries
+    #[serde(skip)]
+    history: StateHistory,
 }
 
 /// Struct for block's aggregated changes
@@ -227,6 +323,8 @@ pub struct StateBlock<'state> {
     pub block_hashes: CellBlock<'state, Vec<HashOf<BlockHeader>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: StorageBlock<'state, HashOf<SignedTransaction>, NonZeroUsize>,
+    /// Fees paid for transactions, recomputed by every peer executing the block
+    pub transaction_fees: StorageBlock<'state, HashOf<SignedTransaction>, TransactionFee>,
     /// Topology used to commit latest block
     pub commit_topology: CellBlock<'state, Vec<PeerId>>,
     /// Topology used to commit previous block
@@ -255,6 +353,9 @@ pub struct StateTransaction<'block, 'state> {
     pub block_hashes: CellTransaction<'block, 'state, Vec<HashOf<BlockHeader>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: StorageTransaction<'block, 'state, HashOf<SignedTransaction>, NonZeroUsize>,
+    /// Fees paid for transactions, recomputed by every peer executing the block
+    pub transaction_fees:
+        StorageTransaction<'block, 'state, HashOf<SignedTransaction>, TransactionFee>,
     /// Topology used to commit latest block
     pub commit_topology: CellTransaction<'block, 'state, Vec<PeerId>>,
     /// Topology used to commit previous block
@@ -281,6 +382,8 @@ pub struct StateView<'state> {
     pub block_hashes: CellView<'state, Vec<HashOf<BlockHeader>>>,
     /// Hashes of transactions mapped onto block height where they stored
     pub transactions: StorageView<'state, HashOf<SignedTransaction>, NonZeroUsize>,
+    /// Fees paid for transactions, recomputed by every peer executing the block
+    pub transaction_fees: StorageView<'state, HashOf<SignedTransaction>, TransactionFee>,
     /// Topology used to commit latest block
     pub commit_topology: CellView<'state, Vec<PeerId>>,
     /// Topology used to commit previous block
@@ -357,9 +460,20 @@ impl World {
             accounts: self.accounts.block(),
             asset_definitions: self.asset_definitions.block(),
             assets: self.assets.block(),
+            allowances: self.allowances.block(),
+            asset_holds: self.asset_holds.block(),
+            hash_locks: self.hash_locks.block(),
+            swap_offers: self.swap_offers.block(),
+            hash_locked_amounts: self.hash_locked_amounts.block(),
+            hash_claimable_amounts: self.hash_claimable_amounts.block(),
+            account_signatories: self.account_signatories.block(),
+            account_aliases: self.account_aliases.block(),
+            account_aliases_by_account: self.account_aliases_by_account.block(),
             roles: self.roles.block(),
             account_permissions: self.account_permissions.block(),
             account_roles: self.account_roles.block(),
+            grant_expiries: self.grant_expiries.block(),
+            grants_by_expiry: self.grants_by_expiry.block(),
             triggers: self.triggers.block(),
             executor: self.executor.block(),
             executor_data_model: self.executor_data_model.block(),
@@ -376,9 +490,20 @@ impl World {
             accounts: self.accounts.block_and_revert(),
             asset_definitions: self.asset_definitions.block_and_revert(),
             assets: self.assets.block_and_revert(),
+            allowances: self.allowances.block_and_revert(),
+            asset_holds: self.asset_holds.block_and_revert(),
+            hash_locks: self.hash_locks.block_and_revert(),
+            swap_offers: self.swap_offers.block_and_revert(),
+            hash_locked_amounts: self.hash_locked_amounts.block_and_revert(),
+            hash_claimable_amounts: self.hash_claimable_amounts.block_and_revert(),
+            account_signatories: self.account_signatories.block_and_revert(),
+            account_aliases: self.account_aliases.block_and_revert(),
+            account_aliases_by_account: self.account_aliases_by_account.block_and_revert(),
             roles: self.roles.block_and_revert(),
             account_permissions: self.account_permissions.block_and_revert(),
             account_roles: self.account_roles.block_and_revert(),
+            grant_expiries: self.grant_expiries.block_and_revert(),
+            grants_by_expiry: self.grants_by_expiry.block_and_revert(),
             triggers: self.triggers.block_and_revert(),
             executor: self.executor.block_and_revert(),
             executor_data_model: self.executor_data_model.block_and_revert(),
@@ -395,9 +520,20 @@ impl World {
             accounts: self.accounts.view(),
             asset_definitions: self.asset_definitions.view(),
             assets: self.assets.view(),
+            allowances: self.allowances.view(),
+            asset_holds: self.asset_holds.view(),
+            hash_locks: self.hash_locks.view(),
+            swap_offers: self.swap_offers.view(),
+            hash_locked_amounts: self.hash_locked_amounts.view(),
+            hash_claimable_amounts: self.hash_claimable_amounts.view(),
+            account_signatories: self.account_signatories.view(),
+            account_aliases: self.account_aliases.view(),
+            account_aliases_by_account: self.account_aliases_by_account.view(),
             roles: self.roles.view(),
             account_permissions: self.account_permissions.view(),
             account_roles: self.account_roles.view(),
+            grant_expiries: self.grant_expiries.view(),
+            grants_by_expiry: self.grants_by_expiry.view(),
             triggers: self.triggers.view(),
             executor: self.executor.view(),
             executor_data_model: self.executor_data_model.view(),
@@ -414,9 +550,19 @@ pub trait WorldReadOnly {
     fn accounts(&self) -> &impl StorageReadOnly<AccountId, Account>;
     fn asset_definitions(&self) -> &impl StorageReadOnly<AssetDefinitionId, AssetDefinition>;
     fn assets(&self) -> &impl StorageReadOnly<AssetId, Asset>;
+    fn allowances(&self) -> &impl StorageReadOnly<AllowanceId, Numeric>;
+    fn asset_holds(&self) -> &impl StorageReadOnly<AssetId, Numeric>;
+    fn hash_locks(&self) -> &impl StorageReadOnly<HashLockId, HashLock>;
+    fn swap_offers(&self) -> &impl StorageReadOnly<SwapOfferId, SwapOffer>;
+    fn hash_locked_amounts(&self) -> &impl StorageReadOnly<AssetId, Numeric>;
+    fn account_signatories(&self) -> &impl StorageReadOnly<AccountId, BTreeSet<PublicKey>>;
+    fn account_aliases(&self) -> &impl StorageReadOnly<AccountAlias, AccountId>;
+    fn account_aliases_by_account(&self) -> &impl StorageReadOnly<AccountAliasWithOwner, ()>;
     fn roles(&self) -> &impl StorageReadOnly<RoleId, Role>;
     fn account_permissions(&self) -> &impl StorageReadOnly<AccountId, Permissions>;
     fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()>;
+    fn grant_expiries(&self) -> &impl StorageReadOnly<GrantWithOwner, u64>;
+    fn grants_by_expiry(&self) -> &impl StorageReadOnly<GrantExpiry, ()>;
     fn triggers(&self) -> &impl TriggerSetReadOnly;
     fn executor(&self) -> &Executor;
     fn executor_data_model(&self) -> &ExecutorDataModel;
@@ -515,6 +661,50 @@ pub trait WorldReadOnly {
         self.assets().iter().map(|(_, a)| a)
     }
 
+    /// Iterate over all allowances
+    #[inline]
+    fn allowances_iter(&self) -> impl Iterator<Item = Allowance> + '_ {
+        self.allowances()
+            .iter()
+            .map(|(id, amount)| Allowance::new(id.clone(), *amount))
+    }
+
+    /// Iterate over all asset holds
+    #[inline]
+    fn asset_holds_iter(&self) -> impl Iterator<Item = AssetHold> + '_ {
+        self.asset_holds()
+            .iter()
+            .map(|(id, amount)| AssetHold::new(id.clone(), *amount))
+    }
+
+    /// Iterate over all open hash locks
+    #[inline]
+    fn hash_locks_iter(&self) -> impl Iterator<Item = &HashLock> {
+        self.hash_locks().iter().map(|(_, hash_lock)| hash_lock)
+    }
+
+    /// Iterate over all pending swap offers
+    #[inline]
+    fn swap_offers_iter(&self) -> impl Iterator<Item = &SwapOffer> {
+        self.swap_offers().iter().map(|(_, swap_offer)| swap_offer)
+    }
+
+    /// Get the locked part of the [`Asset`] balance, either held or locked by open
+    /// [`HashLock`]s, zero if nothing is locked.
+    ///
+    /// # Errors
+    /// Fails if the locked amounts overflow
+    #[inline]
+    fn asset_held_amount(&self, id: &AssetId) -> Result<Numeric, MathError> {
+        let held = self.asset_holds().get(id).copied().unwrap_or(Numeric::ZERO);
+        let hash_locked = self
+            .hash_locked_amounts()
+            .get(id)
+            .copied()
+            .unwrap_or(Numeric::ZERO);
+        held.checked_add(hash_locked).ok_or(MathError::Overflow)
+    }
+
     // Account-related methods
 
     /// Get `Account` and return reference to it.
@@ -543,6 +733,47 @@ pub trait WorldReadOnly {
         Ok(f(account))
     }
 
+    /// Get the keys allowed to sign transactions on behalf of the [`Account`].
+    ///
+    /// [`AccountId::signatory`] is the only signatory of an account until its signatories change.
+    fn account_signatories_of(&self, id: &AccountId) -> BTreeSet<PublicKey> {
+        self.account_signatories()
+            .get(id)
+            .cloned()
+            .unwrap_or_else(|| BTreeSet::from([id.signatory.clone()]))
+    }
+
+    /// Get the [`AccountId`] an [`AccountAlias`] refers to.
+    ///
+    /// # Errors
+    /// Fails if there is no such alias
+    fn account_by_alias(&self, alias: &AccountAlias) -> Result<&AccountId, FindError> {
+        self.account_aliases()
+            .get(alias)
+            .ok_or_else(|| FindError::AccountAlias(alias.clone()))
+    }
+
+    /// Iterate over all aliases of the [`Account`]
+    fn aliases_of_account<'slf>(
+        &'slf self,
+        id: &'slf AccountId,
+    ) -> impl Iterator<Item = &'slf AccountAlias> + 'slf {
+        self.account_aliases_by_account()
+            .range::<dyn AsAccountAliasByAccount>(AccountAliasByAccountBounds::new(id))
+            .map(|(alias, ())| &alias.alias)
+    }
+
+    /// Iterate over permissions and roles granted to the [`Account`] temporarily,
+    /// along with their expiry time (unix time in milliseconds)
+    fn temporary_grants_of_account<'slf>(
+        &'slf self,
+        id: &'slf AccountId,
+    ) -> impl Iterator<Item = (&'slf GrantedObject, u64)> + 'slf {
+        self.grant_expiries()
+            .range::<dyn AsGrantByOwner>(GrantByOwnerBounds::account(id))
+            .map(|(grant, expires_at_ms)| (&grant.object, *expires_at_ms))
+    }
+
     /// Get [`Account`]'s [`RoleId`]s
     // NOTE: have to use concreate type because don't want to capture lifetme of `id`
     #[allow(clippy::type_complexity)]
@@ -658,6 +889,30 @@ macro_rules! impl_world_ro {
             fn assets(&self) -> &impl StorageReadOnly<AssetId, Asset> {
                 &self.assets
             }
+            fn allowances(&self) -> &impl StorageReadOnly<AllowanceId, Numeric> {
+                &self.allowances
+            }
+            fn asset_holds(&self) -> &impl StorageReadOnly<AssetId, Numeric> {
+                &self.asset_holds
+            }
+            fn hash_locks(&self) -> &impl StorageReadOnly<HashLockId, HashLock> {
+                &self.hash_locks
+            }
+            fn swap_offers(&self) -> &impl StorageReadOnly<SwapOfferId, SwapOffer> {
+                &self.swap_offers
+            }
+            fn hash_locked_amounts(&self) -> &impl StorageReadOnly<AssetId, Numeric> {
+                &self.hash_locked_amounts
+            }
+            fn account_signatories(&self) -> &impl StorageReadOnly<AccountId, BTreeSet<PublicKey>> {
+                &self.account_signatories
+            }
+            fn account_aliases(&self) -> &impl StorageReadOnly<AccountAlias, AccountId> {
+                &self.account_aliases
+            }
+            fn account_aliases_by_account(&self) -> &impl StorageReadOnly<AccountAliasWithOwner, ()> {
+                &self.account_aliases_by_account
+            }
             fn roles(&self) -> &impl StorageReadOnly<RoleId, Role> {
                 &self.roles
             }
@@ -667,6 +922,12 @@ macro_rules! impl_world_ro {
             fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()> {
                 &self.account_roles
             }
+            fn grant_expiries(&self) -> &impl StorageReadOnly<GrantWithOwner, u64> {
+                &self.grant_expiries
+            }
+            fn grants_by_expiry(&self) -> &impl StorageReadOnly<GrantExpiry, ()> {
+                &self.grants_by_expiry
+            }
             fn triggers(&self) -> &impl TriggerSetReadOnly {
                 &self.triggers
             }
@@ -694,9 +955,20 @@ impl<'world> WorldBlock<'world> {
             accounts: self.accounts.transaction(),
             asset_definitions: self.asset_definitions.transaction(),
             assets: self.assets.transaction(),
+            allowances: self.allowances.transaction(),
+            asset_holds: self.asset_holds.transaction(),
+            hash_locks: self.hash_locks.transaction(),
+            swap_offers: self.swap_offers.transaction(),
+            hash_locked_amounts: self.hash_locked_amounts.transaction(),
+            hash_claimable_amounts: self.hash_claimable_amounts.transaction(),
+            account_signatories: self.account_signatories.transaction(),
+            account_aliases: self.account_aliases.transaction(),
+            account_aliases_by_account: self.account_aliases_by_account.transaction(),
             roles: self.roles.transaction(),
             account_permissions: self.account_permissions.transaction(),
             account_roles: self.account_roles.transaction(),
+            grant_expiries: self.grant_expiries.transaction(),
+            grants_by_expiry: self.grants_by_expiry.transaction(),
             triggers: self.triggers.transaction(),
             executor: self.executor.transaction(),
             executor_data_model: self.executor_data_model.transaction(),
@@ -717,9 +989,20 @@ impl<'world> WorldBlock<'world> {
             accounts,
             asset_definitions,
             assets,
+            allowances,
+            asset_holds,
+            hash_locks,
+            swap_offers,
+            hash_locked_amounts,
+            hash_claimable_amounts,
+            account_signatories,
+            account_aliases,
+            account_aliases_by_account,
             roles,
             account_permissions,
             account_roles,
+            grant_expiries,
+            grants_by_expiry,
             triggers,
             executor,
             executor_data_model,
@@ -729,9 +1012,20 @@ impl<'world> WorldBlock<'world> {
         executor_data_model.commit();
         executor.commit();
         triggers.commit();
+        grants_by_expiry.commit();
+        grant_expiries.commit();
         account_roles.commit();
         account_permissions.commit();
         roles.commit();
+        account_aliases.commit();
+        account_aliases_by_account.commit();
+        account_signatories.commit();
+        hash_locks.commit();
+        swap_offers.commit();
+        hash_locked_amounts.commit();
+        hash_claimable_amounts.commit();
+        asset_holds.commit();
+        allowances.commit();
         assets.commit();
         asset_definitions.commit();
         accounts.commit();
@@ -752,9 +1046,20 @@ impl WorldTransaction<'_, '_> {
             accounts,
             asset_definitions,
             assets,
+            allowances,
+            asset_holds,
+            hash_locks,
+            swap_offers,
+            hash_locked_amounts,
+            hash_claimable_amounts,
+            account_signatories,
+            account_aliases,
+            account_aliases_by_account,
             roles,
             account_permissions,
             account_roles,
+            grant_expiries,
+            grants_by_expiry,
             triggers,
             executor,
             executor_data_model,
@@ -763,9 +1068,20 @@ impl WorldTransaction<'_, '_> {
         executor_data_model.apply();
         executor.apply();
         triggers.apply();
+        grants_by_expiry.apply();
+        grant_expiries.apply();
         account_roles.apply();
         account_permissions.apply();
         roles.apply();
+        account_aliases.apply();
+        account_aliases_by_account.apply();
+        account_signatories.apply();
+        hash_locks.apply();
+        swap_offers.apply();
+        hash_locked_amounts.apply();
+        hash_claimable_amounts.apply();
+        asset_holds.apply();
+        allowances.apply();
         assets.apply();
         asset_definitions.apply();
         accounts.apply();
@@ -839,6 +1155,282 @@ impl WorldTransaction<'_, '_> {
         }
     }
 
+    /// Replace the keys allowed to sign transactions on behalf of the [`Account`].
+    ///
+    /// The entry is dropped when only [`AccountId::signatory`] remains, which is the default.
+    pub fn set_account_signatories(&mut self, id: &AccountId, signatories: BTreeSet<PublicKey>) {
+        if signatories.len() == 1 && signatories.contains(&id.signatory) {
+            self.account_signatories.remove(id.clone());
+        } else {
+            self.account_signatories.insert(id.clone(), signatories);
+        }
+    }
+
+    /// Remove all [`AccountAlias`]es referring to the [`Account`]
+    pub fn remove_account_aliases(&mut self, account: &AccountId) {
+        let aliases_to_remove = self
+            .aliases_of_account(account)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for alias in aliases_to_remove {
+            self.remove_account_alias(&alias);
+        }
+    }
+
+    /// Make the [`AccountAlias`] refer to the [`Account`], replacing the account it referred to.
+    pub fn insert_account_alias(&mut self, alias: AccountAlias, account: AccountId) {
+        if let Some(prev_account) = self.account_aliases.insert(alias.clone(), account.clone()) {
+            self.account_aliases_by_account
+                .remove(AccountAliasWithOwner::new(prev_account, alias.clone()));
+        }
+        self.account_aliases_by_account
+            .insert(AccountAliasWithOwner::new(account, alias), ());
+    }
+
+    /// Remove the [`AccountAlias`], returning the [`AccountId`] it referred to.
+    pub fn remove_account_alias(&mut self, alias: &AccountAlias) -> Option<AccountId> {
+        let account = self.account_aliases.remove(alias.clone())?;
+        self.account_aliases_by_account
+            .remove(AccountAliasWithOwner::new(account.clone(), alias.clone()));
+        Some(account)
+    }
+
+    /// Make the grant expire at the given time (unix time in milliseconds)
+    pub fn insert_grant_expiry(&mut self, grant: GrantWithOwner, expires_at_ms: u64) {
+        if let Some(prev_expires_at_ms) = self.grant_expiries.insert(grant.clone(), expires_at_ms) {
+            self.grants_by_expiry
+                .remove(GrantExpiry::new(prev_expires_at_ms, grant.clone()));
+        }
+        self.grants_by_expiry
+            .insert(GrantExpiry::new(expires_at_ms, grant), ());
+    }
+
+    /// Remove expiry time of the grant if it was granted temporarily
+    pub fn remove_grant_expiry(&mut self, grant: GrantWithOwner) {
+        if let Some(expires_at_ms) = self.grant_expiries.remove(grant.clone()) {
+            self.grants_by_expiry
+                .remove(GrantExpiry::new(expires_at_ms, grant));
+        }
+    }
+
+    /// Remove expiry times of all permissions and roles granted to the account temporarily
+    pub fn remove_account_grant_expiries(&mut self, account: &AccountId) {
+        self.remove_grant_expiries(GrantByOwnerBounds::account(account));
+    }
+
+    /// Remove expiry times of all permissions granted to the role temporarily
+    pub fn remove_role_grant_expiries(&mut self, role: &RoleId) {
+        self.remove_grant_expiries(GrantByOwnerBounds::role(role));
+    }
+
+    fn remove_grant_expiries(&mut self, bounds: GrantByOwnerBounds<'_>) {
+        let grants_to_remove = self
+            .grant_expiries
+            .range::<dyn AsGrantByOwner>(bounds)
+            .map(|(grant, _)| grant.clone())
+            .collect::<Vec<_>>();
+
+        for grant in grants_to_remove {
+            self.remove_grant_expiry(grant);
+        }
+    }
+
+    /// Fail if the asset is held or locked by a [`HashLock`],
+    /// since removing it would lose the locked funds.
+    ///
+    /// # Errors
+    /// [`Error::InvariantViolation`] naming the asset
+    pub fn ensure_asset_has_no_locked_funds(&self, asset_id: &AssetId) -> Result<(), Error> {
+        Self::ensure_no_locked_funds(
+            self.asset_holds
+                .get(asset_id)
+                .map(|amount| (asset_id, amount)),
+            self.hash_locked_amounts
+                .get(asset_id)
+                .map(|amount| (asset_id, amount)),
+            core::iter::empty(),
+        )
+    }
+
+    /// Fail if any asset of the account is held or locked by a [`HashLock`],
+    /// or if the account is the recipient of an open [`HashLock`].
+    ///
+    /// # Errors
+    /// [`Error::InvariantViolation`] naming the asset
+    pub fn ensure_account_has_no_locked_funds(&self, account_id: &AccountId) -> Result<(), Error> {
+        Self::ensure_no_locked_funds(
+            self.asset_holds
+                .range::<dyn AsAssetIdAccountCompare>(AssetByAccountBounds::new(account_id)),
+            self.hash_locked_amounts
+                .range::<dyn AsAssetIdAccountCompare>(AssetByAccountBounds::new(account_id)),
+            self.hash_claimable_amounts
+                .range::<dyn AsAssetIdAccountCompare>(AssetByAccountBounds::new(account_id)),
+        )
+    }
+
+    /// Fail if any asset matching the predicate is held, locked by a [`HashLock`] or claimable from one.
+    ///
+    /// NOTE: Locked amounts are ordered by account, so all of them are checked
+    ///
+    /// # Errors
+    /// [`Error::InvariantViolation`] naming the asset
+    pub fn ensure_assets_have_no_locked_funds(
+        &self,
+        predicate: impl Fn(&AssetId) -> bool,
+    ) -> Result<(), Error> {
+        Self::ensure_no_locked_funds(
+            self.asset_holds
+                .iter()
+                .filter(|(asset_id, _)| predicate(asset_id)),
+            self.hash_locked_amounts
+                .iter()
+                .filter(|(asset_id, _)| predicate(asset_id)),
+            self.hash_claimable_amounts
+                .iter()
+                .filter(|(asset_id, _)| predicate(asset_id)),
+        )
+    }
+
+    fn ensure_no_locked_funds<'a>(
+        held: impl IntoIterator<Item = (&'a AssetId, &'a Numeric)>,
+        hash_locked: impl IntoIterator<Item = (&'a AssetId, &'a Numeric)>,
+        hash_claimable: impl IntoIterator<Item = (&'a AssetId, &'a Numeric)>,
+    ) -> Result<(), Error> {
+        if let Some((asset_id, _)) = held.into_iter().find(|(_, amount)| !amount.is_zero()) {
+            return Err(Error::InvariantViolation(format!(
+                "Asset `{asset_id}` has held funds, release them first"
+            )));
+        }
+        if let Some((asset_id, _)) = hash_locked.into_iter().next() {
+            return Err(Error::InvariantViolation(format!(
+                "Asset `{asset_id}` is locked by open hash locks, claim or refund them first"
+            )));
+        }
+        if let Some((asset_id, _)) = hash_claimable.into_iter().next() {
+            return Err(Error::InvariantViolation(format!(
+                "Asset `{asset_id}` is claimable from open hash locks, claim or refund them first"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Fail if the [`FeeSchedule`] refers to a fee collector or a fee asset matching the predicates,
+    /// since fees couldn't be charged after removing it.
+    ///
+    /// # Errors
+    /// [`Error::InvariantViolation`] naming the fee collector or the fee asset
+    pub fn ensure_not_used_for_fees(
+        &self,
+        collector_predicate: impl Fn(&AccountId) -> bool,
+        asset_predicate: impl Fn(&AssetDefinitionId) -> bool,
+    ) -> Result<(), Error> {
+        let Some(fee_schedule) = self.parameters.fee() else {
+            return Ok(());
+        };
+
+        if collector_predicate(&fee_schedule.collector) {
+            return Err(Error::InvariantViolation(format!(
+                "Account `{}` collects transaction fees, change the fee schedule first",
+                fee_schedule.collector
+            )));
+        }
+        if asset_predicate(&fee_schedule.asset) {
+            return Err(Error::InvariantViolation(format!(
+                "Asset definition `{}` is used to pay transaction fees, change the fee schedule first",
+                fee_schedule.asset
+            )));
+        }
+        Ok(())
+    }
+
+    /// Open the [`HashLock`], locking its amount of the sender's asset.
+    ///
+    /// # Errors
+    /// Fails if the locked amount overflows
+    pub fn insert_hash_lock(&mut self, hash_lock: HashLock) -> Result<(), Error> {
+        let asset_id = hash_lock.asset();
+        let locked = self
+            .hash_locked_amounts
+            .get(&asset_id)
+            .copied()
+            .unwrap_or(Numeric::ZERO)
+            .checked_add(hash_lock.amount)
+            .ok_or(MathError::Overflow)?;
+        let recipient_asset_id = AssetId::new(
+            hash_lock.asset_definition.clone(),
+            hash_lock.recipient.clone(),
+        );
+        let claimable = self
+            .hash_claimable_amounts
+            .get(&recipient_asset_id)
+            .copied()
+            .unwrap_or(Numeric::ZERO)
+            .checked_add(hash_lock.amount)
+            .ok_or(MathError::Overflow)?;
+        self.hash_locked_amounts.insert(asset_id, locked);
+        self.hash_claimable_amounts
+            .insert(recipient_asset_id, claimable);
+        self.hash_locks.insert(hash_lock.id.clone(), hash_lock);
+        Ok(())
+    }
+
+    /// Close the [`HashLock`], unlocking its amount of the sender's asset.
+    ///
+    /// # Errors
+    /// Fails if there is no such hash lock
+    pub fn remove_hash_lock(&mut self, id: HashLockId) -> Result<HashLock, Error> {
+        let hash_lock = self
+            .hash_locks
+            .remove(id.clone())
+            .ok_or(FindError::HashLock(id))?;
+        let asset_id = hash_lock.asset();
+        let locked = self
+            .hash_locked_amounts
+            .get(&asset_id)
+            .copied()
+            .unwrap_or(Numeric::ZERO)
+            .checked_sub(hash_lock.amount)
+            .ok_or(MathError::NotEnoughQuantity)?;
+        let recipient_asset_id = AssetId::new(
+            hash_lock.asset_definition.clone(),
+            hash_lock.recipient.clone(),
+        );
+        let claimable = self
+            .hash_claimable_amounts
+            .get(&recipient_asset_id)
+            .copied()
+            .unwrap_or(Numeric::ZERO)
+            .checked_sub(hash_lock.amount)
+            .ok_or(MathError::NotEnoughQuantity)?;
+        if locked.is_zero() {
+            self.hash_locked_amounts.remove(asset_id);
+        } else {
+            self.hash_locked_amounts.insert(asset_id, locked);
+        }
+        if claimable.is_zero() {
+            self.hash_claimable_amounts.remove(recipient_asset_id);
+        } else {
+            self.hash_claimable_amounts
+                .insert(recipient_asset_id, claimable);
+        }
+        Ok(hash_lock)
+    }
+
+    /// Remove all [`Allowance`]s matching the predicate
+    pub fn remove_allowances(&mut self, predicate: impl Fn(&AllowanceId) -> bool) {
+        let allowances_to_remove = self
+            .allowances
+            .iter()
+            .filter(|(allowance_id, _)| predicate(allowance_id))
+            .map(|(allowance_id, _)| allowance_id.clone())
+            .collect::<Vec<_>>();
+
+        for allowance_id in allowances_to_remove {
+            self.allowances.remove(allowance_id);
+        }
+    }
+
     /// Get mutable reference to [`Asset`]
     ///
     /// # Errors
@@ -1059,6 +1651,7 @@ impl State {
         Self {
             world,
             transactions: Storage::new(),
+            transaction_fees: Storage::new(),
             commit_topology: Cell::new(Vec::new()),
             prev_commit_topology: Cell::new(Vec::new()),
             block_hashes: Cell::new(Vec::new()),
@@ -1067,15 +1660,44 @@ impl State {
             kura,
             query_handle,
             view_lock: parking_lot::RwLock::new(()),
+            history: StateHistory::default(),
         }
     }
 
+    /// Retain the states of the recent blocks in the given [`StateHistory`].
+    #[must_use]
+    pub fn with_history(mut self, history: StateHistory) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Get the state as it was after the block at the given height was committed.
+    ///
+    /// Returns `None` if the state of this height is not retained.
+    pub fn historical_state(&self, height: NonZeroU64) -> Option<State> {
+        self.history.get(
+            height,
+            deserialize::KuraSeed {
+                kura: Arc::clone(&self.kura),
+                query_handle: self.query_handle.clone(),
+            },
+        )
+    }
+
+    /// Retain the current state for historical queries if the history is enabled.
+    ///
+    /// Should be called after every committed block.
+    pub fn record_history(&self) {
+        self.history.record(self);
+    }
+
     /// Create structure to execute a block
     pub fn block(&self, curr_block: BlockHeader) -> StateBlock<'_> {
         StateBlock {
             world: self.world.block(),
             block_hashes: self.block_hashes.block(),
             transactions: self.transactions.block(),
+            transaction_fees: self.transaction_fees.block(),
             commit_topology: self.commit_topology.block(),
             prev_commit_topology: self.prev_commit_topology.block(),
             engine: &self.engine,
@@ -1093,6 +1715,7 @@ impl State {
             world: self.world.block_and_revert(),
             block_hashes: self.block_hashes.block_and_revert(),
             transactions: self.transactions.block_and_revert(),
+            transaction_fees: self.transaction_fees.block_and_revert(),
             commit_topology: self.commit_topology.block_and_revert(),
             prev_commit_topology: self.prev_commit_topology.block_and_revert(),
             engine: &self.engine,
@@ -1111,6 +1734,7 @@ impl State {
             world: self.world.view(),
             block_hashes: self.block_hashes.view(),
             transactions: self.transactions.view(),
+            transaction_fees: self.transaction_fees.view(),
             commit_topology: self.commit_topology.view(),
             prev_commit_topology: self.prev_commit_topology.view(),
             engine: &self.engine,
@@ -1127,6 +1751,7 @@ pub trait StateReadOnly {
     fn world(&self) -> &impl WorldReadOnly;
     fn block_hashes(&self) -> &[HashOf<BlockHeader>];
     fn transactions(&self) -> &impl StorageReadOnly<HashOf<SignedTransaction>, NonZeroUsize>;
+    fn transaction_fees(&self) -> &impl StorageReadOnly<HashOf<SignedTransaction>, TransactionFee>;
     fn commit_topology(&self) -> &[PeerId];
     fn prev_commit_topology(&self) -> &[PeerId];
     fn engine(&self) -> &wasmtime::Engine;
@@ -1165,15 +1790,24 @@ pub trait StateReadOnly {
         self.block_hashes().iter().nth_back(1).copied()
     }
 
-    /// Load all blocks in the block chain from disc
+    /// Load all blocks in the block chain from disc, skipping the blocks which are allowed to be pruned
+    /// by [`BlockParameters::max_prunable_height`](iroha_data_model::parameter::BlockParameters::max_prunable_height)
     fn all_blocks(
         &self,
         start: NonZeroUsize,
     ) -> impl DoubleEndedIterator<Item = Arc<SignedBlock>> + '_ {
-        (start.get()..=self.height()).map(|height| {
-            NonZeroUsize::new(height)
-                .and_then(|height| self.kura().get_block(height))
-                .expect("INTERNAL BUG: Failed to load block")
+        let max_prunable_height = self.world().parameters().block().max_prunable_height();
+        (start.get()..=self.height()).filter_map(move |height| {
+            if height > 1 && height as u64 <= max_prunable_height {
+                return None;
+            }
+            let height = NonZeroUsize::new(height).expect("Starts from a non-zero height");
+            let block = self.kura().get_block(height);
+            assert!(
+                block.is_some() || self.kura().is_pruned(height),
+                "INTERNAL BUG: Failed to load block"
+            );
+            block
         })
     }
 
@@ -1222,6 +1856,9 @@ macro_rules! impl_state_ro {
             fn transactions(&self) -> &impl StorageReadOnly<HashOf<SignedTransaction>, NonZeroUsize> {
                 &self.transactions
             }
+            fn transaction_fees(&self) -> &impl StorageReadOnly<HashOf<SignedTransaction>, TransactionFee> {
+                &self.transaction_fees
+            }
             fn commit_topology(&self) -> &[PeerId] {
                 &self.commit_topology
             }
@@ -1255,6 +1892,7 @@ impl<'state> StateBlock<'state> {
             world: self.world.trasaction(),
             block_hashes: self.block_hashes.transaction(),
             transactions: self.transactions.transaction(),
+            transaction_fees: self.transaction_fees.transaction(),
             commit_topology: self.commit_topology.transaction(),
             prev_commit_topology: self.prev_commit_topology.transaction(),
             engine: self.engine,
@@ -1265,6 +1903,84 @@ impl<'state> StateBlock<'state> {
         }
     }
 
+    /// Take the events produced in the block so far
+    pub(crate) fn take_events(&mut self) -> Vec<EventBox> {
+        core::mem::take(&mut self.world.events_buffer)
+    }
+
+    /// Revoke permissions and roles whose grants have expired by the creation time of the current block.
+    ///
+    /// Has to be called before the transactions of the block are executed.
+    pub(crate) fn revoke_expired_grants(&mut self) {
+        let now = self.curr_block.creation_time();
+        let expired_grants = self
+            .world
+            .grants_by_expiry
+            .iter()
+            .map(|(expiry, ())| expiry)
+            .take_while(|expiry| Duration::from_millis(expiry.expires_at_ms) <= now)
+            .map(|expiry| expiry.grant.clone())
+            .collect::<Vec<_>>();
+        if expired_grants.is_empty() {
+            return;
+        }
+
+        let mut transaction = self.transaction();
+        for grant in expired_grants {
+            transaction.world.remove_grant_expiry(grant.clone());
+            let GrantWithOwner { owner, object } = grant;
+            match (owner, object) {
+                (GrantOwner::Role(role), GrantedObject::Permission(permission)) => {
+                    let removed = transaction
+                        .world
+                        .roles
+                        .get_mut(&role)
+                        .is_some_and(|role| role.permissions.remove(&permission));
+                    if removed {
+                        transaction
+                            .world
+                            .emit_events(Some(RoleEvent::PermissionRemoved(
+                                RolePermissionChanged { role, permission },
+                            )));
+                    }
+                }
+                // Roles are never granted to roles
+                (GrantOwner::Role(_), GrantedObject::Role(_)) => {}
+                (GrantOwner::Account(account), GrantedObject::Permission(permission)) => {
+                    if transaction
+                        .world
+                        .remove_account_permission(&account, &permission)
+                    {
+                        transaction
+                            .world
+                            .emit_events(Some(AccountEvent::PermissionRemoved(
+                                AccountPermissionChanged {
+                                    account,
+                                    permission,
+                                },
+                            )));
+                    }
+                }
+                (GrantOwner::Account(account), GrantedObject::Role(role)) => {
+                    if transaction
+                        .world
+                        .account_roles
+                        .remove(RoleIdWithOwner::new(account.clone(), role.clone()))
+                        .is_some()
+                    {
+                        transaction
+                            .world
+                            .emit_events(Some(AccountEvent::RoleRevoked(AccountRoleChanged {
+                                account,
+                                role,
+                            })));
+                    }
+                }
+            }
+        }
+        transaction.apply();
+    }
+
     /// Commit changes aggregated during application of block
     pub fn commit(self) {
         // NOTE: intentionally destruct self not to forget commit some fields
@@ -1272,6 +1988,7 @@ impl<'state> StateBlock<'state> {
             world,
             block_hashes,
             transactions,
+            transaction_fees,
             commit_topology: committed_topology,
             prev_commit_topology: prev_committed_topology,
             view_lock,
@@ -1281,6 +1998,7 @@ impl<'state> StateBlock<'state> {
         prev_committed_topology.commit();
         committed_topology.commit();
         transactions.commit();
+        transaction_fees.commit();
         block_hashes.commit();
         world.commit();
     }
@@ -1319,6 +2037,7 @@ impl<'state> StateBlock<'state> {
     /// Fails if transaction instruction execution fails
     fn execute_transactions(&mut self, block: &CommittedBlock) -> Result<()> {
         let block = block.as_ref();
+        self.revoke_expired_grants();
 
         // TODO: Should this block panic instead?
         for (idx, tx) in block.transactions().enumerate() {
@@ -1461,6 +2180,7 @@ impl StateTransaction<'_, '_> {
             world,
             block_hashes,
             transactions,
+            transaction_fees,
             commit_topology: committed_topology,
             prev_commit_topology: prev_committed_topology,
             ..
@@ -1468,6 +2188,7 @@ impl StateTransaction<'_, '_> {
         prev_committed_topology.apply();
         committed_topology.apply();
         transactions.apply();
+        transaction_fees.apply();
         block_hashes.apply();
         world.apply();
     }
@@ -1539,7 +2260,7 @@ mod range_bounds {
     use iroha_primitives::{cmpext::MinMaxExt, impl_as_dyn_key};
 
     use super::*;
-    use crate::role::RoleIdWithOwner;
+    use crate::role::{GrantOwner, GrantWithOwner, RoleIdWithOwner};
 
     /// Key for range queries over account for roles
     #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
@@ -1595,6 +2316,133 @@ mod range_bounds {
         trait: AsRoleIdByAccount
     }
 
+    /// Key for range queries over account for aliases
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+    pub struct AccountAliasByAccount<'a> {
+        account_id: &'a AccountId,
+        alias: MinMaxExt<&'a AccountAlias>,
+    }
+
+    /// Bounds for range queries over account for aliases
+    pub struct AccountAliasByAccountBounds<'a> {
+        start: AccountAliasByAccount<'a>,
+        end: AccountAliasByAccount<'a>,
+    }
+
+    impl<'a> AccountAliasByAccountBounds<'a> {
+        /// Create range bounds for range queries of aliases over account
+        pub fn new(account_id: &'a AccountId) -> Self {
+            Self {
+                start: AccountAliasByAccount {
+                    account_id,
+                    alias: MinMaxExt::Min,
+                },
+                end: AccountAliasByAccount {
+                    account_id,
+                    alias: MinMaxExt::Max,
+                },
+            }
+        }
+    }
+
+    impl<'a> RangeBounds<dyn AsAccountAliasByAccount + 'a> for AccountAliasByAccountBounds<'a> {
+        fn start_bound(&self) -> Bound<&(dyn AsAccountAliasByAccount + 'a)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsAccountAliasByAccount + 'a)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsAccountAliasByAccount for AccountAliasWithOwner {
+        fn as_key(&self) -> AccountAliasByAccount<'_> {
+            AccountAliasByAccount {
+                account_id: &self.account,
+                alias: (&self.alias).into(),
+            }
+        }
+    }
+
+    impl_as_dyn_key! {
+        target: AccountAliasWithOwner,
+        key: AccountAliasByAccount<'_>,
+        trait: AsAccountAliasByAccount
+    }
+
+    /// Reference to [`GrantOwner`], ordered the same way
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+    enum GrantOwnerRef<'a> {
+        Account(&'a AccountId),
+        Role(&'a RoleId),
+    }
+
+    /// Key for range queries over owner for temporary grants
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+    pub struct GrantByOwner<'a> {
+        owner: GrantOwnerRef<'a>,
+        object: MinMaxExt<&'a GrantedObject>,
+    }
+
+    /// Bounds for range queries over owner for temporary grants
+    pub struct GrantByOwnerBounds<'a> {
+        start: GrantByOwner<'a>,
+        end: GrantByOwner<'a>,
+    }
+
+    impl<'a> GrantByOwnerBounds<'a> {
+        /// Create range bounds for range queries of grants over account
+        pub fn account(account_id: &'a AccountId) -> Self {
+            Self::new(GrantOwnerRef::Account(account_id))
+        }
+
+        /// Create range bounds for range queries of grants over role
+        pub fn role(role_id: &'a RoleId) -> Self {
+            Self::new(GrantOwnerRef::Role(role_id))
+        }
+
+        fn new(owner: GrantOwnerRef<'a>) -> Self {
+            Self {
+                start: GrantByOwner {
+                    owner,
+                    object: MinMaxExt::Min,
+                },
+                end: GrantByOwner {
+                    owner,
+                    object: MinMaxExt::Max,
+                },
+            }
+        }
+    }
+
+    impl<'a> RangeBounds<dyn AsGrantByOwner + 'a> for GrantByOwnerBounds<'a> {
+        fn start_bound(&self) -> Bound<&(dyn AsGrantByOwner + 'a)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsGrantByOwner + 'a)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsGrantByOwner for GrantWithOwner {
+        fn as_key(&self) -> GrantByOwner<'_> {
+            GrantByOwner {
+                owner: match &self.owner {
+                    GrantOwner::Account(account_id) => GrantOwnerRef::Account(account_id),
+                    GrantOwner::Role(role_id) => GrantOwnerRef::Role(role_id),
+                },
+                object: (&self.object).into(),
+            }
+        }
+    }
+
+    impl_as_dyn_key! {
+        target: GrantWithOwner,
+        key: GrantByOwner<'_>,
+        trait: AsGrantByOwner
+    }
+
     /// `DomainId` wrapper for fetching accounts beloning to a domain from the global store
     #[derive(PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
     pub struct AccountIdDomainCompare<'a> {
@@ -1858,9 +2706,20 @@ pub(crate) mod deserialize {
                     let mut accounts = None;
                     let mut asset_definitions = None;
                     let mut assets = None;
+                    let mut allowances = None;
+                    let mut asset_holds = None;
+                    let mut hash_locks = None;
+                    let mut swap_offers = None;
+                    let mut hash_locked_amounts = None;
+                    let mut hash_claimable_amounts = None;
+                    let mut account_signatories = None;
+                    let mut account_aliases = None;
+                    let mut account_aliases_by_account = None;
                     let mut roles = None;
                     let mut account_permissions = None;
                     let mut account_roles = None;
+                    let mut grant_expiries = None;
+                    let mut grants_by_expiry = None;
                     let mut triggers = None;
                     let mut executor = None;
                     let mut executor_data_model = None;
@@ -1885,6 +2744,33 @@ pub(crate) mod deserialize {
                             "assets" => {
                                 assets = Some(map.next_value()?);
                             }
+                            "allowances" => {
+                                allowances = Some(map.next_value()?);
+                            }
+                            "asset_holds" => {
+                                asset_holds = Some(map.next_value()?);
+                            }
+                            "hash_locks" => {
+                                hash_locks = Some(map.next_value()?);
+                            }
+                            "swap_offers" => {
+                                swap_offers = Some(map.next_value()?);
+                            }
+                            "hash_locked_amounts" => {
+                                hash_locked_amounts = Some(map.next_value()?);
+                            }
+                            "hash_claimable_amounts" => {
+                                hash_claimable_amounts = Some(map.next_value()?);
+                            }
+                            "account_signatories" => {
+                                account_signatories = Some(map.next_value()?);
+                            }
+                            "account_aliases" => {
+                                account_aliases = Some(map.next_value()?);
+                            }
+                            "account_aliases_by_account" => {
+                                account_aliases_by_account = Some(map.next_value()?);
+                            }
                             "roles" => {
                                 roles = Some(map.next_value()?);
                             }
@@ -1894,6 +2780,12 @@ pub(crate) mod deserialize {
                             "account_roles" => {
                                 account_roles = Some(map.next_value()?);
                             }
+                            "grant_expiries" => {
+                                grant_expiries = Some(map.next_value()?);
+                            }
+                            "grants_by_expiry" => {
+                                grants_by_expiry = Some(map.next_value()?);
+                            }
                             "triggers" => {
                                 triggers =
                                     Some(map.next_value_seed(self.loader.cast::<TriggerSet>())?);
@@ -1922,12 +2814,25 @@ pub(crate) mod deserialize {
                         asset_definitions: asset_definitions
                             .ok_or_else(|| serde::de::Error::missing_field("asset_definitions"))?,
                         assets: assets.ok_or_else(|| serde::de::Error::missing_field("assets"))?,
+                        // Snapshots taken before these storages were introduced don't have them
+                        allowances: allowances.unwrap_or_default(),
+                        asset_holds: asset_holds.unwrap_or_default(),
+                        hash_locks: hash_locks.unwrap_or_default(),
+                        swap_offers: swap_offers.unwrap_or_default(),
+                        hash_locked_amounts: hash_locked_amounts.unwrap_or_default(),
+                        hash_claimable_amounts: hash_claimable_amounts.unwrap_or_default(),
+                        account_signatories: account_signatories.unwrap_or_default(),
+                        account_aliases: account_aliases.unwrap_or_default(),
+                        account_aliases_by_account: account_aliases_by_account.unwrap_or_default(),
                         roles: roles.ok_or_else(|| serde::de::Error::missing_field("roles"))?,
                         account_permissions: account_permissions.ok_or_else(|| {
                             serde::de::Error::missing_field("account_permissions")
                         })?,
                         account_roles: account_roles
                             .ok_or_else(|| serde::de::Error::missing_field("account_roles"))?,
+                        // Snapshots taken before temporary grants were introduced don't have them
+                        grant_expiries: grant_expiries.unwrap_or_default(),
+                        grants_by_expiry: grants_by_expiry.unwrap_or_default(),
                         triggers: triggers
                             .ok_or_else(|| serde::de::Error::missing_field("triggers"))?,
                         executor: executor
@@ -1948,6 +2853,8 @@ pub(crate) mod deserialize {
                     "roles",
                     "account_permissions",
                     "account_roles",
+                    "grant_expiries",
+                    "grants_by_expiry",
                     "triggers",
                     "executor",
                     "executor_data_model",
@@ -1990,6 +2897,7 @@ pub(crate) mod deserialize {
                     let mut world = None;
                     let mut block_hashes = None;
                     let mut transactions = None;
+                    let mut transaction_fees = None;
                     let mut commit_topology = None;
                     let mut prev_commit_topology = None;
 
@@ -2011,6 +2919,9 @@ pub(crate) mod deserialize {
                             "transactions" => {
                                 transactions = Some(map.next_value()?);
                             }
+                            "transaction_fees" => {
+                                transaction_fees = Some(map.next_value()?);
+                            }
                             "commit_topology" => {
                                 commit_topology = Some(map.next_value()?);
                             }
@@ -2027,6 +2938,7 @@ pub(crate) mod deserialize {
                             .ok_or_else(|| serde::de::Error::missing_field("block_hashes"))?,
                         transactions: transactions
                             .ok_or_else(|| serde::de::Error::missing_field("transactions"))?,
+                        transaction_fees: transaction_fees.unwrap_or_default(),
                         commit_topology: commit_topology
                             .ok_or_else(|| serde::de::Error::missing_field("commit_topology"))?,
                         prev_commit_topology: prev_commit_topology.ok_or_else(|| {
@@ -2037,6 +2949,7 @@ pub(crate) mod deserialize {
                         engine,
                         new_tx_amounts: Arc::new(Mutex::new(Vec::new())),
                         view_lock: parking_lot::RwLock::new(()),
+                        history: StateHistory::default(),
                     })
                 }
             }
@@ -2047,6 +2960,7 @@ pub(crate) mod deserialize {
                     "world",
                     "block_hashes",
                     "transactions",
+                    "transaction_fees",
                     "commit_topology",
                     "prev_commit_topology",
                 ],
@@ -1138,6 +1138,7 @@ pub(crate) fn run(
                 is_genesis_peer,
             );
         }
+        state.record_history();
 
         // State could be changed after handling message so it is necessary to reset state before handling message independent step
         let state_view = state.view();
@@ -1242,6 +1243,7 @@ pub(crate) fn run(
 
         if sumeragi.role() == Role::Leader && voting_block.is_none() {
             sumeragi.try_create_block(&state, &mut voting_block);
+            state.record_history();
         }
     }
 }
@@ -1485,8 +1487,14 @@ mod tests {
         let tx = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
             .with_instructions([fail_isi])
             .sign(alice_keypair.private_key());
-        let tx =
-            AcceptedTransaction::accept(tx, chain_id, max_clock_drift, tx_limits).expect("Valid");
+        let tx = AcceptedTransaction::accept(
+            tx,
+            chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )
+        .expect("Valid");
 
         // NOTE: imitate peer registration in the genesis block
         let peers = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
@@ -1498,8 +1506,14 @@ mod tests {
                     .map(InstructionBox::from),
             )
             .sign(alice_keypair.private_key());
-        let peers = AcceptedTransaction::accept(peers, chain_id, max_clock_drift, tx_limits)
-            .expect("Valid");
+        let peers = AcceptedTransaction::accept(
+            peers,
+            chain_id,
+            max_clock_drift,
+            tx_limits,
+            &state.world.view(),
+        )
+        .expect("Valid");
 
         // Creating a block of two identical transactions and validating it
         let unverified_genesis = BlockBuilder::new(vec![peers, tx.clone(), tx])
@@ -1531,15 +1545,27 @@ mod tests {
             let tx1 = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
                 .with_instructions([create_asset_definition1])
                 .sign(alice_keypair.private_key());
-            let tx1 = AcceptedTransaction::accept(tx1, chain_id, max_clock_drift, tx_limits)
-                .map(Into::into)
-                .expect("Valid");
+            let tx1 = AcceptedTransaction::accept(
+                tx1,
+                chain_id,
+                max_clock_drift,
+                tx_limits,
+                &state.world.view(),
+            )
+            .map(Into::into)
+            .expect("Valid");
             let tx2 = TransactionBuilder::new(chain_id.clone(), alice_id)
                 .with_instructions([create_asset_definition2])
                 .sign(alice_keypair.private_key());
-            let tx2 = AcceptedTransaction::accept(tx2, chain_id, max_clock_drift, tx_limits)
-                .map(Into::into)
-                .expect("Valid");
+            let tx2 = AcceptedTransaction::accept(
+                tx2,
+                chain_id,
+                max_clock_drift,
+                tx_limits,
+                &state.world.view(),
+            )
+            .map(Into::into)
+            .expect("Valid");
 
             // Creating a block of two identical transactions and validating it
             BlockBuilder::new(vec![tx1, tx2])
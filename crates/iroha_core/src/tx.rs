@@ -8,12 +8,17 @@
 //! This is also where the actual execution of instructions, as well
 //! as various forms of validation are performed.
 
-use std::time::{Duration, SystemTime};
+use std::{
+    collections::BTreeSet,
+    num::NonZeroU64,
+    time::{Duration, SystemTime},
+};
 
 use eyre::Result;
-use iroha_crypto::SignatureOf;
+use iroha_crypto::{MerkleTree, SignatureOf};
 pub use iroha_data_model::prelude::*;
 use iroha_data_model::{
+    block::BlockHeader,
     isi::error::Mismatch,
     query::error::FindError,
     transaction::{error::TransactionLimitError, TransactionPayload},
@@ -23,8 +28,11 @@ use iroha_macro::FromVariant;
 use mv::storage::StorageReadOnly;
 
 use crate::{
-    smartcontracts::{wasm, wasm::cache::WasmCache},
-    state::{StateBlock, StateTransaction},
+    smartcontracts::{
+        wasm::{self, cache::WasmCache, ConsumedResources},
+        Execute,
+    },
+    state::{State, StateBlock, StateReadOnly, StateTransaction, WorldReadOnly},
 };
 
 /// `AcceptedTransaction` — a transaction accepted by Iroha peer.
@@ -113,11 +121,34 @@ impl AcceptedTransaction {
     /// # Errors
     ///
     /// - if it does not adhere to limits
+    /// - if it isn't signed by any of the current signatories of its authority in `world`
     pub fn accept(
         tx: SignedTransaction,
         expected_chain_id: &ChainId,
         max_clock_drift: Duration,
         limits: TransactionParameters,
+        world: &impl WorldReadOnly,
+    ) -> Result<Self, AcceptTransactionFail> {
+        let tx = Self::accept_in_block(tx, expected_chain_id, max_clock_drift, limits)?;
+        tx.verify_signature(world)?;
+
+        Ok(tx)
+    }
+
+    /// Accept transaction of a block received from another peer.
+    ///
+    /// Unlike [`Self::accept`], the signature isn't verified: signatories of the authority
+    /// can change earlier in the same block, so the caller verifies it against the signatories
+    /// in force before the block with [`Self::verify_signature_by`].
+    ///
+    /// # Errors
+    ///
+    /// - if it does not adhere to limits
+    pub(crate) fn accept_in_block(
+        tx: SignedTransaction,
+        expected_chain_id: &ChainId,
+        max_clock_drift: Duration,
+        limits: TransactionParameters,
     ) -> Result<Self, AcceptTransactionFail> {
         Self::validate(&tx, expected_chain_id, max_clock_drift)?;
 
@@ -173,6 +204,45 @@ impl AcceptedTransaction {
 
         Ok(Self(tx))
     }
+
+    /// Check that the transaction is signed by one of the current signatories of its authority.
+    ///
+    /// # Errors
+    ///
+    /// - if none of the authority's signatories produced the signature
+    pub fn verify_signature(
+        &self,
+        world: &impl WorldReadOnly,
+    ) -> Result<(), SignatureVerificationFail> {
+        self.verify_signature_by(&world.account_signatories_of(self.0.authority()))
+    }
+
+    /// Check that the transaction is signed by one of the given signatories of its authority.
+    ///
+    /// # Errors
+    ///
+    /// - if none of the signatories produced the signature
+    pub fn verify_signature_by(
+        &self,
+        signatories: &BTreeSet<PublicKey>,
+    ) -> Result<(), SignatureVerificationFail> {
+        let tx = &self.0;
+
+        if signatories
+            .iter()
+            .any(|public_key| tx.verify_signature(public_key).is_ok())
+        {
+            return Ok(());
+        }
+
+        Err(SignatureVerificationFail {
+            signature: tx.signature().0.clone(),
+            reason: format!(
+                "Transaction is not signed by any of the signatories of `{}`",
+                tx.authority()
+            ),
+        })
+    }
 }
 
 impl From<AcceptedTransaction> for SignedTransaction {
@@ -193,10 +263,49 @@ impl AsRef<SignedTransaction> for AcceptedTransaction {
     }
 }
 
+impl State {
+    /// Execute the transaction on top of the latest committed block without committing it.
+    ///
+    /// Waits until the block which is being created or validated, if any, is released.
+    pub fn simulate_transaction(&self, tx: AcceptedTransaction) -> TransactionSimulation {
+        let header = {
+            let state_view = self.view();
+            let now = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .expect("INTERNAL BUG: Failed to get the current system time");
+
+            BlockHeader {
+                height: NonZeroU64::new(state_view.height() as u64 + 1)
+                    .expect("INTERNAL BUG: Blockchain height exceeds u64::MAX"),
+                prev_block_hash: state_view.latest_block_hash(),
+                transactions_hash: [tx.as_ref().hash()]
+                    .into_iter()
+                    .collect::<MerkleTree<_>>()
+                    .hash()
+                    .expect("INTERNAL BUG: Merkle tree of a single transaction is empty"),
+                creation_time_ms: now
+                    .as_millis()
+                    .try_into()
+                    .expect("Time should fit into u64"),
+                view_change_index: 0,
+            }
+        };
+
+        // NOTE: The block is dropped without committing it, discarding the changes
+        self.block(header).simulate(tx)
+    }
+}
+
 impl StateBlock<'_> {
     /// Move transaction lifecycle forward by checking if the
     /// instructions can be applied to the [`StateBlock`].
     ///
+    /// If a [`FeeSchedule`] is set, the fee for the instructions and the tip are charged before execution
+    /// and the fee for the fuel consumed by a smart contract is charged after it.
+    /// Without a [`FeeSchedule`] the tip only orders the queue and isn't charged.
+    /// Both are kept even if the transaction is rejected,
+    /// and the fee paid by the authority is recorded in [`StateBlock::transaction_fees`].
+    ///
     /// Validation is skipped for genesis.
     ///
     /// # Errors
@@ -206,21 +315,165 @@ impl StateBlock<'_> {
         tx: AcceptedTransaction,
         wasm_cache: &mut WasmCache<'_, '_, '_>,
     ) -> Result<SignedTransaction, (SignedTransaction, TransactionRejectionReason)> {
-        let mut state_transaction = self.transaction();
-        if let Err(rejection_reason) =
-            Self::validate_internal(tx.clone(), &mut state_transaction, wasm_cache)
-        {
-            return Err((tx.0, rejection_reason));
+        let hash = tx.as_ref().hash();
+        let (fee, _consumed, result) = self.validate_metered(tx, wasm_cache);
+        if let Some(fee) = fee {
+            self.transaction_fees.insert(hash, fee);
         }
-        state_transaction.apply();
+        result
+    }
 
-        Ok(tx.0)
+    /// Execute the transaction on top of the [`StateBlock`] to find out its outcome
+    /// without including it into a block.
+    ///
+    /// Changes are made to the [`StateBlock`] which therefore must not be committed.
+    pub fn simulate(&mut self, tx: AcceptedTransaction) -> TransactionSimulation {
+        // Grants could expire before the transaction is included into a block
+        self.revoke_expired_grants();
+        drop(self.take_events());
+
+        let (fee, consumed, result) = self.validate_metered(tx, &mut WasmCache::new());
+        let events = self
+            .take_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                EventBox::Data(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+
+        TransactionSimulation {
+            rejection_reason: result.err().map(|(_tx, rejection_reason)| rejection_reason),
+            events,
+            fee,
+            fuel: consumed.fuel,
+            instructions: consumed.instructions,
+        }
     }
 
-    fn validate_internal(
+    /// Implementation of [`Self::validate()`] which also reports the resources consumed by the transaction.
+    fn validate_metered(
+        &mut self,
         tx: AcceptedTransaction,
-        state_transaction: &mut StateTransaction<'_, '_>,
         wasm_cache: &mut WasmCache<'_, '_, '_>,
+    ) -> (
+        Option<TransactionFee>,
+        ConsumedResources,
+        Result<SignedTransaction, (SignedTransaction, TransactionRejectionReason)>,
+    ) {
+        let fee_schedule = if self.curr_block.is_genesis() {
+            None
+        } else {
+            self.world.parameters().fee().clone()
+        };
+        let mut state_transaction = self.transaction();
+        if let Err(rejection_reason) = Self::validate_authority(&tx, &state_transaction) {
+            return (
+                None,
+                ConsumedResources::default(),
+                Err((tx.0, rejection_reason)),
+            );
+        }
+
+        let Some(fee_schedule) = fee_schedule else {
+            let (consumed, result) =
+                Self::validate_internal(tx.clone(), &mut state_transaction, wasm_cache);
+            if let Err(rejection_reason) = result {
+                return (None, consumed, Err((tx.0, rejection_reason)));
+            }
+            state_transaction.apply();
+
+            return (None, consumed, Ok(tx.0));
+        };
+
+        let authority = tx.as_ref().authority().clone();
+        let paid_fee = |amount: Numeric| {
+            (!amount.is_zero()).then(|| TransactionFee {
+                asset: AssetId::new(fee_schedule.asset.clone(), authority.clone()),
+                amount,
+            })
+        };
+
+        let instructions = match tx.as_ref().instructions() {
+            Executable::Instructions(instructions) => instructions.len() as u64,
+            Executable::Wasm(_) => 0,
+        };
+        let Some(fixed) = fee_schedule
+            .base
+            .checked_add(tx.as_ref().tip().unwrap_or(Numeric::ZERO))
+        else {
+            return (
+                None,
+                ConsumedResources::default(),
+                Err((tx.0, TransactionRejectionReason::FeeOverflow)),
+            );
+        };
+        let paid = match Self::charge_fee(
+            &authority,
+            &fee_schedule,
+            Numeric::ZERO,
+            fixed,
+            (fee_schedule.per_instruction, instructions),
+            &mut state_transaction,
+        ) {
+            Ok(paid) => paid,
+            Err(rejection_reason) => {
+                return (
+                    None,
+                    ConsumedResources::default(),
+                    Err((tx.0, rejection_reason)),
+                )
+            }
+        };
+        state_transaction.apply();
+
+        let mut state_transaction = self.transaction();
+        let (consumed, result) =
+            Self::validate_internal(tx.clone(), &mut state_transaction, wasm_cache);
+        let fuel_fee = (fee_schedule.per_fuel_unit, consumed.fuel);
+        let result = result.and_then(|()| {
+            Self::charge_fee(
+                &authority,
+                &fee_schedule,
+                paid,
+                Numeric::ZERO,
+                fuel_fee,
+                &mut state_transaction,
+            )
+        });
+        match result {
+            Ok(paid) => {
+                state_transaction.apply();
+
+                (paid_fee(paid), consumed, Ok(tx.0))
+            }
+            Err(rejection_reason) => {
+                // Changes of the rejected transaction are discarded, but the fuel is still paid for
+                drop(state_transaction);
+                let mut state_transaction = self.transaction();
+                let paid = match Self::charge_fee(
+                    &authority,
+                    &fee_schedule,
+                    paid,
+                    Numeric::ZERO,
+                    fuel_fee,
+                    &mut state_transaction,
+                ) {
+                    Ok(paid) => {
+                        state_transaction.apply();
+                        paid
+                    }
+                    Err(_) => paid,
+                };
+
+                (paid_fee(paid), consumed, Err((tx.0, rejection_reason)))
+            }
+        }
+    }
+
+    fn validate_authority(
+        tx: &AcceptedTransaction,
+        state_transaction: &StateTransaction<'_, '_>,
     ) -> Result<(), TransactionRejectionReason> {
         let authority = tx.as_ref().authority();
 
@@ -230,42 +483,115 @@ impl StateBlock<'_> {
             ));
         }
 
-        debug!(tx=%tx.as_ref().hash(), "Validating transaction");
-        Self::validate_with_runtime_executor(tx.clone(), state_transaction, wasm_cache)?;
-
-        if let (authority, Executable::Wasm(bytes)) = tx.into() {
-            Self::validate_wasm(authority, state_transaction, bytes)?
+        if let Err(err) = tx.verify_signature(&state_transaction.world) {
+            debug!(tx=%tx.as_ref().hash(), %err, "Signature verification failed");
+            return Err(TransactionRejectionReason::InvalidSignature);
         }
 
-        debug!("Validation successful");
         Ok(())
     }
 
+    /// Transfer `fixed + rate * units` of the fee asset from `authority` to the fee collector.
+    ///
+    /// Returns the total fee paid by the transaction, i.e. the charged amount added to `paid`.
+    fn charge_fee(
+        authority: &AccountId,
+        fee_schedule: &FeeSchedule,
+        paid: Numeric,
+        fixed: Numeric,
+        (rate, units): (Numeric, u64),
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<Numeric, TransactionRejectionReason> {
+        let asset = AssetId::new(fee_schedule.asset.clone(), authority.clone());
+        let spec = match state_transaction
+            .world
+            .asset_definition(&fee_schedule.asset)
+            .map(|definition| definition.type_)
+        {
+            Ok(AssetType::Numeric(spec)) => spec,
+            _ => NumericSpec::unconstrained(),
+        };
+        let amount = rate
+            .checked_mul(Numeric::from(units), spec)
+            .and_then(|variable| fixed.checked_add(variable))
+            .ok_or(TransactionRejectionReason::FeeOverflow)?;
+        let total = paid
+            .checked_add(amount)
+            .ok_or(TransactionRejectionReason::FeeOverflow)?;
+
+        if amount.is_zero() {
+            return Ok(total);
+        }
+
+        Transfer::asset_numeric(asset.clone(), amount, fee_schedule.collector.clone())
+            .execute(authority, state_transaction)
+            .map_err(|err| {
+                debug!(%asset, %amount, %err, "Failed to charge transaction fee");
+                TransactionRejectionReason::InsufficientFee(TransactionFee { asset, amount })
+            })?;
+
+        Ok(total)
+    }
+
+    fn validate_internal(
+        tx: AcceptedTransaction,
+        state_transaction: &mut StateTransaction<'_, '_>,
+        wasm_cache: &mut WasmCache<'_, '_, '_>,
+    ) -> (ConsumedResources, Result<(), TransactionRejectionReason>) {
+        debug!(tx=%tx.as_ref().hash(), "Validating transaction");
+        let instructions = match tx.as_ref().instructions() {
+            Executable::Instructions(instructions) => instructions.len() as u64,
+            Executable::Wasm(_) => 0,
+        };
+        let no_fuel = ConsumedResources {
+            fuel: 0,
+            instructions,
+        };
+        if let Err(rejection_reason) =
+            Self::validate_with_runtime_executor(tx.clone(), state_transaction, wasm_cache)
+        {
+            return (no_fuel, Err(rejection_reason));
+        }
+
+        let (consumed, result) = match tx.into() {
+            (authority, Executable::Wasm(bytes)) => {
+                Self::validate_wasm(authority, state_transaction, bytes)
+            }
+            (_, Executable::Instructions(_)) => (no_fuel, Ok(())),
+        };
+
+        if result.is_ok() {
+            debug!("Validation successful");
+        }
+        (consumed, result)
+    }
+
     fn validate_wasm(
         authority: AccountId,
         state_transaction: &mut StateTransaction<'_, '_>,
         wasm: WasmSmartContract,
-    ) -> Result<(), TransactionRejectionReason> {
+    ) -> (ConsumedResources, Result<(), TransactionRejectionReason>) {
         debug!("Validating wasm");
 
-        wasm::RuntimeBuilder::<wasm::state::SmartContract>::new()
-            .build()
-            .and_then(|mut wasm_runtime| {
-                wasm_runtime.validate(
-                    state_transaction,
-                    authority,
-                    wasm,
-                    state_transaction
-                        .world
-                        .parameters
-                        .transaction
-                        .max_instructions,
-                )
-            })
-            .map_err(|error| WasmExecutionFail {
+        let max_instructions = state_transaction
+            .world
+            .parameters
+            .transaction
+            .max_instructions;
+        let (consumed, result) =
+            match wasm::RuntimeBuilder::<wasm::state::SmartContract>::new().build() {
+                Ok(mut wasm_runtime) => {
+                    wasm_runtime.validate(state_transaction, authority, wasm, max_instructions)
+                }
+                Err(error) => (ConsumedResources::default(), Err(error)),
+            };
+
+        let result = result.map_err(|error| {
+            TransactionRejectionReason::WasmExecution(WasmExecutionFail {
                 reason: format!("{:?}", eyre::Report::from(error)),
             })
-            .map_err(TransactionRejectionReason::WasmExecution)
+        });
+        (consumed, result)
     }
 
     /// Validate transaction with runtime executors.
@@ -15,6 +15,16 @@ use crate::{Hash, HashOf};
 #[repr(transparent)]
 pub struct MerkleTree<T>(Vec<Option<HashOf<T>>>);
 
+/// Proof that a leaf hash is included in a [`MerkleTree`] with a given root hash
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct MerkleProof<T> {
+    /// Index of the leaf in the order of insertion
+    leaf_idx: u32,
+    /// Sibling nodes on the path from the leaf to the root, starting from the leaf level.
+    /// `None` stands for an empty subtree to the right of the path
+    audit_path: Vec<Option<HashOf<T>>>,
+}
+
 /// Iterator over leaves of [`MerkleTree`]
 pub struct LeafHashIterator<T> {
     tree: MerkleTree<T>,
@@ -159,6 +169,33 @@ impl<T> MerkleTree<T> {
         None
     }
 
+    /// Get the proof of inclusion of the `idx`-th leaf hash.
+    ///
+    /// Returns `None` if there is no such leaf.
+    pub fn get_proof(&self, idx: u32) -> Option<MerkleProof<T>> {
+        let offset = 2_usize.pow(self.height()) - 1;
+        let mut node_idx = offset.checked_add(usize::try_from(idx).ok()?)?;
+        if self.get(node_idx)?.is_none() {
+            return None;
+        }
+
+        let mut audit_path = Vec::new();
+        while self.parent(node_idx).is_some() {
+            let sibling_idx = if node_idx % 2 == 1 {
+                node_idx + 1
+            } else {
+                node_idx - 1
+            };
+            audit_path.push(self.get(sibling_idx).copied().flatten());
+            node_idx = (node_idx - 1) / 2;
+        }
+
+        Some(MerkleProof {
+            leaf_idx: idx,
+            audit_path,
+        })
+    }
+
     /// Add `hash` to the tail of the tree.
     pub fn add(&mut self, hash: HashOf<T>) {
         // If the tree is perfect, increment its height to double the leaf capacity.
@@ -225,6 +262,42 @@ impl<T> MerkleTree<T> {
     }
 }
 
+impl<T> MerkleProof<T> {
+    /// Index of the proven leaf in the order of insertion.
+    pub fn leaf_idx(&self) -> u32 {
+        self.leaf_idx
+    }
+
+    /// Check that `leaf` is the [`Self::leaf_idx`]-th leaf of the [`MerkleTree`] with the given `root` hash.
+    pub fn verify(&self, leaf: &HashOf<T>, root: HashOf<MerkleTree<T>>) -> bool {
+        let Ok(height) = u32::try_from(self.audit_path.len()) else {
+            return false;
+        };
+        if self.leaf_idx.checked_shr(height).unwrap_or(0) != 0 {
+            return false;
+        }
+
+        let mut node = *leaf;
+        let mut idx = self.leaf_idx;
+        for sibling in &self.audit_path {
+            node = match (idx % 2, sibling) {
+                (0, Some(r_hash)) => {
+                    MerkleTree::nodes_pair_hash(Some(&node), Some(r_hash)).expect("Infallible")
+                }
+                (0, None) => node,
+                (_, Some(l_hash)) => {
+                    MerkleTree::nodes_pair_hash(Some(l_hash), Some(&node)).expect("Infallible")
+                }
+                // Only the right subtree can be empty
+                (_, None) => return false,
+            };
+            idx /= 2;
+        }
+
+        HashOf::transmute(node) == root
+    }
+}
+
 impl<T> Iterator for LeafHashIterator<T> {
     type Item = HashOf<T>;
 
@@ -312,4 +385,34 @@ mod tests {
             assert_eq!(testee_leaf, tester_leaf);
         }
     }
+
+    #[test]
+    fn proof() {
+        for n_leaves in 1..=9 {
+            let hashes = test_hashes(n_leaves);
+            let tree = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+            let root = tree.hash().unwrap();
+
+            for (idx, leaf) in (0..).zip(&hashes) {
+                let proof = tree.get_proof(idx).unwrap();
+                assert_eq!(proof.leaf_idx(), idx);
+                assert!(proof.verify(leaf, root));
+
+                let other_leaf = &hashes[(idx as usize + 1) % hashes.len()];
+                assert_eq!(proof.verify(other_leaf, root), other_leaf == leaf);
+            }
+            assert!(tree.get_proof(u32::from(n_leaves)).is_none());
+        }
+    }
+
+    #[test]
+    fn proof_with_wrong_index_is_rejected() {
+        let hashes = test_hashes(5);
+        let tree = hashes.clone().into_iter().collect::<MerkleTree<_>>();
+        let root = tree.hash().unwrap();
+
+        let mut proof = tree.get_proof(4).unwrap();
+        proof.leaf_idx = 8;
+        assert!(!proof.verify(&hashes[4], root));
+    }
 }
@@ -78,6 +78,11 @@ impl Signature {
         }
     }
 
+    /// Raw payload of the signature.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
     /// A shorthand for [`Self::from_bytes`] accepting payload as hex.
     ///
     /// # Errors
@@ -12,8 +12,8 @@ use serde_with::{DeserializeFromStr, SerializeDisplay};
 
 pub use self::model::*;
 use crate::{
-    domain::prelude::*, metadata::Metadata, HasMetadata, Identifiable, ParseError, PublicKey,
-    Registered,
+    domain::prelude::*, metadata::Metadata, name::Name, HasMetadata, Identifiable, ParseError,
+    PublicKey, Registered,
 };
 
 #[model]
@@ -22,8 +22,16 @@ mod model {
 
     use super::*;
 
-    /// Identification of [`Account`] by the combination of the [`PublicKey`] as its sole signatory and the [`Domain`](crate::domain::Domain) it belongs to.
-    /// TODO #4373 include multi-signatory use.
+    /// Identification of [`Account`] by the combination of the [`PublicKey`] it was created with and the [`Domain`](crate::domain::Domain) it belongs to.
+    ///
+    /// # Limitations
+    ///
+    /// The identity of an account is still derived from a key: the id keeps naming the key the
+    /// account was created with even after it's rotated out of the account's signatories with
+    /// [`RotateKey`](crate::isi::RotateKey) or [`RemoveSignatory`](crate::isi::RemoveSignatory).
+    /// The id therefore doesn't tell who controls the account, only its current signatories do.
+    /// Until signatories are changed, the key of the id is the only signatory, which is why
+    /// existing accounts keep working without any migration of their ids.
     ///
     /// # Examples
     ///
@@ -59,10 +67,49 @@ mod model {
     pub struct AccountId {
         /// [`Domain`](crate::domain::Domain) that the [`Account`] belongs to.
         pub domain: DomainId,
-        /// Sole signatory of the [`Account`].
+        /// Key the [`Account`] was created with, its initial signatory.
         pub signatory: PublicKey,
     }
 
+    /// Human-readable name of an [`Account`], unique within the [`Domain`](crate::domain::Domain) it belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iroha_data_model::account::AccountAlias;
+    ///
+    /// let alias = "alice@wonderland"
+    ///     .parse::<AccountAlias>()
+    ///     .expect("name@domain should be valid format");
+    /// ```
+    #[derive(
+        DebugCustom,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        DeserializeFromStr,
+        SerializeDisplay,
+        IntoSchema,
+    )]
+    #[display(fmt = "{name}@{domain}")]
+    #[debug(fmt = "{name}@{domain}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AccountAlias {
+        /// [`Domain`](crate::domain::Domain) that the alias belongs to.
+        pub domain: DomainId,
+        /// Name of the alias.
+        pub name: Name,
+    }
+
     /// Account entity is an authority which is used to execute `Iroha Special Instructions`.
     #[derive(
         Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
@@ -188,9 +235,73 @@ impl FromStr for AccountId {
     }
 }
 
+impl FromStr for AccountAlias {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.rsplit_once('@') {
+            None => Err(ParseError {
+                reason: "Account alias should have format `name@domain`",
+            }),
+            Some(("", _)) => Err(ParseError {
+                reason: "Empty `name` part in `name@domain`",
+            }),
+            Some((_, "")) => Err(ParseError {
+                reason: "Empty `domain` part in `name@domain`",
+            }),
+            Some((name_candidate, domain_id_candidate)) => {
+                let name = name_candidate.parse().map_err(|_| ParseError {
+                    reason: "Failed to parse `name` part in `name@domain`",
+                })?;
+                let domain_id = domain_id_candidate.parse().map_err(|_| ParseError {
+                    reason: "Failed to parse `domain` part in `name@domain`",
+                })?;
+                Ok(Self::new(domain_id, name))
+            }
+        }
+    }
+}
+
+/// Reference to an [`Account`] either by its [`AccountId`] or by one of its [`AccountAlias`]es.
+///
+/// Aliases are resolved to ids by querying the peer, see `FindAccountIdByAlias`.
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
+pub enum AccountRef {
+    /// Account referenced by its id
+    Id(AccountId),
+    /// Account referenced by one of its aliases
+    Alias(AccountAlias),
+}
+
+impl From<AccountId> for AccountRef {
+    fn from(id: AccountId) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<AccountAlias> for AccountRef {
+    fn from(alias: AccountAlias) -> Self {
+        Self::Alias(alias)
+    }
+}
+
+impl FromStr for AccountRef {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // An alias can't be named after a public key, so ids are tried first
+        if let Ok(id) = s.parse() {
+            return Ok(Self::Id(id));
+        }
+        s.parse().map(Self::Alias).map_err(|_| ParseError {
+            reason: "Account should be referenced either as `signatory@domain` or `alias@domain`",
+        })
+    }
+}
+
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
-    pub use super::{Account, AccountId};
+    pub use super::{Account, AccountAlias, AccountId, AccountRef};
 }
 
 #[cfg(test)]
@@ -215,4 +326,21 @@ mod tests {
             .parse::<AccountId>()
             .expect_err("signatory#domain should not be valid");
     }
+
+    #[test]
+    fn parse_account_ref() {
+        const SIGNATORY: &str =
+            "ed0120EDF6D7B52C7032D03AEC696F2068BD53101528F3C7B6081BFF05A1662D7FC245";
+        assert!(matches!(
+            format!("{SIGNATORY}@domain").parse::<AccountRef>(),
+            Ok(AccountRef::Id(_))
+        ));
+        assert!(matches!(
+            "alice@domain".parse::<AccountRef>(),
+            Ok(AccountRef::Alias(_))
+        ));
+        let _err_violates_format = "alice#domain"
+            .parse::<AccountRef>()
+            .expect_err("alias#domain should not be valid");
+    }
 }
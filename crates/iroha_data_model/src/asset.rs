@@ -158,6 +158,278 @@ mod model {
         pub value: AssetValue,
     }
 
+    /// Identification of an [`Allowance`]: the spender allowed to transfer
+    /// a numeric asset of the given definition from the owner's account.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{asset_definition} of {owner} spent by {spender}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AllowanceId {
+        /// Account holding the asset.
+        pub owner: AccountId,
+        /// Account allowed to transfer the asset.
+        pub spender: AccountId,
+        /// Definition of the asset.
+        pub asset_definition: AssetDefinitionId,
+    }
+
+    /// Quantity of an asset that the spender can still transfer from the owner's account.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{id}: {amount}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct Allowance {
+        /// Component Identification.
+        pub id: AllowanceId,
+        /// Remaining quantity.
+        pub amount: Numeric,
+    }
+
+    /// Part of a numeric [`Asset`] balance locked by a hold.
+    ///
+    /// Held quantity stays in the asset's balance but can't be transferred or burned until released.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{asset}: {amount} held")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AssetHold {
+        /// Asset with the locked balance.
+        pub asset: AssetId,
+        /// Locked quantity.
+        pub amount: Numeric,
+    }
+
+    /// Identification of a [`HashLock`], unique among the locks of the sender.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{name} of {sender}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct HashLockId {
+        /// Account whose asset is locked.
+        pub sender: AccountId,
+        /// Name of the lock.
+        pub name: Name,
+    }
+
+    /// Plain SHA-256 digest of the secret unlocking a [`HashLock`].
+    ///
+    /// Unlike Iroha's [`Hash`](iroha_crypto::Hash) it isn't altered, so the same secret can lock
+    /// transfers on other chains as well, e.g. in cross-chain atomic swaps.
+    #[derive(
+        DebugCustom,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Decode,
+        Encode,
+        SerializeDisplay,
+        DeserializeFromStr,
+        IntoSchema,
+    )]
+    #[display(fmt = "{}", "hex::encode_upper(self.0)")]
+    #[debug(fmt = "{}", "hex::encode_upper(self.0)")]
+    #[repr(transparent)]
+    #[ffi_type(unsafe {robust})]
+    pub struct Sha256Hash(pub [u8; 32]);
+
+    /// Hash-time-locked transfer of a numeric asset.
+    ///
+    /// Until `deadline_ms` the recipient can claim the locked quantity by revealing the preimage
+    /// of `hash`. Afterwards it can only be refunded to the sender. While the lock is open the
+    /// quantity stays in the sender's balance but can't be transferred or burned.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{id}: {amount} of {asset_definition} to {recipient}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct HashLock {
+        /// Component Identification.
+        pub id: HashLockId,
+        /// Definition of the locked asset.
+        pub asset_definition: AssetDefinitionId,
+        /// Locked quantity.
+        pub amount: Numeric,
+        /// Account receiving the asset when the lock is claimed.
+        pub recipient: AccountId,
+        /// Hash of the secret preimage which unlocks the transfer.
+        pub hash: Sha256Hash,
+        /// Block creation time (in milliseconds since the Unix epoch) from which the lock can
+        /// no longer be claimed and can be refunded instead.
+        pub deadline_ms: u64,
+    }
+
+    /// Identification of a [`SwapOffer`], unique among the offers of the proposer.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{name} of {proposer}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct SwapOfferId {
+        /// Account which proposed the swap.
+        pub proposer: AccountId,
+        /// Name of the offer.
+        pub name: Name,
+    }
+
+    /// One side of a [`SwapOffer`].
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{amount} of {asset}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct SwapLeg {
+        /// Asset paid by its owner.
+        pub asset: AssetId,
+        /// Quantity paid.
+        pub amount: Numeric,
+    }
+
+    /// Pending atomic exchange of numeric assets between two accounts.
+    ///
+    /// The proposer pays the `offered` leg to the owner of the `requested` leg, who pays it back
+    /// when accepting the offer. Until then no balance is moved or locked. From `expires_at_ms`
+    /// the offer can no longer be accepted and can be cancelled by anyone.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{id}: {offered} for {requested}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct SwapOffer {
+        /// Component Identification.
+        pub id: SwapOfferId,
+        /// Leg paid by the proposer.
+        pub offered: SwapLeg,
+        /// Leg paid by the account accepting the offer.
+        pub requested: SwapLeg,
+        /// Block creation time (in milliseconds since the Unix epoch) from which the offer
+        /// can no longer be accepted.
+        pub expires_at_ms: u64,
+    }
+
     /// Builder which can be submitted in a transaction to create a new [`AssetDefinition`]
     #[derive(
         Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
@@ -235,7 +507,8 @@ mod model {
     }
 
     /// An assets mintability scheme. `Infinitely` means elastic
-    /// supply. `Once` is what you want to use. Don't use `Not` explicitly
+    /// supply. `Once` is what you want to use. `Capped` limits the total
+    /// quantity of a numeric asset. Don't use `Not` explicitly
     /// outside of smartcontracts.
     #[derive(
         Debug,
@@ -264,7 +537,10 @@ mod model {
         /// Non-mintable asset (token), with a fixed supply. Can be burned, but not minted.
         #[display(fmt = "-")]
         Not,
-        // TODO: Support more variants using bit-compacted tag, and `u32` mintability tokens.
+        /// Numeric asset with a capped supply. Can be burned, and minted repeatedly
+        /// as long as its total quantity doesn't exceed the cap.
+        #[display(fmt = "<={_0}")]
+        Capped(Numeric),
     }
 }
 
@@ -312,6 +588,41 @@ impl AssetId {
     }
 }
 
+impl AllowanceId {
+    /// [`AssetId`] of the owner's asset which can be spent
+    pub fn asset(&self) -> AssetId {
+        AssetId::new(self.asset_definition.clone(), self.owner.clone())
+    }
+}
+
+impl Sha256Hash {
+    /// Hash the given preimage.
+    pub fn new(preimage: impl AsRef<[u8]>) -> Self {
+        use sha2::Digest as _;
+
+        Self(sha2::Sha256::digest(preimage).into())
+    }
+}
+
+impl FromStr for Sha256Hash {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut hash = [0; 32];
+        hex::decode_to_slice(s, &mut hash).map_err(|_| ParseError {
+            reason: "SHA-256 hash should be 64 hex digits",
+        })?;
+        Ok(Self(hash))
+    }
+}
+
+impl HashLock {
+    /// [`AssetId`] of the sender's asset which is locked
+    pub fn asset(&self) -> AssetId {
+        AssetId::new(self.asset_definition.clone(), self.id.sender.clone())
+    }
+}
+
 impl Asset {
     /// Constructor
     pub fn new(id: AssetId, value: impl Into<AssetValue>) -> <Self as Registered>::With {
@@ -342,6 +653,14 @@ impl NewAssetDefinition {
         self
     }
 
+    /// Set mintability to [`Mintable::Capped`] with the given `cap` on the total quantity
+    #[inline]
+    #[must_use]
+    pub fn mintable_capped(mut self, cap: Numeric) -> Self {
+        self.mintable = Mintable::Capped(cap);
+        self
+    }
+
     /// Add [`logo`](IpfsPath) to the asset definition replacing previously defined value
     #[must_use]
     pub fn with_logo(mut self, logo: IpfsPath) -> Self {
@@ -486,8 +805,9 @@ impl Registered for AssetDefinition {
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        Asset, AssetDefinition, AssetDefinitionId, AssetId, AssetType, AssetValue, Mintable,
-        NewAssetDefinition,
+        Allowance, AllowanceId, Asset, AssetDefinition, AssetDefinitionId, AssetHold, AssetId,
+        AssetType, AssetValue, HashLock, HashLockId, Mintable, NewAssetDefinition, Sha256Hash,
+        SwapLeg, SwapOffer, SwapOfferId,
     };
 }
 
@@ -528,4 +848,19 @@ mod tests {
             .parse::<AssetId>()
             .expect_err("asset#signatory@domain should not be valid");
     }
+
+    #[test]
+    fn sha256_hash_is_plain_sha256() {
+        const ABC_SHA256: &str = "BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD";
+
+        let hash = Sha256Hash::new(b"abc");
+        assert_eq!(hash.to_string(), ABC_SHA256);
+        assert_eq!(
+            ABC_SHA256.to_lowercase().parse::<Sha256Hash>().unwrap(),
+            hash
+        );
+        let _err = "BA7816"
+            .parse::<Sha256Hash>()
+            .expect_err("hash is too short");
+    }
 }
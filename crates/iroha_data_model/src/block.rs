@@ -9,7 +9,7 @@ use core::{fmt::Display, time::Duration};
 use std::collections::BTreeMap;
 
 use derive_more::Display;
-use iroha_crypto::{HashOf, MerkleTree, SignatureOf};
+use iroha_crypto::{HashOf, MerkleProof, MerkleTree, SignatureOf};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_schema::IntoSchema;
@@ -118,6 +118,15 @@ mod model {
         /// Transaction errors are not part of the block hash or protected by the block signature.
         pub(super) errors: BTreeMap<u64, TransactionRejectionReason>,
     }
+
+    /// Proof that a transaction is included in a block, verifiable with the block header alone
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct TransactionInclusionProof {
+        /// Header of the block containing the transaction
+        pub block_header: BlockHeader,
+        /// Proof of the transaction hash against [`BlockHeader::transactions_hash`]
+        pub merkle_proof: MerkleProof<SignedTransaction>,
+    }
 }
 
 #[cfg(any(feature = "ffi_export", feature = "ffi_import"))]
@@ -144,6 +153,16 @@ impl BlockHeader {
     }
 }
 
+impl TransactionInclusionProof {
+    /// Check that the transaction with the given hash is included in the block with [`Self::block_header`].
+    ///
+    /// The block header itself must be trusted, e.g. its hash must be compared with the hash of a known block.
+    pub fn verify(&self, hash: &HashOf<SignedTransaction>) -> bool {
+        self.merkle_proof
+            .verify(hash, self.block_header.transactions_hash)
+    }
+}
+
 impl SignedBlockV1 {
     fn hash(&self) -> HashOf<BlockHeader> {
         self.payload.header.hash()
@@ -229,6 +248,26 @@ impl SignedBlock {
         block.payload.transactions.iter()
     }
 
+    /// Construct the proof that the transaction with the given hash is included in this block.
+    ///
+    /// Returns `None` if the block doesn't contain the transaction.
+    pub fn transaction_inclusion_proof(
+        &self,
+        hash: &HashOf<SignedTransaction>,
+    ) -> Option<TransactionInclusionProof> {
+        let idx = self.transactions().position(|tx| tx.hash() == *hash)?;
+        let merkle_proof = self
+            .transactions()
+            .map(SignedTransaction::hash)
+            .collect::<MerkleTree<_>>()
+            .get_proof(idx.try_into().ok()?)?;
+
+        Some(TransactionInclusionProof {
+            block_header: self.header(),
+            merkle_proof,
+        })
+    }
+
     /// Collection of rejection reasons for every transaction if exists
     ///
     /// # Warning
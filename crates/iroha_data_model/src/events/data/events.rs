@@ -120,6 +120,10 @@ mod asset {
             MetadataInserted(AssetMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target)]
             MetadataRemoved(AssetMetadataChanged),
+            #[has_origin(asset_changed => &asset_changed.asset)]
+            Held(AssetChanged),
+            #[has_origin(asset_changed => &asset_changed.asset)]
+            Released(AssetChanged),
         }
     }
 
@@ -138,6 +142,7 @@ mod asset {
             TotalQuantityChanged(AssetDefinitionTotalQuantityChanged),
             #[has_origin(ownership_changed => &ownership_changed.asset_definition)]
             OwnerChanged(AssetDefinitionOwnerChanged),
+            MintCapReached(AssetDefinitionId),
         }
     }
 
@@ -312,6 +317,28 @@ mod account {
             MetadataInserted(AccountMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target)]
             MetadataRemoved(AccountMetadataChanged),
+            #[has_origin(allowance => &allowance.id.owner)]
+            AllowanceChanged(Allowance),
+            #[has_origin(hash_lock => &hash_lock.id.sender)]
+            HashLockCreated(HashLock),
+            #[has_origin(hash_lock => &hash_lock.id.sender)]
+            HashLockClaimed(HashLock),
+            #[has_origin(hash_lock => &hash_lock.id.sender)]
+            HashLockRefunded(HashLock),
+            #[has_origin(swap_offer => &swap_offer.id.proposer)]
+            SwapProposed(SwapOffer),
+            #[has_origin(swap_offer => &swap_offer.id.proposer)]
+            SwapAccepted(SwapOffer),
+            #[has_origin(swap_offer => &swap_offer.id.proposer)]
+            SwapCancelled(SwapOffer),
+            #[has_origin(signatory_changed => &signatory_changed.account)]
+            SignatoryAdded(AccountSignatoryChanged),
+            #[has_origin(signatory_changed => &signatory_changed.account)]
+            SignatoryRemoved(AccountSignatoryChanged),
+            #[has_origin(alias_changed => &alias_changed.account)]
+            AliasRegistered(AccountAliasChanged),
+            #[has_origin(alias_changed => &alias_changed.account)]
+            AliasUnregistered(AccountAliasChanged),
         }
     }
 
@@ -364,6 +391,50 @@ mod account {
             pub account: AccountId,
             pub role: RoleId,
         }
+
+        /// Depending on the wrapping event, [`AccountSignatoryChanged`] represents the added or removed signatory
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AccountSignatoryChanged {
+            pub account: AccountId,
+            pub public_key: PublicKey,
+        }
+
+        /// Depending on the wrapping event, [`AccountAliasChanged`] represents the registered or unregistered alias
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AccountAliasChanged {
+            pub account: AccountId,
+            pub alias: AccountAlias,
+        }
     }
 
     impl AccountPermissionChanged {
@@ -660,7 +731,10 @@ impl<Id> MetadataChanged<Id> {
 
 pub mod prelude {
     pub use super::{
-        account::{AccountEvent, AccountEventSet, AccountPermissionChanged, AccountRoleChanged},
+        account::{
+            AccountAliasChanged, AccountEvent, AccountEventSet, AccountPermissionChanged,
+            AccountRoleChanged, AccountSignatoryChanged,
+        },
         asset::{
             AssetChanged, AssetDefinitionEvent, AssetDefinitionEventSet,
             AssetDefinitionOwnerChanged, AssetDefinitionTotalQuantityChanged, AssetEvent,
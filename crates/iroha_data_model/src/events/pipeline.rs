@@ -139,6 +139,8 @@ mod model {
         Approved,
         /// Transaction was stored in the block as invalid
         Rejected(Box<crate::transaction::error::TransactionRejectionReason>),
+        /// Transaction was dropped from the full queue in favour of a higher priority one
+        Evicted,
     }
 
     #[derive(
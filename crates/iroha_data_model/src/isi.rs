@@ -117,6 +117,40 @@ mod model {
         Upgrade(Upgrade),
         #[debug(fmt = "{_0:?}")]
         Log(Log),
+        #[debug(fmt = "{_0:?}")]
+        SetMintCap(SetMintCap),
+        #[debug(fmt = "{_0:?}")]
+        Approve(Approve),
+        #[debug(fmt = "{_0:?}")]
+        TransferFrom(TransferFrom),
+        #[debug(fmt = "{_0:?}")]
+        HoldAsset(HoldAsset),
+        #[debug(fmt = "{_0:?}")]
+        ReleaseAsset(ReleaseAsset),
+        #[debug(fmt = "{_0:?}")]
+        LockAsset(LockAsset),
+        #[debug(fmt = "{_0:?}")]
+        ClaimLockedAsset(ClaimLockedAsset),
+        #[debug(fmt = "{_0:?}")]
+        RefundLockedAsset(RefundLockedAsset),
+        #[debug(fmt = "{_0:?}")]
+        ProposeSwap(ProposeSwap),
+        #[debug(fmt = "{_0:?}")]
+        AcceptSwap(AcceptSwap),
+        #[debug(fmt = "{_0:?}")]
+        CancelSwap(CancelSwap),
+        #[debug(fmt = "{_0:?}")]
+        AddSignatory(AddSignatory),
+        #[debug(fmt = "{_0:?}")]
+        RemoveSignatory(RemoveSignatory),
+        #[debug(fmt = "{_0:?}")]
+        RotateKey(RotateKey),
+        #[debug(fmt = "{_0:?}")]
+        RegisterAccountAlias(RegisterAccountAlias),
+        #[debug(fmt = "{_0:?}")]
+        TransferAccountAlias(TransferAccountAlias),
+        #[debug(fmt = "{_0:?}")]
+        UnregisterAccountAlias(UnregisterAccountAlias),
 
         #[debug(fmt = "{_0:?}")]
         Custom(CustomInstruction),
@@ -175,6 +209,23 @@ impl_instruction! {
     Revoke<RoleId, Account>,
     Revoke<Permission, Role>,
     SetParameter,
+    SetMintCap,
+    Approve,
+    TransferFrom,
+    HoldAsset,
+    ReleaseAsset,
+    LockAsset,
+    ClaimLockedAsset,
+    RefundLockedAsset,
+    ProposeSwap,
+    AcceptSwap,
+    CancelSwap,
+    AddSignatory,
+    RemoveSignatory,
+    RotateKey,
+    RegisterAccountAlias,
+    TransferAccountAlias,
+    UnregisterAccountAlias,
     Upgrade,
     ExecuteTrigger,
     Log,
@@ -798,6 +849,9 @@ mod transparent {
             pub object: O,
             /// Entity to which to grant this token.
             pub destination: D::Id,
+            /// Time (unix time in milliseconds) at which the grant is revoked automatically.
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub expires_at_ms: Option<u64>,
         }
     }
 
@@ -807,6 +861,7 @@ mod transparent {
             Self {
                 object: permission.into(),
                 destination: to,
+                expires_at_ms: None,
             }
         }
     }
@@ -817,16 +872,32 @@ mod transparent {
             Self {
                 object: role_id,
                 destination: to,
+                expires_at_ms: None,
             }
         }
     }
 
+    impl<O, D: Identifiable> Grant<O, D> {
+        /// Make the grant expire at the given unix time, after which it is revoked automatically.
+        #[must_use]
+        pub fn expiring_at(mut self, expires_at: core::time::Duration) -> Self {
+            self.expires_at_ms = Some(
+                expires_at
+                    .as_millis()
+                    .try_into()
+                    .expect("INTERNAL BUG: Unix timestamp exceedes u64::MAX"),
+            );
+            self
+        }
+    }
+
     impl Grant<Permission, Role> {
         /// Constructs a new [`Grant`] for giving a [`Permission`] to [`Role`].
         pub fn role_permission(permission: impl Into<Permission>, to: RoleId) -> Self {
             Self {
                 object: permission.into(),
                 destination: to,
+                expires_at_ms: None,
             }
         }
     }
@@ -940,6 +1011,211 @@ mod transparent {
         }
     }
 
+    isi! {
+        /// Instruction to change the cap on the total quantity of a [`Mintable::Capped`] asset.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "SET MINT CAP OF `{asset_definition}` TO `{cap}`")]
+        pub struct SetMintCap {
+            /// Asset definition to change the cap of
+            pub asset_definition: AssetDefinitionId,
+            /// New cap on the total quantity
+            pub cap: Numeric,
+        }
+    }
+
+    isi! {
+        /// Instruction to allow the spender to transfer up to `amount` of the owner's asset.
+        ///
+        /// Replaces the previous allowance, zero `amount` revokes it.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "APPROVE `{amount}` OF `{allowance}`")]
+        pub struct Approve {
+            /// Allowance to set
+            pub allowance: AllowanceId,
+            /// Quantity the spender can transfer
+            pub amount: Numeric,
+        }
+    }
+
+    isi! {
+        /// Instruction to transfer the owner's asset on behalf of the spender, consuming the allowance.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "TRANSFER `{amount}` OF `{allowance}` TO `{destination}`")]
+        pub struct TransferFrom {
+            /// Allowance to consume
+            pub allowance: AllowanceId,
+            /// Quantity to transfer
+            pub amount: Numeric,
+            /// Account receiving the asset
+            pub destination: AccountId,
+        }
+    }
+
+    isi! {
+        /// Instruction to lock `amount` of a numeric asset so that it can't be transferred or burned.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "HOLD `{amount}` OF `{asset}`")]
+        pub struct HoldAsset {
+            /// Asset to lock the balance of
+            pub asset: AssetId,
+            /// Quantity to lock
+            pub amount: Numeric,
+        }
+    }
+
+    isi! {
+        /// Instruction to unlock `amount` of a numeric asset previously locked by [`HoldAsset`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "RELEASE `{amount}` OF `{asset}`")]
+        pub struct ReleaseAsset {
+            /// Asset to unlock the balance of
+            pub asset: AssetId,
+            /// Quantity to unlock
+            pub amount: Numeric,
+        }
+    }
+
+    isi! {
+        /// Instruction to open a hash-time-locked transfer from the sender's asset.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "LOCK `{lock}`")]
+        pub struct LockAsset {
+            /// Terms of the lock
+            pub lock: HashLock,
+        }
+    }
+
+    isi! {
+        /// Instruction to complete a [`HashLock`] transfer by revealing the preimage of its hash.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "CLAIM `{lock}`")]
+        pub struct ClaimLockedAsset {
+            /// Lock to claim
+            pub lock: HashLockId,
+            /// Secret which hashes to the hash of the lock
+            pub preimage: Vec<u8>,
+        }
+    }
+
+    isi! {
+        /// Instruction to return the quantity of an expired [`HashLock`] to the sender.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REFUND `{lock}`")]
+        pub struct RefundLockedAsset {
+            /// Lock to refund
+            pub lock: HashLockId,
+        }
+    }
+
+    isi! {
+        /// Instruction to propose an exchange of numeric assets to another account.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "PROPOSE SWAP `{offer}`")]
+        pub struct ProposeSwap {
+            /// Terms of the swap
+            pub offer: SwapOffer,
+        }
+    }
+
+    isi! {
+        /// Instruction to carry out a [`SwapOffer`] made to the owner of its requested asset.
+        ///
+        /// The terms have to match the stored offer, so that an offer replaced by its proposer
+        /// isn't accepted unknowingly.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ACCEPT SWAP `{offer}`")]
+        pub struct AcceptSwap {
+            /// Terms of the swap being accepted
+            pub offer: SwapOffer,
+        }
+    }
+
+    isi! {
+        /// Instruction to withdraw a [`SwapOffer`], allowed to anyone once the offer has expired.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "CANCEL SWAP `{offer}`")]
+        pub struct CancelSwap {
+            /// Offer to withdraw
+            pub offer: SwapOfferId,
+        }
+    }
+
+    isi! {
+        /// Instruction to let one more key sign transactions on behalf of an account.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ADD SIGNATORY `{public_key}` TO `{account}`")]
+        pub struct AddSignatory {
+            /// Account to add the key to
+            pub account: AccountId,
+            /// Key to add
+            pub public_key: PublicKey,
+        }
+    }
+
+    isi! {
+        /// Instruction to stop a key from signing transactions on behalf of an account.
+        ///
+        /// The last signatory of an account can't be removed, use [`RotateKey`] to replace it.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REMOVE SIGNATORY `{public_key}` FROM `{account}`")]
+        pub struct RemoveSignatory {
+            /// Account to remove the key from
+            pub account: AccountId,
+            /// Key to remove
+            pub public_key: PublicKey,
+        }
+    }
+
+    isi! {
+        /// Instruction to replace all signatories of an account with a single key.
+        ///
+        /// The account keeps its id, assets, roles and permissions.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ROTATE KEY OF `{account}` TO `{public_key}`")]
+        pub struct RotateKey {
+            /// Account to rotate the key of
+            pub account: AccountId,
+            /// New and only signatory of the account
+            pub public_key: PublicKey,
+        }
+    }
+
+    isi! {
+        /// Instruction to give an account a human-readable [`AccountAlias`].
+        ///
+        /// The alias has to belong to the domain of the account.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REGISTER ALIAS `{alias}` FOR `{account}`")]
+        pub struct RegisterAccountAlias {
+            /// Alias to register
+            pub alias: AccountAlias,
+            /// Account the alias refers to
+            pub account: AccountId,
+        }
+    }
+
+    isi! {
+        /// Instruction to make an existing [`AccountAlias`] refer to another account of the same domain.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "TRANSFER ALIAS `{alias}` TO `{destination}`")]
+        pub struct TransferAccountAlias {
+            /// Alias to transfer
+            pub alias: AccountAlias,
+            /// Account the alias will refer to
+            pub destination: AccountId,
+        }
+    }
+
+    isi! {
+        /// Instruction to free an [`AccountAlias`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "UNREGISTER ALIAS `{alias}`")]
+        pub struct UnregisterAccountAlias {
+            /// Alias to unregister
+            pub alias: AccountAlias,
+        }
+    }
+
     isi! {
         /// Generic instruction for upgrading runtime objects.
         #[derive(Constructor, Display)]
@@ -1433,6 +1709,10 @@ pub mod error {
             MintUnmintable,
             /// This asset was set as infinitely mintable. You cannot forbid its minting
             ForbidMintOnMintable,
+            /// This asset has a capped supply. Minting it would exceed the cap or the cap is below the minted quantity
+            MintCapExceeded,
+            /// This asset has a fixed supply. You cannot set a mint cap on it
+            CapOnUnmintable,
         }
 
         /// Invalid instruction parameter error
@@ -1512,9 +1792,12 @@ pub mod error {
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        AssetTransferBox, Burn, BurnBox, CustomInstruction, ExecuteTrigger, Grant, GrantBox,
-        InstructionBox, Log, Mint, MintBox, Register, RegisterBox, RemoveKeyValue,
-        RemoveKeyValueBox, Revoke, RevokeBox, SetKeyValue, SetKeyValueBox, SetParameter, Transfer,
-        TransferBox, Unregister, UnregisterBox, Upgrade,
+        AcceptSwap, AddSignatory, Approve, AssetTransferBox, Burn, BurnBox, CancelSwap,
+        ClaimLockedAsset, CustomInstruction, ExecuteTrigger, Grant, GrantBox, HoldAsset,
+        InstructionBox, LockAsset, Log, Mint, MintBox, ProposeSwap, RefundLockedAsset, Register,
+        RegisterAccountAlias, RegisterBox, ReleaseAsset, RemoveKeyValue, RemoveKeyValueBox,
+        RemoveSignatory, Revoke, RevokeBox, RotateKey, SetKeyValue, SetKeyValueBox, SetMintCap,
+        SetParameter, Transfer, TransferAccountAlias, TransferBox, TransferFrom, Unregister,
+        UnregisterAccountAlias, UnregisterBox, Upgrade,
     };
 }
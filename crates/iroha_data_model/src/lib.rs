@@ -116,6 +116,23 @@ mod seal {
         Revoke<Permission, Role>,
 
         SetParameter,
+        SetMintCap,
+        Approve,
+        TransferFrom,
+        HoldAsset,
+        ReleaseAsset,
+        LockAsset,
+        ClaimLockedAsset,
+        RefundLockedAsset,
+        ProposeSwap,
+        AcceptSwap,
+        CancelSwap,
+        AddSignatory,
+        RemoveSignatory,
+        RotateKey,
+        RegisterAccountAlias,
+        TransferAccountAlias,
+        UnregisterAccountAlias,
         Upgrade,
         ExecuteTrigger,
         Log,
@@ -126,6 +143,10 @@ mod seal {
         FindAccountsWithAsset,
         FindAssets,
         FindAssetsDefinitions,
+        FindAllowances,
+        FindAssetHolds,
+        FindHashLocks,
+        FindSwapOffers,
         FindDomains,
         FindPeers,
         FindBlocks,
@@ -139,6 +160,9 @@ mod seal {
         FindRoleIds,
         FindRolesByAccountId,
         FindParameters,
+        FindAccountIdByAlias,
+        FindAliasesByAccountId,
+        FindTemporaryGrantsByAccountId,
     }
 }
 
@@ -6,10 +6,10 @@ use core::{num::NonZeroU64, time::Duration};
 use std::collections::btree_map;
 
 use iroha_data_model_derive::model;
-use iroha_primitives::json::Json;
+use iroha_primitives::{json::Json, numeric::Numeric};
 
 pub use self::model::*;
-use crate::{name::Name, Identifiable};
+use crate::{account::AccountId, asset::AssetDefinitionId, name::Name, Identifiable};
 
 /// Collection of [`CustomParameter`]s
 pub(crate) type CustomParameters = btree_map::BTreeMap<CustomParameterId, CustomParameter>;
@@ -129,7 +129,7 @@ mod model {
         Serialize,
         IntoSchema,
     )]
-    #[display(fmt = "{max_transactions}_BL")]
+    #[display(fmt = "{max_transactions},{max_prunable_height}_BL")]
     #[getset(get_copy = "pub")]
     pub struct BlockParameters {
         /// Maximal number of transactions in a block.
@@ -137,6 +137,12 @@ mod model {
         /// A block is created if this limit is reached or [`SumeragiParameters::block_time_ms`] has expired,
         /// whichever comes first. Regardless of the limits, an empty block is never created.
         pub max_transactions: NonZeroU64,
+        /// Maximal height of the blocks that peers are allowed to prune, `0` forbids pruning.
+        ///
+        /// Blocks up to this height, except the genesis block, are excluded from block and transaction queries
+        /// on every peer so that smart contracts observe the same blocks regardless of local pruning.
+        /// This height can't be decreased, since the blocks might be pruned already.
+        pub max_prunable_height: u64,
     }
 
     /// Single block parameter
@@ -147,6 +153,7 @@ mod model {
     )]
     pub enum BlockParameter {
         MaxTransactions(NonZeroU64),
+        MaxPrunableHeight(u64),
     }
 
     /// Limits that a transaction must obey to be accepted.
@@ -219,6 +226,48 @@ mod model {
         Memory(NonZeroU64),
     }
 
+    /// Fees charged to the authority of every non-genesis transaction
+    ///
+    /// The fee is `base + per_instruction * instructions` for [`crate::transaction::Executable::Instructions`]
+    /// and `base + per_fuel_unit * fuel` for [`crate::transaction::Executable::Wasm`], where `fuel`
+    /// is the amount of fuel consumed by the smart contract.
+    /// The [`tip`](crate::transaction::SignedTransaction::tip) of the transaction is charged together with `base`.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        CopyGetters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{base},{per_instruction},{per_fuel_unit}_{asset}_FEE")]
+    pub struct FeeSchedule {
+        /// Numeric asset in which fees are paid
+        #[getset(get = "pub")]
+        pub asset: AssetDefinitionId,
+        /// Account receiving the fees
+        #[getset(get = "pub")]
+        pub collector: AccountId,
+        /// Fee charged for every transaction
+        #[getset(get_copy = "pub")]
+        pub base: Numeric,
+        /// Fee charged for every instruction of the transaction
+        #[getset(get_copy = "pub")]
+        pub per_instruction: Numeric,
+        /// Fee charged for every unit of fuel consumed by the smart contract
+        #[getset(get_copy = "pub")]
+        pub per_fuel_unit: Numeric,
+    }
+
     /// Blockchain specific parameter defined in the executor
     #[derive(
         Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
@@ -273,6 +322,11 @@ mod model {
         #[getset(get_copy = "pub")]
         #[serde(default)]
         pub smart_contract: SmartContractParameters,
+        /// Transaction fee schedule. Transactions are free if it isn't set
+        #[getset(get = "pub")]
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub fee: Option<FeeSchedule>,
         /// Collection of blockchain specific parameters
         #[getset(get = "pub")]
         #[serde(default)]
@@ -304,6 +358,7 @@ mod model {
         Transaction(TransactionParameter),
         SmartContract(SmartContractParameter),
         Executor(SmartContractParameter),
+        Fee(Option<FeeSchedule>),
         Custom(CustomParameter),
     }
 }
@@ -315,6 +370,8 @@ impl core::fmt::Display for Parameter {
             Self::Block(v) => core::fmt::Display::fmt(&v, f),
             Self::Transaction(v) => core::fmt::Display::fmt(&v, f),
             Self::SmartContract(v) | Self::Executor(v) => core::fmt::Display::fmt(&v, f),
+            Self::Fee(Some(v)) => core::fmt::Display::fmt(&v, f),
+            Self::Fee(None) => write!(f, "NO_FEE"),
             Self::Custom(v) => write!(f, "{}({})", v.id, v.payload),
         }
     }
@@ -381,6 +438,9 @@ mod defaults {
         pub const fn max_transactions() -> NonZeroU64 {
             nonzero!(2_u64.pow(9))
         }
+        pub const fn max_prunable_height() -> u64 {
+            0
+        }
     }
 
     pub mod transaction {
@@ -422,7 +482,10 @@ impl Default for SumeragiParameters {
 }
 impl Default for BlockParameters {
     fn default() -> Self {
-        Self::new(defaults::block::max_transactions())
+        Self::new(
+            defaults::block::max_transactions(),
+            defaults::block::max_prunable_height(),
+        )
     }
 }
 
@@ -466,6 +529,7 @@ impl Parameters {
                     .parameters()
                     .map(Parameter::SmartContract),
             )
+            .chain(self.fee.clone().map(|fee| Parameter::Fee(Some(fee))))
             .chain(self.custom.values().cloned().map(Parameter::Custom))
     }
 
@@ -479,6 +543,9 @@ impl Parameters {
                         self.$param.$field = next;
                     }
                     )*
+                    Parameter::Fee(next) => {
+                        self.fee = next;
+                    }
                     Parameter::Custom(next) => {
                         self.custom.insert(next.id.clone(), next);
                     }
@@ -492,6 +559,7 @@ impl Parameters {
             Sumeragi(sumeragi.commit_time_ms) => SumeragiParameter::CommitTimeMs,
 
             Block(block.max_transactions) => BlockParameter::MaxTransactions,
+            Block(block.max_prunable_height) => BlockParameter::MaxPrunableHeight,
 
             Transaction(transaction.max_instructions) => TransactionParameter::MaxInstructions,
             Transaction(transaction.smart_contract_size) => TransactionParameter::SmartContractSize,
@@ -537,13 +605,20 @@ impl SumeragiParameters {
 
 impl BlockParameters {
     /// Construct [`Self`]
-    pub const fn new(max_transactions: NonZeroU64) -> Self {
-        Self { max_transactions }
+    pub const fn new(max_transactions: NonZeroU64, max_prunable_height: u64) -> Self {
+        Self {
+            max_transactions,
+            max_prunable_height,
+        }
     }
 
     /// Convert [`Self`] into iterator of individual parameters
     pub fn parameters(&self) -> impl Iterator<Item = BlockParameter> {
-        [BlockParameter::MaxTransactions(self.max_transactions)].into_iter()
+        [
+            BlockParameter::MaxTransactions(self.max_transactions),
+            BlockParameter::MaxPrunableHeight(self.max_prunable_height),
+        ]
+        .into_iter()
     }
 }
 
@@ -625,12 +700,15 @@ mod candidate {
     #[derive(Decode, Deserialize)]
     enum BlockParameterCandidate {
         MaxTransactions(NonZeroU64),
+        MaxPrunableHeight(u64),
     }
 
     #[derive(Decode, Deserialize)]
     struct BlockParametersCandidate {
         #[serde(default = "super::defaults::block::max_transactions")]
         max_transactions: NonZeroU64,
+        #[serde(default = "super::defaults::block::max_prunable_height")]
+        max_prunable_height: u64,
     }
 
     #[derive(Decode, Deserialize)]
@@ -656,6 +734,9 @@ mod candidate {
 
                     BlockParameter::MaxTransactions(max_transactions)
                 }
+                Self::MaxPrunableHeight(max_prunable_height) => {
+                    BlockParameter::MaxPrunableHeight(max_prunable_height)
+                }
             })
         }
     }
@@ -667,6 +748,7 @@ mod candidate {
 
             Ok(BlockParameters {
                 max_transactions: self.max_transactions,
+                max_prunable_height: self.max_prunable_height,
             })
         }
     }
@@ -856,5 +938,7 @@ mod candidate {
 pub mod prelude {
     //! Prelude: re-export of most commonly used traits, structs and macros in this crate.
 
-    pub use super::{Parameter, Parameters, SmartContractParameters, TransactionParameters};
+    pub use super::{
+        FeeSchedule, Parameter, Parameters, SmartContractParameters, TransactionParameters,
+    };
 }
@@ -0,0 +1,138 @@
+//! Aggregate queries, reducing the results of an iterable query to a single value on the server side.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use derive_more::Constructor;
+use iroha_data_model_derive::model;
+use iroha_primitives::numeric::Numeric;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+pub use self::model::*;
+use crate::query::{QueryOutputBatchBox, QueryWithParams};
+
+#[model]
+mod model {
+    use super::*;
+
+    /// An aggregate function computed over the results of an iterable query.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub enum Aggregation {
+        /// Number of the results
+        Count,
+        /// Sum of the selected numeric values
+        Sum,
+        /// Smallest of the selected numeric values
+        Min,
+        /// Largest of the selected numeric values
+        Max,
+    }
+
+    /// An iterable query whose results are reduced with an [`Aggregation`] instead of being returned to the client.
+    ///
+    /// The aggregated values are taken from the selector of the query:
+    /// - without grouping, [`Aggregation::Sum`], [`Aggregation::Min`] and [`Aggregation::Max`] use the first selected field, which must be [`Numeric`];
+    /// - with grouping, the first selected field is the grouping key and the second one is the aggregated value.
+    ///
+    /// Filtering, sorting and pagination are applied before the aggregation.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub struct AggregateQuery {
+        pub query: QueryWithParams,
+        pub aggregation: Aggregation,
+        /// Whether to aggregate the results separately for each distinct value of the first selected field
+        #[serde(default)]
+        pub group_by: bool,
+    }
+
+    /// A single value computed by an [`Aggregation`].
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub enum AggregateValue {
+        /// Result of [`Aggregation::Count`]
+        Count(u64),
+        /// Result of a numeric aggregation, `None` if there were no values to compute the minimum or maximum of
+        Numeric(Option<Numeric>),
+    }
+
+    /// Results of a grouped aggregation, ordered by the grouping key.
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct AggregateGroups {
+        /// Distinct values of the grouping key
+        pub keys: QueryOutputBatchBox,
+        /// Aggregated value for each of the `keys`
+        pub values: Vec<AggregateValue>,
+    }
+
+    /// Output of an [`AggregateQuery`].
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub enum AggregateOutput {
+        /// Aggregate over all the results
+        Value(AggregateValue),
+        /// Aggregates over the groups of the results
+        Groups(AggregateGroups),
+    }
+}
+
+impl AggregateValue {
+    /// Get the number of results if this value was produced by [`Aggregation::Count`]
+    pub fn as_count(&self) -> Option<u64> {
+        match self {
+            Self::Count(count) => Some(*count),
+            Self::Numeric(_) => None,
+        }
+    }
+
+    /// Get the numeric value if this value was produced by a numeric aggregation
+    pub fn as_numeric(&self) -> Option<Numeric> {
+        match self {
+            Self::Count(_) => None,
+            Self::Numeric(numeric) => *numeric,
+        }
+    }
+}
+
+impl AggregateGroups {
+    /// Pair the grouping keys with the aggregated values.
+    ///
+    /// Returns `None` if the keys are not of type `K`.
+    pub fn into_typed<K>(self) -> Option<Vec<(K, AggregateValue)>>
+    where
+        Vec<K>: TryFrom<QueryOutputBatchBox>,
+    {
+        let keys = Vec::<K>::try_from(self.keys).ok()?;
+        Some(keys.into_iter().zip(self.values).collect())
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::{AggregateOutput, AggregateValue, Aggregation};
+}
@@ -5,7 +5,7 @@ mod iter;
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
-use core::marker::PhantomData;
+use core::{marker::PhantomData, num::NonZeroU64};
 
 use derive_where::derive_where;
 pub use iter::QueryIterator;
@@ -13,12 +13,13 @@ use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 use crate::query::{
+    aggregate::{AggregateOutput, AggregateQuery, Aggregation},
     builder::batch_downcast::HasTypedBatchIter,
     dsl::{
-        BaseProjector, CompoundPredicate, HasPrototype, IntoSelectorTuple, PredicateMarker,
-        SelectorMarker, SelectorTuple,
+        BaseProjector, CompoundPredicate, HasProjection, HasPrototype, IntoSelector,
+        IntoSelectorTuple, PredicateMarker, SelectorMarker, SelectorTuple,
     },
-    parameters::{FetchSize, Pagination, QueryParams, Sorting},
+    parameters::{FetchSize, Pagination, QueryParams, SortKey, SortOrder, SortSelector, Sorting},
     Query, QueryBox, QueryOutputBatchBoxTuple, QueryWithFilter, QueryWithParams, SingularQueryBox,
     SingularQueryOutputBox,
 };
@@ -62,6 +63,16 @@ pub trait QueryExecutor {
     fn continue_query(
         cursor: Self::Cursor,
     ) -> Result<(QueryOutputBatchBoxTuple, u64, Option<Self::Cursor>), Self::Error>;
+
+    /// Executes an aggregate query and returns the aggregated value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    fn execute_aggregate_query(
+        &self,
+        query: AggregateQuery,
+    ) -> Result<AggregateOutput, Self::Error>;
 }
 
 /// An error that can occur when constraining the number of results of an iterable query to one.
@@ -110,6 +121,7 @@ where
     pagination: Pagination,
     sorting: Sorting,
     fetch_size: FetchSize,
+    at_height: Option<NonZeroU64>,
     // NOTE: T is a phantom type used to denote the selected tuple in `selector`
     phantom: PhantomData<T>,
 }
@@ -128,6 +140,7 @@ where
             pagination: Pagination::default(),
             sorting: Sorting::default(),
             fetch_size: FetchSize::default(),
+            at_height: None,
             phantom: PhantomData,
         }
     }
@@ -198,6 +211,7 @@ where
             pagination: self.pagination,
             sorting: self.sorting,
             fetch_size: self.fetch_size,
+            at_height: self.at_height,
             phantom: PhantomData,
         }
     }
@@ -208,6 +222,34 @@ where
         Self { sorting, ..self }
     }
 
+    /// Sort the results by the field specified by the given closure.
+    ///
+    /// If called multiple times, the results are sorted by the first field, with ties broken by the following ones.
+    #[must_use]
+    pub fn sort_by_with<B, O>(self, order: SortOrder, f: B) -> Self
+    where
+        Q::Item: HasPrototype,
+        B: FnOnce(
+            <Q::Item as HasPrototype>::Prototype<
+                SelectorMarker,
+                BaseProjector<SelectorMarker, Q::Item>,
+            >,
+        ) -> O,
+        <Q::Item as HasPrototype>::Prototype<
+            SelectorMarker,
+            BaseProjector<SelectorMarker, Q::Item>,
+        >: Default,
+        O: IntoSelector<SelectingType = Q::Item>,
+        SortSelector: From<<Q::Item as HasProjection<SelectorMarker>>::Projection>,
+    {
+        let selector = f(Default::default()).into_selector();
+
+        Self {
+            sorting: self.sorting.then_by(SortKey::new(selector.into(), order)),
+            ..self
+        }
+    }
+
     /// Only return part of the results specified by the pagination.
     #[must_use]
     pub fn with_pagination(self, pagination: Pagination) -> Self {
@@ -221,9 +263,20 @@ where
     pub fn with_fetch_size(self, fetch_size: FetchSize) -> Self {
         Self { fetch_size, ..self }
     }
+
+    /// Query the world state as it was after the block at the given height was committed.
+    ///
+    /// Peers only retain the state of a limited number of recent blocks, older heights are rejected.
+    #[must_use]
+    pub fn at_height(self, height: NonZeroU64) -> Self {
+        Self {
+            at_height: Some(height),
+            ..self
+        }
+    }
 }
 
-impl<E, Q, T> QueryBuilder<'_, E, Q, T>
+impl<'e, E, Q, T> QueryBuilder<'e, E, Q, T>
 where
     Q: Query,
     E: QueryExecutor,
@@ -236,6 +289,45 @@ where
     ///
     /// Returns an error if the query execution fails.
     pub fn execute(self) -> Result<QueryIterator<E, T>, E::Error> {
+        let (query_executor, query) = self.into_query_with_params();
+
+        let (first_batch, remaining_items, continue_cursor) = query_executor.start_query(query)?;
+
+        let iterator = QueryIterator::<E, T>::new(first_batch, remaining_items, continue_cursor)
+            .expect(
+                "INTERNAL BUG: iroha returned unexpected type in iterable query. Is there a schema mismatch?",
+            );
+
+        Ok(iterator)
+    }
+
+    /// Aggregate the results of the query on the server side instead of returning them.
+    ///
+    /// Numeric aggregations are computed over the first selected field, which must be numeric.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn aggregate(self, aggregation: Aggregation) -> Result<AggregateOutput, E::Error> {
+        let (query_executor, query) = self.into_query_with_params();
+
+        query_executor.execute_aggregate_query(AggregateQuery::new(query, aggregation, false))
+    }
+
+    /// Aggregate the results of the query on the server side, separately for each distinct value of the first selected field.
+    ///
+    /// Numeric aggregations are computed over the second selected field, which must be numeric.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn aggregate_by_group(self, aggregation: Aggregation) -> Result<AggregateOutput, E::Error> {
+        let (query_executor, query) = self.into_query_with_params();
+
+        query_executor.execute_aggregate_query(AggregateQuery::new(query, aggregation, true))
+    }
+
+    fn into_query_with_params(self) -> (&'e E, QueryWithParams) {
         let with_filter = QueryWithFilter::new(self.query, self.filter, self.selector);
         let boxed: QueryBox = with_filter.into();
 
@@ -246,17 +338,10 @@ where
                 sorting: self.sorting,
                 fetch_size: self.fetch_size,
             },
+            at_height: self.at_height,
         };
 
-        let (first_batch, remaining_items, continue_cursor) =
-            self.query_executor.start_query(query)?;
-
-        let iterator = QueryIterator::<E, T>::new(first_batch, remaining_items, continue_cursor)
-            .expect(
-                "INTERNAL BUG: iroha returned unexpected type in iterable query. Is there a schema mismatch?",
-            );
-
-        Ok(iterator)
+        (self.query_executor, query)
     }
 }
 
@@ -1,14 +1,22 @@
 //! This module contains predicate definitions for all queryable types. See the [module-level documentation](crate::query::dsl) for more information.
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::FromStr;
 
 use iroha_crypto::{HashOf, PublicKey};
 use iroha_primitives::{json::Json, numeric::Numeric};
 
 use crate::{
     account::{Account, AccountId},
-    asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId, AssetValue},
+    asset::{
+        Allowance, AllowanceId, Asset, AssetDefinition, AssetDefinitionId, AssetHold, AssetId,
+        AssetValue, HashLock, HashLockId, SwapOffer, SwapOfferId,
+    },
     block::{BlockHeader, SignedBlock},
     domain::{Domain, DomainId},
     metadata::Metadata,
@@ -19,15 +27,17 @@ use crate::{
     query::{
         dsl::{
             type_descriptions::{
-                AccountIdPrototype, AccountPrototype, ActionPrototype, AssetDefinitionIdPrototype,
-                AssetDefinitionPrototype, AssetIdPrototype, AssetPrototype, AssetValuePrototype,
+                AccountIdPrototype, AccountPrototype, ActionPrototype, AllowanceIdPrototype,
+                AllowancePrototype, AssetDefinitionIdPrototype, AssetDefinitionPrototype,
+                AssetHoldPrototype, AssetIdPrototype, AssetPrototype, AssetValuePrototype,
                 BlockHeaderHashPrototype, BlockHeaderPrototype, CommittedTransactionPrototype,
-                DomainIdPrototype, DomainPrototype, JsonPrototype, MetadataPrototype,
-                NamePrototype, NumericPrototype, ParameterPrototype, PeerIdPrototype,
-                PermissionPrototype, PublicKeyPrototype, RoleIdPrototype, RolePrototype,
-                SignedBlockPrototype, SignedTransactionPrototype, StringPrototype,
+                DomainIdPrototype, DomainPrototype, HashLockIdPrototype, HashLockPrototype,
+                JsonPrototype, MetadataPrototype, NamePrototype, NumericPrototype,
+                ParameterPrototype, PeerIdPrototype, PermissionPrototype, PublicKeyPrototype,
+                RoleIdPrototype, RolePrototype, SignedBlockPrototype, SignedTransactionPrototype,
+                StringPrototype, SwapOfferIdPrototype, SwapOfferPrototype,
                 TransactionErrorPrototype, TransactionHashPrototype, TriggerIdPrototype,
-                TriggerPrototype,
+                TriggerPrototype, U64Prototype,
             },
             CompoundPredicate, ObjectProjector, PredicateMarker,
         },
@@ -253,20 +263,297 @@ where
     }
 }
 
+/// A half-open range of [`Numeric`] values, used by [`NumericPredicateAtom::InRange`].
+///
+/// Like [`core::ops::Range`], the range includes `start` and excludes `end`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    parity_scale_codec::Decode,
+    parity_scale_codec::Encode,
+    serde::Deserialize,
+    serde::Serialize,
+    iroha_schema::IntoSchema,
+)]
+pub struct NumericRange {
+    /// The lower bound of the range (inclusive).
+    pub start: Numeric,
+    /// The upper bound of the range (exclusive).
+    pub end: Numeric,
+}
+
+impl NumericRange {
+    /// Construct a new range from `start` (inclusive) to `end` (exclusive).
+    pub const fn new(start: Numeric, end: Numeric) -> Self {
+        Self { start, end }
+    }
+
+    /// Checks if the value lies within the range.
+    pub fn contains(&self, value: &Numeric) -> bool {
+        self.start <= *value && *value < self.end
+    }
+}
+
+impl From<core::ops::Range<Numeric>> for NumericRange {
+    fn from(range: core::ops::Range<Numeric>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+/// An atomic predicate on [`Json`]
+// Defined separately because some of the predicates are evaluated on nested JSON values, not on the `Json` wrapper itself
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    parity_scale_codec::Decode,
+    parity_scale_codec::Encode,
+    serde::Deserialize,
+    serde::Serialize,
+    iroha_schema::IntoSchema,
+)]
+pub enum JsonPredicateAtom {
+    /// Checks if the input is equal to the expected value.
+    Equals(Json),
+    /// Checks if the input is a number (or a string containing a number) satisfying the numeric predicate.
+    ///
+    /// Values that cannot be represented as a [`Numeric`] (negative numbers, numbers with more than 28
+    /// decimal places or exceeding [`Numeric::MAX`]) never satisfy this predicate.
+    Numeric(NumericPredicateAtom),
+    /// Checks if the input is a string satisfying the string predicate.
+    String(StringPredicateAtom),
+    /// Checks if the value found by a JSON pointer inside the input satisfies the nested predicate.
+    Pointer(JsonPointerPredicate),
+}
+
+/// An atomic predicate on a value found by a [`JsonPointerPredicate`].
+///
+/// Same as [`JsonPredicateAtom`] except that it can't contain another pointer,
+/// which bounds the nesting of predicates. Pointers compose, so a longer pointer is used instead.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    parity_scale_codec::Decode,
+    parity_scale_codec::Encode,
+    serde::Deserialize,
+    serde::Serialize,
+    iroha_schema::IntoSchema,
+)]
+pub enum JsonValuePredicateAtom {
+    /// Same as [`JsonPredicateAtom::Equals`].
+    Equals(Json),
+    /// Same as [`JsonPredicateAtom::Numeric`].
+    Numeric(NumericPredicateAtom),
+    /// Same as [`JsonPredicateAtom::String`].
+    String(StringPredicateAtom),
+}
+
+/// A predicate on a value nested inside a [`Json`] document, used by [`JsonPredicateAtom::Pointer`].
+///
+/// The value is addressed with a JSON pointer as defined in [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901), e.g. `/kyc/level` or `/limits/0`.
+/// If the pointer does not resolve to a value, the predicate evaluates to `false`.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    parity_scale_codec::Decode,
+    parity_scale_codec::Encode,
+    serde::Deserialize,
+    serde::Serialize,
+    iroha_schema::IntoSchema,
+)]
+pub struct JsonPointerPredicate {
+    /// JSON pointer to the value inside the document.
+    pub pointer: String,
+    /// Predicate to apply to the value found by the pointer.
+    pub predicate: JsonValuePredicateAtom,
+}
+
+impl JsonPointerPredicate {
+    /// Construct a new predicate applying `predicate` to the value at `pointer`.
+    pub fn new(pointer: impl Into<String>, predicate: JsonValuePredicateAtom) -> Self {
+        Self {
+            pointer: pointer.into(),
+            predicate,
+        }
+    }
+}
+
+impl super::HasPredicateAtom for Json {
+    type Predicate = JsonPredicateAtom;
+}
+
+impl JsonPredicateAtom {
+    fn applies_to_value(&self, input: &serde_json::Value) -> bool {
+        match self {
+            JsonPredicateAtom::Equals(expected) => json_equals(expected, input),
+            JsonPredicateAtom::Numeric(predicate) => json_numeric_applies(predicate, input),
+            JsonPredicateAtom::String(predicate) => json_string_applies(predicate, input),
+            JsonPredicateAtom::Pointer(JsonPointerPredicate { pointer, predicate }) => input
+                .pointer(pointer)
+                .is_some_and(|value| predicate.applies_to_value(value)),
+        }
+    }
+}
+
+impl JsonValuePredicateAtom {
+    fn applies_to_value(&self, input: &serde_json::Value) -> bool {
+        match self {
+            JsonValuePredicateAtom::Equals(expected) => json_equals(expected, input),
+            JsonValuePredicateAtom::Numeric(predicate) => json_numeric_applies(predicate, input),
+            JsonValuePredicateAtom::String(predicate) => json_string_applies(predicate, input),
+        }
+    }
+}
+
+fn json_equals(expected: &Json, input: &serde_json::Value) -> bool {
+    expected
+        .try_into_any::<serde_json::Value>()
+        .is_ok_and(|expected| *input == expected)
+}
+
+fn json_numeric_applies(predicate: &NumericPredicateAtom, input: &serde_json::Value) -> bool {
+    let numeric = match input {
+        serde_json::Value::Number(number) => json_number_to_numeric(number),
+        serde_json::Value::String(string) => Numeric::from_str(string).ok(),
+        _ => return false,
+    };
+    numeric.is_some_and(|numeric| super::EvaluatePredicate::applies(predicate, &numeric))
+}
+
+fn json_string_applies(predicate: &StringPredicateAtom, input: &serde_json::Value) -> bool {
+    match input {
+        serde_json::Value::String(string) => predicate.applies_to_str(string),
+        _ => false,
+    }
+}
+
+/// Convert a JSON number into a [`Numeric`], expanding the exponent form (e.g. `1e21`) large and small floats are printed in.
+///
+/// Returns `None` if the number can't be represented as a [`Numeric`].
+fn json_number_to_numeric(number: &serde_json::Number) -> Option<Numeric> {
+    let number = number.to_string();
+    let (mantissa, exponent) = match number.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().ok()?),
+        None => (number.as_str(), 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    // Negative numbers are rejected here
+    let digits = format!("{integer}{fraction}").parse::<u128>().ok()?;
+    let scale = i64::try_from(fraction.len()).ok()? - exponent;
+
+    if scale < 0 {
+        let factor = 10_u128.checked_pow(u32::try_from(-scale).ok()?)?;
+        Numeric::try_new(digits.checked_mul(factor)?, 0).ok()
+    } else {
+        Numeric::try_new(digits, u32::try_from(scale).ok()?).ok()
+    }
+}
+
+impl super::EvaluatePredicate<Json> for JsonPredicateAtom {
+    fn applies(&self, input: &Json) -> bool {
+        match self {
+            // avoid parsing the input when it can be compared as-is
+            JsonPredicateAtom::Equals(expected) => input == expected,
+            _ => input
+                .try_into_any::<serde_json::Value>()
+                .is_ok_and(|value| self.applies_to_value(&value)),
+        }
+    }
+}
+
+impl<Projector> JsonPrototype<PredicateMarker, Projector>
+where
+    Projector: ObjectProjector<PredicateMarker, InputType = Json>,
+{
+    /// Checks if the input is equal to the expected value.
+    pub fn eq(self, expected: Json) -> CompoundPredicate<Projector::OutputType> {
+        CompoundPredicate::Atom(
+            self.projector
+                .wrap_atom(JsonPredicateAtom::Equals(expected)),
+        )
+    }
+
+    /// Checks if the input is a number (or a string containing a number) satisfying the numeric predicate.
+    pub fn numeric(
+        self,
+        predicate: NumericPredicateAtom,
+    ) -> CompoundPredicate<Projector::OutputType> {
+        CompoundPredicate::Atom(
+            self.projector
+                .wrap_atom(JsonPredicateAtom::Numeric(predicate)),
+        )
+    }
+
+    /// Checks if the input is a string satisfying the string predicate.
+    pub fn string(
+        self,
+        predicate: StringPredicateAtom,
+    ) -> CompoundPredicate<Projector::OutputType> {
+        CompoundPredicate::Atom(
+            self.projector
+                .wrap_atom(JsonPredicateAtom::String(predicate)),
+        )
+    }
+
+    /// Checks if the value found by a JSON pointer (e.g. `/kyc/level`) inside the input satisfies the nested predicate.
+    pub fn pointer(
+        self,
+        pointer: impl Into<String>,
+        predicate: JsonValuePredicateAtom,
+    ) -> CompoundPredicate<Projector::OutputType> {
+        CompoundPredicate::Atom(self.projector.wrap_atom(JsonPredicateAtom::Pointer(
+            JsonPointerPredicate::new(pointer, predicate),
+        )))
+    }
+}
+
 impl_predicate_atom! {
-    MetadataPredicateAtom(_input: Metadata) [MetadataPrototype] {
-        // TODO: populate
+    MetadataPredicateAtom(input: Metadata) [MetadataPrototype] {
+        /// Checks if the metadata contains the expected key.
+        ContainsKey(key: Name) [contains_key] => input.contains(key),
     }
     PublicKeyPredicateAtom(input: PublicKey) [PublicKeyPrototype] {
         /// Checks if the input is equal to the expected value.
         Equals(expected: PublicKey) [eq] => input == expected,
     }
-    JsonPredicateAtom(input: Json) [JsonPrototype] {
-        /// Checks if the input is equal to the expected value.
-        Equals(expected: Json) [eq] => input == expected,
+    NumericPredicateAtom(input: Numeric) [NumericPrototype] {
+        /// Checks if the input is numerically equal to the expected value, regardless of scale (i.e. `1.0` is equal to `1.00`).
+        Equals(expected: Numeric) [eq] => input == expected,
+        /// Checks if the input is less than the expected value.
+        Lt(expected: Numeric) [lt] => input < expected,
+        /// Checks if the input is less than or equal to the expected value.
+        Le(expected: Numeric) [le] => input <= expected,
+        /// Checks if the input is greater than the expected value.
+        Gt(expected: Numeric) [gt] => input > expected,
+        /// Checks if the input is greater than or equal to the expected value.
+        Ge(expected: Numeric) [ge] => input >= expected,
+        /// Checks if the input lies within the expected range.
+        InRange(range: NumericRange) [in_range] => range.contains(input),
+        /// Checks if the input is written with exactly the expected number of fractional digits (e.g. `1.50` has a scale of 2).
+        ScaleEquals(scale: u32) [scale_eq] => input.scale() == *scale,
+        /// Checks if the input is written with at most the expected number of fractional digits.
+        ScaleLe(scale: u32) [scale_le] => input.scale() <= *scale,
     }
-    NumericPredicateAtom(_input: Numeric) [NumericPrototype] {
-        // TODO: populate
+
+    U64PredicateAtom(input: u64) [U64Prototype] {
+        /// Checks if the input is equal to the expected value.
+        Equals(expected: u64) [eq] => input == expected,
+        /// Checks if the input is less than the expected value.
+        Lt(expected: u64) [lt] => input < expected,
+        /// Checks if the input is less than or equal to the expected value.
+        Le(expected: u64) [le] => input <= expected,
+        /// Checks if the input is greater than the expected value.
+        Gt(expected: u64) [gt] => input > expected,
+        /// Checks if the input is greater than or equal to the expected value.
+        Ge(expected: u64) [ge] => input >= expected,
     }
 
     // account
@@ -293,6 +580,22 @@ impl_predicate_atom! {
         /// Checks if the input is equal to the expected value.
         Equals(expected: AssetDefinitionId) [eq] => input == expected,
     }
+    AllowancePredicateAtom(_input: Allowance) [AllowancePrototype] {}
+    AllowanceIdPredicateAtom(input: AllowanceId) [AllowanceIdPrototype] {
+        /// Checks if the input is equal to the expected value.
+        Equals(expected: AllowanceId) [eq] => input == expected,
+    }
+    AssetHoldPredicateAtom(_input: AssetHold) [AssetHoldPrototype] {}
+    HashLockPredicateAtom(_input: HashLock) [HashLockPrototype] {}
+    HashLockIdPredicateAtom(input: HashLockId) [HashLockIdPrototype] {
+        /// Checks if the input is equal to the expected value.
+        Equals(expected: HashLockId) [eq] => input == expected,
+    }
+    SwapOfferPredicateAtom(_input: SwapOffer) [SwapOfferPrototype] {}
+    SwapOfferIdPredicateAtom(input: SwapOfferId) [SwapOfferIdPrototype] {
+        /// Checks if the input is equal to the expected value.
+        Equals(expected: SwapOfferId) [eq] => input == expected,
+    }
 
     // block
     BlockHeaderHashPredicateAtom(input: HashOf<BlockHeader>) [BlockHeaderHashPrototype] {
@@ -348,13 +651,16 @@ pub mod prelude {
     //! Re-export all predicate boxes for a glob import `(::*)`
     pub use super::{
         AccountIdPredicateAtom, AccountPredicateAtom, ActionPredicateAtom,
-        AssetDefinitionIdPredicateAtom, AssetDefinitionPredicateAtom, AssetIdPredicateAtom,
+        AllowanceIdPredicateAtom, AllowancePredicateAtom, AssetDefinitionIdPredicateAtom,
+        AssetDefinitionPredicateAtom, AssetHoldPredicateAtom, AssetIdPredicateAtom,
         AssetPredicateAtom, AssetValuePredicateAtom, BlockHeaderHashPredicateAtom,
         BlockHeaderPredicateAtom, CommittedTransactionPredicateAtom, DomainIdPredicateAtom,
-        DomainPredicateAtom, JsonPredicateAtom, MetadataPredicateAtom, NumericPredicateAtom,
-        ParameterPredicateAtom, PeerIdPredicateAtom, PermissionPredicateAtom,
+        DomainPredicateAtom, HashLockIdPredicateAtom, HashLockPredicateAtom, JsonPointerPredicate,
+        JsonPredicateAtom, JsonValuePredicateAtom, MetadataPredicateAtom, NumericPredicateAtom,
+        NumericRange, ParameterPredicateAtom, PeerIdPredicateAtom, PermissionPredicateAtom,
         PublicKeyPredicateAtom, RoleIdPredicateAtom, RolePredicateAtom, SignedBlockPredicateAtom,
-        SignedTransactionPredicateAtom, StringPredicateAtom, TransactionErrorPredicateAtom,
-        TransactionHashPredicateAtom, TriggerIdPredicateAtom, TriggerPredicateAtom,
+        SignedTransactionPredicateAtom, StringPredicateAtom, SwapOfferIdPredicateAtom,
+        SwapOfferPredicateAtom, TransactionErrorPredicateAtom, TransactionHashPredicateAtom,
+        TriggerIdPredicateAtom, TriggerPredicateAtom, U64PredicateAtom,
     };
 }
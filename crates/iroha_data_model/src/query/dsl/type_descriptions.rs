@@ -18,7 +18,10 @@ use crate::query::dsl::{
 };
 use crate::{
     account::{Account, AccountId},
-    asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId, AssetValue},
+    asset::{
+        Allowance, AllowanceId, Asset, AssetDefinition, AssetDefinitionId, AssetHold, AssetId,
+        AssetValue, HashLock, HashLockId, SwapOffer, SwapOfferId,
+    },
     block::{BlockHeader, SignedBlock},
     domain::{Domain, DomainId},
     metadata::Metadata,
@@ -257,9 +260,10 @@ type_descriptions! {
     }
 
     // asset
-    AssetDefinition[AssetDefinitionProjection, AssetDefinitionPrototype]: AssetDefinitionId, DomainId, Name, Metadata, Json {
+    AssetDefinition[AssetDefinitionProjection, AssetDefinitionPrototype]: AssetDefinitionId, DomainId, Name, Metadata, Json, Numeric {
         id(Id, AssetDefinitionIdProjector): AssetDefinitionId,
         metadata(Metadata, AssetDefinitionMetadataProjector): Metadata,
+        total_quantity(TotalQuantity, AssetDefinitionTotalQuantityProjector): Numeric,
     }
     AssetDefinitionId[AssetDefinitionIdProjection, AssetDefinitionIdPrototype]: DomainId, Name {
         domain(Domain, AssetDefinitionIdDomainProjector): DomainId,
@@ -278,25 +282,59 @@ type_descriptions! {
         numeric(Numeric, AssetValueNumericProjector): Numeric,
         store(Store, AssetValueStoreProjector): Metadata,
     }
+    Allowance[AllowanceProjection, AllowancePrototype]: AllowanceId, AccountId, DomainId, Name, PublicKey, AssetDefinitionId, Numeric {
+        id(Id, AllowanceIdProjector): AllowanceId,
+        amount(Amount, AllowanceAmountProjector): Numeric,
+    }
+    AllowanceId[AllowanceIdProjection, AllowanceIdPrototype]: AccountId, DomainId, Name, PublicKey, AssetDefinitionId {
+        owner(Owner, AllowanceIdOwnerProjector): AccountId,
+        spender(Spender, AllowanceIdSpenderProjector): AccountId,
+        asset_definition(AssetDefinition, AllowanceIdAssetDefinitionProjector): AssetDefinitionId,
+    }
+    AssetHold[AssetHoldProjection, AssetHoldPrototype]: AssetId, AccountId, DomainId, Name, PublicKey, AssetDefinitionId, Numeric {
+        asset(Asset, AssetHoldAssetProjector): AssetId,
+        amount(Amount, AssetHoldAmountProjector): Numeric,
+    }
+    HashLock[HashLockProjection, HashLockPrototype]: HashLockId, AccountId, DomainId, Name, PublicKey, AssetDefinitionId, Numeric, u64 {
+        id(Id, HashLockIdProjector): HashLockId,
+        asset_definition(AssetDefinition, HashLockAssetDefinitionProjector): AssetDefinitionId,
+        amount(Amount, HashLockAmountProjector): Numeric,
+        recipient(Recipient, HashLockRecipientProjector): AccountId,
+        deadline_ms(DeadlineMs, HashLockDeadlineMsProjector): u64,
+    }
+    HashLockId[HashLockIdProjection, HashLockIdPrototype]: AccountId, DomainId, Name, PublicKey {
+        sender(Sender, HashLockIdSenderProjector): AccountId,
+        name(Name, HashLockIdNameProjector): Name,
+    }
+    SwapOffer[SwapOfferProjection, SwapOfferPrototype]: SwapOfferId, AccountId, DomainId, Name, PublicKey, u64 {
+        id(Id, SwapOfferIdProjector): SwapOfferId,
+        expires_at_ms(ExpiresAtMs, SwapOfferExpiresAtMsProjector): u64,
+    }
+    SwapOfferId[SwapOfferIdProjection, SwapOfferIdPrototype]: AccountId, DomainId, Name, PublicKey {
+        proposer(Proposer, SwapOfferIdProposerProjector): AccountId,
+        name(Name, SwapOfferIdNameProjector): Name,
+    }
 
     // block
     HashOf<BlockHeader>[BlockHeaderHashProjection, BlockHeaderHashPrototype] {}
     #[custom_evaluate] // hash needs to be computed on-the-fly
-    BlockHeader[BlockHeaderProjection, BlockHeaderPrototype]: HashOf<BlockHeader> {
+    BlockHeader[BlockHeaderProjection, BlockHeaderPrototype]: HashOf<BlockHeader>, u64 {
         hash(Hash, BlockHeaderHashProjector): HashOf<BlockHeader>,
+        creation_time_ms(CreationTimeMs, BlockHeaderCreationTimeMsProjector): u64,
     }
     #[custom_evaluate] // SignedBlock is opaque, so `header` is a method
-    SignedBlock[SignedBlockProjection, SignedBlockPrototype]: BlockHeader, HashOf<BlockHeader> {
+    SignedBlock[SignedBlockProjection, SignedBlockPrototype]: BlockHeader, HashOf<BlockHeader>, u64 {
         header(Header, SignedBlockHeaderProjector): BlockHeader,
     }
     HashOf<SignedTransaction>[TransactionHashProjection, TransactionHashPrototype] {}
     #[custom_evaluate] // hash needs to be computed on-the-fly
-    SignedTransaction[SignedTransactionProjection, SignedTransactionPrototype]: HashOf<SignedTransaction>, AccountId, DomainId, Name, PublicKey {
+    SignedTransaction[SignedTransactionProjection, SignedTransactionPrototype]: HashOf<SignedTransaction>, AccountId, DomainId, Name, PublicKey, u64 {
         hash(Hash, SignedTransactionHashProjector): HashOf<SignedTransaction>,
         authority(Authority, SignedTransactionAuthorityProjector): AccountId,
+        creation_time_ms(CreationTimeMs, SignedTransactionCreationTimeMsProjector): u64,
     }
     Option<TransactionRejectionReason>[TransactionErrorProjection, TransactionErrorPrototype] {}
-    CommittedTransaction[CommittedTransactionProjection, CommittedTransactionPrototype]: HashOf<BlockHeader>, SignedTransaction, HashOf<SignedTransaction>, AccountId, DomainId, Name, PublicKey, Option<TransactionRejectionReason> {
+    CommittedTransaction[CommittedTransactionProjection, CommittedTransactionPrototype]: HashOf<BlockHeader>, SignedTransaction, HashOf<SignedTransaction>, AccountId, DomainId, Name, PublicKey, u64, Option<TransactionRejectionReason> {
         block_hash(BlockHash, CommittedTransactionBlockHashProjector): HashOf<BlockHeader>,
         value(Value, CommittedTransactionValueProjector): SignedTransaction,
         error(Error, CommittedTransactionErrorProjector): Option<TransactionRejectionReason>,
@@ -352,6 +390,7 @@ type_descriptions! {
     PublicKey[PublicKeyProjection, PublicKeyPrototype] {}
     Json[JsonProjection, JsonPrototype] {}
     Numeric[NumericProjection, NumericPrototype] {}
+    u64[U64Projection, U64Prototype] {}
 }
 
 /// A set of helpers for [`EvaluateSelector`] implementations that are fallible
@@ -471,6 +510,9 @@ impl EvaluatePredicate<BlockHeader> for BlockHeaderProjection<PredicateMarker> {
         match self {
             BlockHeaderProjection::Atom(atom) => atom.applies(input),
             BlockHeaderProjection::Hash(hash) => hash.applies(&input.hash()),
+            BlockHeaderProjection::CreationTimeMs(creation_time_ms) => {
+                creation_time_ms.applies(&input.creation_time_ms)
+            }
         }
     }
 }
@@ -484,6 +526,9 @@ impl EvaluateSelector<BlockHeader> for BlockHeaderProjection<SelectorMarker> {
         match self {
             BlockHeaderProjection::Atom(()) => Ok(batch.copied().collect::<Vec<_>>().into()),
             BlockHeaderProjection::Hash(hash) => hash.project(batch.map(BlockHeader::hash)),
+            BlockHeaderProjection::CreationTimeMs(creation_time_ms) => {
+                creation_time_ms.project(batch.map(|item| item.creation_time_ms))
+            }
         }
     }
 
@@ -494,6 +539,9 @@ impl EvaluateSelector<BlockHeader> for BlockHeaderProjection<SelectorMarker> {
         match self {
             BlockHeaderProjection::Atom(()) => Ok(batch.collect::<Vec<_>>().into()),
             BlockHeaderProjection::Hash(hash) => hash.project(batch.map(|item| item.hash())),
+            BlockHeaderProjection::CreationTimeMs(creation_time_ms) => {
+                creation_time_ms.project(batch.map(|item| item.creation_time_ms))
+            }
         }
     }
 }
@@ -540,6 +588,9 @@ impl EvaluatePredicate<SignedTransaction> for SignedTransactionProjection<Predic
             SignedTransactionProjection::Authority(authority) => {
                 authority.applies(input.authority())
             }
+            SignedTransactionProjection::CreationTimeMs(creation_time_ms) => {
+                creation_time_ms.applies(&input.creation_time_ms())
+            }
         }
     }
 }
@@ -558,6 +609,9 @@ impl EvaluateSelector<SignedTransaction> for SignedTransactionProjection<Selecto
             SignedTransactionProjection::Authority(authority) => {
                 authority.project_clone(batch.map(SignedTransaction::authority))
             }
+            SignedTransactionProjection::CreationTimeMs(creation_time_ms) => {
+                creation_time_ms.project(batch.map(SignedTransaction::creation_time_ms))
+            }
         }
     }
 
@@ -571,6 +625,9 @@ impl EvaluateSelector<SignedTransaction> for SignedTransactionProjection<Selecto
             SignedTransactionProjection::Authority(authority) => {
                 authority.project(batch.map(|item| item.authority().clone()))
             }
+            SignedTransactionProjection::CreationTimeMs(creation_time_ms) => {
+                creation_time_ms.project(batch.map(|item| item.creation_time_ms()))
+            }
         }
     }
 }
@@ -751,7 +808,7 @@ impl EvaluateSelector<Metadata> for MetadataProjection<SelectorMarker> {
 /// A prototype for the [`Metadata`] type.
 #[derive_where(Default, Copy, Clone; Projector)]
 pub struct MetadataPrototype<Marker, Projector> {
-    projector: Projector,
+    pub(super) projector: Projector,
     phantom: core::marker::PhantomData<Marker>,
 }
 
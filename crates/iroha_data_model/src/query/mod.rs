@@ -9,6 +9,7 @@ use alloc::{
     string::String,
     vec::{self, Vec},
 };
+use core::num::NonZeroU64;
 #[cfg(feature = "std")]
 use std::vec;
 
@@ -30,7 +31,10 @@ use self::{
 };
 use crate::{
     account::{Account, AccountId},
-    asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId, AssetValue},
+    asset::{
+        Allowance, AllowanceId, Asset, AssetDefinition, AssetDefinitionId, AssetHold, AssetId,
+        AssetValue, HashLock, HashLockId, SwapOffer, SwapOfferId,
+    },
     block::{BlockHeader, SignedBlock},
     domain::{Domain, DomainId},
     metadata::Metadata,
@@ -40,10 +44,11 @@ use crate::{
     permission::Permission,
     role::{Role, RoleId},
     seal::Sealed,
-    transaction::{error::TransactionRejectionReason, SignedTransaction},
+    transaction::{error::TransactionRejectionReason, SignedTransaction, TransactionFee},
     trigger::{Trigger, TriggerId},
 };
 
+pub mod aggregate;
 pub mod builder;
 pub mod dsl;
 pub mod parameters;
@@ -121,6 +126,10 @@ mod model {
         FindTransactions(QueryWithFilter<FindTransactions>),
         FindBlocks(QueryWithFilter<FindBlocks>),
         FindBlockHeaders(QueryWithFilter<FindBlockHeaders>),
+        FindAllowances(QueryWithFilter<FindAllowances>),
+        FindAssetHolds(QueryWithFilter<FindAssetHolds>),
+        FindHashLocks(QueryWithFilter<FindHashLocks>),
+        FindSwapOffers(QueryWithFilter<FindSwapOffers>),
     }
 
     /// An enum of all possible iterable query batches.
@@ -145,6 +154,13 @@ mod model {
         AssetValue(Vec<AssetValue>),
         AssetDefinitionId(Vec<AssetDefinitionId>),
         AssetDefinition(Vec<AssetDefinition>),
+        AllowanceId(Vec<AllowanceId>),
+        Allowance(Vec<Allowance>),
+        AssetHold(Vec<AssetHold>),
+        HashLockId(Vec<HashLockId>),
+        HashLock(Vec<HashLock>),
+        SwapOfferId(Vec<SwapOfferId>),
+        SwapOffer(Vec<SwapOffer>),
         Role(Vec<Role>),
         Parameter(Vec<Parameter>),
         Permission(Vec<Permission>),
@@ -160,6 +176,7 @@ mod model {
         Block(Vec<SignedBlock>),
         BlockHeader(Vec<BlockHeader>),
         BlockHeaderHash(Vec<HashOf<BlockHeader>>),
+        U64(Vec<u64>),
     }
 
     #[derive(
@@ -176,6 +193,9 @@ mod model {
     pub enum SingularQueryBox {
         FindExecutorDataModel(FindExecutorDataModel),
         FindParameters(FindParameters),
+        FindAccountIdByAlias(FindAccountIdByAlias),
+        FindAliasesByAccountId(FindAliasesByAccountId),
+        FindTemporaryGrantsByAccountId(FindTemporaryGrantsByAccountId),
     }
 
     /// An enum of all possible singular query outputs
@@ -185,6 +205,9 @@ mod model {
     pub enum SingularQueryOutputBox {
         ExecutorDataModel(crate::executor::ExecutorDataModel),
         Parameters(Parameters),
+        AccountId(AccountId),
+        AccountAliases(Vec<crate::account::AccountAlias>),
+        TemporaryGrants(Vec<crate::role::TemporaryGrant>),
     }
 
     /// The results of a single iterable query request.
@@ -199,13 +222,27 @@ mod model {
     }
 
     /// A type-erased iterable query, along with all the parameters needed to execute it
-    #[derive(
-        Debug, Clone, PartialEq, Eq, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema,
-    )]
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
     pub struct QueryWithParams {
         pub query: QueryBox,
         #[serde(default)]
         pub params: QueryParams,
+        /// Height of the block after which the world state is queried. If `None`, the latest state is queried.
+        ///
+        /// Peers only retain the state of a limited number of recent blocks.
+        #[serde(default)]
+        pub at_height: Option<NonZeroU64>,
+    }
+
+    /// A type-erased singular query, along with all the parameters needed to execute it
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+    pub struct SingularQueryWithParams {
+        pub query: SingularQueryBox,
+        /// Height of the block after which the world state is queried. If `None`, the latest state is queried.
+        ///
+        /// Peers only retain the state of a limited number of recent blocks.
+        #[serde(default)]
+        pub at_height: Option<NonZeroU64>,
     }
 
     /// A query request that can be sent to an Iroha peer.
@@ -213,9 +250,10 @@ mod model {
     /// In case of HTTP API, the query request must also be signed (see [`QueryRequestWithAuthority`] and [`SignedQuery`]).
     #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
     pub enum QueryRequest {
-        Singular(SingularQueryBox),
+        Singular(SingularQueryWithParams),
         Start(QueryWithParams),
         Continue(ForwardCursor),
+        Aggregate(aggregate::AggregateQuery),
     }
 
     /// An enum containing either a singular or an iterable query
@@ -230,6 +268,7 @@ mod model {
     pub enum QueryResponse {
         Singular(SingularQueryOutputBox),
         Iterable(QueryOutput),
+        Aggregate(aggregate::AggregateOutput),
     }
 
     /// A [`QueryRequest`], combined with an authority that wants to execute the query
@@ -246,7 +285,7 @@ mod model {
     declare_versioned!(SignedQuery 1..2, Debug, Clone, FromVariant, IntoSchema);
 
     /// A signed and authorized query request
-    #[derive(Debug, Clone, Encode, Serialize, IntoSchema)]
+    #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
     #[version_with_scale(version = 1, versioned_alias = "SignedQuery")]
     pub struct SignedQueryV1 {
         pub signature: QuerySignature,
@@ -278,6 +317,8 @@ mod model {
         pub value: SignedTransaction,
         /// Reason of rejection, if any
         pub error: Option<TransactionRejectionReason>,
+        /// Fee paid by the authority, if any
+        pub fee: Option<TransactionFee>,
     }
 }
 
@@ -293,6 +334,7 @@ impl QueryOutputBatchBox {
             (Self::PublicKey(v1), Self::PublicKey(v2)) => v1.extend(v2),
             (Self::String(v1), Self::String(v2)) => v1.extend(v2),
             (Self::Metadata(v1), Self::Metadata(v2)) => v1.extend(v2),
+            (Self::Json(v1), Self::Json(v2)) => v1.extend(v2),
             (Self::Numeric(v1), Self::Numeric(v2)) => v1.extend(v2),
             (Self::Name(v1), Self::Name(v2)) => v1.extend(v2),
             (Self::DomainId(v1), Self::DomainId(v2)) => v1.extend(v2),
@@ -304,6 +346,13 @@ impl QueryOutputBatchBox {
             (Self::AssetValue(v1), Self::AssetValue(v2)) => v1.extend(v2),
             (Self::AssetDefinitionId(v1), Self::AssetDefinitionId(v2)) => v1.extend(v2),
             (Self::AssetDefinition(v1), Self::AssetDefinition(v2)) => v1.extend(v2),
+            (Self::AllowanceId(v1), Self::AllowanceId(v2)) => v1.extend(v2),
+            (Self::Allowance(v1), Self::Allowance(v2)) => v1.extend(v2),
+            (Self::AssetHold(v1), Self::AssetHold(v2)) => v1.extend(v2),
+            (Self::HashLockId(v1), Self::HashLockId(v2)) => v1.extend(v2),
+            (Self::HashLock(v1), Self::HashLock(v2)) => v1.extend(v2),
+            (Self::SwapOfferId(v1), Self::SwapOfferId(v2)) => v1.extend(v2),
+            (Self::SwapOffer(v1), Self::SwapOffer(v2)) => v1.extend(v2),
             (Self::Role(v1), Self::Role(v2)) => v1.extend(v2),
             (Self::Parameter(v1), Self::Parameter(v2)) => v1.extend(v2),
             (Self::Permission(v1), Self::Permission(v2)) => v1.extend(v2),
@@ -321,6 +370,7 @@ impl QueryOutputBatchBox {
             (Self::Block(v1), Self::Block(v2)) => v1.extend(v2),
             (Self::BlockHeader(v1), Self::BlockHeader(v2)) => v1.extend(v2),
             (Self::BlockHeaderHash(v1), Self::BlockHeaderHash(v2)) => v1.extend(v2),
+            (Self::U64(v1), Self::U64(v2)) => v1.extend(v2),
             _ => panic!("Cannot extend different types of IterableQueryOutputBatchBox"),
         }
     }
@@ -344,6 +394,13 @@ impl QueryOutputBatchBox {
             Self::AssetValue(v) => v.len(),
             Self::AssetDefinitionId(v) => v.len(),
             Self::AssetDefinition(v) => v.len(),
+            Self::AllowanceId(v) => v.len(),
+            Self::Allowance(v) => v.len(),
+            Self::AssetHold(v) => v.len(),
+            Self::HashLockId(v) => v.len(),
+            Self::HashLock(v) => v.len(),
+            Self::SwapOfferId(v) => v.len(),
+            Self::SwapOffer(v) => v.len(),
             Self::Role(v) => v.len(),
             Self::Parameter(v) => v.len(),
             Self::Permission(v) => v.len(),
@@ -359,6 +416,112 @@ impl QueryOutputBatchBox {
             Self::Block(v) => v.len(),
             Self::BlockHeader(v) => v.len(),
             Self::BlockHeaderHash(v) => v.len(),
+            Self::U64(v) => v.len(),
+        }
+    }
+
+    /// Compares two elements of this batch by their indices.
+    ///
+    /// Returns `None` if the elements have no total order (like [`action::Action`](crate::trigger::action::Action)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices is out of bounds
+    pub fn compare_elements(&self, left: usize, right: usize) -> Option<core::cmp::Ordering> {
+        match self {
+            Self::PublicKey(v) => Some(v[left].cmp(&v[right])),
+            Self::String(v) => Some(v[left].cmp(&v[right])),
+            Self::Metadata(v) => Some(v[left].cmp(&v[right])),
+            Self::Json(v) => Some(v[left].cmp(&v[right])),
+            Self::Numeric(v) => Some(v[left].cmp(&v[right])),
+            Self::Name(v) => Some(v[left].cmp(&v[right])),
+            Self::DomainId(v) => Some(v[left].cmp(&v[right])),
+            Self::Domain(v) => Some(v[left].cmp(&v[right])),
+            Self::AccountId(v) => Some(v[left].cmp(&v[right])),
+            Self::Account(v) => Some(v[left].cmp(&v[right])),
+            Self::AssetId(v) => Some(v[left].cmp(&v[right])),
+            Self::Asset(v) => Some(v[left].cmp(&v[right])),
+            Self::AssetValue(v) => Some(v[left].cmp(&v[right])),
+            Self::AssetDefinitionId(v) => Some(v[left].cmp(&v[right])),
+            Self::AssetDefinition(v) => Some(v[left].cmp(&v[right])),
+            Self::AllowanceId(v) => Some(v[left].cmp(&v[right])),
+            Self::Allowance(v) => Some(v[left].cmp(&v[right])),
+            Self::AssetHold(v) => Some(v[left].cmp(&v[right])),
+            Self::HashLockId(v) => Some(v[left].cmp(&v[right])),
+            Self::HashLock(v) => Some(v[left].cmp(&v[right])),
+            Self::SwapOfferId(v) => Some(v[left].cmp(&v[right])),
+            Self::SwapOffer(v) => Some(v[left].cmp(&v[right])),
+            Self::Role(v) => Some(v[left].cmp(&v[right])),
+            Self::Parameter(v) => Some(v[left].cmp(&v[right])),
+            Self::Permission(v) => Some(v[left].cmp(&v[right])),
+            Self::CommittedTransaction(v) => Some(v[left].cmp(&v[right])),
+            Self::SignedTransaction(v) => Some(v[left].cmp(&v[right])),
+            Self::TransactionHash(v) => Some(v[left].cmp(&v[right])),
+            Self::TransactionRejectionReason(v) => Some(v[left].cmp(&v[right])),
+            Self::Peer(v) => Some(v[left].cmp(&v[right])),
+            Self::RoleId(v) => Some(v[left].cmp(&v[right])),
+            Self::TriggerId(v) => Some(v[left].cmp(&v[right])),
+            Self::Trigger(v) => Some(v[left].cmp(&v[right])),
+            Self::Action(_) => None,
+            Self::Block(v) => Some(v[left].cmp(&v[right])),
+            Self::BlockHeader(v) => Some(v[left].cmp(&v[right])),
+            Self::BlockHeaderHash(v) => Some(v[left].cmp(&v[right])),
+            Self::U64(v) => Some(v[left].cmp(&v[right])),
+        }
+    }
+
+    /// Builds a new batch out of the elements of this batch with the given indices, in the order of `indices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the indices is out of bounds
+    #[must_use]
+    pub fn select_elements(&self, indices: &[usize]) -> Self {
+        fn select<T: Clone>(v: &[T], indices: &[usize]) -> Vec<T> {
+            indices.iter().map(|&idx| v[idx].clone()).collect()
+        }
+
+        match self {
+            Self::PublicKey(v) => Self::PublicKey(select(v, indices)),
+            Self::String(v) => Self::String(select(v, indices)),
+            Self::Metadata(v) => Self::Metadata(select(v, indices)),
+            Self::Json(v) => Self::Json(select(v, indices)),
+            Self::Numeric(v) => Self::Numeric(select(v, indices)),
+            Self::Name(v) => Self::Name(select(v, indices)),
+            Self::DomainId(v) => Self::DomainId(select(v, indices)),
+            Self::Domain(v) => Self::Domain(select(v, indices)),
+            Self::AccountId(v) => Self::AccountId(select(v, indices)),
+            Self::Account(v) => Self::Account(select(v, indices)),
+            Self::AssetId(v) => Self::AssetId(select(v, indices)),
+            Self::Asset(v) => Self::Asset(select(v, indices)),
+            Self::AssetValue(v) => Self::AssetValue(select(v, indices)),
+            Self::AssetDefinitionId(v) => Self::AssetDefinitionId(select(v, indices)),
+            Self::AssetDefinition(v) => Self::AssetDefinition(select(v, indices)),
+            Self::AllowanceId(v) => Self::AllowanceId(select(v, indices)),
+            Self::Allowance(v) => Self::Allowance(select(v, indices)),
+            Self::AssetHold(v) => Self::AssetHold(select(v, indices)),
+            Self::HashLockId(v) => Self::HashLockId(select(v, indices)),
+            Self::HashLock(v) => Self::HashLock(select(v, indices)),
+            Self::SwapOfferId(v) => Self::SwapOfferId(select(v, indices)),
+            Self::SwapOffer(v) => Self::SwapOffer(select(v, indices)),
+            Self::Role(v) => Self::Role(select(v, indices)),
+            Self::Parameter(v) => Self::Parameter(select(v, indices)),
+            Self::Permission(v) => Self::Permission(select(v, indices)),
+            Self::CommittedTransaction(v) => Self::CommittedTransaction(select(v, indices)),
+            Self::SignedTransaction(v) => Self::SignedTransaction(select(v, indices)),
+            Self::TransactionHash(v) => Self::TransactionHash(select(v, indices)),
+            Self::TransactionRejectionReason(v) => {
+                Self::TransactionRejectionReason(select(v, indices))
+            }
+            Self::Peer(v) => Self::Peer(select(v, indices)),
+            Self::RoleId(v) => Self::RoleId(select(v, indices)),
+            Self::TriggerId(v) => Self::TriggerId(select(v, indices)),
+            Self::Trigger(v) => Self::Trigger(select(v, indices)),
+            Self::Action(v) => Self::Action(select(v, indices)),
+            Self::Block(v) => Self::Block(select(v, indices)),
+            Self::BlockHeader(v) => Self::BlockHeader(select(v, indices)),
+            Self::BlockHeaderHash(v) => Self::BlockHeaderHash(select(v, indices)),
+            Self::U64(v) => Self::U64(select(v, indices)),
         }
     }
 }
@@ -423,6 +586,47 @@ impl SingularQuery for SingularQueryBox {
     type Output = SingularQueryOutputBox;
 }
 
+impl SingularQueryWithParams {
+    /// Create a new [`SingularQueryWithParams`] querying the latest state.
+    pub fn new(query: SingularQueryBox) -> Self {
+        Self {
+            query,
+            at_height: None,
+        }
+    }
+
+    /// Query the world state as it was after the block at the given height was committed.
+    #[must_use]
+    pub fn with_at_height(mut self, height: NonZeroU64) -> Self {
+        self.at_height = Some(height);
+        self
+    }
+}
+
+impl From<SingularQueryBox> for SingularQueryWithParams {
+    fn from(query: SingularQueryBox) -> Self {
+        Self::new(query)
+    }
+}
+
+impl QueryWithParams {
+    /// Create a new [`QueryWithParams`] querying the latest state.
+    pub fn new(query: QueryBox, params: QueryParams) -> Self {
+        Self {
+            query,
+            params,
+            at_height: None,
+        }
+    }
+
+    /// Query the world state as it was after the block at the given height was committed.
+    #[must_use]
+    pub fn with_at_height(mut self, height: NonZeroU64) -> Self {
+        self.at_height = Some(height);
+        self
+    }
+}
+
 impl QueryOutput {
     /// Create a new [`QueryOutput`] from the iroha response parts.
     pub fn new(
@@ -451,6 +655,16 @@ impl QueryRequest {
             request: self,
         }
     }
+
+    /// Height of the historical state this request is made against, `None` for the latest state.
+    pub fn at_height(&self) -> Option<NonZeroU64> {
+        match self {
+            Self::Singular(query) => query.at_height,
+            Self::Start(query) => query.at_height,
+            Self::Aggregate(query) => query.query.at_height,
+            Self::Continue(_) => None,
+        }
+    }
 }
 
 impl QueryRequestWithAuthority {
@@ -480,161 +694,95 @@ impl SignedQuery {
         let SignedQuery::V1(query) = self;
         &query.payload.request
     }
+
+    /// Verify that the query is signed with the private key of `public_key`.
+    ///
+    /// Signatures aren't checked on decoding because the authority can be
+    /// controlled by keys other than its [`AccountId::signatory`].
+    ///
+    /// # Errors
+    /// Fails if the signature doesn't match `public_key`.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn verify_signature(&self, public_key: &PublicKey) -> Result<(), iroha_crypto::Error> {
+        let SignedQuery::V1(query) = self;
+        query.signature.0.verify(public_key, &query.payload)
+    }
 }
 
-mod candidate {
-    use parity_scale_codec::Input;
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use iroha_crypto::KeyPair;
+    use parity_scale_codec::{DecodeAll, Encode};
 
     use super::*;
 
-    #[derive(Decode, Deserialize)]
-    struct SignedQueryCandidate {
-        signature: QuerySignature,
-        payload: QueryRequestWithAuthority,
+    static ALICE_ID: LazyLock<AccountId> = LazyLock::new(|| {
+        format!("{}@{}", ALICE_KEYPAIR.public_key(), "wonderland")
+            .parse()
+            .unwrap()
+    });
+    static ALICE_KEYPAIR: LazyLock<KeyPair> = LazyLock::new(|| {
+        KeyPair::new(
+            "ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03"
+                .parse()
+                .unwrap(),
+            "802620CCF31D85E3B32A4BEA59987CE0C78E3B8E2DB93881468AB2435FE45D5C9DCD53"
+                .parse()
+                .unwrap(),
+        )
+        .unwrap()
+    });
+
+    static BOB_KEYPAIR: LazyLock<KeyPair> = LazyLock::new(|| {
+        KeyPair::new(
+            "ed012004FF5B81046DDCCF19E2E451C45DFB6F53759D4EB30FA2EFA807284D1CC33016"
+                .parse()
+                .unwrap(),
+            "802620AF3F96DEEF44348FEB516C057558972CEC4C75C4DB9C5B3AAC843668854BF828"
+                .parse()
+                .unwrap(),
+        )
+        .unwrap()
+    });
+
+    fn signed_query(key_pair: &KeyPair) -> SignedQuery {
+        QueryRequest::Singular(
+            SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel).into(),
+        )
+        .with_authority(ALICE_ID.clone())
+        .sign(key_pair)
     }
 
-    impl SignedQueryCandidate {
-        fn validate(self) -> Result<SignedQueryV1, &'static str> {
-            #[cfg(not(target_family = "wasm"))]
-            {
-                let QuerySignature(signature) = &self.signature;
-                signature
-                    .verify(&self.payload.authority.signatory, &self.payload)
-                    .map_err(|_| "Query request signature is not valid")?;
-            }
-
-            Ok(SignedQueryV1 {
-                payload: self.payload,
-                signature: self.signature,
-            })
-        }
+    #[test]
+    fn valid() {
+        signed_query(&ALICE_KEYPAIR)
+            .verify_signature(ALICE_KEYPAIR.public_key())
+            .unwrap();
     }
 
-    impl Decode for SignedQueryV1 {
-        fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
-            SignedQueryCandidate::decode(input)?
-                .validate()
-                .map_err(Into::into)
-        }
-    }
+    #[test]
+    fn invalid_signature() {
+        let SignedQuery::V1(mut signed_query) = signed_query(&ALICE_KEYPAIR);
 
-    impl<'de> Deserialize<'de> for SignedQueryV1 {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: serde::Deserializer<'de>,
-        {
-            use serde::de::Error as _;
+        // corrupt the signature by changing a single byte in an encoded signature
+        let mut signature_bytes = signed_query.signature.encode();
+        let idx = signature_bytes.len() - 1;
+        signature_bytes[idx] = signature_bytes[idx].wrapping_add(1);
+        signed_query.signature = QuerySignature::decode_all(&mut &signature_bytes[..]).unwrap();
 
-            SignedQueryCandidate::deserialize(deserializer)?
-                .validate()
-                .map_err(D::Error::custom)
-        }
+        SignedQuery::from(signed_query)
+            .verify_signature(ALICE_KEYPAIR.public_key())
+            .unwrap_err();
     }
 
-    #[cfg(test)]
-    mod tests {
-        use std::sync::LazyLock;
-
-        use iroha_crypto::KeyPair;
-        use parity_scale_codec::{DecodeAll, Encode};
-
-        use crate::{
-            account::AccountId,
-            query::{
-                candidate::SignedQueryCandidate, FindExecutorDataModel, QueryRequest,
-                QuerySignature, SignedQuery, SingularQueryBox,
-            },
-        };
-
-        static ALICE_ID: LazyLock<AccountId> = LazyLock::new(|| {
-            format!("{}@{}", ALICE_KEYPAIR.public_key(), "wonderland")
-                .parse()
-                .unwrap()
-        });
-        static ALICE_KEYPAIR: LazyLock<KeyPair> = LazyLock::new(|| {
-            KeyPair::new(
-                "ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03"
-                    .parse()
-                    .unwrap(),
-                "802620CCF31D85E3B32A4BEA59987CE0C78E3B8E2DB93881468AB2435FE45D5C9DCD53"
-                    .parse()
-                    .unwrap(),
-            )
-            .unwrap()
-        });
-
-        static BOB_KEYPAIR: LazyLock<KeyPair> = LazyLock::new(|| {
-            KeyPair::new(
-                "ed012004FF5B81046DDCCF19E2E451C45DFB6F53759D4EB30FA2EFA807284D1CC33016"
-                    .parse()
-                    .unwrap(),
-                "802620AF3F96DEEF44348FEB516C057558972CEC4C75C4DB9C5B3AAC843668854BF828"
-                    .parse()
-                    .unwrap(),
-            )
-            .unwrap()
-        });
-
-        #[test]
-        fn valid() {
-            let SignedQuery::V1(signed_query) = QueryRequest::Singular(
-                SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
-            )
-            .with_authority(ALICE_ID.clone())
-            .sign(&ALICE_KEYPAIR);
-
-            let candidate = SignedQueryCandidate {
-                signature: signed_query.signature,
-                payload: signed_query.payload,
-            };
-
-            candidate.validate().unwrap();
-        }
-
-        #[test]
-        fn invalid_signature() {
-            let SignedQuery::V1(signed_query) = QueryRequest::Singular(
-                SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
-            )
-            .with_authority(ALICE_ID.clone())
-            .sign(&ALICE_KEYPAIR);
-
-            let mut candidate = SignedQueryCandidate {
-                signature: signed_query.signature,
-                payload: signed_query.payload,
-            };
-
-            // corrupt the signature by changing a single byte in an encoded signature
-            let mut signature_bytes = candidate.signature.encode();
-            let idx = signature_bytes.len() - 1;
-            signature_bytes[idx] = signature_bytes[idx].wrapping_add(1);
-            candidate.signature = QuerySignature::decode_all(&mut &signature_bytes[..]).unwrap();
-
-            assert_eq!(
-                candidate.validate().unwrap_err(),
-                "Query request signature is not valid"
-            );
-        }
-
-        #[test]
-        fn mismatching_authority() {
-            let SignedQuery::V1(signed_query) = QueryRequest::Singular(
-                SingularQueryBox::FindExecutorDataModel(FindExecutorDataModel),
-            )
-            // signing with a wrong key here
-            .with_authority(ALICE_ID.clone())
-            .sign(&BOB_KEYPAIR);
-
-            let candidate = SignedQueryCandidate {
-                signature: signed_query.signature,
-                payload: signed_query.payload,
-            };
-
-            assert_eq!(
-                candidate.validate().unwrap_err(),
-                "Query request signature is not valid"
-            );
-        }
+    #[test]
+    fn mismatching_key() {
+        // signing with a key which isn't the authority's signatory
+        signed_query(&BOB_KEYPAIR)
+            .verify_signature(ALICE_KEYPAIR.public_key())
+            .unwrap_err();
     }
 }
 
@@ -676,6 +824,10 @@ impl_iter_queries! {
     FindAccounts => crate::account::Account,
     FindAssets => crate::asset::Asset,
     FindAssetsDefinitions => crate::asset::AssetDefinition,
+    FindAllowances => crate::asset::Allowance,
+    FindAssetHolds => crate::asset::AssetHold,
+    FindHashLocks => crate::asset::HashLock,
+    FindSwapOffers => crate::asset::SwapOffer,
     FindDomains => crate::domain::Domain,
     FindPeers => crate::peer::PeerId,
     FindActiveTriggerIds => crate::trigger::TriggerId,
@@ -689,6 +841,9 @@ impl_iter_queries! {
 impl_singular_queries! {
     FindParameters => crate::parameter::Parameters,
     FindExecutorDataModel => crate::executor::ExecutorDataModel,
+    FindAccountIdByAlias => crate::account::AccountId,
+    FindAliasesByAccountId => Vec<crate::account::AccountAlias>,
+    FindTemporaryGrantsByAccountId => Vec<crate::role::TemporaryGrant>,
 }
 
 impl AsRef<SignedTransaction> for CommittedTransaction {
@@ -751,11 +906,25 @@ pub mod role {
             /// `Id` of an account to find.
             pub id: AccountId,
         }
+
+        /// [`FindTemporaryGrantsByAccountId`] Iroha Query finds all permissions and roles
+        /// granted to a specified account until they expire.
+        #[derive(Display)]
+        #[display(fmt = "Find all temporary grants of `{id}` account")]
+        #[repr(transparent)]
+        // SAFETY: `FindTemporaryGrantsByAccountId` has no trap representation in `AccountId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindTemporaryGrantsByAccountId {
+            /// `Id` of an account to find.
+            pub id: AccountId,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this module.
     pub mod prelude {
-        pub use super::{FindRoleIds, FindRoles, FindRolesByAccountId};
+        pub use super::{
+            FindRoleIds, FindRoles, FindRolesByAccountId, FindTemporaryGrantsByAccountId,
+        };
     }
 }
 
@@ -818,11 +987,35 @@ pub mod account {
             /// `Id` of the definition of the asset which should be stored in founded accounts.
             pub asset_definition: AssetDefinitionId,
         }
+
+        /// [`FindAccountIdByAlias`] Iroha Query resolves an [`AccountAlias`] to the [`AccountId`] it refers to.
+        #[derive(Display)]
+        #[display(fmt = "Find account aliased `{alias}`")]
+        #[repr(transparent)]
+        // SAFETY: `FindAccountIdByAlias` has no trap representation in `AccountAlias`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAccountIdByAlias {
+            /// Alias to resolve.
+            pub alias: AccountAlias,
+        }
+
+        /// [`FindAliasesByAccountId`] Iroha Query finds all [`AccountAlias`]es of a specified account.
+        #[derive(Display)]
+        #[display(fmt = "Find all aliases of `{id}` account")]
+        #[repr(transparent)]
+        // SAFETY: `FindAliasesByAccountId` has no trap representation in `AccountId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAliasesByAccountId {
+            /// `Id` of an account to find.
+            pub id: AccountId,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAccounts, FindAccountsWithAsset};
+        pub use super::{
+            FindAccountIdByAlias, FindAccounts, FindAccountsWithAsset, FindAliasesByAccountId,
+        };
     }
 }
 
@@ -849,10 +1042,41 @@ pub mod asset {
         #[display(fmt = "Find all asset definitions")]
         #[ffi_type]
         pub struct FindAssetsDefinitions;
+
+        /// [`FindAllowances`] Iroha Query finds all [`Allowance`]s granted by asset owners
+        /// in Iroha Peer.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all allowances")]
+        #[ffi_type]
+        pub struct FindAllowances;
+
+        /// [`FindAssetHolds`] Iroha Query finds all locked [`AssetHold`] balances
+        /// in Iroha Peer.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all asset holds")]
+        #[ffi_type]
+        pub struct FindAssetHolds;
+
+        /// [`FindHashLocks`] Iroha Query finds all open [`HashLock`]s
+        /// in Iroha Peer.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all hash locks")]
+        #[ffi_type]
+        pub struct FindHashLocks;
+
+        /// [`FindSwapOffers`] Iroha Query finds all pending [`SwapOffer`]s
+        /// in Iroha Peer.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all swap offers")]
+        #[ffi_type]
+        pub struct FindSwapOffers;
     }
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAssets, FindAssetsDefinitions};
+        pub use super::{
+            FindAllowances, FindAssetHolds, FindAssets, FindAssetsDefinitions, FindHashLocks,
+            FindSwapOffers,
+        };
     }
 }
 
@@ -1071,6 +1295,26 @@ pub mod error {
             InvalidSingularParameters,
             /// Reached the limit of parallel queries. Either wait for previous queries to complete, or increase the limit in the config.
             CapacityLimit,
+            /// Sort key does not select a field of the query output or the selected field cannot be ordered
+            InvalidSortKey,
+            /// Aggregation cannot be applied to the selected fields of the query output
+            InvalidAggregation,
+            /// Overflow while computing the aggregate value
+            AggregationOverflow,
+            /// Aggregation produces more than {MAX_FETCH_SIZE:?} groups
+            TooManyGroups,
+            /// State at height {0} is not available: it is either not committed yet or no longer retained by the peer
+            HeightNotAvailable(
+                #[skip_from]
+                #[skip_try_from]
+                u64,
+            ),
+            /// Blocks up to height {0} are pruned by the peer, query an archival peer instead
+            BlocksPruned(
+                #[skip_from]
+                #[skip_try_from]
+                u64,
+            ),
         }
 
         /// Type assertion error
@@ -1116,6 +1360,14 @@ pub mod error {
             Permission(Permission),
             /// Failed to find public key: `{0}`
             PublicKey(PublicKey),
+            /// Failed to find allowance: `{0}`
+            Allowance(AllowanceId),
+            /// Failed to find hash lock: `{0}`
+            HashLock(HashLockId),
+            /// Failed to find swap offer: `{0}`
+            SwapOffer(SwapOfferId),
+            /// Failed to find account alias: `{0}`
+            AccountAlias(AccountAlias),
         }
     }
 }
@@ -1124,9 +1376,10 @@ pub mod error {
 #[allow(ambiguous_glob_reexports)]
 pub mod prelude {
     pub use super::{
-        account::prelude::*, asset::prelude::*, block::prelude::*, builder::prelude::*,
-        domain::prelude::*, dsl::prelude::*, executor::prelude::*, parameters::prelude::*,
-        peer::prelude::*, permission::prelude::*, role::prelude::*, transaction::prelude::*,
-        trigger::prelude::*, CommittedTransaction, QueryBox, QueryRequest, SingularQueryBox,
+        account::prelude::*, aggregate::prelude::*, asset::prelude::*, block::prelude::*,
+        builder::prelude::*, domain::prelude::*, dsl::prelude::*, executor::prelude::*,
+        parameters::prelude::*, peer::prelude::*, permission::prelude::*, role::prelude::*,
+        transaction::prelude::*, trigger::prelude::*, CommittedTransaction, QueryBox, QueryRequest,
+        SingularQueryBox,
     };
 }
@@ -1,18 +1,33 @@
 //! Defines parameters that can be sent along with a query.
 
 #[cfg(not(feature = "std"))]
-use alloc::{borrow::ToOwned, format, string::String, string::ToString, vec::Vec};
+use alloc::{borrow::ToOwned, format, string::String, string::ToString, vec, vec::Vec};
 use core::num::NonZeroU64;
 
 use derive_more::{Constructor, Display};
 use getset::Getters;
 use iroha_data_model_derive::model;
+use iroha_macro::FromVariant;
 use iroha_schema::IntoSchema;
 use iroha_version::{Decode, Encode};
 use nonzero_ext::nonzero;
 use serde::{Deserialize, Serialize};
 
-use crate::name::Name;
+use crate::{
+    account::Account,
+    asset::{Allowance, Asset, AssetDefinition, AssetHold, HashLock, SwapOffer},
+    block::{BlockHeader, SignedBlock},
+    domain::Domain,
+    name::Name,
+    peer::PeerId,
+    permission::Permission,
+    query::{
+        dsl::{type_descriptions::prelude::*, HasProjection, SelectorMarker},
+        CommittedTransaction,
+    },
+    role::{Role, RoleId},
+    trigger::{Trigger, TriggerId},
+};
 
 /// Default value for `fetch_size` parameter in queries.
 pub const DEFAULT_FETCH_SIZE: NonZeroU64 = nonzero!(100_u64);
@@ -70,6 +85,8 @@ mod model {
     }
 
     /// Struct for sorting requests
+    ///
+    /// If both the metadata key and sort keys are specified, the results are sorted by the metadata key first, with ties broken by the sort keys.
     #[derive(
         Debug,
         Clone,
@@ -86,6 +103,86 @@ mod model {
     pub struct Sorting {
         /// Sort query result using [`Name`] of the key in [`Asset`]'s metadata.
         pub sort_by_metadata_key: Option<Name>,
+        /// Sort query result by the selected fields, in order of priority: each next key is only used to break ties of the previous ones.
+        #[serde(default)]
+        pub keys: Vec<SortKey>,
+    }
+
+    /// A single key of a [`Sorting`]: a field of the query output along with the direction to sort in
+    #[derive(
+        Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, Constructor,
+    )]
+    pub struct SortKey {
+        /// Selector of the field to sort by
+        pub selector: SortSelector,
+        /// Direction of sorting
+        #[serde(default)]
+        pub order: SortOrder,
+    }
+
+    /// Direction of sorting
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub enum SortOrder {
+        /// From the smallest value to the largest
+        #[default]
+        Ascending,
+        /// From the largest value to the smallest
+        Descending,
+    }
+
+    /// A selector of a field to sort by, type-erased over all the iterable query outputs
+    ///
+    /// Selecting a field missing from the output (e.g. a nonexistent metadata key) fails the query, same as in the query selectors.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, FromVariant,
+    )]
+    pub enum SortSelector {
+        /// Field of a domain
+        Domain(DomainProjection<SelectorMarker>),
+        /// Field of an account
+        Account(AccountProjection<SelectorMarker>),
+        /// Field of an asset
+        Asset(AssetProjection<SelectorMarker>),
+        /// Field of an asset definition
+        AssetDefinition(AssetDefinitionProjection<SelectorMarker>),
+        /// Field of a role
+        Role(RoleProjection<SelectorMarker>),
+        /// Field of a role id
+        RoleId(RoleIdProjection<SelectorMarker>),
+        /// Field of a permission
+        Permission(PermissionProjection<SelectorMarker>),
+        /// Field of a peer id
+        Peer(PeerIdProjection<SelectorMarker>),
+        /// Field of a trigger id
+        TriggerId(TriggerIdProjection<SelectorMarker>),
+        /// Field of a trigger
+        Trigger(TriggerProjection<SelectorMarker>),
+        /// Field of a committed transaction
+        CommittedTransaction(CommittedTransactionProjection<SelectorMarker>),
+        /// Field of a block
+        Block(SignedBlockProjection<SelectorMarker>),
+        /// Field of a block header
+        BlockHeader(BlockHeaderProjection<SelectorMarker>),
+        /// Field of an allowance
+        Allowance(AllowanceProjection<SelectorMarker>),
+        /// Field of an asset hold
+        AssetHold(AssetHoldProjection<SelectorMarker>),
+        /// Field of a hash lock
+        HashLock(HashLockProjection<SelectorMarker>),
+        /// Field of a swap offer
+        SwapOffer(SwapOfferProjection<SelectorMarker>),
     }
 
     /// Structure for query fetch size parameter encoding/decoding
@@ -136,11 +233,85 @@ impl Sorting {
     pub fn by_metadata_key(key: Name) -> Self {
         Self {
             sort_by_metadata_key: Some(key),
+            keys: Vec::new(),
+        }
+    }
+
+    /// Creates a sorting by a single [`SortKey`].
+    pub fn by_key(key: SortKey) -> Self {
+        Self {
+            sort_by_metadata_key: None,
+            keys: vec![key],
         }
     }
+
+    /// Add a [`SortKey`] to break ties left by the existing keys.
+    #[must_use]
+    pub fn then_by(mut self, key: SortKey) -> Self {
+        self.keys.push(key);
+        self
+    }
+
+    /// Returns `true` if no sorting was requested.
+    pub fn is_empty(&self) -> bool {
+        self.sort_by_metadata_key.is_none() && self.keys.is_empty()
+    }
+}
+
+impl SortKey {
+    /// Creates a key sorting by the given selector in ascending order.
+    pub fn ascending(selector: impl Into<SortSelector>) -> Self {
+        Self::new(selector.into(), SortOrder::Ascending)
+    }
+
+    /// Creates a key sorting by the given selector in descending order.
+    pub fn descending(selector: impl Into<SortSelector>) -> Self {
+        Self::new(selector.into(), SortOrder::Descending)
+    }
+}
+
+/// Trait implemented on all iterable query outputs that can be sorted by a [`SortSelector`].
+pub trait HasSortSelector: HasProjection<SelectorMarker, AtomType = ()> {
+    /// Get the typed selector out of a [`SortSelector`], returning `None` if it selects from another type.
+    fn from_sort_selector(selector: &SortSelector) -> Option<&Self::Projection>;
+}
+
+macro_rules! impl_has_sort_selector {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl HasSortSelector for $ty {
+                fn from_sort_selector(selector: &SortSelector) -> Option<&Self::Projection> {
+                    match selector {
+                        SortSelector::$variant(projection) => Some(projection),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_has_sort_selector! {
+    Domain => Domain,
+    Account => Account,
+    Asset => Asset,
+    AssetDefinition => AssetDefinition,
+    Role => Role,
+    RoleId => RoleId,
+    Permission => Permission,
+    PeerId => Peer,
+    TriggerId => TriggerId,
+    Trigger => Trigger,
+    CommittedTransaction => CommittedTransaction,
+    SignedBlock => Block,
+    BlockHeader => BlockHeader,
+    Allowance => Allowance,
+    AssetHold => AssetHold,
+    HashLock => HashLock,
+    SwapOffer => SwapOffer,
 }
 
 pub mod prelude {
     //! Prelude: re-export most commonly used traits, structs and macros from this module.
-    pub use super::{FetchSize, Pagination, Sorting};
+    pub use super::{FetchSize, Pagination, SortKey, SortOrder, Sorting};
 }
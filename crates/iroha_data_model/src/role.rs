@@ -17,6 +17,7 @@ mod model {
     use derive_more::{Constructor, Display, FromStr};
     use getset::Getters;
     use iroha_data_model_derive::IdEqOrdHash;
+    use iroha_macro::FromVariant;
     use iroha_schema::IntoSchema;
     use parity_scale_codec::{Decode, Encode};
     use serde::{Deserialize, Serialize};
@@ -88,6 +89,60 @@ mod model {
         /// First owner
         pub grant_to: AccountId,
     }
+
+    /// Permission or role granted to an account.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        FromVariant,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub enum GrantedObject {
+        /// Permission granted to the account.
+        Permission(Permission),
+        /// Role granted to the account.
+        Role(RoleId),
+    }
+
+    /// Permission or role granted to an account until it expires.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{object} expiring in {remaining_ms}ms")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct TemporaryGrant {
+        /// Granted permission or role.
+        pub object: GrantedObject,
+        /// Time (unix time in milliseconds) at which the grant is revoked.
+        #[getset(skip)]
+        pub expires_at_ms: u64,
+        /// Time (in milliseconds) left until the grant is revoked, as of the latest block.
+        #[getset(skip)]
+        pub remaining_ms: u64,
+    }
 }
 
 impl Role {
@@ -127,11 +182,23 @@ impl NewRole {
     }
 }
 
+impl TemporaryGrant {
+    /// Unix time at which the grant is revoked.
+    pub fn expires_at(&self) -> core::time::Duration {
+        core::time::Duration::from_millis(self.expires_at_ms)
+    }
+
+    /// Time left until the grant is revoked, as of the latest block.
+    pub fn remaining(&self) -> core::time::Duration {
+        core::time::Duration::from_millis(self.remaining_ms)
+    }
+}
+
 impl Registered for Role {
     type With = NewRole;
 }
 
 /// The prelude re-exports most commonly used traits, structs and macros from this module.
 pub mod prelude {
-    pub use super::{NewRole, Role, RoleId};
+    pub use super::{GrantedObject, NewRole, Role, RoleId, TemporaryGrant};
 }
@@ -12,6 +12,7 @@ use derive_more::{DebugCustom, Display};
 use iroha_crypto::{Signature, SignatureOf};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
+use iroha_primitives::numeric::Numeric;
 use iroha_schema::IntoSchema;
 use iroha_version::{declare_versioned, version};
 use parity_scale_codec::{Decode, Encode};
@@ -20,6 +21,8 @@ use serde::{Deserialize, Serialize};
 pub use self::model::*;
 use crate::{
     account::AccountId,
+    asset::AssetId,
+    events::data::DataEvent,
     isi::{Instruction, InstructionBox},
     metadata::Metadata,
     ChainId,
@@ -27,6 +30,7 @@ use crate::{
 
 #[model]
 mod model {
+    use getset::Getters;
     use iroha_primitives::const_vec::ConstVec;
 
     use super::*;
@@ -109,8 +113,13 @@ mod model {
         pub instructions: Executable,
         /// If transaction is not committed by this time it will be dropped.
         pub time_to_live_ms: Option<NonZeroU64>,
+        /// Transaction can't be committed before this time (unix time in milliseconds).
+        pub not_before_ms: Option<NonZeroU64>,
         /// Random value to make different hashes for transactions which occur repeatedly and simultaneously.
         pub nonce: Option<NonZeroU32>,
+        /// Amount of the fee asset paid to the fee collector on top of the fee.
+        /// Transactions with higher tips are taken from the queue first.
+        pub tip: Option<Numeric>,
         /// Store for additional information.
         pub metadata: Metadata,
     }
@@ -158,6 +167,53 @@ mod model {
         /// [`Transaction`] payload.
         pub(super) payload: TransactionPayload,
     }
+
+    /// Fee paid by the authority of a transaction
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{amount} of {asset}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct TransactionFee {
+        /// Asset of the authority from which the fee was debited
+        pub asset: AssetId,
+        /// Amount debited
+        pub amount: Numeric,
+    }
+
+    /// Outcome of executing a transaction on top of the current state without committing it
+    #[derive(
+        Debug, Clone, PartialEq, Eq, Getters, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct TransactionSimulation {
+        /// Reason for which the transaction would be rejected, `None` if it would be committed
+        pub rejection_reason: Option<error::TransactionRejectionReason>,
+        /// Events that the transaction would emit
+        pub events: Vec<DataEvent>,
+        /// Fee that the authority would pay
+        pub fee: Option<TransactionFee>,
+        /// Amount of fuel consumed by the smart contract
+        #[getset(skip)]
+        pub fuel: u64,
+        /// Number of instructions executed
+        #[getset(skip)]
+        pub instructions: u64,
+    }
 }
 
 impl<A: Instruction> FromIterator<A> for Executable {
@@ -234,8 +290,14 @@ impl SignedTransaction {
     /// Creation timestamp as [`core::time::Duration`]
     #[inline]
     pub fn creation_time(&self) -> Duration {
+        Duration::from_millis(self.creation_time_ms())
+    }
+
+    /// Creation timestamp (unix time in milliseconds)
+    #[inline]
+    pub(crate) fn creation_time_ms(&self) -> u64 {
         let SignedTransaction::V1(tx) = self;
-        Duration::from_millis(tx.payload.creation_time_ms)
+        tx.payload.creation_time_ms
     }
 
     /// If transaction is not committed by this time it will be dropped.
@@ -247,6 +309,15 @@ impl SignedTransaction {
             .map(|ttl| Duration::from_millis(ttl.into()))
     }
 
+    /// Transaction can't be committed before this time (unix time).
+    #[inline]
+    pub fn not_before(&self) -> Option<Duration> {
+        let SignedTransaction::V1(tx) = self;
+        tx.payload
+            .not_before_ms
+            .map(|not_before| Duration::from_millis(not_before.into()))
+    }
+
     /// Transaction nonce
     #[inline]
     pub fn nonce(&self) -> Option<NonZeroU32> {
@@ -254,6 +325,13 @@ impl SignedTransaction {
         tx.payload.nonce
     }
 
+    /// Tip paid by the authority on top of the fee, if any
+    #[inline]
+    pub fn tip(&self) -> Option<Numeric> {
+        let SignedTransaction::V1(tx) = self;
+        tx.payload.tip
+    }
+
     /// Transaction chain id
     #[inline]
     pub fn chain(&self) -> &ChainId {
@@ -273,6 +351,22 @@ impl SignedTransaction {
     pub fn hash(&self) -> iroha_crypto::HashOf<Self> {
         iroha_crypto::HashOf::new(self)
     }
+
+    /// Verify that the transaction is signed with the private key of `public_key`.
+    ///
+    /// Signatures aren't checked on decoding because the authority can be
+    /// controlled by keys other than its [`AccountId::signatory`].
+    ///
+    /// # Errors
+    /// Fails if the signature doesn't match `public_key`.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn verify_signature(
+        &self,
+        public_key: &iroha_crypto::PublicKey,
+    ) -> Result<(), iroha_crypto::Error> {
+        let SignedTransaction::V1(tx) = self;
+        tx.signature.0.verify(public_key, &tx.payload)
+    }
 }
 
 #[cfg(feature = "transparent_api")]
@@ -306,6 +400,8 @@ impl TransactionBuilder {
                 creation_time_ms,
                 nonce: None,
                 time_to_live_ms: None,
+                not_before_ms: None,
+                tip: None,
                 instructions: Vec::<InstructionBox>::new().into(),
                 metadata: Metadata::default(),
             },
@@ -402,6 +498,22 @@ impl TransactionBuilder {
         self
     }
 
+    /// Schedule [`Transaction`] so that it can't be committed before the given unix time
+    pub fn set_not_before(&mut self, not_before: Duration) -> &mut Self {
+        let not_before: u64 = not_before
+            .as_millis()
+            .try_into()
+            .expect("INTERNAL BUG: Unix timestamp exceedes u64::MAX");
+        self.payload.not_before_ms = NonZeroU64::new(not_before);
+        self
+    }
+
+    /// Set the tip paid on top of the fee for [`Transaction`] to be taken from the queue earlier
+    pub fn set_tip(&mut self, tip: Numeric) -> &mut Self {
+        self.payload.tip = Some(tip);
+        self
+    }
+
     /// Set creation time of transaction
     pub fn set_creation_time(&mut self, value: Duration) -> &mut Self {
         self.payload.creation_time_ms = u64::try_from(value.as_millis())
@@ -437,8 +549,6 @@ mod candidate {
         fn validate(self) -> Result<SignedTransactionV1, &'static str> {
             #[cfg(not(target_family = "wasm"))]
             self.validate_instructions()?;
-            #[cfg(not(target_family = "wasm"))]
-            self.validate_signature()?;
 
             Ok(SignedTransactionV1 {
                 signature: self.signature,
@@ -456,17 +566,6 @@ mod candidate {
 
             Ok(())
         }
-
-        #[cfg(not(target_family = "wasm"))]
-        fn validate_signature(&self) -> Result<(), &'static str> {
-            let TransactionSignature(signature) = &self.signature;
-
-            signature
-                .verify(&self.payload.authority.signatory, &self.payload)
-                .map_err(|_| "Transaction signature is invalid")?;
-
-            Ok(())
-        }
     }
 
     impl Decode for SignedTransactionV1 {
@@ -652,6 +751,16 @@ pub mod error {
             InstructionExecution(#[cfg_attr(feature = "std", source)] InstructionExecutionFail),
             /// Failure in WebAssembly execution
             WasmExecution(#[cfg_attr(feature = "std", source)] WasmExecutionFail),
+            /// Transaction is not signed by any of the current signatories of its authority
+            InvalidSignature,
+            /// Authority can't pay the transaction fee
+            InsufficientFee(
+                #[skip_from]
+                #[skip_try_from]
+                TransactionFee,
+            ),
+            /// Transaction fee exceeds the maximum amount of the fee asset
+            FeeOverflow,
         }
     }
 
@@ -670,6 +779,23 @@ pub mod error {
                 Revoke(_) => "revoke",
                 ExecuteTrigger(_) => "execute trigger",
                 SetParameter(_) => "set parameter",
+                SetMintCap(_) => "set mint cap",
+                Approve(_) => "approve",
+                TransferFrom(_) => "transfer from",
+                HoldAsset(_) => "hold asset",
+                ReleaseAsset(_) => "release asset",
+                LockAsset(_) => "lock asset",
+                ClaimLockedAsset(_) => "claim locked asset",
+                RefundLockedAsset(_) => "refund locked asset",
+                ProposeSwap(_) => "propose swap",
+                AcceptSwap(_) => "accept swap",
+                CancelSwap(_) => "cancel swap",
+                AddSignatory(_) => "add signatory",
+                RemoveSignatory(_) => "remove signatory",
+                RotateKey(_) => "rotate key",
+                RegisterAccountAlias(_) => "register account alias",
+                TransferAccountAlias(_) => "transfer account alias",
+                UnregisterAccountAlias(_) => "unregister account alias",
                 Upgrade(_) => "upgrade",
                 Log(_) => "log",
                 Custom(_) => "custom",
@@ -701,7 +827,8 @@ pub mod error {
 /// The prelude re-exports most commonly used traits, structs and macros from this module.
 pub mod prelude {
     pub use super::{
-        error::prelude::*, Executable, SignedTransaction, TransactionBuilder, WasmSmartContract,
+        error::prelude::*, Executable, SignedTransaction, TransactionBuilder, TransactionFee,
+        TransactionSimulation, WasmSmartContract,
     };
 }
 
@@ -48,18 +48,42 @@ pub trait Visit {
 
         visit_execute_trigger(&ExecuteTrigger),
         visit_set_parameter(&SetParameter),
+        visit_set_mint_cap(&SetMintCap),
+        visit_approve(&Approve),
+        visit_transfer_from(&TransferFrom),
+        visit_hold_asset(&HoldAsset),
+        visit_release_asset(&ReleaseAsset),
+        visit_lock_asset(&LockAsset),
+        visit_claim_locked_asset(&ClaimLockedAsset),
+        visit_refund_locked_asset(&RefundLockedAsset),
+        visit_propose_swap(&ProposeSwap),
+        visit_accept_swap(&AcceptSwap),
+        visit_cancel_swap(&CancelSwap),
+        visit_add_signatory(&AddSignatory),
+        visit_remove_signatory(&RemoveSignatory),
+        visit_rotate_key(&RotateKey),
+        visit_register_account_alias(&RegisterAccountAlias),
+        visit_transfer_account_alias(&TransferAccountAlias),
+        visit_unregister_account_alias(&UnregisterAccountAlias),
         visit_log(&Log),
         visit_custom_instruction(&CustomInstruction),
 
         // Visit SingularQueryBox
         visit_find_executor_data_model(&FindExecutorDataModel),
         visit_find_parameters(&FindParameters),
+        visit_find_account_id_by_alias(&FindAccountIdByAlias),
+        visit_find_aliases_by_account_id(&FindAliasesByAccountId),
+        visit_find_temporary_grants_by_account_id(&FindTemporaryGrantsByAccountId),
 
         // Visit IterableQueryBox
         visit_find_domains(&QueryWithFilter<FindDomains>),
         visit_find_accounts(&QueryWithFilter<FindAccounts>),
         visit_find_assets(&QueryWithFilter<FindAssets>),
         visit_find_assets_definitions(&QueryWithFilter<FindAssetsDefinitions>),
+        visit_find_allowances(&QueryWithFilter<FindAllowances>),
+        visit_find_asset_holds(&QueryWithFilter<FindAssetHolds>),
+        visit_find_hash_locks(&QueryWithFilter<FindHashLocks>),
+        visit_find_swap_offers(&QueryWithFilter<FindSwapOffers>),
         visit_find_roles(&QueryWithFilter<FindRoles>),
         visit_find_role_ids(&QueryWithFilter<FindRoleIds>),
         visit_find_permissions_by_account_id(&QueryWithFilter<FindPermissionsByAccountId>),
@@ -154,6 +178,9 @@ pub fn visit_singular_query<V: Visit + ?Sized>(visitor: &mut V, query: &Singular
     singular_query_visitors! {
         visit_find_executor_data_model(FindExecutorDataModel),
         visit_find_parameters(FindParameters),
+        visit_find_account_id_by_alias(FindAccountIdByAlias),
+        visit_find_aliases_by_account_id(FindAliasesByAccountId),
+        visit_find_temporary_grants_by_account_id(FindTemporaryGrantsByAccountId),
     }
 }
 
@@ -171,6 +198,10 @@ pub fn visit_iter_query<V: Visit + ?Sized>(visitor: &mut V, query: &QueryWithPar
         visit_find_accounts(FindAccounts),
         visit_find_assets(FindAssets),
         visit_find_assets_definitions(FindAssetsDefinitions),
+        visit_find_allowances(FindAllowances),
+        visit_find_asset_holds(FindAssetHolds),
+        visit_find_hash_locks(FindHashLocks),
+        visit_find_swap_offers(FindSwapOffers),
         visit_find_roles(FindRoles),
         visit_find_role_ids(FindRoleIds),
         visit_find_permissions_by_account_id(FindPermissionsByAccountId),
@@ -202,6 +233,35 @@ pub fn visit_wasm<V: Visit + ?Sized>(_visitor: &mut V, _wasm: &WasmSmartContract
 pub fn visit_instruction<V: Visit + ?Sized>(visitor: &mut V, isi: &InstructionBox) {
     match isi {
         InstructionBox::SetParameter(variant_value) => visitor.visit_set_parameter(variant_value),
+        InstructionBox::SetMintCap(variant_value) => visitor.visit_set_mint_cap(variant_value),
+        InstructionBox::Approve(variant_value) => visitor.visit_approve(variant_value),
+        InstructionBox::TransferFrom(variant_value) => visitor.visit_transfer_from(variant_value),
+        InstructionBox::HoldAsset(variant_value) => visitor.visit_hold_asset(variant_value),
+        InstructionBox::ReleaseAsset(variant_value) => visitor.visit_release_asset(variant_value),
+        InstructionBox::LockAsset(variant_value) => visitor.visit_lock_asset(variant_value),
+        InstructionBox::ClaimLockedAsset(variant_value) => {
+            visitor.visit_claim_locked_asset(variant_value)
+        }
+        InstructionBox::RefundLockedAsset(variant_value) => {
+            visitor.visit_refund_locked_asset(variant_value)
+        }
+        InstructionBox::ProposeSwap(variant_value) => visitor.visit_propose_swap(variant_value),
+        InstructionBox::AcceptSwap(variant_value) => visitor.visit_accept_swap(variant_value),
+        InstructionBox::CancelSwap(variant_value) => visitor.visit_cancel_swap(variant_value),
+        InstructionBox::AddSignatory(variant_value) => visitor.visit_add_signatory(variant_value),
+        InstructionBox::RemoveSignatory(variant_value) => {
+            visitor.visit_remove_signatory(variant_value)
+        }
+        InstructionBox::RotateKey(variant_value) => visitor.visit_rotate_key(variant_value),
+        InstructionBox::RegisterAccountAlias(variant_value) => {
+            visitor.visit_register_account_alias(variant_value)
+        }
+        InstructionBox::TransferAccountAlias(variant_value) => {
+            visitor.visit_transfer_account_alias(variant_value)
+        }
+        InstructionBox::UnregisterAccountAlias(variant_value) => {
+            visitor.visit_unregister_account_alias(variant_value)
+        }
         InstructionBox::ExecuteTrigger(variant_value) => {
             visitor.visit_execute_trigger(variant_value)
         }
@@ -359,6 +419,23 @@ leaf_visitors! {
     visit_burn_trigger_repetitions(&Burn<u32, Trigger>),
     visit_upgrade(&Upgrade),
     visit_set_parameter(&SetParameter),
+    visit_set_mint_cap(&SetMintCap),
+    visit_approve(&Approve),
+    visit_transfer_from(&TransferFrom),
+    visit_hold_asset(&HoldAsset),
+    visit_release_asset(&ReleaseAsset),
+    visit_lock_asset(&LockAsset),
+    visit_claim_locked_asset(&ClaimLockedAsset),
+    visit_refund_locked_asset(&RefundLockedAsset),
+    visit_propose_swap(&ProposeSwap),
+    visit_accept_swap(&AcceptSwap),
+    visit_cancel_swap(&CancelSwap),
+    visit_add_signatory(&AddSignatory),
+    visit_remove_signatory(&RemoveSignatory),
+    visit_rotate_key(&RotateKey),
+    visit_register_account_alias(&RegisterAccountAlias),
+    visit_transfer_account_alias(&TransferAccountAlias),
+    visit_unregister_account_alias(&UnregisterAccountAlias),
     visit_execute_trigger(&ExecuteTrigger),
     visit_log(&Log),
     visit_custom_instruction(&CustomInstruction),
@@ -366,12 +443,19 @@ leaf_visitors! {
     // Singular Query visitors
     visit_find_executor_data_model(&FindExecutorDataModel),
     visit_find_parameters(&FindParameters),
+    visit_find_account_id_by_alias(&FindAccountIdByAlias),
+    visit_find_aliases_by_account_id(&FindAliasesByAccountId),
+    visit_find_temporary_grants_by_account_id(&FindTemporaryGrantsByAccountId),
 
     // Iterable Query visitors
     visit_find_domains(&QueryWithFilter<FindDomains>),
     visit_find_accounts(&QueryWithFilter<FindAccounts>),
     visit_find_assets(&QueryWithFilter<FindAssets>),
     visit_find_assets_definitions(&QueryWithFilter<FindAssetsDefinitions>),
+    visit_find_allowances(&QueryWithFilter<FindAllowances>),
+    visit_find_asset_holds(&QueryWithFilter<FindAssetHolds>),
+    visit_find_hash_locks(&QueryWithFilter<FindHashLocks>),
+    visit_find_swap_offers(&QueryWithFilter<FindSwapOffers>),
     visit_find_roles(&QueryWithFilter<FindRoles>),
     visit_find_role_ids(&QueryWithFilter<FindRoleIds>),
     visit_find_permissions_by_account_id(&QueryWithFilter<FindPermissionsByAccountId>),
@@ -4,17 +4,21 @@
 use alloc::format;
 
 pub use account::{
-    visit_register_account, visit_remove_account_key_value, visit_set_account_key_value,
-    visit_unregister_account,
+    visit_add_signatory, visit_register_account, visit_register_account_alias,
+    visit_remove_account_key_value, visit_remove_signatory, visit_rotate_key,
+    visit_set_account_key_value, visit_transfer_account_alias, visit_unregister_account,
+    visit_unregister_account_alias,
 };
 pub use asset::{
-    visit_burn_asset_numeric, visit_mint_asset_numeric, visit_register_asset,
+    visit_accept_swap, visit_approve, visit_burn_asset_numeric, visit_cancel_swap,
+    visit_claim_locked_asset, visit_hold_asset, visit_lock_asset, visit_mint_asset_numeric,
+    visit_propose_swap, visit_refund_locked_asset, visit_register_asset, visit_release_asset,
     visit_remove_asset_key_value, visit_set_asset_key_value, visit_transfer_asset_numeric,
-    visit_transfer_asset_store, visit_unregister_asset,
+    visit_transfer_asset_store, visit_transfer_from, visit_unregister_asset,
 };
 pub use asset_definition::{
     visit_register_asset_definition, visit_remove_asset_definition_key_value,
-    visit_set_asset_definition_key_value, visit_transfer_asset_definition,
+    visit_set_asset_definition_key_value, visit_set_mint_cap, visit_transfer_asset_definition,
     visit_unregister_asset_definition,
 };
 pub use domain::{
@@ -82,6 +86,57 @@ pub fn visit_instruction<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &In
         InstructionBox::SetParameter(isi) => {
             executor.visit_set_parameter(isi);
         }
+        InstructionBox::SetMintCap(isi) => {
+            executor.visit_set_mint_cap(isi);
+        }
+        InstructionBox::Approve(isi) => {
+            executor.visit_approve(isi);
+        }
+        InstructionBox::TransferFrom(isi) => {
+            executor.visit_transfer_from(isi);
+        }
+        InstructionBox::HoldAsset(isi) => {
+            executor.visit_hold_asset(isi);
+        }
+        InstructionBox::ReleaseAsset(isi) => {
+            executor.visit_release_asset(isi);
+        }
+        InstructionBox::LockAsset(isi) => {
+            executor.visit_lock_asset(isi);
+        }
+        InstructionBox::ClaimLockedAsset(isi) => {
+            executor.visit_claim_locked_asset(isi);
+        }
+        InstructionBox::RefundLockedAsset(isi) => {
+            executor.visit_refund_locked_asset(isi);
+        }
+        InstructionBox::ProposeSwap(isi) => {
+            executor.visit_propose_swap(isi);
+        }
+        InstructionBox::AcceptSwap(isi) => {
+            executor.visit_accept_swap(isi);
+        }
+        InstructionBox::CancelSwap(isi) => {
+            executor.visit_cancel_swap(isi);
+        }
+        InstructionBox::AddSignatory(isi) => {
+            executor.visit_add_signatory(isi);
+        }
+        InstructionBox::RemoveSignatory(isi) => {
+            executor.visit_remove_signatory(isi);
+        }
+        InstructionBox::RotateKey(isi) => {
+            executor.visit_rotate_key(isi);
+        }
+        InstructionBox::RegisterAccountAlias(isi) => {
+            executor.visit_register_account_alias(isi);
+        }
+        InstructionBox::TransferAccountAlias(isi) => {
+            executor.visit_transfer_account_alias(isi);
+        }
+        InstructionBox::UnregisterAccountAlias(isi) => {
+            executor.visit_unregister_account_alias(isi);
+        }
         InstructionBox::Log(isi) => {
             executor.visit_log(isi);
         }
@@ -323,6 +378,9 @@ pub mod domain {
             AnyPermission::CanTransferAssetWithDefinition(permission) => {
                 permission.asset_definition.domain() == domain_id
             }
+            AnyPermission::CanHoldAssetWithDefinition(permission) => {
+                permission.asset_definition.domain() == domain_id
+            }
             AnyPermission::CanRegisterAsset(permission) => permission.owner.domain() == domain_id,
             AnyPermission::CanUnregisterAsset(permission) => {
                 permission.asset.definition().domain() == domain_id
@@ -371,6 +429,9 @@ pub mod account {
         CanModifyAccountMetadata, CanRegisterAccount, CanUnregisterAccount,
     };
 
+    use iroha_smart_contract::data_model::isi::BuiltInInstruction;
+    use iroha_smart_contract_utils::Encode;
+
     use super::*;
     use crate::permission::{account::is_account_owner, revoke_permissions};
 
@@ -494,6 +555,90 @@ pub mod account {
         );
     }
 
+    fn execute_signatory_change<V, I>(executor: &mut V, account_id: &AccountId, isi: &I)
+    where
+        V: Execute + Visit + ?Sized,
+        I: BuiltInInstruction + Encode,
+    {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't change signatories of another account");
+    }
+
+    pub fn visit_add_signatory<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &AddSignatory) {
+        execute_signatory_change(executor, isi.account(), isi);
+    }
+
+    pub fn visit_remove_signatory<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RemoveSignatory,
+    ) {
+        execute_signatory_change(executor, isi.account(), isi);
+    }
+
+    pub fn visit_rotate_key<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &RotateKey) {
+        execute_signatory_change(executor, isi.account(), isi);
+    }
+
+    fn execute_alias_change<V, I>(executor: &mut V, account_id: &AccountId, isi: &I)
+    where
+        V: Execute + Visit + ?Sized,
+        I: BuiltInInstruction + Encode,
+    {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_account_owner(account_id, &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't change aliases of another account");
+    }
+
+    pub fn visit_register_account_alias<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RegisterAccountAlias,
+    ) {
+        execute_alias_change(executor, isi.account(), isi);
+    }
+
+    pub fn visit_transfer_account_alias<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &TransferAccountAlias,
+    ) {
+        let account_id = match executor
+            .host()
+            .query_single(FindAccountIdByAlias::new(isi.alias().clone()))
+        {
+            Err(err) => deny!(executor, err),
+            Ok(account_id) => account_id,
+        };
+        execute_alias_change(executor, &account_id, isi);
+    }
+
+    pub fn visit_unregister_account_alias<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &UnregisterAccountAlias,
+    ) {
+        let account_id = match executor
+            .host()
+            .query_single(FindAccountIdByAlias::new(isi.alias().clone()))
+        {
+            Err(err) => deny!(executor, err),
+            Ok(account_id) => account_id,
+        };
+        execute_alias_change(executor, &account_id, isi);
+    }
+
     fn is_permission_account_associated(permission: &Permission, account_id: &AccountId) -> bool {
         let Ok(permission) = AnyPermission::try_from(permission) else {
             return false;
@@ -531,6 +676,7 @@ pub mod account {
             | AnyPermission::CanMintAssetWithDefinition(_)
             | AnyPermission::CanBurnAssetWithDefinition(_)
             | AnyPermission::CanTransferAssetWithDefinition(_)
+            | AnyPermission::CanHoldAssetWithDefinition(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanManageRoles(_)
             | AnyPermission::CanUpgradeExecutor(_) => false,
@@ -715,6 +861,28 @@ pub mod asset_definition {
         );
     }
 
+    pub fn visit_set_mint_cap<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &SetMintCap) {
+        let asset_definition_id = isi.asset_definition();
+
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_asset_definition_owner(
+            asset_definition_id,
+            &executor.context().authority,
+            executor.host(),
+        ) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't set the mint cap of the asset definition owned by another account"
+        );
+    }
+
     fn is_permission_asset_definition_associated(
         permission: &Permission,
         asset_definition_id: &AssetDefinitionId,
@@ -744,6 +912,9 @@ pub mod asset_definition {
             AnyPermission::CanTransferAssetWithDefinition(permission) => {
                 &permission.asset_definition == asset_definition_id
             }
+            AnyPermission::CanHoldAssetWithDefinition(permission) => {
+                &permission.asset_definition == asset_definition_id
+            }
             AnyPermission::CanUnregisterAsset(permission) => {
                 permission.asset.definition() == asset_definition_id
             }
@@ -782,12 +953,14 @@ pub mod asset_definition {
 
 pub mod asset {
     use iroha_executor_data_model::permission::asset::{
-        CanBurnAsset, CanBurnAssetWithDefinition, CanMintAsset, CanMintAssetWithDefinition,
-        CanModifyAssetMetadata, CanRegisterAsset, CanRegisterAssetWithDefinition, CanTransferAsset,
-        CanTransferAssetWithDefinition, CanUnregisterAsset, CanUnregisterAssetWithDefinition,
+        CanBurnAsset, CanBurnAssetWithDefinition, CanHoldAssetWithDefinition, CanMintAsset,
+        CanMintAssetWithDefinition, CanModifyAssetMetadata, CanRegisterAsset,
+        CanRegisterAssetWithDefinition, CanTransferAsset, CanTransferAssetWithDefinition,
+        CanUnregisterAsset, CanUnregisterAssetWithDefinition,
     };
-    use iroha_smart_contract::data_model::{
-        asset::AssetValue, isi::BuiltInInstruction, metadata::Metadata,
+    use iroha_smart_contract::{
+        data_model::{asset::AssetValue, isi::BuiltInInstruction, metadata::Metadata},
+        Iroha,
     };
     use iroha_smart_contract_utils::Encode;
 
@@ -979,15 +1152,94 @@ pub mod asset {
         Q: Into<AssetValue>,
         Transfer<Asset, Q, Account>: BuiltInInstruction + Encode,
     {
-        let asset_id = isi.source();
         if executor.context().curr_block.is_genesis() {
             execute!(executor, isi);
         }
-        match is_asset_owner(asset_id, &executor.context().authority, executor.host()) {
+        match can_transfer_asset(isi.source(), &executor.context().authority, executor.host()) {
             Err(err) => deny!(executor, err),
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
+
+        deny!(executor, "Can't transfer assets of another account");
+    }
+
+    /// Check if `authority` is allowed to transfer the asset: it owns the asset or
+    /// its definition, or has been granted a transfer permission.
+    fn can_transfer_asset(
+        asset_id: &AssetId,
+        authority: &AccountId,
+        host: &Iroha,
+    ) -> Result<bool, ValidationFail> {
+        if is_asset_owner(asset_id, authority, host)?
+            || is_asset_definition_owner(asset_id.definition(), authority, host)?
+        {
+            return Ok(true);
+        }
+        let can_transfer_assets_with_definition_token = CanTransferAssetWithDefinition {
+            asset_definition: asset_id.definition().clone(),
+        };
+        let can_transfer_user_asset_token = CanTransferAsset {
+            asset: asset_id.clone(),
+        };
+
+        Ok(
+            can_transfer_assets_with_definition_token.is_owned_by(authority, host)
+                || can_transfer_user_asset_token.is_owned_by(authority, host),
+        )
+    }
+
+    pub fn visit_transfer_asset_numeric<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Transfer<Asset, Numeric, Account>,
+    ) {
+        execute_transfer_asset(executor, isi);
+    }
+
+    pub fn visit_transfer_asset_store<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Transfer<Asset, Metadata, Account>,
+    ) {
+        execute_transfer_asset(executor, isi);
+    }
+
+    pub fn visit_approve<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Approve) {
+        let asset_id = isi.allowance().asset();
+
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_asset_owner(&asset_id, &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't approve spending of another account's asset"
+        );
+    }
+
+    pub fn visit_transfer_from<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &TransferFrom) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        if isi.allowance().spender() == &executor.context().authority {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Only the spender can use an allowance");
+    }
+
+    fn execute_hold_or_release<V, I>(executor: &mut V, asset_id: &AssetId, isi: &I)
+    where
+        V: Execute + Visit + ?Sized,
+        I: BuiltInInstruction + Encode,
+    {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
         match is_asset_definition_owner(
             asset_id.definition(),
             &executor.context().authority,
@@ -997,37 +1249,103 @@ pub mod asset {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        let can_transfer_assets_with_definition_token = CanTransferAssetWithDefinition {
+        let can_hold_assets_with_definition_token = CanHoldAssetWithDefinition {
             asset_definition: asset_id.definition().clone(),
         };
-        if can_transfer_assets_with_definition_token
+        if can_hold_assets_with_definition_token
             .is_owned_by(&executor.context().authority, executor.host())
         {
             execute!(executor, isi);
         }
-        let can_transfer_user_asset_token = CanTransferAsset {
-            asset: asset_id.clone(),
-        };
-        if can_transfer_user_asset_token.is_owned_by(&executor.context().authority, executor.host())
-        {
+
+        deny!(
+            executor,
+            "Can't hold or release assets with definitions registered by other accounts"
+        );
+    }
+
+    pub fn visit_hold_asset<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &HoldAsset) {
+        execute_hold_or_release(executor, isi.asset(), isi);
+    }
+
+    pub fn visit_release_asset<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &ReleaseAsset) {
+        execute_hold_or_release(executor, isi.asset(), isi);
+    }
+
+    pub fn visit_lock_asset<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &LockAsset) {
+        if executor.context().curr_block.is_genesis() {
             execute!(executor, isi);
         }
+        match can_transfer_asset(
+            &isi.lock().asset(),
+            &executor.context().authority,
+            executor.host(),
+        ) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
 
-        deny!(executor, "Can't transfer assets of another account");
+        deny!(executor, "Can't lock assets of another account");
     }
 
-    pub fn visit_transfer_asset_numeric<V: Execute + Visit + ?Sized>(
+    /// Anyone knowing the preimage can claim: the lock itself fixes where the asset goes.
+    pub fn visit_claim_locked_asset<V: Execute + Visit + ?Sized>(
         executor: &mut V,
-        isi: &Transfer<Asset, Numeric, Account>,
+        isi: &ClaimLockedAsset,
     ) {
-        execute_transfer_asset(executor, isi);
+        execute!(executor, isi);
     }
 
-    pub fn visit_transfer_asset_store<V: Execute + Visit + ?Sized>(
+    /// Anyone can refund an expired lock: the asset can only go back to the sender.
+    pub fn visit_refund_locked_asset<V: Execute + Visit + ?Sized>(
         executor: &mut V,
-        isi: &Transfer<Asset, Metadata, Account>,
+        isi: &RefundLockedAsset,
     ) {
-        execute_transfer_asset(executor, isi);
+        execute!(executor, isi);
+    }
+
+    pub fn visit_propose_swap<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &ProposeSwap) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match can_transfer_asset(
+            isi.offer().offered().asset(),
+            &executor.context().authority,
+            executor.host(),
+        ) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't offer assets of another account");
+    }
+
+    pub fn visit_accept_swap<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &AcceptSwap) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match can_transfer_asset(
+            isi.offer().requested().asset(),
+            &executor.context().authority,
+            executor.host(),
+        ) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't accept swaps paid with assets of another account"
+        );
+    }
+
+    /// The proposer can cancel an offer at any time, anyone else once it has expired,
+    /// which is checked when the instruction is executed.
+    pub fn visit_cancel_swap<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &CancelSwap) {
+        execute!(executor, isi);
     }
 
     pub fn visit_set_asset_key_value<V: Execute + Visit + ?Sized>(
@@ -1547,6 +1865,7 @@ pub mod trigger {
             | AnyPermission::CanMintAssetWithDefinition(_)
             | AnyPermission::CanBurnAssetWithDefinition(_)
             | AnyPermission::CanTransferAssetWithDefinition(_)
+            | AnyPermission::CanHoldAssetWithDefinition(_)
             | AnyPermission::CanModifyAssetMetadata(_)
             | AnyPermission::CanMintAsset(_)
             | AnyPermission::CanBurnAsset(_)
@@ -107,6 +107,7 @@ declare_permissions! {
     iroha_executor_data_model::permission::asset::{CanMintAssetWithDefinition},
     iroha_executor_data_model::permission::asset::{CanBurnAssetWithDefinition},
     iroha_executor_data_model::permission::asset::{CanTransferAssetWithDefinition},
+    iroha_executor_data_model::permission::asset::{CanHoldAssetWithDefinition},
     iroha_executor_data_model::permission::asset::{CanRegisterAsset},
     iroha_executor_data_model::permission::asset::{CanUnregisterAsset},
     iroha_executor_data_model::permission::asset::{CanMintAsset},
@@ -290,9 +291,10 @@ pub mod asset {
     //! Module with pass conditions for asset related tokens
 
     use iroha_executor_data_model::permission::asset::{
-        CanBurnAsset, CanBurnAssetWithDefinition, CanMintAsset, CanMintAssetWithDefinition,
-        CanModifyAssetMetadata, CanRegisterAsset, CanRegisterAssetWithDefinition, CanTransferAsset,
-        CanTransferAssetWithDefinition, CanUnregisterAsset, CanUnregisterAssetWithDefinition,
+        CanBurnAsset, CanBurnAssetWithDefinition, CanHoldAssetWithDefinition, CanMintAsset,
+        CanMintAssetWithDefinition, CanModifyAssetMetadata, CanRegisterAsset,
+        CanRegisterAssetWithDefinition, CanTransferAsset, CanTransferAssetWithDefinition,
+        CanUnregisterAsset, CanUnregisterAssetWithDefinition,
     };
 
     use super::*;
@@ -399,6 +401,20 @@ pub mod asset {
         }
     }
 
+    impl ValidateGrantRevoke for CanHoldAssetWithDefinition {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+    }
+
     impl ValidateGrantRevoke for CanRegisterAsset {
         fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
             super::account::Owner::from(self).validate(authority, host, context)
@@ -644,6 +660,7 @@ pub mod asset_definition {
         iroha_executor_data_model::permission::asset::CanMintAssetWithDefinition,
         iroha_executor_data_model::permission::asset::CanBurnAssetWithDefinition,
         iroha_executor_data_model::permission::asset::CanTransferAssetWithDefinition,
+        iroha_executor_data_model::permission::asset::CanHoldAssetWithDefinition,
     );
 }
 
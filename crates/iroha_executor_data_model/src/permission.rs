@@ -138,6 +138,12 @@ pub mod asset {
         }
     }
 
+    permission! {
+        pub struct CanHoldAssetWithDefinition {
+            pub asset_definition: AssetDefinitionId,
+        }
+    }
+
     permission! {
         pub struct CanRegisterAsset {
             pub owner: AccountId,
@@ -153,6 +153,23 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_burn_trigger_repetitions(operation: &Burn<u32, Trigger>)",
         "fn visit_execute_trigger(operation: &ExecuteTrigger)",
         "fn visit_set_parameter(operation: &SetParameter)",
+        "fn visit_set_mint_cap(operation: &SetMintCap)",
+        "fn visit_approve(operation: &Approve)",
+        "fn visit_transfer_from(operation: &TransferFrom)",
+        "fn visit_hold_asset(operation: &HoldAsset)",
+        "fn visit_release_asset(operation: &ReleaseAsset)",
+        "fn visit_lock_asset(operation: &LockAsset)",
+        "fn visit_claim_locked_asset(operation: &ClaimLockedAsset)",
+        "fn visit_refund_locked_asset(operation: &RefundLockedAsset)",
+        "fn visit_propose_swap(operation: &ProposeSwap)",
+        "fn visit_accept_swap(operation: &AcceptSwap)",
+        "fn visit_cancel_swap(operation: &CancelSwap)",
+        "fn visit_add_signatory(operation: &AddSignatory)",
+        "fn visit_remove_signatory(operation: &RemoveSignatory)",
+        "fn visit_rotate_key(operation: &RotateKey)",
+        "fn visit_register_account_alias(operation: &RegisterAccountAlias)",
+        "fn visit_transfer_account_alias(operation: &TransferAccountAlias)",
+        "fn visit_unregister_account_alias(operation: &UnregisterAccountAlias)",
         "fn visit_upgrade(operation: &Upgrade)",
         "fn visit_log(operation: &Log)",
         "fn visit_custom_instruction(operation: &CustomInstruction)",
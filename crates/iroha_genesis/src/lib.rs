@@ -151,9 +151,13 @@ impl RawGenesisTransaction {
         let upgrade_executor = Upgrade::new(Executor::new(self.executor.try_into()?)).into();
         instructions_list.push(vec![upgrade_executor]);
 
+        let mut fee_schedule = None;
         if let Some(parameters) = self.parameters {
+            fee_schedule = parameters.fee().clone();
             let instructions = parameters
                 .parameters()
+                // Fee asset and collector are registered by the genesis instructions
+                .filter(|parameter| !matches!(parameter, Parameter::Fee(_)))
                 .map(SetParameter::new)
                 .map(InstructionBox::from)
                 .collect();
@@ -164,6 +168,12 @@ impl RawGenesisTransaction {
             instructions_list.push(self.instructions);
         }
 
+        if let Some(fee_schedule) = fee_schedule {
+            instructions_list.push(vec![
+                SetParameter::new(Parameter::Fee(Some(fee_schedule))).into()
+            ]);
+        }
+
         if !self.wasm_triggers.is_empty() {
             let instructions = self
                 .wasm_triggers
@@ -3,7 +3,10 @@
 //! types are included in the schema.
 use iroha_crypto::MerkleTree;
 use iroha_data_model::{
-    block::stream::{BlockMessage, BlockSubscriptionRequest},
+    block::{
+        stream::{BlockMessage, BlockSubscriptionRequest},
+        TransactionInclusionProof,
+    },
     query::{QueryResponse, SignedQuery},
 };
 use iroha_schema::prelude::*;
@@ -59,6 +62,12 @@ pub fn build_schemas() -> MetaMap {
         BlockMessage,
         BlockSubscriptionRequest,
 
+        // Transaction inclusion proof
+        TransactionInclusionProof,
+
+        // Transaction simulation
+        TransactionSimulation,
+
         // Never referenced, but present in type signature. Like `PhantomData<X>`
         MerkleTree<SignedTransaction>,
 
@@ -82,6 +91,7 @@ pub fn build_schemas() -> MetaMap {
         permission::asset::CanMintAssetWithDefinition,
         permission::asset::CanBurnAssetWithDefinition,
         permission::asset::CanTransferAssetWithDefinition,
+        permission::asset::CanHoldAssetWithDefinition,
         permission::asset::CanRegisterAsset,
         permission::asset::CanUnregisterAsset,
         permission::asset::CanMintAsset,
@@ -113,7 +123,10 @@ pub fn build_schemas() -> MetaMap {
 }
 
 types!(
+    AcceptSwap,
     Account,
+    AccountAlias,
+    AccountAliasChanged,
     AccountEvent,
     AccountEventFilter,
     AccountEventSet,
@@ -126,11 +139,27 @@ types!(
     AccountProjection<PredicateMarker>,
     AccountProjection<SelectorMarker>,
     AccountRoleChanged,
+    AccountSignatoryChanged,
     Action,
+    AggregateGroups,
+    AggregateOutput,
+    AggregateQuery,
+    AggregateValue,
+    Aggregation,
     ActionPredicateAtom,
     ActionProjection<PredicateMarker>,
     ActionProjection<SelectorMarker>,
+    AddSignatory,
     Algorithm,
+    Allowance,
+    AllowanceId,
+    AllowanceIdPredicateAtom,
+    AllowanceIdProjection<PredicateMarker>,
+    AllowanceIdProjection<SelectorMarker>,
+    AllowancePredicateAtom,
+    AllowanceProjection<PredicateMarker>,
+    AllowanceProjection<SelectorMarker>,
+    Approve,
     Asset,
     AssetChanged,
     AssetDefinition,
@@ -149,6 +178,10 @@ types!(
     AssetEvent,
     AssetEventFilter,
     AssetEventSet,
+    AssetHold,
+    AssetHoldPredicateAtom,
+    AssetHoldProjection<PredicateMarker>,
+    AssetHoldProjection<SelectorMarker>,
     AssetId,
     AssetIdPredicateAtom,
     AssetIdProjection<PredicateMarker>,
@@ -187,38 +220,48 @@ types!(
     BlockStatus,
     BlockSubscriptionRequest,
     Box<CompoundPredicate<Account>>,
+    Box<CompoundPredicate<Allowance>>,
     Box<CompoundPredicate<AssetDefinition>>,
     Box<CompoundPredicate<Asset>>,
+    Box<CompoundPredicate<AssetHold>>,
     Box<CompoundPredicate<BlockHeader>>,
     Box<CompoundPredicate<CommittedTransaction>>,
     Box<CompoundPredicate<Domain>>,
+    Box<CompoundPredicate<HashLock>>,
     Box<CompoundPredicate<PeerId>>,
     Box<CompoundPredicate<Permission>>,
     Box<CompoundPredicate<RoleId>>,
     Box<CompoundPredicate<Role>>,
     Box<CompoundPredicate<SignedBlock>>,
+    Box<CompoundPredicate<SwapOffer>>,
     Box<CompoundPredicate<TriggerId>>,
     Box<CompoundPredicate<Trigger>>,
     Box<TransactionRejectionReason>,
     Burn<Numeric, Asset>,
     Burn<u32, Trigger>,
     BurnBox,
+    CancelSwap,
     ChainId,
+    ClaimLockedAsset,
     CommittedTransaction,
     CommittedTransactionPredicateAtom,
     CommittedTransactionProjection<PredicateMarker>,
     CommittedTransactionProjection<SelectorMarker>,
     CompoundPredicate<Account>,
+    CompoundPredicate<Allowance>,
     CompoundPredicate<AssetDefinition>,
     CompoundPredicate<Asset>,
+    CompoundPredicate<AssetHold>,
     CompoundPredicate<BlockHeader>,
     CompoundPredicate<CommittedTransaction>,
     CompoundPredicate<Domain>,
+    CompoundPredicate<HashLock>,
     CompoundPredicate<PeerId>,
     CompoundPredicate<Permission>,
     CompoundPredicate<RoleId>,
     CompoundPredicate<Role>,
     CompoundPredicate<SignedBlock>,
+    CompoundPredicate<SwapOffer>,
     CompoundPredicate<TriggerId>,
     CompoundPredicate<Trigger>,
     ConfigurationEvent,
@@ -258,17 +301,40 @@ types!(
     ExecutorEvent,
     ExecutorEventFilter,
     ExecutorEventSet,
+    FindSwapOffers,
+    ProposeSwap,
+    QueryWithFilter<FindSwapOffers>,
+    SelectorTuple<SwapOffer>,
+    SwapLeg,
+    SwapOffer,
+    SwapOfferId,
+    SwapOfferIdPredicateAtom,
+    SwapOfferIdProjection<PredicateMarker>,
+    SwapOfferIdProjection<SelectorMarker>,
+    SwapOfferPredicateAtom,
+    SwapOfferProjection<PredicateMarker>,
+    SwapOfferProjection<SelectorMarker>,
+    Vec<CompoundPredicate<SwapOffer>>,
+    Vec<SwapOffer>,
+    Vec<SwapOfferId>,
+    Vec<SwapOfferProjection<SelectorMarker>>,
     WasmPath,
     ExecutorUpgrade,
+    FeeSchedule,
     FetchSize,
+    FindAccountIdByAlias,
     FindAccounts,
     FindAccountsWithAsset,
+    FindAliasesByAccountId,
     FindActiveTriggerIds,
+    FindAllowances,
+    FindAssetHolds,
     FindAssets,
     FindAssetsDefinitions,
     FindBlockHeaders,
     FindBlocks,
     FindDomains,
+    FindHashLocks,
     FindError,
     FindExecutorDataModel,
     FindParameters,
@@ -277,6 +343,7 @@ types!(
     FindRoleIds,
     FindRoles,
     FindRolesByAccountId,
+    FindTemporaryGrantsByAccountId,
     FindTransactions,
     FindTriggers,
     ForwardCursor,
@@ -286,8 +353,18 @@ types!(
     Grant<Permission, Role>,
     Grant<RoleId, Account>,
     GrantBox,
+    GrantedObject,
     Hash,
+    HashLock,
+    HashLockId,
+    HashLockIdPredicateAtom,
+    HashLockIdProjection<PredicateMarker>,
+    HashLockIdProjection<SelectorMarker>,
+    HashLockPredicateAtom,
+    HashLockProjection<PredicateMarker>,
+    HashLockProjection<SelectorMarker>,
     HashOf<BlockHeader>,
+    HoldAsset,
     HashOf<MerkleTree<SignedTransaction>>,
     HashOf<SignedTransaction>,
     HashOf<Vec<InstructionBox>>,
@@ -302,12 +379,16 @@ types!(
     Ipv6Addr,
     Ipv4Addr,
     Json,
+    JsonPointerPredicate,
     JsonPredicateAtom,
+    JsonValuePredicateAtom,
     JsonProjection<PredicateMarker>,
     JsonProjection<SelectorMarker>,
     Level,
+    LockAsset,
     Log,
     MathError,
+    MerkleProof<SignedTransaction>,
     MerkleTree<SignedTransaction>,
     Metadata,
     MetadataChanged<AccountId>,
@@ -340,12 +421,14 @@ types!(
     NumericPredicateAtom,
     NumericProjection<PredicateMarker>,
     NumericProjection<SelectorMarker>,
+    NumericRange,
     NumericSpec,
     Option<AccountId>,
     Option<AssetDefinitionId>,
     Option<AssetId>,
     Option<BlockStatus>,
     Option<DomainId>,
+    Option<FeeSchedule>,
     Option<ForwardCursor>,
     Option<HashOf<BlockHeader>>,
     Option<HashOf<SignedTransaction>>,
@@ -353,10 +436,12 @@ types!(
     Option<Name>,
     Option<NonZeroU32>,
     Option<NonZeroU64>,
+    Option<Numeric>,
     Option<Option<NonZeroU64>>,
     Option<Parameters>,
     Option<PeerId>,
     Option<RoleId>,
+    Option<TransactionFee>,
     Option<TransactionRejectionReason>,
     Option<TransactionStatus>,
     Option<TriggerCompletedOutcomeType>,
@@ -399,11 +484,14 @@ types!(
     QueryWithFilter<FindAccounts>,
     QueryWithFilter<FindAccountsWithAsset>,
     QueryWithFilter<FindActiveTriggerIds>,
+    QueryWithFilter<FindAllowances>,
+    QueryWithFilter<FindAssetHolds>,
     QueryWithFilter<FindAssets>,
     QueryWithFilter<FindAssetsDefinitions>,
     QueryWithFilter<FindBlockHeaders>,
     QueryWithFilter<FindBlocks>,
     QueryWithFilter<FindDomains>,
+    QueryWithFilter<FindHashLocks>,
     QueryWithFilter<FindPeers>,
     QueryWithFilter<FindPermissionsByAccountId>,
     QueryWithFilter<FindRoleIds>,
@@ -419,13 +507,17 @@ types!(
     Register<Peer>,
     Register<Role>,
     Register<Trigger>,
+    RegisterAccountAlias,
     RegisterBox,
+    RefundLockedAsset,
+    ReleaseAsset,
     RemoveKeyValue<Account>,
     RemoveKeyValue<Asset>,
     RemoveKeyValue<AssetDefinition>,
     RemoveKeyValue<Domain>,
     RemoveKeyValue<Trigger>,
     RemoveKeyValueBox,
+    RemoveSignatory,
     Repeats,
     RepetitionError,
     Revoke<Permission, Account>,
@@ -444,12 +536,16 @@ types!(
     RolePredicateAtom,
     RoleProjection<PredicateMarker>,
     RoleProjection<SelectorMarker>,
+    RotateKey,
     SelectorTuple<Account>,
+    SelectorTuple<Allowance>,
     SelectorTuple<AssetDefinition>,
     SelectorTuple<Asset>,
+    SelectorTuple<AssetHold>,
     SelectorTuple<BlockHeader>,
     SelectorTuple<CommittedTransaction>,
     SelectorTuple<Domain>,
+    SelectorTuple<HashLock>,
     SelectorTuple<PeerId>,
     SelectorTuple<Permission>,
     SelectorTuple<RoleId>,
@@ -463,7 +559,9 @@ types!(
     SetKeyValue<Domain>,
     SetKeyValue<Trigger>,
     SetKeyValueBox,
+    SetMintCap,
     SetParameter,
+    Sha256Hash,
     Signature,
     SignatureOf<BlockHeader>,
     SignatureOf<QueryRequestWithAuthority>,
@@ -482,17 +580,22 @@ types!(
     SignedTransactionV1,
     SingularQueryBox,
     SingularQueryOutputBox,
+    SingularQueryWithParams,
     SmartContractParameter,
     SmartContractParameters,
     SocketAddr,
     SocketAddrHost,
     SocketAddrV4,
     SocketAddrV6,
+    SortKey,
+    SortOrder,
+    SortSelector,
     Sorting,
     String,
     StringPredicateAtom,
     SumeragiParameter,
     SumeragiParameters,
+    TemporaryGrant,
     TimeEvent,
     TimeEventFilter,
     TimeInterval,
@@ -502,21 +605,26 @@ types!(
     TransactionErrorProjection<SelectorMarker>,
     TransactionEvent,
     TransactionEventFilter,
+    TransactionFee,
     TransactionHashPredicateAtom,
     TransactionHashProjection<PredicateMarker>,
     TransactionHashProjection<SelectorMarker>,
+    TransactionInclusionProof,
     TransactionLimitError,
     TransactionParameter,
     TransactionParameters,
     TransactionPayload,
     TransactionRejectionReason,
     TransactionSignature,
+    TransactionSimulation,
     TransactionStatus,
     Transfer<Account, AssetDefinitionId, Account>,
     Transfer<Account, DomainId, Account>,
     Transfer<Asset, Metadata, Account>,
     Transfer<Asset, Numeric, Account>,
+    TransferAccountAlias,
     TransferBox,
+    TransferFrom,
     Trigger,
     TriggerCompletedEvent,
     TriggerCompletedEventFilter,
@@ -534,6 +642,9 @@ types!(
     TriggerProjection<PredicateMarker>,
     TriggerProjection<SelectorMarker>,
     TypeError,
+    U64PredicateAtom,
+    U64Projection<PredicateMarker>,
+    U64Projection<SelectorMarker>,
     Unregister<Account>,
     Unregister<Asset>,
     Unregister<AssetDefinition>,
@@ -541,13 +652,19 @@ types!(
     Unregister<Peer>,
     Unregister<Role>,
     Unregister<Trigger>,
+    UnregisterAccountAlias,
     UnregisterBox,
     Upgrade,
     ValidationFail,
     Vec<Account>,
+    Vec<AccountAlias>,
     Vec<AccountId>,
     Vec<Action>,
+    Vec<AggregateValue>,
+    Vec<Allowance>,
+    Vec<AllowanceId>,
     Vec<Asset>,
+    Vec<AssetHold>,
     Vec<AssetId>,
     Vec<AssetDefinition>,
     Vec<AssetDefinitionId>,
@@ -557,10 +674,13 @@ types!(
     Vec<CommittedTransaction>,
     Vec<CompoundPredicate<Account>>,
     Vec<CompoundPredicate<AssetDefinition>>,
+    Vec<CompoundPredicate<Allowance>>,
     Vec<CompoundPredicate<Asset>>,
+    Vec<CompoundPredicate<AssetHold>>,
     Vec<CompoundPredicate<BlockHeader>>,
     Vec<CompoundPredicate<CommittedTransaction>>,
     Vec<CompoundPredicate<Domain>>,
+    Vec<CompoundPredicate<HashLock>>,
     Vec<CompoundPredicate<PeerId>>,
     Vec<CompoundPredicate<Permission>>,
     Vec<CompoundPredicate<RoleId>>,
@@ -572,6 +692,8 @@ types!(
     Vec<DomainId>,
     Vec<EventFilterBox>,
     Vec<GenesisWasmTrigger>,
+    Vec<HashLock>,
+    Vec<HashLockId>,
     Vec<InstructionBox>,
     Vec<Json>,
     Vec<Parameter>,
@@ -582,17 +704,23 @@ types!(
     Vec<RoleId>,
     Vec<SignedBlock>,
     Vec<SignedTransaction>,
+    Vec<TemporaryGrant>,
     Vec<AccountProjection<SelectorMarker>>,
+    Vec<AllowanceProjection<SelectorMarker>>,
     Vec<AssetDefinitionProjection<SelectorMarker>>,
+    Vec<AssetHoldProjection<SelectorMarker>>,
     Vec<AssetProjection<SelectorMarker>>,
     Vec<BlockHeaderProjection<SelectorMarker>>,
     Vec<CommittedTransactionProjection<SelectorMarker>>,
+    Vec<DataEvent>,
     Vec<DomainProjection<SelectorMarker>>,
+    Vec<HashLockProjection<SelectorMarker>>,
     Vec<HashOf<BlockHeader>>,
     Vec<HashOf<SignedTransaction>>,
     Vec<Metadata>,
     Vec<Name>,
     Vec<Numeric>,
+    Vec<Option<HashOf<SignedTransaction>>>,
     Vec<Option<TransactionRejectionReason>>,
     Vec<PeerIdProjection<SelectorMarker>>,
     Vec<PermissionProjection<SelectorMarker>>,
@@ -605,7 +733,9 @@ types!(
     Vec<TriggerProjection<SelectorMarker>>,
     Vec<Trigger>,
     Vec<TriggerId>,
+    Vec<SortKey>,
     Vec<u8>,
+    Vec<u64>,
     WasmExecutionFail,
     WasmSmartContract,
 
@@ -636,6 +766,7 @@ pub mod complete_data_model {
             error::BlockRejectionReason,
             stream::{BlockMessage, BlockSubscriptionRequest},
             BlockHeader, BlockPayload, BlockSignature, SignedBlock, SignedBlockV1,
+            TransactionInclusionProof,
         },
         domain::NewDomain,
         events::pipeline::{BlockEventFilter, TransactionEventFilter},
@@ -655,12 +786,14 @@ pub mod complete_data_model {
         },
         prelude::*,
         query::{
+            aggregate::{AggregateGroups, AggregateQuery},
             dsl::{CompoundPredicate, PredicateMarker, SelectorMarker},
             error::{FindError, QueryExecutionFail},
-            parameters::{ForwardCursor, QueryParams},
+            parameters::{ForwardCursor, QueryParams, SortKey, SortOrder, SortSelector},
             CommittedTransaction, QueryOutput, QueryOutputBatchBox, QueryOutputBatchBoxTuple,
             QueryRequestWithAuthority, QueryResponse, QuerySignature, QueryWithFilter,
             QueryWithParams, SignedQuery, SignedQueryV1, SingularQueryOutputBox,
+            SingularQueryWithParams,
         },
         transaction::{
             error::TransactionLimitError, SignedTransactionV1, TransactionPayload,
@@ -742,6 +875,9 @@ mod tests {
         insert_into_test_map!(
             iroha_executor_data_model::permission::asset::CanTransferAssetWithDefinition
         );
+        insert_into_test_map!(
+            iroha_executor_data_model::permission::asset::CanHoldAssetWithDefinition
+        );
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanRegisterAsset);
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanUnregisterAsset);
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanMintAsset);
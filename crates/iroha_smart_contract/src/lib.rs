@@ -16,6 +16,7 @@ use data_model::{
 };
 pub use iroha_data_model as data_model;
 use iroha_data_model::query::{
+    aggregate::{AggregateOutput, AggregateQuery},
     builder::{QueryBuilder, QueryExecutor},
     QueryOutputBatchBoxTuple, QueryRequest, QueryResponse, QueryWithParams, SingularQuery,
     SingularQueryBox, SingularQueryOutputBox,
@@ -149,7 +150,8 @@ impl QueryExecutor for Iroha {
         &self,
         query: SingularQueryBox,
     ) -> Result<SingularQueryOutputBox, Self::Error> {
-        let QueryResponse::Singular(output) = Self::execute_query(&QueryRequest::Singular(query))?
+        let QueryResponse::Singular(output) =
+            Self::execute_query(&QueryRequest::Singular(query.into()))?
         else {
             dbg_panic!("BUG: iroha returned unexpected type in singular query");
         };
@@ -192,6 +194,19 @@ impl QueryExecutor for Iroha {
             cursor.map(|cursor| QueryCursor { cursor }),
         ))
     }
+
+    fn execute_aggregate_query(
+        &self,
+        query: AggregateQuery,
+    ) -> Result<AggregateOutput, Self::Error> {
+        let QueryResponse::Aggregate(output) =
+            Self::execute_query(&QueryRequest::Aggregate(query))?
+        else {
+            dbg_panic!("BUG: iroha returned unexpected type in aggregate query");
+        };
+
+        Ok(output)
+    }
 }
 
 #[no_mangle]
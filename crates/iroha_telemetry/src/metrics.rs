@@ -68,6 +68,9 @@ pub struct Status {
     /// Number of the transactions in the queue
     #[codec(compact)]
     pub queue_size: u64,
+    /// Number of the scheduled transactions held in the queue until they become valid
+    #[codec(compact)]
+    pub scheduled_queue_size: u64,
 }
 
 impl<T: Deref<Target = Metrics>> From<&T> for Status {
@@ -85,6 +88,7 @@ impl<T: Deref<Target = Metrics>> From<&T> for Status {
                 .try_into()
                 .expect("INTERNAL BUG: Number of view changes exceeds u32::MAX"),
             queue_size: val.queue_size.get(),
+            scheduled_queue_size: val.scheduled_queue_size.get(),
         }
     }
 }
@@ -114,8 +118,12 @@ pub struct Metrics {
     pub view_changes: ViewChangesGauge,
     /// Number of transactions in the queue
     pub queue_size: GenericGauge<AtomicU64>,
+    /// Number of scheduled transactions in the queue
+    pub scheduled_queue_size: GenericGauge<AtomicU64>,
     /// Number of sumeragi dropped messages
     pub dropped_messages: DroppedMessagesCounter,
+    /// Number of requests rejected by the Torii rate limits
+    pub throttled_requests: IntCounterVec,
     /// Internal use only. Needed for generating the response.
     registry: Registry,
 }
@@ -164,8 +172,21 @@ impl Default for Metrics {
         .expect("Infallible");
         let queue_size = GenericGauge::new("queue_size", "Number of the transactions in the queue")
             .expect("Infallible");
+        let scheduled_queue_size = GenericGauge::new(
+            "scheduled_queue_size",
+            "Number of the scheduled transactions in the queue",
+        )
+        .expect("Infallible");
         let dropped_messages =
             IntCounter::new("dropped_messages", "Sumeragi dropped messages").expect("Infallible");
+        let throttled_requests = IntCounterVec::new(
+            Opts::new(
+                "throttled_requests",
+                "Requests rejected by the Torii rate limits",
+            ),
+            &["endpoint", "limit"],
+        )
+        .expect("Infallible");
         let registry = Registry::new();
 
         macro_rules! register {
@@ -190,7 +211,9 @@ impl Default for Metrics {
             isi_times,
             view_changes,
             queue_size,
-            dropped_messages
+            scheduled_queue_size,
+            dropped_messages,
+            throttled_requests
         );
 
         Self {
@@ -205,7 +228,9 @@ impl Default for Metrics {
             isi_times,
             view_changes,
             queue_size,
+            scheduled_queue_size,
             dropped_messages,
+            throttled_requests,
             registry,
         }
     }
@@ -254,6 +279,7 @@ mod test {
             uptime: Uptime(Duration::new(5, 937_000_000)),
             view_changes: 2,
             queue_size: 18,
+            scheduled_queue_size: 2,
         }
     }
 
@@ -275,7 +301,8 @@ mod test {
                 "nanos": 937000000
               },
               "view_changes": 2,
-              "queue_size": 18
+              "queue_size": 18,
+              "scheduled_queue_size": 2
             }"#]];
         expected.assert_eq(&actual);
     }
@@ -288,7 +315,7 @@ mod test {
         let actual = hex::encode_upper(bytes);
         // CAUTION: if this is outdated, make sure to update the documentation:
         // https://docs.iroha.tech/reference/torii-endpoints.html#status
-        let expected = expect_test::expect!["10147C0C14407CD9370848"];
+        let expected = expect_test::expect!["10147C0C14407CD937084808"];
         expected.assert_eq(&actual);
     }
 }
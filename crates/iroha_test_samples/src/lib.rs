@@ -7,7 +7,11 @@ use std::{
 };
 
 use iroha_crypto::KeyPair;
-use iroha_data_model::prelude::{AccountId, WasmSmartContract};
+use iroha_data_model::{
+    isi::error::InstructionExecutionError,
+    prelude::{AccountId, ValidationFail, WasmSmartContract},
+    transaction::error::TransactionRejectionReason,
+};
 
 /// Generate [`AccountId`](iroha_data_model::account::AccountId) in the given `domain`.
 ///
@@ -126,3 +130,14 @@ pub fn load_sample_wasm(name: impl AsRef<str>) -> WasmSmartContract {
         Ok(blob) => WasmSmartContract::from_compiled(blob),
     }
 }
+
+/// Check if a transaction was rejected because one of its instructions violated
+/// an invariant of the world state.
+pub fn is_invariant_violation(err: &eyre::Report) -> bool {
+    matches!(
+        err.downcast_ref::<TransactionRejectionReason>(),
+        Some(TransactionRejectionReason::Validation(
+            ValidationFail::InstructionFailed(InstructionExecutionError::InvariantViolation(_))
+        ))
+    )
+}
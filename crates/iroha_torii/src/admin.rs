@@ -0,0 +1,246 @@
+//! Authentication of requests to the administrative endpoints.
+//!
+//! A request is signed over its method, path, timestamp and body (see
+//! [`admin::signature_payload`]) by one of the keys listed in the `torii.admin_public_keys`.
+//! Each signed request is only accepted once.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
+
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{uri::PathAndQuery, HeaderMap},
+    middleware::Next,
+};
+use eyre::eyre;
+use iroha_crypto::{PublicKey, Signature};
+use iroha_torii_const::admin;
+use parking_lot::Mutex;
+
+use super::*;
+
+/// How far the timestamp of a request may deviate from the local time
+const MAX_CLOCK_DRIFT: Duration = Duration::from_secs(30);
+
+/// Signatures of the accepted requests, along with their timestamps.
+///
+/// A signature is kept until its request becomes stale, so that the request can't be replayed.
+#[derive(Debug, Default)]
+pub struct SeenSignatures(Mutex<HashMap<Signature, Duration>>);
+
+impl SeenSignatures {
+    /// Remember the signature, returning `false` if it has already been seen.
+    fn insert(&self, signature: Signature, timestamp: Duration, now: Duration) -> bool {
+        let mut seen = self.0.lock();
+        seen.retain(|_, timestamp| now.abs_diff(*timestamp) <= MAX_CLOCK_DRIFT);
+        seen.insert(signature, timestamp).is_none()
+    }
+}
+
+/// Pass the request further only if it is signed by one of the `public_keys`
+/// and hasn't been passed before.
+pub async fn authenticate(
+    public_keys: Arc<[PublicKey]>,
+    seen_signatures: Arc<SeenSignatures>,
+    max_content_len: usize,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (parts, body) = request.into_parts();
+    let body = match axum::body::to_bytes(body, max_content_len).await {
+        Ok(body) => body,
+        Err(err) => return (StatusCode::PAYLOAD_TOO_LARGE, err.to_string()).into_response(),
+    };
+    let path_and_query = parts
+        .uri
+        .path_and_query()
+        .map_or(parts.uri.path(), PathAndQuery::as_str);
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap();
+
+    if let Err(err) = verify(
+        &public_keys,
+        &seen_signatures,
+        parts.method.as_str(),
+        path_and_query,
+        &parts.headers,
+        &body,
+        now,
+    ) {
+        iroha_logger::warn!(%err, path_and_query, "Rejected request to an administrative endpoint");
+        return err.into_response();
+    }
+
+    next.run(Request::from_parts(parts, Body::from(body))).await
+}
+
+fn verify(
+    public_keys: &[PublicKey],
+    seen_signatures: &SeenSignatures,
+    method: &str,
+    path_and_query: &str,
+    headers: &HeaderMap,
+    body: &[u8],
+    now: Duration,
+) -> Result<()> {
+    let header = |name: &'static str| {
+        headers
+            .get(name)
+            .ok_or_else(|| Error::Unauthorized(eyre!("Missing `{name}` header")))?
+            .to_str()
+            .map_err(|err| Error::Unauthorized(eyre!(err)))
+    };
+
+    let public_key = header(admin::PUBLIC_KEY_HEADER)?
+        .parse::<PublicKey>()
+        .map_err(|err| Error::Unauthorized(eyre!("Invalid public key: {err}")))?;
+    let timestamp_ms = header(admin::TIMESTAMP_HEADER)?
+        .parse::<u64>()
+        .map_err(|err| Error::Unauthorized(eyre!("Invalid timestamp: {err}")))?;
+    let signature = Signature::from_hex(header(admin::SIGNATURE_HEADER)?)
+        .map_err(|err| Error::Unauthorized(eyre!("Invalid signature: {err}")))?;
+
+    let timestamp = Duration::from_millis(timestamp_ms);
+    if now.abs_diff(timestamp) > MAX_CLOCK_DRIFT {
+        return Err(Error::Unauthorized(eyre!(
+            "Request timestamp deviates from the local time by more than {MAX_CLOCK_DRIFT:?}"
+        )));
+    }
+    signature
+        .verify(
+            &public_key,
+            &admin::signature_payload(method, path_and_query, timestamp_ms, body),
+        )
+        .map_err(|err| Error::Unauthorized(eyre!("Signature verification failed: {err}")))?;
+
+    if !public_keys.contains(&public_key) {
+        return Err(Error::Forbidden(public_key));
+    }
+    if !seen_signatures.insert(signature, timestamp, now) {
+        return Err(Error::Unauthorized(eyre!(
+            "Request has already been accepted"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+    use iroha_crypto::KeyPair;
+
+    use super::*;
+
+    const PATH: &str = "/configuration";
+    const BODY: &[u8] = br#"{"logger":{"level":"DEBUG"}}"#;
+    const NOW: Duration = Duration::from_secs(1_700_000_000);
+
+    fn signed_headers(key_pair: &KeyPair, timestamp: Duration) -> HeaderMap {
+        let timestamp_ms = u64::try_from(timestamp.as_millis()).unwrap();
+        let signature = Signature::new(
+            key_pair.private_key(),
+            &admin::signature_payload("POST", PATH, timestamp_ms, BODY),
+        );
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in [
+            (admin::PUBLIC_KEY_HEADER, key_pair.public_key().to_string()),
+            (admin::TIMESTAMP_HEADER, timestamp_ms.to_string()),
+            (admin::SIGNATURE_HEADER, hex::encode(signature.payload())),
+        ] {
+            headers.insert(name, HeaderValue::from_str(&value).unwrap());
+        }
+        headers
+    }
+
+    fn status_of(public_keys: &[PublicKey], headers: &HeaderMap, body: &[u8]) -> StatusCode {
+        match verify(
+            public_keys,
+            &SeenSignatures::default(),
+            "POST",
+            PATH,
+            headers,
+            body,
+            NOW,
+        ) {
+            Ok(()) => StatusCode::OK,
+            Err(err) => err.status_code(),
+        }
+    }
+
+    #[test]
+    fn request_signed_by_allowed_key_is_accepted() {
+        let key_pair = KeyPair::random();
+        let headers = signed_headers(&key_pair, NOW);
+
+        assert_eq!(
+            status_of(&[key_pair.public_key().clone()], &headers, BODY),
+            StatusCode::OK
+        );
+    }
+
+    #[test]
+    fn request_signed_by_other_key_is_forbidden() {
+        let headers = signed_headers(&KeyPair::random(), NOW);
+
+        assert_eq!(
+            status_of(&[KeyPair::random().public_key().clone()], &headers, BODY),
+            StatusCode::FORBIDDEN
+        );
+    }
+
+    #[test]
+    fn unsigned_tampered_or_stale_request_is_unauthorized() {
+        let key_pair = KeyPair::random();
+        let public_keys = [key_pair.public_key().clone()];
+
+        assert_eq!(
+            status_of(&public_keys, &HeaderMap::new(), BODY),
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status_of(&public_keys, &signed_headers(&key_pair, NOW), b"{}"),
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status_of(
+                &public_keys,
+                &signed_headers(&key_pair, NOW - 2 * MAX_CLOCK_DRIFT),
+                BODY
+            ),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[test]
+    fn replayed_request_is_unauthorized() {
+        let key_pair = KeyPair::random();
+        let public_keys = [key_pair.public_key().clone()];
+        let headers = signed_headers(&key_pair, NOW);
+        let seen_signatures = SeenSignatures::default();
+        let verify_at = |now| {
+            verify(
+                &public_keys,
+                &seen_signatures,
+                "POST",
+                PATH,
+                &headers,
+                BODY,
+                now,
+            )
+            .map_err(|err| err.status_code())
+        };
+
+        assert_eq!(verify_at(NOW), Ok(()));
+        assert_eq!(verify_at(NOW), Err(StatusCode::UNAUTHORIZED));
+        assert_eq!(
+            verify_at(NOW + MAX_CLOCK_DRIFT),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+    }
+}
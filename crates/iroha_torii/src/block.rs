@@ -14,6 +14,9 @@ pub enum Error {
     /// Error from provided stream/websocket
     #[error("Stream error: {0}")]
     Stream(Box<stream::Error>),
+    /// Requested block was pruned by the peer
+    #[error("Block at height {0} is pruned")]
+    Pruned(NonZeroU64),
 }
 
 impl From<stream::Error> for Error {
@@ -55,11 +58,14 @@ impl<'ws> Consumer<'ws> {
     /// Can fail due to timeout. Also receiving might fail
     #[iroha_futures::telemetry_future]
     pub async fn consume(&mut self) -> Result<()> {
-        if let Some(block) = self.kura.get_block(
-            self.height
-                .try_into()
-                .expect("INTERNAL BUG: Number of blocks exceeds usize::MAX"),
-        ) {
+        let height = self
+            .height
+            .try_into()
+            .expect("INTERNAL BUG: Number of blocks exceeds usize::MAX");
+        if self.kura.is_pruned(height) {
+            return Err(Error::Pruned(self.height));
+        }
+        if let Some(block) = self.kura.get_block(height) {
             // TODO: to avoid clone `BlockMessage` could be split into sending and receiving parts
             self.stream
                 .send(BlockMessage(SignedBlock::clone(&block)))
@@ -4,12 +4,21 @@
 //!
 //! - `telemetry`: enables Status, Metrics, and API Version endpoints
 //! - `schema`: enables Data Model Schema endpoint
+//!
+//! The administrative endpoints (configuration, metrics and profiling) can be served on a separate
+//! listener and require requests to be signed by one of the configured keys.
+//! Metrics are exempt from the signature so that Prometheus can scrape them.
+//!
+//! If a certificate is configured, all listeners only accept TLS connections, optionally
+//! verifying the certificates of the clients.
+//!
+//! Transactions, queries and subscriptions can be rate limited per IP address and per signing account.
 
 use std::{fmt::Debug, sync::Arc, time::Duration};
 
 use axum::{
     extract::{DefaultBodyLimit, WebSocketUpgrade},
-    http::StatusCode,
+    http::{header::RETRY_AFTER, StatusCode},
     response::{IntoResponse, Json, Response},
     routing::{get, post},
     Router,
@@ -17,7 +26,7 @@ use axum::{
 use error_stack::ResultExt;
 use iroha_config::{
     base::{util::Bytes, WithOrigin},
-    parameters::actual::Torii as Config,
+    parameters::actual::{Torii as Config, ToriiTls as TlsConfig},
 };
 #[cfg(feature = "telemetry")]
 use iroha_core::metrics::MetricsReporter;
@@ -30,11 +39,13 @@ use iroha_core::{
     state::State,
     EventsSender,
 };
+use iroha_crypto::PublicKey;
 use iroha_data_model::ChainId;
 use iroha_futures::supervisor::ShutdownSignal;
 use iroha_primitives::addr::SocketAddr;
 use iroha_torii_const::uri;
-use tokio::net::TcpListener;
+use rate_limit::RateLimits;
+use tokio::{net::TcpListener, sync::Semaphore};
 use tower_http::{
     timeout::TimeoutLayer,
     trace::{DefaultMakeSpan, TraceLayer},
@@ -43,10 +54,13 @@ use utils::{extractors::ScaleVersioned, Scale};
 
 #[macro_use]
 pub(crate) mod utils;
+mod admin;
 mod block;
 mod event;
+mod rate_limit;
 mod routing;
 mod stream;
+mod tls;
 
 const SERVER_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(60);
 
@@ -60,6 +74,11 @@ pub struct Torii {
     kura: Arc<Kura>,
     transaction_max_content_len: Bytes<u64>,
     address: WithOrigin<SocketAddr>,
+    admin_address: Option<WithOrigin<SocketAddr>>,
+    admin_public_keys: Arc<[PublicKey]>,
+    tls: Option<TlsConfig>,
+    rate_limits: RateLimits,
+    simulation_permit: Arc<Semaphore>,
     state: Arc<State>,
     #[cfg(feature = "telemetry")]
     metrics_reporter: MetricsReporter,
@@ -79,6 +98,12 @@ impl Torii {
         state: Arc<State>,
         #[cfg(feature = "telemetry")] metrics_reporter: MetricsReporter,
     ) -> Self {
+        let rate_limits = RateLimits::new(
+            config.rate_limits,
+            #[cfg(feature = "telemetry")]
+            &metrics_reporter,
+        );
+
         Self {
             chain_id: Arc::new(chain_id),
             kiso,
@@ -90,6 +115,12 @@ impl Torii {
             #[cfg(feature = "telemetry")]
             metrics_reporter,
             address: config.address,
+            admin_address: config.admin_address,
+            admin_public_keys: config.admin_public_keys.into(),
+            tls: config.tls,
+            rate_limits,
+            // Simulations hold up the block creation, so only one is executed at a time
+            simulation_permit: Arc::new(Semaphore::new(1)),
             transaction_max_content_len: config.max_content_len,
         }
     }
@@ -99,13 +130,6 @@ impl Torii {
     fn create_api_router(&self) -> axum::Router {
         let router = Router::new()
             .route(uri::HEALTH, get(routing::handle_health))
-            .route(
-                uri::CONFIGURATION,
-                get({
-                    let kiso = self.kiso.clone();
-                    move || routing::handle_get_configuration(kiso)
-                }),
-            )
             .route(
                 uri::API_VERSION,
                 get({
@@ -144,70 +168,99 @@ impl Torii {
                         core::future::ready(routing::handle_status(&metrics_reporter, accept.map(|extract| extract.0), None))
                     }
                 }),
-            )
-            .route(
-                uri::METRICS,
-                get({
-                    let metrics_reporter = self.metrics_reporter.clone();
-                    move || core::future::ready(routing::handle_metrics(&metrics_reporter))
-                }),
             );
 
         #[cfg(feature = "schema")]
         let router = router.route(uri::SCHEMA, get(routing::handle_schema));
 
-        #[cfg(feature = "profiling")]
-        let router = router.route(
-            uri::PROFILE,
-            get({
-                let profiling_lock = std::sync::Arc::new(tokio::sync::Mutex::new(()));
-                move |axum::extract::Query(params): axum::extract::Query<_>| {
-                    let profiling_lock = Arc::clone(&profiling_lock);
-                    routing::profiling::handle_profile(params, profiling_lock)
-                }
-            }),
-        );
-
         let router = router
             .route(
                 uri::TRANSACTION,
-                post({
-                    let chain_id = self.chain_id.clone();
+                self.rate_limits.transactions.limit_per_ip(
+                    post({
+                        let chain_id = self.chain_id.clone();
+                        let queue = self.queue.clone();
+                        let state = self.state.clone();
+                        let rate_limit = self.rate_limits.transactions.clone();
+                        move |ScaleVersioned(transaction): ScaleVersioned<_>| {
+                            routing::handle_transaction(
+                                chain_id,
+                                queue,
+                                state,
+                                rate_limit,
+                                transaction,
+                            )
+                        }
+                    })
+                    .layer(DefaultBodyLimit::max(
+                        self.transaction_max_content_len
+                            .get()
+                            .try_into()
+                            .expect("should't exceed usize"),
+                    )),
+                ),
+            )
+            .route(
+                uri::TRANSACTION_SIMULATION,
+                self.rate_limits.transactions.limit_per_ip(
+                    post({
+                        let chain_id = self.chain_id.clone();
+                        let state = self.state.clone();
+                        let rate_limit = self.rate_limits.transactions.clone();
+                        let simulation_permit = self.simulation_permit.clone();
+                        move |ScaleVersioned(transaction): ScaleVersioned<_>| {
+                            routing::handle_transaction_simulation(
+                                chain_id,
+                                state,
+                                rate_limit,
+                                simulation_permit,
+                                transaction,
+                            )
+                        }
+                    })
+                    .layer(DefaultBodyLimit::max(
+                        self.transaction_max_content_len
+                            .get()
+                            .try_into()
+                            .expect("should't exceed usize"),
+                    )),
+                ),
+            )
+            .route(
+                &format!("{}/:hash", uri::TRANSACTION_STATUS),
+                get({
                     let queue = self.queue.clone();
                     let state = self.state.clone();
-                    move |ScaleVersioned(transaction): ScaleVersioned<_>| {
-                        routing::handle_transaction(chain_id, queue, state, transaction)
+                    move |axum::extract::Path(hash): axum::extract::Path<_>| {
+                        routing::handle_transaction_status(queue, state, hash)
                     }
-                })
-                .layer(DefaultBodyLimit::max(
-                    self.transaction_max_content_len
-                        .get()
-                        .try_into()
-                        .expect("should't exceed usize"),
-                )),
+                }),
             )
             .route(
-                uri::QUERY,
-                post({
-                    let query_service = self.query_service.clone();
+                &format!("{}/:hash", uri::TRANSACTION_PROOF),
+                get({
                     let state = self.state.clone();
-                    move |ScaleVersioned(query_request): ScaleVersioned<_>| {
-                        routing::handle_queries(query_service, state, query_request)
+                    move |axum::extract::Path(hash): axum::extract::Path<_>| {
+                        routing::handle_transaction_proof(state, hash)
                     }
                 }),
             )
             .route(
-                uri::CONFIGURATION,
-                post({
-                    let kiso = self.kiso.clone();
-                    move |Json(config): Json<_>| routing::handle_post_configuration(kiso, config)
-                }),
+                uri::QUERY,
+                self.rate_limits.queries.limit_per_ip(post({
+                    let query_service = self.query_service.clone();
+                    let state = self.state.clone();
+                    let rate_limit = self.rate_limits.queries.clone();
+                    move |ScaleVersioned(query_request): ScaleVersioned<_>| {
+                        routing::handle_queries(query_service, state, rate_limit, query_request)
+                    }
+                })),
             );
 
         let router = router
             .route(
                 uri::SUBSCRIPTION,
-                get({
+                self.rate_limits.subscriptions.limit_per_ip(get({
                     let events = self.events.clone();
                     move |ws: WebSocketUpgrade| {
                         core::future::ready(ws.on_upgrade(|ws| async move {
@@ -218,11 +271,11 @@ impl Torii {
                             }
                         }))
                     }
-                }),
+                })),
             )
             .route(
                 uri::BLOCKS_STREAM,
-                get({
+                self.rate_limits.subscriptions.limit_per_ip(get({
                     let kura = self.kura.clone();
                     move |ws: WebSocketUpgrade| {
                         core::future::ready(ws.on_upgrade(|ws| async move {
@@ -232,9 +285,78 @@ impl Torii {
                             }
                         }))
                     }
-                }),
+                })),
             );
 
+        Self::with_common_layers(router)
+    }
+
+    /// Helper function to create router for the administrative endpoints.
+    ///
+    /// If any admin keys are configured, requests must be signed by one of them,
+    /// except for the metrics which are meant to be scraped by Prometheus.
+    fn create_admin_router(&self) -> axum::Router {
+        let router = Router::new().route(
+            uri::CONFIGURATION,
+            get({
+                let kiso = self.kiso.clone();
+                move || routing::handle_get_configuration(kiso)
+            })
+            .post({
+                let kiso = self.kiso.clone();
+                move |Json(config): Json<_>| routing::handle_post_configuration(kiso, config)
+            }),
+        );
+
+        #[cfg(feature = "profiling")]
+        let router = router.route(
+            uri::PROFILE,
+            get({
+                let profiling_lock = std::sync::Arc::new(tokio::sync::Mutex::new(()));
+                move |axum::extract::Query(params): axum::extract::Query<_>| {
+                    let profiling_lock = Arc::clone(&profiling_lock);
+                    routing::profiling::handle_profile(params, profiling_lock)
+                }
+            }),
+        );
+
+        let router = if self.admin_public_keys.is_empty() {
+            router
+        } else {
+            let public_keys = self.admin_public_keys.clone();
+            let seen_signatures = Arc::new(admin::SeenSignatures::default());
+            let max_content_len = self
+                .transaction_max_content_len
+                .get()
+                .try_into()
+                .expect("should't exceed usize");
+            router.route_layer(axum::middleware::from_fn(
+                move |request: axum::extract::Request, next: axum::middleware::Next| {
+                    admin::authenticate(
+                        public_keys.clone(),
+                        seen_signatures.clone(),
+                        max_content_len,
+                        request,
+                        next,
+                    )
+                },
+            ))
+        };
+
+        // NOTE: Added after the authentication layer, which only applies to the routes above
+        #[cfg(feature = "telemetry")]
+        let router = router.route(
+            uri::METRICS,
+            get({
+                let metrics_reporter = self.metrics_reporter.clone();
+                move || core::future::ready(routing::handle_metrics(&metrics_reporter))
+            }),
+        );
+
+        Self::with_common_layers(router)
+    }
+
+    fn with_common_layers(router: axum::Router) -> axum::Router {
         router.layer((
             TraceLayer::new_for_http()
                 .make_span_with(DefaultMakeSpan::default().include_headers(true)),
@@ -250,22 +372,69 @@ impl Torii {
     /// Can fail due to listening to network or if http server fails
     // #[iroha_futures::telemetry_future]
     pub async fn start(self, shutdown_signal: ShutdownSignal) -> error_stack::Result<(), Error> {
-        let torii_address = self.address.value().clone();
+        let tls_acceptor = self.tls.as_ref().map(tls::acceptor).transpose()?;
+        let listener = Self::bind(&self.address).await?;
+        let api_router = self.create_api_router();
+        let admin_router = self.create_admin_router();
+
+        let Some(admin_address) = &self.admin_address else {
+            if self.admin_public_keys.is_empty() {
+                iroha_logger::warn!(
+                    "Administrative endpoints are served on the public listener without authentication, \
+                     set `torii.admin_public_keys` or `torii.admin_address` to protect them"
+                );
+            }
+            return Self::serve(
+                listener,
+                api_router.merge(admin_router),
+                tls_acceptor,
+                shutdown_signal,
+            )
+            .await;
+        };
+        let admin_listener = Self::bind(admin_address).await?;
 
-        let listener = match torii_address {
+        tokio::try_join!(
+            Self::serve(
+                listener,
+                api_router,
+                tls_acceptor.clone(),
+                shutdown_signal.clone()
+            ),
+            Self::serve(admin_listener, admin_router, tls_acceptor, shutdown_signal),
+        )
+        .map(|_| ())
+    }
+
+    async fn bind(address: &WithOrigin<SocketAddr>) -> error_stack::Result<TcpListener, Error> {
+        match address.value().clone() {
             SocketAddr::Ipv4(v) => TcpListener::bind(std::net::SocketAddr::V4(v.into())).await,
             SocketAddr::Ipv6(v) => TcpListener::bind(std::net::SocketAddr::V6(v.into())).await,
             SocketAddr::Host(v) => TcpListener::bind((v.host.as_ref(), v.port)).await,
         }
         .change_context(Error::StartServer)
         .attach_printable("failed to bind to the specified address")
-        .attach_printable_lazy(|| self.address.clone().into_attachment())?;
-        let api_router = self.create_api_router();
+        .attach_printable_lazy(|| address.clone().into_attachment())
+    }
+
+    async fn serve(
+        listener: TcpListener,
+        router: axum::Router,
+        tls_acceptor: Option<tokio_rustls::TlsAcceptor>,
+        shutdown_signal: ShutdownSignal,
+    ) -> error_stack::Result<(), Error> {
+        if let Some(acceptor) = tls_acceptor {
+            tls::serve(listener, acceptor, router, shutdown_signal).await;
+            return Ok(());
+        }
 
-        axum::serve(listener, api_router)
-            .with_graceful_shutdown(async move { shutdown_signal.receive().await })
-            .await
-            .change_context(Error::FailedExit)
+        axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+        )
+        .with_graceful_shutdown(async move { shutdown_signal.receive().await })
+        .await
+        .change_context(Error::FailedExit)
     }
 }
 
@@ -293,6 +462,14 @@ pub enum Error {
     ConfigurationFailure(#[from] KisoError),
     /// Failed to find status segment by provided path
     StatusSegmentNotFound(#[source] eyre::Report),
+    /// Request to an administrative endpoint isn't properly signed
+    Unauthorized(#[source] eyre::Report),
+    /// Key {0} isn't allowed to access the administrative endpoints
+    Forbidden(PublicKey),
+    /// Too many requests, retry in {0:?}
+    TooManyRequests(Duration),
+    /// Another transaction is being simulated, retry later
+    SimulationBusy,
     /// Failed to start Torii
     StartServer,
     /// Torii server terminated with an error
@@ -303,6 +480,16 @@ impl IntoResponse for Error {
     fn into_response(self) -> Response {
         match self {
             Self::Query(err) => (Self::query_status_code(&err), utils::Scale(err)).into_response(),
+            Self::TooManyRequests(retry_after) => {
+                // The header only allows whole seconds
+                let secs = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+                (
+                    self.status_code(),
+                    [(RETRY_AFTER, secs.to_string())],
+                    format!("{self:?}"),
+                )
+                    .into_response()
+            }
             _ => (self.status_code(), format!("{self:?}")).into_response(),
         }
     }
@@ -317,7 +504,9 @@ impl Error {
             AcceptTransaction(_) => StatusCode::BAD_REQUEST,
             Config(_) | StatusSegmentNotFound(_) => StatusCode::NOT_FOUND,
             PushIntoQueue(err) => match **err {
-                queue::Error::Full => StatusCode::INTERNAL_SERVER_ERROR,
+                queue::Error::Full | queue::Error::ScheduledFull => {
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
                 _ => StatusCode::BAD_REQUEST,
             },
             #[cfg(feature = "telemetry")]
@@ -325,6 +514,10 @@ impl Error {
             #[cfg(feature = "profiling")]
             Pprof(_) => StatusCode::INTERNAL_SERVER_ERROR,
             ConfigurationFailure(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            Forbidden(_) => StatusCode::FORBIDDEN,
+            TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            SimulationBusy => StatusCode::SERVICE_UNAVAILABLE,
             StartServer | FailedExit => unreachable!("these never occur during request handling"),
         }
     }
@@ -345,9 +538,13 @@ impl Error {
                 | CursorDone
                 | NotFound
                 | FetchSizeTooBig
-                | InvalidSingularParameters => StatusCode::BAD_REQUEST,
-                Find(_) => StatusCode::NOT_FOUND,
+                | InvalidSingularParameters
+                | InvalidSortKey
+                | InvalidAggregation => StatusCode::BAD_REQUEST,
+                AggregationOverflow | TooManyGroups => StatusCode::UNPROCESSABLE_ENTITY,
+                Find(_) | HeightNotAvailable(_) => StatusCode::NOT_FOUND,
                 CapacityLimit => StatusCode::TOO_MANY_REQUESTS,
+                BlocksPruned(_) => StatusCode::GONE,
             },
             TooComplex => StatusCode::UNPROCESSABLE_ENTITY,
             InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -0,0 +1,268 @@
+//! Token-bucket rate limiting of the Torii endpoints.
+//!
+//! Requests from a single IP address are limited by a middleware before they are processed.
+//! Requests signed by a single account are limited by the handlers once the signature is verified.
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::{ConnectInfo, Request},
+    middleware::Next,
+    routing::MethodRouter,
+};
+use iroha_config::{parameters::actual::ToriiRateLimits as Config, torii::TokenBucket};
+use iroha_data_model::account::AccountId;
+use parking_lot::Mutex;
+
+use super::*;
+
+/// Minimal number of the tracked keys at which the buckets which are full again are dropped
+const MIN_CLEANUP_LEN: usize = 1024;
+
+/// Rate limits of the Torii endpoints
+#[derive(Clone)]
+pub struct RateLimits {
+    /// Limits of the transaction submission and simulation
+    pub transactions: Arc<RateLimit>,
+    /// Limits of the queries
+    pub queries: Arc<RateLimit>,
+    /// Limits of the event and block stream subscriptions
+    pub subscriptions: Arc<RateLimit>,
+}
+
+impl RateLimits {
+    /// Construct the limiters from the `config`.
+    pub fn new(
+        config: Config,
+        #[cfg(feature = "telemetry")] metrics_reporter: &MetricsReporter,
+    ) -> Self {
+        let limit = |endpoint, per_ip: Option<TokenBucket>, per_account: Option<TokenBucket>| {
+            Arc::new(RateLimit {
+                endpoint,
+                per_ip: per_ip.map(RateLimiter::new),
+                per_account: per_account.map(RateLimiter::new),
+                #[cfg(feature = "telemetry")]
+                metrics_reporter: metrics_reporter.clone(),
+            })
+        };
+
+        Self {
+            transactions: limit(
+                "transactions",
+                config.transactions_per_ip,
+                config.transactions_per_account,
+            ),
+            queries: limit("queries", config.queries_per_ip, config.queries_per_account),
+            subscriptions: limit("subscriptions", config.subscriptions_per_ip, None),
+        }
+    }
+}
+
+/// Limits of requests to a group of endpoints
+pub struct RateLimit {
+    /// Name of the endpoints reported in the metrics
+    endpoint: &'static str,
+    per_ip: Option<RateLimiter<IpAddr>>,
+    per_account: Option<RateLimiter<AccountId>>,
+    #[cfg(feature = "telemetry")]
+    metrics_reporter: MetricsReporter,
+}
+
+impl RateLimit {
+    /// Reject the requests to the `route` from IP addresses which exceed the limit.
+    ///
+    /// The server must provide [`ConnectInfo`] if the limit is set.
+    pub fn limit_per_ip(self: &Arc<Self>, route: MethodRouter) -> MethodRouter {
+        if self.per_ip.is_none() {
+            return route;
+        }
+
+        let limit = Arc::clone(self);
+        route.layer(axum::middleware::from_fn(
+            move |ConnectInfo(addr): ConnectInfo<std::net::SocketAddr>,
+                  request: Request,
+                  next: Next| {
+                let limit = Arc::clone(&limit);
+                async move {
+                    limit.check_ip(addr.ip())?;
+                    Ok::<_, Error>(next.run(request).await)
+                }
+            },
+        ))
+    }
+
+    /// Take a token from the bucket of the IP address.
+    ///
+    /// # Errors
+    /// If the IP address exceeds the limit
+    pub fn check_ip(&self, ip: IpAddr) -> Result<()> {
+        let Some(per_ip) = &self.per_ip else {
+            return Ok(());
+        };
+        per_ip
+            .acquire(ip, Instant::now())
+            .map_err(|retry_after| self.throttled("ip", retry_after))
+    }
+
+    /// Take a token from the bucket of the account which signed the request.
+    ///
+    /// # Errors
+    /// If the account exceeds the limit
+    pub fn check_account(&self, account: &AccountId) -> Result<()> {
+        let Some(per_account) = &self.per_account else {
+            return Ok(());
+        };
+        per_account
+            .acquire(account.clone(), Instant::now())
+            .map_err(|retry_after| self.throttled("account", retry_after))
+    }
+
+    fn throttled(&self, limit: &'static str, retry_after: Duration) -> Error {
+        iroha_logger::debug!(
+            endpoint = self.endpoint,
+            limit,
+            ?retry_after,
+            "Request throttled"
+        );
+        #[cfg(feature = "telemetry")]
+        self.metrics_reporter
+            .metrics()
+            .throttled_requests
+            .with_label_values(&[self.endpoint, limit])
+            .inc();
+        Error::TooManyRequests(retry_after)
+    }
+}
+
+/// Rate limiter with a separate token bucket for every key.
+///
+/// Implements the generic cell rate algorithm: instead of counting the tokens,
+/// it stores the time at which the bucket of each key becomes full again.
+struct RateLimiter<K> {
+    /// Time it takes to refill a single token
+    interval: Duration,
+    /// Time it takes to refill all the tokens but one
+    tolerance: Duration,
+    buckets: Mutex<Buckets<K>>,
+}
+
+struct Buckets<K> {
+    full_at: HashMap<K, Instant>,
+    cleanup_len: usize,
+}
+
+impl<K: Eq + Hash> RateLimiter<K> {
+    fn new(bucket: TokenBucket) -> Self {
+        let interval = Duration::from_secs(1) / bucket.rate_per_sec.get();
+
+        Self {
+            interval,
+            tolerance: interval * (bucket.burst.get() - 1),
+            buckets: Mutex::new(Buckets {
+                full_at: HashMap::new(),
+                cleanup_len: MIN_CLEANUP_LEN,
+            }),
+        }
+    }
+
+    /// Take a token from the bucket of the `key`.
+    ///
+    /// # Errors
+    /// If the bucket is empty, returns the time after which a token becomes available
+    fn acquire(&self, key: K, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock();
+
+        let full_at = buckets.full_at.entry(key).or_insert(now);
+        let refill_time = full_at.saturating_duration_since(now);
+        if refill_time > self.tolerance {
+            return Err(refill_time - self.tolerance);
+        }
+        *full_at = now.max(*full_at) + self.interval;
+
+        // Full buckets are indistinguishable from the ones of the unknown keys
+        if buckets.full_at.len() >= buckets.cleanup_len {
+            buckets.full_at.retain(|_, full_at| *full_at > now);
+            let len = buckets.full_at.len();
+            buckets.cleanup_len = MIN_CLEANUP_LEN.max(2 * len);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(rate_per_sec: u32, burst: u32) -> RateLimiter<u8> {
+        RateLimiter::new(TokenBucket {
+            rate_per_sec: rate_per_sec.try_into().unwrap(),
+            burst: burst.try_into().unwrap(),
+        })
+    }
+
+    #[test]
+    fn burst_is_allowed_then_rate() {
+        let limiter = limiter(2, 3);
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire(0, now).expect("within burst");
+        }
+        assert_eq!(
+            limiter.acquire(0, now),
+            Err(Duration::from_millis(500)),
+            "burst is exhausted"
+        );
+
+        let now = now + Duration::from_millis(500);
+        limiter.acquire(0, now).expect("a token is refilled");
+        assert_eq!(limiter.acquire(0, now), Err(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn keys_have_separate_buckets() {
+        let limiter = limiter(1, 1);
+        let now = Instant::now();
+
+        limiter.acquire(0, now).expect("first request");
+        limiter
+            .acquire(0, now)
+            .expect_err("bucket of the key is empty");
+        limiter.acquire(1, now).expect("other key isn't limited");
+    }
+
+    #[test]
+    fn full_buckets_are_dropped() {
+        let limiter = limiter(1, 1);
+        let now = Instant::now();
+
+        for key in 0..=u8::MAX {
+            limiter.acquire(key, now).expect("first request");
+        }
+        let later = now + Duration::from_secs(1);
+        limiter.acquire(0, later).expect("bucket is refilled");
+
+        assert_eq!(limiter.buckets.lock().full_at.len(), 256);
+        limiter.buckets.lock().cleanup_len = 0;
+        limiter.acquire(1, later).expect("bucket is refilled");
+        assert_eq!(limiter.buckets.lock().full_at.len(), 2);
+    }
+
+    #[test]
+    fn too_many_requests_response_has_retry_after() {
+        let response = Error::TooManyRequests(Duration::from_millis(1500)).into_response();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            response.headers()[axum::http::header::RETRY_AFTER],
+            "2",
+            "retry hint is rounded up to seconds"
+        );
+    }
+}
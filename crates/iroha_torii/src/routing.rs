@@ -9,20 +9,27 @@ use iroha_config::client_api::ConfigDTO;
 use iroha_core::{query::store::LiveQueryStoreHandle, smartcontracts::query::ValidQueryRequest};
 use iroha_data_model::{
     self,
+    block::TransactionInclusionProof,
+    events::pipeline::{TransactionEvent, TransactionStatus},
     prelude::*,
-    query::{QueryRequestWithAuthority, QueryResponse, SignedQuery},
+    query::{
+        error::{FindError, QueryExecutionFail},
+        QueryRequestWithAuthority, QueryResponse, SignedQuery,
+    },
 };
 #[cfg(feature = "telemetry")]
 use iroha_telemetry::metrics::Status;
 use tokio::task;
 
 use super::*;
+use crate::rate_limit::RateLimit;
 
 #[iroha_futures::telemetry_future]
 pub async fn handle_transaction(
     chain_id: Arc<ChainId>,
     queue: Arc<Queue>,
     state: Arc<State>,
+    rate_limit: Arc<RateLimit>,
     tx: SignedTransaction,
 ) -> Result<()> {
     let (max_clock_drift, tx_limits) = {
@@ -31,8 +38,16 @@ pub async fn handle_transaction(
         (params.sumeragi.max_clock_drift(), params.transaction)
     };
 
-    let accepted_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
-        .map_err(Error::AcceptTransaction)?;
+    let accepted_tx = AcceptedTransaction::accept(
+        tx,
+        &chain_id,
+        max_clock_drift,
+        tx_limits,
+        &state.world.view(),
+    )
+    .map_err(Error::AcceptTransaction)?;
+    // Limited only after the signature of the authority is verified on acceptance
+    rate_limit.check_account(accepted_tx.as_ref().authority())?;
 
     queue
         .push(accepted_tx, state.view())
@@ -47,12 +62,156 @@ pub async fn handle_transaction(
         .map_err(Error::PushIntoQueue)
 }
 
+#[iroha_futures::telemetry_future]
+pub async fn handle_transaction_simulation(
+    chain_id: Arc<ChainId>,
+    state: Arc<State>,
+    rate_limit: Arc<RateLimit>,
+    simulation_permit: Arc<Semaphore>,
+    tx: SignedTransaction,
+) -> Result<Scale<TransactionSimulation>> {
+    let (max_clock_drift, tx_limits) = {
+        let state_view = state.world.view();
+        let params = state_view.parameters();
+        (params.sumeragi.max_clock_drift(), params.transaction)
+    };
+
+    let accepted_tx = AcceptedTransaction::accept(
+        tx,
+        &chain_id,
+        max_clock_drift,
+        tx_limits,
+        &state.world.view(),
+    )
+    .map_err(Error::AcceptTransaction)?;
+    // Limited only after the signature of the authority is verified on acceptance
+    rate_limit.check_account(accepted_tx.as_ref().authority())?;
+
+    // Simulation waits for the block being created or validated by the node,
+    // so requests are rejected instead of queueing up behind one another
+    let permit = simulation_permit
+        .try_acquire_owned()
+        .map_err(|_| Error::SimulationBusy)?;
+    let handle = task::spawn_blocking(move || {
+        let simulation = state.simulate_transaction(accepted_tx);
+        drop(permit);
+        simulation
+    });
+    Ok(Scale(
+        handle
+            .await
+            .expect("Failed to join transaction simulation task"),
+    ))
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_transaction_status(
+    queue: Arc<Queue>,
+    state: Arc<State>,
+    hash: HashOf<SignedTransaction>,
+) -> Result<Scale<TransactionEvent>> {
+    let handle = task::spawn_blocking(move || {
+        let state_view = state.view();
+        let not_found =
+            || ValidationFail::QueryFailed(QueryExecutionFail::Find(FindError::Transaction(hash)));
+
+        let Some(&height) = state_view.transactions().get(&hash) else {
+            let status = if queue.contains(&hash) {
+                TransactionStatus::Queued
+            } else if let Some(status) = queue.dropped_status(&hash) {
+                status
+            } else {
+                return Err(not_found());
+            };
+
+            return Ok(TransactionEvent {
+                hash,
+                block_height: None,
+                status,
+            });
+        };
+
+        let kura = state_view.kura();
+        if kura.is_pruned(height) {
+            return Err(ValidationFail::QueryFailed(
+                QueryExecutionFail::BlocksPruned(kura.pruned_height() as u64),
+            ));
+        }
+        let block = kura.get_block(height).ok_or_else(not_found)?;
+        let idx = block
+            .transactions()
+            .position(|tx| tx.hash() == hash)
+            .ok_or_else(not_found)?;
+
+        Ok(TransactionEvent {
+            hash,
+            block_height: Some(block.header().height()),
+            status: block
+                .error(idx)
+                .map_or(TransactionStatus::Approved, |reason| {
+                    TransactionStatus::Rejected(Box::new(reason.clone()))
+                }),
+        })
+    });
+    handle
+        .await
+        .expect("Failed to join transaction status task")
+        .map(Scale)
+        .map_err(Into::into)
+}
+
+#[iroha_futures::telemetry_future]
+pub async fn handle_transaction_proof(
+    state: Arc<State>,
+    hash: HashOf<SignedTransaction>,
+) -> Result<Scale<TransactionInclusionProof>> {
+    let handle = task::spawn_blocking(move || {
+        let state_view = state.view();
+        let kura = state_view.kura();
+
+        let not_found =
+            || ValidationFail::QueryFailed(QueryExecutionFail::Find(FindError::Transaction(hash)));
+        let height = *state_view.transactions().get(&hash).ok_or_else(not_found)?;
+        if kura.is_pruned(height) {
+            return Err(ValidationFail::QueryFailed(
+                QueryExecutionFail::BlocksPruned(kura.pruned_height() as u64),
+            ));
+        }
+
+        kura.get_block(height)
+            .and_then(|block| block.transaction_inclusion_proof(&hash))
+            .ok_or_else(not_found)
+    });
+    handle
+        .await
+        .expect("Failed to join transaction proof task")
+        .map(Scale)
+        .map_err(Into::into)
+}
+
 #[iroha_futures::telemetry_future]
 pub async fn handle_queries(
     live_query_store: LiveQueryStoreHandle,
     state: Arc<State>,
+    rate_limit: Arc<RateLimit>,
     query: SignedQuery,
 ) -> Result<Scale<QueryResponse>> {
+    // Verified first so that queries signed by someone else don't exhaust the limit of the authority
+    if !state
+        .world
+        .view()
+        .account_signatories_of(query.authority())
+        .iter()
+        .any(|public_key| query.verify_signature(public_key).is_ok())
+    {
+        return Err(ValidationFail::NotPermitted(format!(
+            "Query is not signed by any of the signatories of `{}`",
+            query.authority()
+        ))
+        .into());
+    }
+    rate_limit.check_account(query.authority())?;
+
     let handle = task::spawn_blocking(move || {
         let state_view = state.view();
 
@@ -61,7 +220,15 @@ pub async fn handle_queries(
         let authority = query.authority.clone();
 
         let valid_query = ValidQueryRequest::validate_for_client(query, &state_view)?;
-        let response = valid_query.execute(&live_query_store, &state_view, &authority)?;
+        let response = match valid_query.at_height() {
+            Some(height) if height.get() != state_view.height() as u64 => {
+                let historical_state = state
+                    .historical_state(height)
+                    .ok_or(QueryExecutionFail::HeightNotAvailable(height.get()))?;
+                valid_query.execute(&live_query_store, &historical_state.view(), &authority)?
+            }
+            _ => valid_query.execute(&live_query_store, &state_view, &authority)?,
+        };
 
         Ok::<_, ValidationFail>(response)
     });
@@ -0,0 +1,265 @@
+//! TLS termination for the Torii listeners.
+
+use std::{fs::File, io::BufReader, path::PathBuf, time::Duration};
+
+use axum::extract::ConnectInfo;
+use error_stack::Report;
+use hyper::{
+    body::Incoming,
+    server::conn::http1,
+    service::{service_fn, Service as _},
+};
+use hyper_util::{rt::TokioIo, service::TowerToHyperService};
+use iroha_config::parameters::actual::ToriiTls as Config;
+use tokio::task::JoinSet;
+use tokio_rustls::{
+    rustls::{
+        pki_types::CertificateDer, server::WebPkiClientVerifier, RootCertStore, ServerConfig,
+    },
+    TlsAcceptor,
+};
+
+use super::*;
+
+/// How long a client may take to complete the TLS handshake
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Build the acceptor of TLS connections from the certificates and the private key listed in the `config`.
+///
+/// # Errors
+/// If the files can't be read or don't contain a valid certificate chain or private key
+pub fn acceptor(config: &Config) -> error_stack::Result<TlsAcceptor, Error> {
+    let certificates = read_certificates(&config.certificate)?;
+    let private_key = rustls_pemfile::private_key(&mut open(&config.private_key)?)
+        .change_context(Error::StartServer)
+        .attach_printable_lazy(|| config.private_key.clone().into_attachment().display_path())?
+        .ok_or_else(|| {
+            Report::new(Error::StartServer)
+                .attach_printable("no private key found in the file")
+                .attach_printable(config.private_key.clone().into_attachment().display_path())
+        })?;
+
+    let builder = ServerConfig::builder();
+    let builder = match &config.client_ca {
+        Some(client_ca) => {
+            let mut roots = RootCertStore::empty();
+            for certificate in read_certificates(client_ca)? {
+                roots
+                    .add(certificate)
+                    .change_context(Error::StartServer)
+                    .attach_printable_lazy(|| client_ca.clone().into_attachment().display_path())?;
+            }
+            let verifier = WebPkiClientVerifier::builder(Arc::new(roots))
+                .build()
+                .change_context(Error::StartServer)
+                .attach_printable_lazy(|| client_ca.clone().into_attachment().display_path())?;
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+    let mut server_config = builder
+        .with_single_cert(certificates, private_key)
+        .change_context(Error::StartServer)
+        .attach_printable("invalid TLS certificate or private key")?;
+    server_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+    Ok(TlsAcceptor::from(Arc::new(server_config)))
+}
+
+fn open(path: &WithOrigin<PathBuf>) -> error_stack::Result<BufReader<File>, Error> {
+    File::open(path.resolve_relative_path())
+        .map(BufReader::new)
+        .change_context(Error::StartServer)
+        .attach_printable_lazy(|| path.clone().into_attachment().display_path())
+}
+
+fn read_certificates(
+    path: &WithOrigin<PathBuf>,
+) -> error_stack::Result<Vec<CertificateDer<'static>>, Error> {
+    let certificates = rustls_pemfile::certs(&mut open(path)?)
+        .collect::<std::io::Result<Vec<_>>>()
+        .change_context(Error::StartServer)
+        .attach_printable_lazy(|| path.clone().into_attachment().display_path())?;
+
+    if certificates.is_empty() {
+        return Err(Report::new(Error::StartServer)
+            .attach_printable("no certificates found in the file")
+            .attach_printable(path.clone().into_attachment().display_path()));
+    }
+
+    Ok(certificates)
+}
+
+/// Serve the `router` over TLS connections accepted from the `listener` until the shutdown is signalled.
+///
+/// Like [`axum::serve`], waits for the outstanding requests to complete before returning.
+pub async fn serve(
+    listener: TcpListener,
+    acceptor: TlsAcceptor,
+    router: axum::Router,
+    shutdown_signal: ShutdownSignal,
+) {
+    let mut connections = JoinSet::new();
+
+    loop {
+        let (stream, remote_addr) = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => accepted,
+                Err(error) => {
+                    iroha_logger::debug!(%error, "Failed to accept connection");
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    continue;
+                }
+            },
+            Some(_) = connections.join_next(), if !connections.is_empty() => continue,
+            () = shutdown_signal.receive() => break,
+        };
+
+        let acceptor = acceptor.clone();
+        let service = TowerToHyperService::new(router.clone());
+        // Same as `axum::serve` with `into_make_service_with_connect_info`
+        let service = service_fn(move |mut request: hyper::Request<Incoming>| {
+            request.extensions_mut().insert(ConnectInfo(remote_addr));
+            service.call(request)
+        });
+        let shutdown_signal = shutdown_signal.clone();
+        connections.spawn(async move {
+            let stream =
+                match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(stream)).await {
+                    Ok(Ok(stream)) => stream,
+                    Ok(Err(error)) => {
+                        iroha_logger::debug!(%error, %remote_addr, "TLS handshake failed");
+                        return;
+                    }
+                    Err(_) => {
+                        iroha_logger::debug!(%remote_addr, "TLS handshake timed out");
+                        return;
+                    }
+                };
+
+            let connection = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .with_upgrades();
+            tokio::pin!(connection);
+            let result = tokio::select! {
+                result = connection.as_mut() => result,
+                () = shutdown_signal.receive() => {
+                    connection.as_mut().graceful_shutdown();
+                    connection.await
+                }
+            };
+            if let Err(error) = result {
+                iroha_logger::debug!(%error, %remote_addr, "Failed to serve connection");
+            }
+        });
+    }
+
+    while connections.join_next().await.is_some() {}
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use tokio::{
+        io::{AsyncReadExt as _, AsyncWriteExt as _},
+        net::TcpStream,
+    };
+    use tokio_rustls::{
+        rustls::{pki_types::ServerName, ClientConfig},
+        TlsConnector,
+    };
+
+    use super::*;
+
+    fn fixture(name: &str) -> WithOrigin<PathBuf> {
+        WithOrigin::inline(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/fixtures/tls")
+                .join(name),
+        )
+    }
+
+    fn server_config(verify_clients: bool) -> Config {
+        Config {
+            certificate: fixture("server.pem"),
+            private_key: fixture("server.key"),
+            client_ca: verify_clients.then(|| fixture("ca.pem")),
+        }
+    }
+
+    async fn start_server(config: &Config) -> std::net::SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let router = axum::Router::new().route(uri::HEALTH, get(|| async { "Healthy" }));
+        tokio::spawn(serve(
+            listener,
+            acceptor(config).unwrap(),
+            router,
+            ShutdownSignal::new(),
+        ));
+        address
+    }
+
+    /// Request the health over TLS, presenting the client certificate if `client_auth` is set.
+    async fn get_health(
+        address: std::net::SocketAddr,
+        client_auth: bool,
+    ) -> std::io::Result<String> {
+        let mut roots = RootCertStore::empty();
+        for certificate in read_certificates(&fixture("ca.pem")).unwrap() {
+            roots.add(certificate).unwrap();
+        }
+        let builder = ClientConfig::builder().with_root_certificates(roots);
+        let client_config = if client_auth {
+            let private_key =
+                rustls_pemfile::private_key(&mut open(&fixture("client.key")).unwrap())
+                    .unwrap()
+                    .unwrap();
+            builder
+                .with_client_auth_cert(
+                    read_certificates(&fixture("client.pem")).unwrap(),
+                    private_key,
+                )
+                .unwrap()
+        } else {
+            builder.with_no_client_auth()
+        };
+
+        let stream = TcpStream::connect(address).await?;
+        let mut stream = TlsConnector::from(Arc::new(client_config))
+            .connect(ServerName::try_from("localhost").unwrap(), stream)
+            .await?;
+        stream
+            .write_all(b"GET /health HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+            .await?;
+        let mut response = Vec::new();
+        match stream.read_to_end(&mut response).await {
+            // The connection may be closed without `close_notify`
+            Err(error) if error.kind() != std::io::ErrorKind::UnexpectedEof => return Err(error),
+            _ => {}
+        }
+        Ok(String::from_utf8_lossy(&response).into_owned())
+    }
+
+    fn is_healthy(response: &std::io::Result<String>) -> bool {
+        response.as_ref().is_ok_and(|response| {
+            response.starts_with("HTTP/1.1 200") && response.ends_with("Healthy")
+        })
+    }
+
+    #[tokio::test]
+    async fn requests_are_served_over_tls() {
+        let address = start_server(&server_config(false)).await;
+
+        assert!(is_healthy(&get_health(address, false).await));
+    }
+
+    #[tokio::test]
+    async fn client_certificate_is_required_if_client_ca_is_set() {
+        let address = start_server(&server_config(true)).await;
+
+        assert!(is_healthy(&get_health(address, true).await));
+        assert!(!is_healthy(&get_health(address, false).await));
+    }
+}
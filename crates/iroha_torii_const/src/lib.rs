@@ -10,6 +10,12 @@ pub mod uri {
     pub const QUERY: &str = "/query";
     /// Transaction URI is used to handle incoming ISI requests.
     pub const TRANSACTION: &str = "/transaction";
+    /// Transaction proof URI is used to get the proof of inclusion of a committed transaction, followed by the transaction hash.
+    pub const TRANSACTION_PROOF: &str = "/transaction/proof";
+    /// Transaction status URI is used to get the status of a transaction, followed by the transaction hash.
+    pub const TRANSACTION_STATUS: &str = "/transaction/status";
+    /// Transaction simulation URI is used to execute a transaction without committing it.
+    pub const TRANSACTION_SIMULATION: &str = "/transaction/simulate";
     /// Block URI is used to handle incoming Block requests.
     pub const CONSENSUS: &str = "/consensus";
     /// Health URI is used to handle incoming Healthcheck requests.
@@ -36,3 +42,26 @@ pub mod uri {
     /// URI for getting cpu profile
     pub const PROFILE: &str = "/debug/pprof/profile";
 }
+
+pub mod admin {
+    //! Authentication of requests to the administrative endpoints.
+
+    /// Header with the public key the request is signed with.
+    pub const PUBLIC_KEY_HEADER: &str = "x-iroha-public-key";
+    /// Header with the creation time of the request, in milliseconds since the Unix epoch.
+    pub const TIMESTAMP_HEADER: &str = "x-iroha-timestamp";
+    /// Header with the hex encoded signature of the [`signature_payload`].
+    pub const SIGNATURE_HEADER: &str = "x-iroha-signature";
+
+    /// Bytes which the request with the given method, path (including the query), timestamp and body is signed over.
+    pub fn signature_payload(
+        method: &str,
+        path_and_query: &str,
+        timestamp_ms: u64,
+        body: &[u8],
+    ) -> Vec<u8> {
+        let mut payload = format!("{method}\n{path_and_query}\n{timestamp_ms}\n").into_bytes();
+        payload.extend_from_slice(body);
+        payload
+    }
+}
@@ -12,6 +12,7 @@ use clap::Parser;
 use error_stack::{IntoReportCompat, Report, Result, ResultExt};
 use iroha_config::{
     base::{read::ConfigReader, util::Emitter, WithOrigin},
+    kura::PruningMode,
     parameters::{actual::Root as Config, user::Root as UserConfig},
 };
 use iroha_core::{
@@ -20,7 +21,7 @@ use iroha_core::{
     kiso::KisoHandle,
     kura::Kura,
     peers_gossiper::{PeersGossiper, PeersGossiperHandle},
-    query::store::LiveQueryStore,
+    query::{history::StateHistory, store::LiveQueryStore},
     queue::Queue,
     smartcontracts::isi::Registrable as _,
     snapshot::{try_read_snapshot, SnapshotMaker, TryReadError as TryReadSnapshotError},
@@ -119,6 +120,8 @@ pub enum StartError {
     StartP2p,
     #[error("Unable to initialize Kura (block storage)")]
     InitKura,
+    #[error("Unable to restore the state")]
+    RestoreState,
     #[error("Unable to start dev telemetry service")]
     StartDevTelemetry,
     #[error("Unable to start telemetry service")]
@@ -232,7 +235,19 @@ impl Iroha {
                 Arc::clone(&kura),
                 live_query_store.clone(),
             )
-        });
+        })
+        .with_history(StateHistory::new(
+            config.live_query_store.history_depth,
+            config.live_query_store.history_max_bytes.get(),
+        ));
+        if state.view().height() < kura.pruned_height() {
+            return Err(
+                Report::new(StartError::RestoreState).attach_printable(format!(
+                    "blocks up to height {} are pruned and there is no snapshot covering them",
+                    kura.pruned_height()
+                )),
+            );
+        }
         let state = Arc::new(state);
 
         let (events_sender, _) = broadcast::channel(EVENTS_BUFFER_CAPACITY);
@@ -322,7 +337,7 @@ impl Iroha {
         ));
 
         if let Some(snapshot_maker) =
-            SnapshotMaker::from_config(&config.snapshot, Arc::clone(&state))
+            SnapshotMaker::from_config(&config.snapshot, Arc::clone(&state), Arc::clone(&kura))
         {
             supervisor.monitor(snapshot_maker.start(supervisor.shutdown_signal()));
         }
@@ -549,6 +564,9 @@ fn validate_config(config: &Config) -> Result<(), ConfigError> {
         validate_try_bind_address(&mut emitter, &config.torii.address);
     }
     validate_directory_path(&mut emitter, &config.kura.store_dir);
+    if config.kura.pruning_mode == PruningMode::Archive {
+        validate_directory_path(&mut emitter, &config.kura.pruning_archive_dir);
+    }
     // maybe validate only if snapshot mode is enabled
     validate_directory_path(&mut emitter, &config.snapshot.store_dir);
 
@@ -1,10 +1,25 @@
 //! Kura inspector binary. For usage run with `--help`.
-use std::path::{Path, PathBuf};
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
 
-use clap::{Parser, Subcommand};
-use iroha_core::kura::{BlockIndex, BlockStore};
-use iroha_data_model::block::SignedBlock;
-use iroha_version::scale::DecodeVersioned;
+use clap::{Parser, Subcommand, ValueEnum};
+use iroha_config::kura::Compression;
+use iroha_core::{
+    kura::{
+        BlockIndex, BlockStore, DATA_FILE_NAME, HASHES_FILE_NAME, INDEX_FILE_NAME, PRUNED_FILE_NAME,
+    },
+    sumeragi::network_topology::Topology,
+};
+use iroha_crypto::HashOf;
+use iroha_data_model::{
+    block::{BlockHeader, SignedBlock},
+    isi::{InstructionBox, RegisterBox, UnregisterBox},
+    peer::PeerId,
+    transaction::Executable,
+};
+use iroha_version::scale::{DecodeVersioned, EncodeVersioned};
 
 /// Kura inspector
 #[derive(Parser)]
@@ -29,6 +44,46 @@ enum Command {
         #[clap(short = 'n', long, default_value_t = 1)]
         length: u64,
     },
+    /// Check that the blocks are intact, chained and signed by the peers
+    /// and that the index, data and hashes files agree with each other
+    Verify,
+    /// Write a certain length of the blocks to files, one file per block
+    Export {
+        /// Number of the blocks to export.
+        /// The excess will be truncated
+        #[clap(short = 'n', long, default_value_t = 1)]
+        length: u64,
+        /// Format of the written blocks
+        #[clap(long, value_enum, default_value_t = ExportFormat::Json)]
+        format: ExportFormat,
+        /// Directory to write the blocks to
+        output: PathBuf,
+    },
+    /// Remove the blocks above the given height, e.g. to repair a corrupted tail.
+    /// The peer must be stopped
+    Truncate {
+        /// Height of the latest block to keep
+        #[clap(long)]
+        to_height: u64,
+    },
+    /// Print sizes of the block store files and the number of transactions in every block
+    Stats,
+    /// Copy the blocks into a new block store, compressing them
+    Migrate {
+        /// Compression of the blocks in the new block store
+        #[clap(short, long, default_value_t = Compression::Lz4)]
+        compression: Compression,
+        /// Path to the new block store, which must be empty
+        output: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    /// Human-readable JSON
+    Json,
+    /// Versioned SCALE encoding, the same as in the block store
+    Scale,
 }
 
 fn main() {
@@ -40,41 +95,53 @@ fn main() {
         height - 1
     });
 
+    let block_store_path = block_store_dir(&args.path_to_block_store);
     match args.command {
-        Command::Print { length } => print_blockchain(
-            &args.path_to_block_store,
+        Command::Print { length } => {
+            print_blockchain(&block_store_path, from_height.unwrap_or(u64::MAX), length)
+        }
+        Command::Verify => verify_blockchain(&block_store_path),
+        Command::Export {
+            length,
+            format,
+            output,
+        } => export_blockchain(
+            &block_store_path,
             from_height.unwrap_or(u64::MAX),
             length,
+            format,
+            &output,
         ),
+        Command::Truncate { to_height } => truncate_blockchain(&block_store_path, to_height),
+        Command::Stats => print_stats(&block_store_path),
+        Command::Migrate {
+            compression,
+            output,
+        } => migrate_blockchain(&block_store_path, &output, compression),
     }
 }
 
-fn print_blockchain(block_store_path: &Path, from_height: u64, block_count: u64) {
+fn block_store_dir(block_store_path: &Path) -> std::borrow::Cow<'_, Path> {
     let mut block_store_path: std::borrow::Cow<'_, Path> = block_store_path.into();
 
     if let Some(os_str_file_name) = block_store_path.file_name() {
         let file_name_str = os_str_file_name.to_str().unwrap_or("");
-        if file_name_str == "blocks.data" || file_name_str == "blocks.index" {
+        if file_name_str == DATA_FILE_NAME || file_name_str == INDEX_FILE_NAME {
             block_store_path.to_mut().pop();
         }
     }
 
-    let block_store = BlockStore::new(&block_store_path);
-
-    let index_count = block_store
-        .read_index_count()
-        .expect("Failed to read index count from block store {block_store_path:?}.");
+    block_store_path
+}
 
+/// Clamp the range of the blocks to the blocks in the store.
+///
+/// Returns `None` if the store is empty.
+fn block_range(index_count: u64, from_height: u64, block_count: u64) -> Option<(u64, u64)> {
     if index_count == 0 {
-        println!("The block store is empty.");
-        return;
+        return None;
     }
 
-    assert!(
-        index_count != 0,
-        "Index count is zero. This could be because there are no blocks in the store: {block_store_path:?}"
-    );
-
     let from_height = if from_height >= index_count {
         index_count - 1
     } else {
@@ -87,11 +154,27 @@ fn print_blockchain(block_store_path: &Path, from_height: u64, block_count: u64)
         block_count
     };
 
+    Some((from_height, block_count))
+}
+
+fn print_blockchain(block_store_path: &Path, from_height: u64, block_count: u64) {
+    let block_store = BlockStore::new(block_store_path);
+
+    let index_count = block_store
+        .read_index_count()
+        .expect("Failed to read index count from block store {block_store_path:?}.");
+
+    let Some((from_height, block_count)) = block_range(index_count, from_height, block_count)
+    else {
+        println!("The block store is empty.");
+        return;
+    };
+    let pruned = block_store
+        .read_pruned_blocks()
+        .expect("Failed to read pruned blocks");
+
     let mut block_indices = vec![
-        BlockIndex {
-            start: 0,
-            length: 0
-        };
+        BlockIndex::default();
         block_count
             .try_into()
             .expect("block_count didn't fit in 32-bits")
@@ -113,16 +196,20 @@ fn print_blockchain(block_store_path: &Path, from_height: u64, block_count: u64)
         let idx = block_indices[usize::try_from(i).expect("i didn't fit in 32-bits")];
         let meta_index = from_height + i;
 
+        if pruned.contains(meta_index) {
+            println!("Block#{} is pruned.", meta_index + 1);
+            continue;
+        }
+
         println!(
-            "Block#{} starts at byte offset {} and is {} bytes long.",
+            "Block#{} starts at byte offset {} and is {} bytes long with {} compression.",
             meta_index + 1,
             idx.start,
-            idx.length
+            idx.length,
+            idx.compression
         );
-        let mut block_buf =
-            vec![0_u8; usize::try_from(idx.length).expect("index_len didn't fit in 32-bits")];
-        block_store
-            .read_block_data(idx.start, &mut block_buf)
+        let block_buf = block_store
+            .read_block_bytes(idx)
             .unwrap_or_else(|_| panic!("Failed to read block № {} data.", meta_index + 1));
         let block = SignedBlock::decode_all_versioned(&block_buf)
             .unwrap_or_else(|_| panic!("Failed to decode block № {}", meta_index + 1));
@@ -130,3 +217,406 @@ fn print_blockchain(block_store_path: &Path, from_height: u64, block_count: u64)
         println!("{block:#?}");
     }
 }
+
+fn migrate_blockchain(block_store_path: &Path, output_path: &Path, compression: Compression) {
+    let block_store = BlockStore::new(block_store_path);
+    let mut output = BlockStore::new(output_path).with_compression(compression);
+    output
+        .create_files_if_they_do_not_exist()
+        .unwrap_or_else(|err| panic!("Failed to create block store {output_path:?}: {err}"));
+
+    block_store
+        .migrate_to(&mut output)
+        .unwrap_or_else(|err| panic!("Failed to migrate blocks to {output_path:?}: {err}"));
+
+    let index_count = output
+        .read_index_count()
+        .expect("Failed to read index count from the new block store.");
+    println!("Migrated {index_count} blocks to {output_path:?} with {compression} compression.");
+}
+
+fn read_block(block_store: &BlockStore, block_index: u64) -> Result<SignedBlock, String> {
+    let index = block_store
+        .read_block_index(block_index)
+        .map_err(|err| format!("failed to read the block index: {err}"))?;
+    let block_buf = block_store
+        .read_block_bytes(index)
+        .map_err(|err| format!("failed to read the block data: {err}"))?;
+    SignedBlock::decode_all_versioned(&block_buf)
+        .map_err(|err| format!("failed to decode the block: {err}"))
+}
+
+/// The state of the chain required to verify the next block
+struct ChainVerifier {
+    prev_block_hash: Option<HashOf<BlockHeader>>,
+    /// Peers registered by the verified blocks, `None` if they can't be tracked
+    peers: Option<Vec<PeerId>>,
+    /// Topology the latest block was committed with, `None` if it can't be restored
+    commit_topology: Option<Vec<PeerId>>,
+    /// Number of the blocks whose signatures weren't verified
+    unverified_signatures: u64,
+}
+
+impl Default for ChainVerifier {
+    fn default() -> Self {
+        Self {
+            prev_block_hash: None,
+            peers: Some(Vec::new()),
+            commit_topology: None,
+            unverified_signatures: 0,
+        }
+    }
+}
+
+impl ChainVerifier {
+    fn verify(
+        &mut self,
+        block: &SignedBlock,
+        height: u64,
+        expected_hash: HashOf<BlockHeader>,
+    ) -> Result<(), String> {
+        let header = block.header();
+        if header.height().get() != height {
+            return Err(format!("the block has height {}", header.height()));
+        }
+        if header.prev_block_hash() != self.prev_block_hash {
+            return Err(format!(
+                "the previous block hash is {:?}, but it should be {:?}",
+                header.prev_block_hash(),
+                self.prev_block_hash
+            ));
+        }
+        let hash = block.hash();
+        if hash != expected_hash {
+            return Err(format!(
+                "the block hash is {hash}, but the hashes file has {expected_hash}"
+            ));
+        }
+
+        // Same as in block sync: the topology is rebuilt from the peers which committed
+        // the previous block and rotated up to the view change index of the block
+        let topology = if header.is_genesis() {
+            verify_genesis_signature(block)?;
+            None
+        } else if let Some(commit_topology) = self.commit_topology.take() {
+            let mut topology = Topology::new(commit_topology);
+            topology.nth_rotation(header.view_change_index as usize);
+            verify_signatures(block, &topology)?;
+            Some(topology)
+        } else {
+            self.unverified_signatures += 1;
+            None
+        };
+
+        self.track_peers(block);
+
+        self.commit_topology = match (&self.peers, topology) {
+            (Some(peers), _) if peers.is_empty() => None,
+            (Some(peers), None) if header.is_genesis() => {
+                let mut topology = Topology::new(peers.clone());
+                topology.block_committed(peers.clone());
+                Some(topology.as_ref().to_owned())
+            }
+            (Some(peers), Some(mut topology)) => {
+                topology.block_committed(peers.clone());
+                Some(topology.as_ref().to_owned())
+            }
+            _ => None,
+        };
+        self.prev_block_hash = Some(hash);
+
+        Ok(())
+    }
+
+    /// Apply the changes of the peers made by the committed transactions of the block.
+    ///
+    /// Peers can't be tracked once they may be changed by smart contracts or triggers.
+    fn track_peers(&mut self, block: &SignedBlock) {
+        for (idx, tx) in block.transactions().enumerate() {
+            if block.error(idx).is_some() {
+                continue;
+            }
+            let Some(peers) = &mut self.peers else {
+                return;
+            };
+            let Executable::Instructions(instructions) = tx.instructions() else {
+                self.peers = None;
+                return;
+            };
+            for instruction in instructions {
+                match instruction {
+                    InstructionBox::Register(RegisterBox::Peer(register)) => {
+                        if !peers.contains(register.object()) {
+                            peers.push(register.object().clone());
+                        }
+                    }
+                    InstructionBox::Unregister(UnregisterBox::Peer(unregister)) => {
+                        peers.retain(|peer| peer != unregister.object());
+                    }
+                    InstructionBox::Register(RegisterBox::Trigger(register))
+                        if changes_peers(register.object().action().executable()) =>
+                    {
+                        self.peers = None;
+                        return;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Changes of the peers in the pruned blocks are unknown, so the signatures
+    /// of the following blocks can't be verified
+    fn skip_pruned(&mut self, hash: HashOf<BlockHeader>) {
+        self.prev_block_hash = Some(hash);
+        self.peers = None;
+        self.commit_topology = None;
+    }
+}
+
+/// Check if the executable may register or unregister peers
+fn changes_peers(executable: &Executable) -> bool {
+    match executable {
+        Executable::Wasm(_) => true,
+        Executable::Instructions(instructions) => instructions.iter().any(|instruction| {
+            matches!(
+                instruction,
+                InstructionBox::Register(RegisterBox::Peer(_))
+                    | InstructionBox::Unregister(UnregisterBox::Peer(_))
+            )
+        }),
+    }
+}
+
+fn verify_genesis_signature(block: &SignedBlock) -> Result<(), String> {
+    let mut signatures = block.signatures();
+    let (Some(signature), None) = (signatures.next(), signatures.next()) else {
+        return Err("the genesis block must have exactly one signature".to_owned());
+    };
+    let genesis_account = block
+        .transactions()
+        .next()
+        .ok_or("the genesis block has no transactions")?
+        .authority();
+
+    signature
+        .payload()
+        .verify(genesis_account.signatory(), &block.header())
+        .map_err(|_err| "the genesis block signature is invalid".to_owned())
+}
+
+fn verify_signatures(block: &SignedBlock, topology: &Topology) -> Result<(), String> {
+    for signature in block.signatures() {
+        let peer = usize::try_from(signature.index())
+            .ok()
+            .and_then(|idx| topology.as_ref().get(idx))
+            .ok_or_else(|| format!("the block is signed by unknown peer {}", signature.index()))?;
+        signature
+            .payload()
+            .verify(peer.public_key(), &block.header())
+            .map_err(|_err| format!("the signature of peer {peer} is invalid"))?;
+    }
+
+    let required = topology.min_votes_for_commit();
+    if block.signatures().len() < required {
+        return Err(format!(
+            "the block has {} signatures, but {required} are required",
+            block.signatures().len()
+        ));
+    }
+
+    Ok(())
+}
+
+fn verify_blockchain(block_store_path: &Path) {
+    let block_store = BlockStore::new(block_store_path);
+
+    let index_count = block_store
+        .read_index_count()
+        .expect("Failed to read index count");
+    let hashes_count = block_store
+        .read_hashes_count()
+        .expect("Failed to read hashes count");
+    let pruned = block_store
+        .read_pruned_blocks()
+        .expect("Failed to read pruned blocks");
+    let hashes = block_store
+        .read_block_hashes(
+            0,
+            hashes_count
+                .min(index_count)
+                .try_into()
+                .expect("hashes_count didn't fit in 32-bits"),
+        )
+        .expect("Failed to read block hashes");
+
+    let mut is_valid = true;
+    if hashes_count != index_count {
+        println!("Hashes file has {hashes_count} hashes, but index file has {index_count} blocks.");
+        is_valid = false;
+    }
+
+    let mut verifier = ChainVerifier::default();
+    let mut valid_height = 0;
+    for (block_index, hash) in (0..index_count).zip(hashes) {
+        let height = block_index + 1;
+        let result = if pruned.contains(block_index) {
+            verifier.skip_pruned(hash);
+            Ok(())
+        } else {
+            read_block(&block_store, block_index)
+                .and_then(|block| verifier.verify(&block, height, hash))
+        };
+        if let Err(error) = result {
+            println!("Block#{height} is invalid: {error}");
+            is_valid = false;
+            break;
+        }
+        valid_height = height;
+    }
+
+    if verifier.unverified_signatures > 0 {
+        println!(
+            "Signatures of {} blocks weren't verified, since the topology is unknown.",
+            verifier.unverified_signatures
+        );
+    }
+    if is_valid {
+        println!("All {index_count} blocks are valid.");
+    } else {
+        println!(
+            "Blocks up to height {valid_height} are valid. \
+             Run `truncate --to-height {valid_height}` to remove the rest."
+        );
+        std::process::exit(1);
+    }
+}
+
+fn export_blockchain(
+    block_store_path: &Path,
+    from_height: u64,
+    block_count: u64,
+    format: ExportFormat,
+    output_path: &Path,
+) {
+    let block_store = BlockStore::new(block_store_path);
+
+    let index_count = block_store
+        .read_index_count()
+        .expect("Failed to read index count");
+    let Some((from_height, block_count)) = block_range(index_count, from_height, block_count)
+    else {
+        println!("The block store is empty.");
+        return;
+    };
+    let pruned = block_store
+        .read_pruned_blocks()
+        .expect("Failed to read pruned blocks");
+
+    std::fs::create_dir_all(output_path)
+        .unwrap_or_else(|err| panic!("Failed to create directory {output_path:?}: {err}"));
+
+    let mut exported = 0;
+    for block_index in from_height..from_height + block_count {
+        let height = block_index + 1;
+        if pruned.contains(block_index) {
+            println!("Block#{height} is pruned, skipping it.");
+            continue;
+        }
+
+        let block = read_block(&block_store, block_index)
+            .unwrap_or_else(|err| panic!("Failed to read block № {height}: {err}"));
+        let (file_name, contents) = match format {
+            ExportFormat::Json => (
+                format!("{height}.json"),
+                serde_json::to_vec_pretty(&block).expect("Block is serializable"),
+            ),
+            ExportFormat::Scale => (format!("{height}.scale"), block.encode_versioned()),
+        };
+        let path = output_path.join(file_name);
+        std::fs::write(&path, contents)
+            .unwrap_or_else(|err| panic!("Failed to write block № {height} to {path:?}: {err}"));
+        exported += 1;
+    }
+
+    println!("Exported {exported} blocks to {output_path:?}.");
+}
+
+fn truncate_blockchain(block_store_path: &Path, to_height: u64) {
+    let mut block_store = BlockStore::new(block_store_path);
+    block_store
+        .truncate(to_height)
+        .unwrap_or_else(|err| panic!("Failed to truncate the block store: {err}"));
+
+    let index_count = block_store
+        .read_index_count()
+        .expect("Failed to read index count");
+    println!("The block store has {index_count} blocks now.");
+}
+
+fn print_stats(block_store_path: &Path) {
+    let block_store = BlockStore::new(block_store_path);
+
+    for file_name in [
+        INDEX_FILE_NAME,
+        DATA_FILE_NAME,
+        HASHES_FILE_NAME,
+        PRUNED_FILE_NAME,
+    ] {
+        let path = block_store_path.join(file_name);
+        match std::fs::metadata(&path) {
+            Ok(metadata) => println!("{file_name}: {} bytes", metadata.len()),
+            // The pruned blocks file only exists once some blocks are pruned
+            Err(err) if file_name == PRUNED_FILE_NAME && err.kind() == ErrorKind::NotFound => {}
+            Err(err) => panic!("Failed to read metadata of {path:?}: {err}"),
+        }
+    }
+
+    let index_count = block_store
+        .read_index_count()
+        .expect("Failed to read index count");
+    let pruned = block_store
+        .read_pruned_blocks()
+        .expect("Failed to read pruned blocks");
+    println!("Index file says there are {index_count} blocks.");
+    if pruned.count > 0 {
+        println!(
+            "Blocks 2-{} are pruned, {} bytes were removed from the data file.",
+            pruned.height(),
+            pruned.data_end - pruned.data_start
+        );
+    }
+
+    let mut block_indices = vec![
+        BlockIndex::default();
+        index_count
+            .try_into()
+            .expect("index_count didn't fit in 32-bits")
+    ];
+    block_store
+        .read_block_indices(0, &mut block_indices)
+        .expect("Failed to read block indices");
+
+    let (mut total_length, mut total_transactions, mut total_rejected) = (0, 0, 0);
+    for (block_index, idx) in (0..).zip(block_indices) {
+        if pruned.contains(block_index) {
+            continue;
+        }
+        let height = block_index + 1;
+        let block = read_block(&block_store, block_index)
+            .unwrap_or_else(|err| panic!("Failed to read block № {height}: {err}"));
+        let transactions = block.transactions().len();
+        let rejected = block.errors().len();
+        println!(
+            "Block#{height}: {} bytes with {} compression, {transactions} transactions, {rejected} rejected",
+            idx.length, idx.compression
+        );
+        total_length += idx.length;
+        total_transactions += transactions;
+        total_rejected += rejected;
+    }
+
+    println!(
+        "Total: {total_length} bytes, {total_transactions} transactions, {total_rejected} rejected"
+    );
+}